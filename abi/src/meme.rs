@@ -1,7 +1,8 @@
 use crate::{
     store_type::StoreType,
     swap::pool::{
-        AddLiquidityTransferReceiptPayload, PoolInitializeLiquidityCall, SwapTransferReceiptPayload,
+        AddLiquidityTransferReceiptPayload, ClaimTransferReceiptPayload, LaunchProtection,
+        PoolInitializeLiquidityCall, SwapTransferReceiptPayload,
     },
 };
 use async_graphql::{scalar, InputObject, Request, Response, SimpleObject};
@@ -237,6 +238,8 @@ pub struct MemeParameters {
     pub swap_creator_chain_id: ChainId,
     pub enable_mining: bool,
     pub mining_supply: Option<Amount>,
+    // Guards of the meme pool right after initialization, None launches without protection
+    pub launch_protection: Option<LaunchProtection>,
 }
 
 scalar!(MemeParameters);
@@ -264,6 +267,7 @@ pub enum TransferFromApplicationReceiptPurpose {
 pub enum TransferFromApplicationReceiptPayload {
     PoolAddLiquidity(AddLiquidityTransferReceiptPayload),
     PoolSwap(SwapTransferReceiptPayload),
+    PoolClaim(ClaimTransferReceiptPayload),
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
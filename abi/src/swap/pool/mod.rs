@@ -1,14 +1,17 @@
-use async_graphql::{scalar, Enum, InputObject, Request, Response};
+use async_graphql::{scalar, Enum, InputObject, Request, Response, SimpleObject};
 use linera_sdk::{
     graphql::GraphQLMutationRoot,
-    linera_base_types::{Account, Amount, ApplicationId, ContractAbi, ServiceAbi, Timestamp},
+    linera_base_types::{
+        Account, Amount, ApplicationId, ContractAbi, ServiceAbi, TimeDelta, Timestamp,
+    },
 };
 use primitive_types::U256;
 use rust_decimal::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use thiserror::Error;
 
-use crate::swap::transaction::Transaction;
+use crate::swap::{router::SwapRoute, transaction::Transaction};
 
 pub struct PoolAbi;
 
@@ -34,10 +37,18 @@ pub struct ClaimTransferReceipt {
     pub token: ApplicationId,
     pub amount: Amount,
     pub result: Result<(), String>,
+    pub route: Option<SwapRoute>,
 }
 
 scalar!(ClaimTransferReceipt);
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ClaimTransferReceiptPayload {
+    pub route: SwapRoute,
+}
+
+scalar!(ClaimTransferReceiptPayload);
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AddLiquidityTransferReceiptPayload {
     pub prev: Option<FundRequest>,
@@ -77,6 +88,10 @@ pub enum FundType {
     Swap,
     InitializeLiquidity,
     AddLiquidity,
+    // Funded like a swap, then escrowed on the pool creator chain
+    LimitOrder,
+    // Funded like a swap, then split into a swap and liquidity on the pool creator chain
+    ZapIn,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -91,6 +106,12 @@ pub struct FundRequest {
     pub to: Option<Account>,
     pub block_timestamp: Option<Timestamp>,
     pub fund_type: FundType,
+    pub route: Option<SwapRoute>,
+    // Exact output of a swap, amount_in is then the maximum input
+    pub counterparty_amount_out: Option<Amount>,
+    pub deadline: Option<Timestamp>,
+    // Transfer swap output to `to` right after settlement instead of leaving it claimable
+    pub auto_claim: bool,
 }
 
 pub struct FundRequestBuilder {
@@ -116,6 +137,10 @@ impl FundRequest {
                 to: None,
                 block_timestamp: None,
                 fund_type,
+                route: None,
+                counterparty_amount_out: None,
+                deadline: None,
+                auto_claim: false,
             },
         }
     }
@@ -155,6 +180,26 @@ impl FundRequestBuilder {
         self
     }
 
+    pub fn route(mut self, route: Option<SwapRoute>) -> Self {
+        self.request.route = route;
+        self
+    }
+
+    pub fn counterparty_amount_out(mut self, counterparty_amount_out: Option<Amount>) -> Self {
+        self.request.counterparty_amount_out = counterparty_amount_out;
+        self
+    }
+
+    pub fn deadline(mut self, deadline: Option<Timestamp>) -> Self {
+        self.request.deadline = deadline;
+        self
+    }
+
+    pub fn auto_claim(mut self, auto_claim: bool) -> Self {
+        self.request.auto_claim = auto_claim;
+        self
+    }
+
     pub fn build(self) -> FundRequest {
         self.request
     }
@@ -181,6 +226,8 @@ pub enum PoolOperation {
         amount_1_out_min: Option<Amount>,
         to: Option<Account>,
         block_timestamp: Option<Timestamp>,
+        // Funds are refunded if the pool creator chain executes it after deadline
+        deadline: Option<Timestamp>,
     },
     RemoveLiquidity {
         liquidity: Amount,
@@ -188,6 +235,7 @@ pub enum PoolOperation {
         amount_1_out_min: Option<Amount>,
         to: Option<Account>,
         block_timestamp: Option<Timestamp>,
+        deadline: Option<Timestamp>,
     },
     Swap {
         amount_0_in: Option<Amount>,
@@ -196,6 +244,11 @@ pub enum PoolOperation {
         amount_1_out_min: Option<Amount>,
         to: Option<Account>,
         block_timestamp: Option<Timestamp>,
+        // Remaining hops when executed as part of a routed swap
+        route: Option<SwapRoute>,
+        deadline: Option<Timestamp>,
+        // Transfer output to `to` right after settlement, it stays claimable if transfer fails
+        auto_claim: bool,
     },
     Claim {
         token: Option<ApplicationId>,
@@ -210,12 +263,101 @@ pub enum PoolOperation {
     SwapTransferReceipt {
         receipt: SwapTransferReceipt,
     },
+    Twap {
+        window_seconds: u64,
+    },
+    // Exactly one of the outputs, the other token is paid with at most amount_in_max
+    SwapExactOut {
+        amount_0_out: Option<Amount>,
+        amount_1_out: Option<Amount>,
+        amount_in_max: Amount,
+        to: Option<Account>,
+        block_timestamp: Option<Timestamp>,
+        deadline: Option<Timestamp>,
+        auto_claim: bool,
+    },
+    // fee_to takes 1/protocol_fee_share_denominator of the fee growth, 0 turns it off
+    SetProtocolFeeShare {
+        protocol_fee_share_denominator: u16,
+    },
+    TransferLiquidity {
+        to: Account,
+        liquidity: Amount,
+    },
+    // Sets the spender allowance over the owner shares, zero revokes it. Shares stay with the
+    // owner and are only checked when the spender transfers them
+    ApproveLiquidity {
+        spender: Account,
+        liquidity: Amount,
+    },
+    TransferLiquidityFrom {
+        from: Account,
+        to: Account,
+        liquidity: Amount,
+    },
+    // Exactly one of the inputs, filled as a whole once the pool pays at least amount_out_min
+    PlaceLimitOrder {
+        amount_0_in: Option<Amount>,
+        amount_1_in: Option<Amount>,
+        amount_out_min: Amount,
+        to: Option<Account>,
+    },
+    CancelLimitOrder {
+        order_id: u64,
+    },
+    // Signed by fee_to_setter, or called by the swap router application
+    SetPoolStatus {
+        status: PoolStatus,
+    },
+    // Claim whole claimable balance of each token, both pool tokens if not specified
+    ClaimAll {
+        tokens: Option<Vec<Option<ApplicationId>>>,
+    },
+    // Add liquidity with one token only, the optimal portion is swapped for the other token
+    ZapIn {
+        token: Option<ApplicationId>,
+        amount_in: Amount,
+        min_liquidity: Option<Amount>,
+        to: Option<Account>,
+        block_timestamp: Option<Timestamp>,
+        deadline: Option<Timestamp>,
+    },
+    // Remove liquidity then swap the other token, all output is paid in token
+    ZapOut {
+        liquidity: Amount,
+        token: Option<ApplicationId>,
+        amount_out_min: Option<Amount>,
+        to: Option<Account>,
+        block_timestamp: Option<Timestamp>,
+        deadline: Option<Timestamp>,
+    },
+    // Signed by fee_to_setter. Native pools only, meme holdings can't be read on the pool
+    // creator chain. Reserve 1 is set to the holding minus claimable, claiming and escrowed
+    // amounts, plus the virtual initial liquidity which is never held
+    Sync,
+    // Signed by fee_to_setter, native pools only. Transfer native holdings above the held
+    // reserve and obligations to `to`
+    Skim {
+        to: Account,
+    },
+    // Signed by fee_to_setter, None goes back to pool_fee_percent_mul_100
+    SetDynamicFee {
+        dynamic_fee: Option<DynamicFee>,
+    },
+    // Burn liquidity and deposit the underlying into the latest pool version of the pair
+    MigrateLiquidity {
+        liquidity: Amount,
+        amount_0_out_min: Option<Amount>,
+        amount_1_out_min: Option<Amount>,
+        deadline: Option<Timestamp>,
+    },
 }
 
 #[derive(Debug, Deserialize, Serialize, Default)]
 pub enum PoolResponse {
     #[default]
     Ok,
+    Twap(PoolTwap),
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -249,6 +391,12 @@ pub enum PoolMessage {
         amount_1_out_min: Option<Amount>,
         to: Option<Account>,
         block_timestamp: Option<Timestamp>,
+        route: Option<SwapRoute>,
+        // Exact output, the funded amount in is then the maximum input
+        amount_0_out: Option<Amount>,
+        amount_1_out: Option<Amount>,
+        deadline: Option<Timestamp>,
+        auto_claim: bool,
     },
     AddLiquidity {
         // Used to refund
@@ -259,6 +407,7 @@ pub enum PoolMessage {
         amount_1_out_min: Option<Amount>,
         to: Option<Account>,
         block_timestamp: Option<Timestamp>,
+        deadline: Option<Timestamp>,
     },
     // Unified first economic initialization entry.
     // This is valid only before finalized reserve/share facts exist.
@@ -279,6 +428,7 @@ pub enum PoolMessage {
         amount_1_out_min: Option<Amount>,
         to: Option<Account>,
         block_timestamp: Option<Timestamp>,
+        deadline: Option<Timestamp>,
     },
     SetFeeTo {
         operator: Account,
@@ -296,6 +446,160 @@ pub enum PoolMessage {
         token: Option<ApplicationId>,
         amount: Amount,
     },
+    SetProtocolFeeShare {
+        operator: Account,
+        protocol_fee_share_denominator: u16,
+    },
+    TransferLiquidity {
+        origin: Account,
+        to: Account,
+        liquidity: Amount,
+    },
+    ApproveLiquidity {
+        origin: Account,
+        spender: Account,
+        liquidity: Amount,
+    },
+    TransferLiquidityFrom {
+        // Spender of the allowance
+        origin: Account,
+        from: Account,
+        to: Account,
+        liquidity: Amount,
+    },
+    PlaceLimitOrder {
+        // Used to refund
+        origin: Account,
+        amount_0_in: Option<Amount>,
+        amount_1_in: Option<Amount>,
+        amount_out_min: Amount,
+        to: Option<Account>,
+    },
+    CancelLimitOrder {
+        origin: Account,
+        order_id: u64,
+    },
+    SetPoolStatus {
+        operator: Account,
+        status: PoolStatus,
+    },
+    ClaimAll {
+        origin: Account,
+        tokens: Option<Vec<Option<ApplicationId>>>,
+    },
+    ZapIn {
+        // Used to refund
+        origin: Account,
+        amount_0_in: Option<Amount>,
+        amount_1_in: Option<Amount>,
+        min_liquidity: Option<Amount>,
+        to: Option<Account>,
+        block_timestamp: Option<Timestamp>,
+        deadline: Option<Timestamp>,
+    },
+    ZapOut {
+        origin: Account,
+        liquidity: Amount,
+        token: Option<ApplicationId>,
+        amount_out_min: Option<Amount>,
+        to: Option<Account>,
+        block_timestamp: Option<Timestamp>,
+        deadline: Option<Timestamp>,
+    },
+    Sync {
+        operator: Account,
+    },
+    Skim {
+        operator: Account,
+        to: Account,
+    },
+    SetDynamicFee {
+        operator: Account,
+        dynamic_fee: Option<DynamicFee>,
+    },
+    MigrateLiquidity {
+        origin: Account,
+        liquidity: Amount,
+        amount_0_out_min: Option<Amount>,
+        amount_1_out_min: Option<Amount>,
+        deadline: Option<Timestamp>,
+    },
+    // Execute on origin chain so the failure is also visible there
+    RequestFailed {
+        origin: Account,
+        request: FailedRequest,
+    },
+    // Execute on origin chain to keep the swap outcome in its inbox
+    SwapSettled {
+        transaction: Transaction,
+        status: SwapSettlementStatus,
+    },
+}
+
+pub const POOL_EVENT_STREAM: &str = "pool_events";
+
+// Emitted on POOL_EVENT_STREAM of the pool chain for indexers, prices and reserves are the ones
+// after the transaction. Applications only read their own streams, so the router still gets
+// catalog updates through UpdatePool
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+pub enum PoolEvent {
+    Swap {
+        transaction: Transaction,
+        token_0_price: Amount,
+        token_1_price: Amount,
+        reserve_0: Amount,
+        reserve_1: Amount,
+    },
+    AddLiquidity {
+        transaction: Transaction,
+        reserve_0: Amount,
+        reserve_1: Amount,
+    },
+    RemoveLiquidity {
+        transaction: Transaction,
+        reserve_0: Amount,
+        reserve_1: Amount,
+    },
+    Claim {
+        owner: Account,
+        token: Option<ApplicationId>,
+        amount: Amount,
+        created_at: Timestamp,
+    },
+    FeeChange {
+        operator: Account,
+        pool_fee_percent_mul_100: u16,
+        protocol_fee_share_denominator: u16,
+        dynamic_fee: Option<DynamicFee>,
+        created_at: Timestamp,
+    },
+}
+
+// Set by fee_to_setter or swap router operators to halt a pool
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Enum, Eq, Copy, PartialEq)]
+pub enum PoolStatus {
+    #[default]
+    Active,
+    // Liquidity can still be added and removed
+    SwapsPaused,
+    // Nothing but status changes
+    FullyPaused,
+    // Only exits: remove liquidity, cancel orders, claim and move shares
+    WithdrawOnly,
+}
+
+impl PoolStatus {
+    pub fn swaps_enabled(&self) -> bool {
+        *self == PoolStatus::Active
+    }
+
+    pub fn deposits_enabled(&self) -> bool {
+        matches!(self, PoolStatus::Active | PoolStatus::SwapsPaused)
+    }
+
+    pub fn withdrawals_enabled(&self) -> bool {
+        *self != PoolStatus::FullyPaused
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -310,10 +614,168 @@ pub struct PoolParameters {
     pub token_0: ApplicationId,
     pub token_1: Option<ApplicationId>,
     pub bootstrap_policy: BootstrapPolicy,
+    pub launch_protection: Option<LaunchProtection>,
 }
 
 scalar!(PoolParameters);
 
+pub const DEFAULT_LAUNCH_DURATION_MINUTES: u32 = 10;
+pub const DEFAULT_LAUNCH_MAX_TRADE_PERCENT_MUL_100: u16 = 200;
+pub const DEFAULT_LAUNCH_COOLDOWN_SECS: u32 = 30;
+pub const DEFAULT_LAUNCH_EXTRA_FEE_PERCENT_MUL_100: u16 = 1000;
+// Launch guards hold back zaps and limit order fills, so they can't last for long
+pub const MAX_LAUNCH_DURATION_MINUTES: u32 = 24 * 60;
+
+// Swap fee rises linearly from min to max fee as realized volatility approaches max volatility
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, InputObject, SimpleObject)]
+#[graphql(input_name = "DynamicFeeInput")]
+pub struct DynamicFee {
+    pub min_fee_percent_mul_100: u16,
+    pub max_fee_percent_mul_100: u16,
+    // Sum of relative price moves in the window, percent mul 100
+    pub max_volatility_percent_mul_100: u32,
+    // Price moves fade out linearly over the window
+    pub window_seconds: u64,
+}
+
+impl DynamicFee {
+    pub fn validate(&self) {
+        assert!(
+            self.min_fee_percent_mul_100 <= self.max_fee_percent_mul_100
+                && self.max_fee_percent_mul_100 < 10000,
+            "Invalid fee"
+        );
+        assert!(
+            self.max_volatility_percent_mul_100 > 0,
+            "Invalid volatility"
+        );
+    }
+
+    pub fn fee_percent_mul_100(&self, volatility: Decimal) -> u16 {
+        let max_volatility = Decimal::new(i64::from(self.max_volatility_percent_mul_100), 4);
+        if max_volatility.is_zero() {
+            return self.max_fee_percent_mul_100;
+        }
+        let ratio = (volatility / max_volatility).min(Decimal::ONE);
+        let range =
+            Decimal::from(self.max_fee_percent_mul_100 - self.min_fee_percent_mul_100) * ratio;
+        self.min_fee_percent_mul_100 + range.floor().to_u16().unwrap_or(0)
+    }
+}
+
+// Guards against sniping during the first minutes after the pool is initialized, each guard
+// is off if not set
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, InputObject, SimpleObject)]
+#[graphql(input_name = "LaunchProtectionInput")]
+pub struct LaunchProtection {
+    pub duration_minutes: u32,
+    // Largest swap input, relative to the reserve of the input token
+    pub max_trade_percent_mul_100: Option<u16>,
+    // Least time between two swaps of the same account
+    pub cooldown_secs: Option<u32>,
+    // Charged on the swap input on top of the pool fee, decays linearly to zero at the end
+    pub extra_fee_percent_mul_100: Option<u16>,
+}
+
+impl Default for LaunchProtection {
+    fn default() -> Self {
+        Self {
+            duration_minutes: DEFAULT_LAUNCH_DURATION_MINUTES,
+            max_trade_percent_mul_100: Some(DEFAULT_LAUNCH_MAX_TRADE_PERCENT_MUL_100),
+            cooldown_secs: Some(DEFAULT_LAUNCH_COOLDOWN_SECS),
+            extra_fee_percent_mul_100: Some(DEFAULT_LAUNCH_EXTRA_FEE_PERCENT_MUL_100),
+        }
+    }
+}
+
+impl LaunchProtection {
+    pub fn validate(&self) {
+        assert!(
+            self.duration_minutes <= MAX_LAUNCH_DURATION_MINUTES,
+            "Invalid duration"
+        );
+        assert!(
+            self.max_trade_percent_mul_100
+                .is_none_or(|percent| percent > 0 && percent <= 10000),
+            "Invalid max trade"
+        );
+        assert!(
+            self.extra_fee_percent_mul_100
+                .is_none_or(|percent| percent < 10000),
+            "Invalid fee"
+        );
+    }
+
+    pub fn ends_at(&self, launched_at: Timestamp) -> Timestamp {
+        launched_at.saturating_add(TimeDelta::from_secs(u64::from(self.duration_minutes) * 60))
+    }
+
+    pub fn active(&self, launched_at: Timestamp, now: Timestamp) -> bool {
+        now < self.ends_at(launched_at)
+    }
+
+    pub fn max_trade(&self, reserve_in: Amount) -> Option<Amount> {
+        self.max_trade_percent_mul_100
+            .map(|percent| percent_of(reserve_in, u128::from(percent), 10000))
+    }
+
+    pub fn cooldown_ends_at(&self, last_swap_at: Timestamp) -> Timestamp {
+        last_swap_at.saturating_add(TimeDelta::from_secs(u64::from(
+            self.cooldown_secs.unwrap_or(0),
+        )))
+    }
+
+    pub fn extra_fee_percent_mul_100(&self, launched_at: Timestamp, now: Timestamp) -> u16 {
+        let Some(extra_fee_percent_mul_100) = self.extra_fee_percent_mul_100 else {
+            return 0;
+        };
+        let duration = self
+            .ends_at(launched_at)
+            .delta_since(launched_at)
+            .as_micros();
+        if duration == 0 {
+            return 0;
+        }
+        // Saturates to zero once the launch phase is over
+        let remaining = self.ends_at(launched_at).delta_since(now).as_micros();
+        (u128::from(extra_fee_percent_mul_100) * u128::from(remaining) / u128::from(duration))
+            as u16
+    }
+
+    pub fn extra_fee(&self, amount_in: Amount, launched_at: Timestamp, now: Timestamp) -> Amount {
+        percent_of(
+            amount_in,
+            u128::from(self.extra_fee_percent_mul_100(launched_at, now)),
+            10000,
+        )
+    }
+
+    // Input which still leaves amount_in after the extra fee, rounded up
+    pub fn amount_in_with_extra_fee(
+        &self,
+        amount_in: Amount,
+        launched_at: Timestamp,
+        now: Timestamp,
+    ) -> Amount {
+        let fee_base = U256::from(10000u128);
+        let denominator = fee_base - U256::from(self.extra_fee_percent_mul_100(launched_at, now));
+        let amount_in =
+            (U256::from(u128::from(amount_in)) * fee_base + denominator - 1) / denominator;
+        Amount::from_attos(u128::try_from(amount_in).unwrap_or(u128::MAX))
+    }
+}
+
+fn percent_of(amount: Amount, numerator: u128, denominator: u128) -> Amount {
+    Amount::from_attos(
+        U256::from(u128::from(amount))
+            .checked_mul(U256::from(numerator))
+            .unwrap()
+            .checked_div(U256::from(denominator))
+            .unwrap()
+            .as_u128(),
+    )
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, InputObject)]
 pub struct InstantiationArgument {
     pub pool_fee_percent_mul_100: u16,
@@ -333,6 +795,289 @@ pub enum PoolError {
 
     #[error("Insufficient liquidity")]
     InsufficientLiquidity,
+
+    #[error("Invalid window")]
+    InvalidWindow,
+
+    #[error("Insufficient observations")]
+    InsufficientObservations,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, Enum, Eq, PartialEq)]
+pub enum FailedRequestKind {
+    Swap,
+    AddLiquidity,
+    ZapIn,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, Enum, Eq, PartialEq)]
+pub enum FailureReason {
+    NotReady,
+    NotEnabled,
+    DeadlineExceeded,
+    // Slippage, zero output or amounts the pool math rejects
+    InvalidAmount,
+    InsufficientLiquidity,
+    BrokenK,
+    // Output of a committed swap could not be credited to the receiver
+    CreditFailed,
+    // Input could not be transferred from origin, e.g. it is not settled there yet
+    TransferFailed,
+    Other,
+}
+
+impl From<&PoolError> for FailureReason {
+    fn from(err: &PoolError) -> Self {
+        match err {
+            PoolError::InvalidAmount
+            | PoolError::InvalidWindow
+            | PoolError::InsufficientObservations => FailureReason::InvalidAmount,
+            PoolError::BrokenK => FailureReason::BrokenK,
+            PoolError::InsufficientLiquidity => FailureReason::InsufficientLiquidity,
+        }
+    }
+}
+
+pub const MAX_FAILED_REQUESTS: usize = 20;
+
+// Request refunded to origin as claimable balance, amounts are the refunded input. A swap
+// failing with CreditFailed keeps its input, amounts are the output which was not credited.
+// Liquidity failing with TransferFailed only refunds the input already transferred, amounts
+// are the requested input
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq, SimpleObject)]
+pub struct FailedRequest {
+    pub kind: FailedRequestKind,
+    pub amount_0: Amount,
+    pub amount_1: Amount,
+    pub reason: FailureReason,
+    pub created_at: Timestamp,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, Enum, Eq, PartialEq)]
+pub enum SwapSettlementStatus {
+    // Output is kept as claimable balance on the pool creator chain
+    Claimable,
+    // Output is being delivered to the receiver with auto_claim
+    Delivering,
+    // Output is forwarded to the next hop of the route
+    Routed,
+    // Swap is committed but its output could not be credited, recorded as a failed request
+    Failed,
+}
+
+pub const MAX_SWAP_SETTLEMENTS: usize = 50;
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, Eq, PartialEq, SimpleObject)]
+pub struct SwapSettlement {
+    pub transaction: Transaction,
+    pub status: SwapSettlementStatus,
+}
+
+// Cumulative prices at the time the pool reserves last changed
+#[derive(Debug, Clone, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PriceObservation {
+    pub timestamp: Timestamp,
+    pub price_0_cumulative: Decimal,
+    pub price_1_cumulative: Decimal,
+}
+
+scalar!(PriceObservation);
+
+impl PriceObservation {
+    // Cumulative prices grow linearly between two observations
+    fn interpolate(&self, next: &PriceObservation, timestamp: Timestamp) -> PriceObservation {
+        if next.timestamp <= self.timestamp {
+            return self.clone();
+        }
+
+        let elapsed = Decimal::from(timestamp.delta_since(self.timestamp).as_micros());
+        let interval = Decimal::from(next.timestamp.delta_since(self.timestamp).as_micros());
+        let cumulative_at = |from: Decimal, to: Decimal| {
+            from.checked_add(
+                to.checked_sub(from)
+                    .unwrap()
+                    .checked_mul(elapsed)
+                    .unwrap()
+                    .checked_div(interval)
+                    .unwrap(),
+            )
+            .unwrap()
+        };
+
+        PriceObservation {
+            timestamp,
+            price_0_cumulative: cumulative_at(self.price_0_cumulative, next.price_0_cumulative),
+            price_1_cumulative: cumulative_at(self.price_1_cumulative, next.price_1_cumulative),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Eq, PartialEq, Serialize, SimpleObject)]
+pub struct PoolTwap {
+    pub window_seconds: u64,
+    pub price_0: Amount,
+    pub price_1: Amount,
+}
+
+// Input is escrowed on the pool creator chain until the order is filled or cancelled
+#[derive(Debug, Clone, Deserialize, Eq, PartialEq, Serialize, SimpleObject)]
+pub struct LimitOrder {
+    pub order_id: u64,
+    pub owner: Account,
+    pub amount_0_in: Option<Amount>,
+    pub amount_1_in: Option<Amount>,
+    pub amount_out_min: Amount,
+    pub to: Option<Account>,
+    pub created_at: Timestamp,
+}
+
+impl LimitOrder {
+    pub fn amount_in(&self) -> Amount {
+        self.amount_0_in
+            .or(self.amount_1_in)
+            .unwrap_or(Amount::ZERO)
+    }
+
+    // Key of the order book, output per input scaled by 2^64 so that a smaller key is a
+    // better price for the pool
+    pub fn limit_price_key(&self) -> u128 {
+        let price = (U256::from(u128::from(self.amount_out_min)) << 64)
+            / U256::from(u128::from(self.amount_in()));
+        u128::try_from(price).unwrap_or(u128::MAX)
+    }
+
+    // Orders asking less output per input are filled first
+    pub fn cmp_limit_price(&self, other: &LimitOrder) -> Ordering {
+        let price = |order: &LimitOrder, other: &LimitOrder| {
+            U256::from(u128::from(order.amount_out_min))
+                .checked_mul(U256::from(u128::from(other.amount_in())))
+                .unwrap()
+        };
+        price(self, other)
+            .cmp(&price(other, self))
+            .then(self.order_id.cmp(&other.order_id))
+    }
+}
+
+// Cumulative trading figures since the pool was created, volume counts both in and out of
+// each token while fees are the swap fee charged on the input
+#[derive(
+    Debug, Clone, Copy, Default, Deserialize, Eq, PartialEq, Serialize, InputObject, SimpleObject,
+)]
+#[graphql(input_name = "PoolStatsInput")]
+pub struct PoolStats {
+    pub volume_0: Amount,
+    pub volume_1: Amount,
+    pub fees_0: Amount,
+    pub fees_1: Amount,
+    pub swap_count: u64,
+    pub last_trade_at: Option<Timestamp>,
+}
+
+impl PoolStats {
+    pub fn record_swap(
+        &mut self,
+        amount_0_in: Amount,
+        amount_1_in: Amount,
+        amount_0_out: Amount,
+        amount_1_out: Amount,
+        fee_0: Amount,
+        fee_1: Amount,
+        timestamp: Timestamp,
+    ) {
+        // Zaps with balanced amounts don't swap at all
+        if amount_0_in == Amount::ZERO && amount_1_in == Amount::ZERO {
+            return;
+        }
+        self.volume_0 = self
+            .volume_0
+            .saturating_add(amount_0_in)
+            .saturating_add(amount_0_out);
+        self.volume_1 = self
+            .volume_1
+            .saturating_add(amount_1_in)
+            .saturating_add(amount_1_out);
+        self.fees_0 = self.fees_0.saturating_add(fee_0);
+        self.fees_1 = self.fees_1.saturating_add(fee_1);
+        self.swap_count += 1;
+        self.last_trade_at = Some(timestamp);
+    }
+}
+
+// LP fee accumulated per share since the pool was created
+#[derive(Debug, Clone, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct FeeGrowth {
+    pub fee_0_per_share: Decimal,
+    pub fee_1_per_share: Decimal,
+}
+
+impl FeeGrowth {
+    pub fn accrue(&mut self, fee_0: Amount, fee_1: Amount, total_supply: Amount) {
+        if total_supply == Amount::ZERO {
+            return;
+        }
+        let total_supply = amount_to_decimal(total_supply);
+        self.fee_0_per_share += amount_to_decimal(fee_0) / total_supply;
+        self.fee_1_per_share += amount_to_decimal(fee_1) / total_supply;
+    }
+}
+
+// Fee growth an LP position was last settled at, with the fees it earned so far
+#[derive(Debug, Clone, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct FeeCheckpoint {
+    pub fee_growth: FeeGrowth,
+    pub earned_0: Amount,
+    pub earned_1: Amount,
+}
+
+impl FeeCheckpoint {
+    pub fn settle(&self, shares: Amount, fee_growth: &FeeGrowth) -> FeeCheckpoint {
+        let shares = amount_to_decimal(shares);
+        let earned_0 = decimal_to_amount(
+            shares * (fee_growth.fee_0_per_share - self.fee_growth.fee_0_per_share),
+        );
+        let earned_1 = decimal_to_amount(
+            shares * (fee_growth.fee_1_per_share - self.fee_growth.fee_1_per_share),
+        );
+
+        FeeCheckpoint {
+            fee_growth: fee_growth.clone(),
+            earned_0: self.earned_0.saturating_add(earned_0),
+            earned_1: self.earned_1.saturating_add(earned_1),
+        }
+    }
+}
+
+// Prices are quoted as output token per input token
+#[derive(Debug, Clone, Deserialize, Eq, PartialEq, Serialize, SimpleObject)]
+pub struct SwapQuote {
+    pub amount_0_in: Amount,
+    pub amount_1_in: Amount,
+    pub amount_0_out: Amount,
+    pub amount_1_out: Amount,
+    // Charged on the input token, protocol_fee is the part of it minted to fee_to
+    pub fee_percent_mul_100: u16,
+    pub lp_fee: Amount,
+    pub protocol_fee: Amount,
+    pub execution_price: Amount,
+    pub spot_price_before: Amount,
+    pub spot_price_after: Amount,
+    // Excludes the LP fee, which is reported separately
+    pub price_impact_percent: Amount,
+}
+
+fn amount_to_decimal(amount: Amount) -> Decimal {
+    Decimal::from_str(&format!("{}", amount)).unwrap()
+}
+
+fn decimal_to_amount(decimal: Decimal) -> Amount {
+    Amount::from_str(
+        &decimal
+            .max(Decimal::ZERO)
+            .round_dp(Amount::DECIMAL_PLACES as u32)
+            .to_string(),
+    )
+    .unwrap()
 }
 
 #[derive(Debug, Clone, Deserialize, Eq, PartialEq, Serialize)]
@@ -349,10 +1094,19 @@ pub struct Pool {
     pub price_1_cumulative: Decimal,
     pub k_last: Amount,
     pub block_timestamp: Timestamp,
+    // fee_to takes 1/protocol_fee_share_denominator of the fee growth, 0 turns it off
+    pub protocol_fee_share_denominator: u16,
+    pub status: PoolStatus,
+    // Replaces pool_fee_percent_mul_100 if set
+    pub dynamic_fee: Option<DynamicFee>,
+    // Relative price moves of recent reserve updates, see realized_volatility()
+    pub volatility: Decimal,
 }
 
 scalar!(Pool);
 
+pub const DEFAULT_PROTOCOL_FEE_SHARE_DENOMINATOR: u16 = 6;
+
 impl Pool {
     pub fn create(
         token_0: ApplicationId,
@@ -375,6 +1129,10 @@ impl Pool {
             price_1_cumulative: Decimal::default(),
             k_last: Amount::ZERO,
             block_timestamp,
+            protocol_fee_share_denominator: DEFAULT_PROTOCOL_FEE_SHARE_DENOMINATOR,
+            status: PoolStatus::Active,
+            dynamic_fee: None,
+            volatility: Decimal::ZERO,
         }
     }
 
@@ -431,8 +1189,27 @@ impl Pool {
         }
     }
 
+    pub fn protocol_fee_on(&self) -> bool {
+        self.protocol_fee_share_denominator > 0
+    }
+
+    // Fee charged on amount_in and the part of it mint_fee() mints to fee_to, which takes
+    // 1/protocol_fee_share_denominator of the fee growth
+    pub fn swap_fees(&self, amount_in: Amount) -> (Amount, Amount) {
+        let lp_fee = percent_of(amount_in, u128::from(self.fee_percent_mul_100()), 10000);
+        (lp_fee, self.protocol_fee(lp_fee))
+    }
+
+    // Part of a fee kept in the reserves which mint_fee() mints to fee_to
+    pub fn protocol_fee(&self, fee: Amount) -> Amount {
+        if !self.protocol_fee_on() {
+            return Amount::ZERO;
+        }
+        Amount::from_attos(u128::from(fee) / u128::from(self.protocol_fee_share_denominator))
+    }
+
     pub fn mint_fee(&self, total_supply: Amount) -> Amount {
-        if self.k_last == Amount::ZERO {
+        if !self.protocol_fee_on() || self.k_last == Amount::ZERO {
             return Amount::ZERO;
         }
         let root_k = U256::from(u128::from(self.reserve_0))
@@ -442,7 +1219,7 @@ impl Pool {
         let root_k_last = U256::from(u128::from(self.k_last));
         if root_k > root_k_last {
             let denominator = root_k
-                .checked_mul(U256::from(5))
+                .checked_mul(U256::from(self.protocol_fee_share_denominator - 1))
                 .unwrap()
                 .checked_add(root_k_last)
                 .unwrap();
@@ -462,6 +1239,11 @@ impl Pool {
     }
 
     pub fn update_k_last(&mut self) {
+        // Fee growth while the protocol fee is off belongs to liquidity providers only
+        if !self.protocol_fee_on() {
+            self.k_last = Amount::ZERO;
+            return;
+        }
         self.k_last = Amount::from_attos(
             U256::from(u128::from(self.reserve_0))
                 .checked_mul(U256::from(u128::from(self.reserve_1)))
@@ -471,6 +1253,65 @@ impl Pool {
         );
     }
 
+    // Fee accrued at the previous share must be minted with mint_fee() before switching
+    pub fn set_protocol_fee_share_denominator(&mut self, protocol_fee_share_denominator: u16) {
+        self.protocol_fee_share_denominator = protocol_fee_share_denominator;
+        self.update_k_last();
+    }
+
+    pub fn set_dynamic_fee(&mut self, dynamic_fee: Option<DynamicFee>) {
+        if let Some(dynamic_fee) = &dynamic_fee {
+            dynamic_fee.validate();
+        }
+        self.dynamic_fee = dynamic_fee;
+        self.volatility = Decimal::ZERO;
+    }
+
+    // Swap fee charged on the input right now
+    pub fn fee_percent_mul_100(&self) -> u16 {
+        match &self.dynamic_fee {
+            Some(dynamic_fee) => dynamic_fee.fee_percent_mul_100(self.volatility),
+            None => self.pool_fee_percent_mul_100,
+        }
+    }
+
+    // Sum of relative price 0 moves, each fading out linearly over the dynamic fee window
+    fn realized_volatility(
+        &self,
+        balance_0: Amount,
+        balance_1: Amount,
+        block_timestamp: Timestamp,
+    ) -> Decimal {
+        let Some(dynamic_fee) = &self.dynamic_fee else {
+            return Decimal::ZERO;
+        };
+        if self.reserve_0 == Amount::ZERO
+            || self.reserve_1 == Amount::ZERO
+            || balance_0 == Amount::ZERO
+            || balance_1 == Amount::ZERO
+        {
+            return self.volatility;
+        }
+
+        let window = dynamic_fee.window_seconds;
+        let elapsed = block_timestamp
+            .delta_since(self.block_timestamp)
+            .as_duration()
+            .as_secs()
+            .min(window);
+        let remaining = if window == 0 {
+            Decimal::ZERO
+        } else {
+            Decimal::from(window - elapsed) / Decimal::from(window)
+        };
+
+        let price_before = amount_to_decimal(self.reserve_1) / amount_to_decimal(self.reserve_0);
+        let price_after = amount_to_decimal(balance_1) / amount_to_decimal(balance_0);
+        let change = ((price_after - price_before) / price_before).abs();
+
+        self.volatility * remaining + change
+    }
+
     // TODO: this should be calculate only once for each block
     pub fn liquid(&mut self, balance_0: Amount, balance_1: Amount, block_timestamp: Timestamp) {
         let time_elapsed = u128::from(
@@ -484,6 +1325,7 @@ impl Pool {
                 self.calculate_price_cumulative_pair(time_elapsed);
         }
 
+        self.volatility = self.realized_volatility(balance_0, balance_1, block_timestamp);
         self.reserve_0 = balance_0;
         self.reserve_1 = balance_1;
         self.block_timestamp = block_timestamp;
@@ -534,7 +1376,7 @@ impl Pool {
 
         let fee_base = U256::from(10000u128);
         let fee_multiplier = fee_base
-            .checked_sub(U256::from(self.pool_fee_percent_mul_100))
+            .checked_sub(U256::from(self.fee_percent_mul_100()))
             .unwrap();
         let amount_in_with_fee = U256::from(u128::from(amount_0))
             .checked_mul(fee_multiplier)
@@ -556,38 +1398,235 @@ impl Pool {
         ))
     }
 
-    pub fn calculate_swap_amount_0(&self, amount_1: Amount) -> Result<Amount, PoolError> {
+    pub fn calculate_swap_amount_0(&self, amount_1: Amount) -> Result<Amount, PoolError> {
+        if self.reserve_0 <= Amount::ZERO
+            || self.reserve_1 <= Amount::ZERO
+            || amount_1 <= Amount::ZERO
+        {
+            return Err(PoolError::InvalidAmount);
+        }
+
+        let fee_base = U256::from(10000u128);
+        let fee_multiplier = fee_base
+            .checked_sub(U256::from(self.fee_percent_mul_100()))
+            .unwrap();
+        let amount_in_with_fee = U256::from(u128::from(amount_1))
+            .checked_mul(fee_multiplier)
+            .unwrap();
+        let numerator = amount_in_with_fee
+            .checked_mul(U256::from(u128::from(self.reserve_0)))
+            .unwrap();
+        let denominator = U256::from(u128::from(self.reserve_1))
+            .checked_mul(fee_base)
+            .unwrap()
+            .checked_add(amount_in_with_fee)
+            .unwrap();
+
+        Ok(Amount::from_attos(
+            numerator
+                .checked_div(denominator)
+                .unwrap_or(U256::from(0))
+                .as_u128(),
+        ))
+    }
+
+    fn calculate_swap_amount_in(
+        &self,
+        amount_out: Amount,
+        reserve_in: Amount,
+        reserve_out: Amount,
+    ) -> Result<Amount, PoolError> {
+        if reserve_in <= Amount::ZERO || reserve_out <= Amount::ZERO || amount_out <= Amount::ZERO {
+            return Err(PoolError::InvalidAmount);
+        }
+        if amount_out >= reserve_out {
+            return Err(PoolError::InsufficientLiquidity);
+        }
+
+        let fee_base = U256::from(10000u128);
+        let fee_multiplier = fee_base
+            .checked_sub(U256::from(self.fee_percent_mul_100()))
+            .unwrap();
+        let numerator = U256::from(u128::from(reserve_in))
+            .checked_mul(U256::from(u128::from(amount_out)))
+            .unwrap()
+            .checked_mul(fee_base)
+            .unwrap();
+        let denominator = U256::from(u128::from(reserve_out))
+            .checked_sub(U256::from(u128::from(amount_out)))
+            .unwrap()
+            .checked_mul(fee_multiplier)
+            .unwrap();
+
+        // Round up so the calculated output never falls below amount_out. Close to the whole
+        // reserve the required input no longer fits an amount
+        let amount_in = numerator
+            .checked_div(denominator)
+            .unwrap()
+            .checked_add(U256::from(1))
+            .unwrap();
+        Ok(Amount::from_attos(
+            u128::try_from(amount_in).map_err(|_| PoolError::InsufficientLiquidity)?,
+        ))
+    }
+
+    pub fn calculate_swap_amount_0_in(&self, amount_1_out: Amount) -> Result<Amount, PoolError> {
+        self.calculate_swap_amount_in(amount_1_out, self.reserve_0, self.reserve_1)
+    }
+
+    pub fn calculate_swap_amount_1_in(&self, amount_0_out: Amount) -> Result<Amount, PoolError> {
+        self.calculate_swap_amount_in(amount_0_out, self.reserve_1, self.reserve_0)
+    }
+
+    // Portion of a single sided deposit to swap so the remainder matches the post swap ratio:
+    //   s = (sqrt(R^2 * (F + g)^2 + 4 * g * F * A * R) - R * (F + g)) / (2 * g)
+    // with R the reserve of the deposited token, F the fee base and g = F - pool fee
+    fn calculate_zap_in_swap_amount(
+        &self,
+        amount_in: Amount,
+        reserve_in: Amount,
+    ) -> Result<Amount, PoolError> {
         if self.reserve_0 <= Amount::ZERO
             || self.reserve_1 <= Amount::ZERO
-            || amount_1 <= Amount::ZERO
+            || amount_in <= Amount::ZERO
         {
             return Err(PoolError::InvalidAmount);
         }
 
         let fee_base = U256::from(10000u128);
         let fee_multiplier = fee_base
-            .checked_sub(U256::from(self.pool_fee_percent_mul_100))
-            .unwrap();
-        let amount_in_with_fee = U256::from(u128::from(amount_1))
-            .checked_mul(fee_multiplier)
+            .checked_sub(U256::from(self.fee_percent_mul_100()))
             .unwrap();
-        let numerator = amount_in_with_fee
-            .checked_mul(U256::from(u128::from(self.reserve_0)))
+        let reserve_in = U256::from(u128::from(reserve_in));
+        let b = reserve_in
+            .checked_mul(fee_base.checked_add(fee_multiplier).unwrap())
             .unwrap();
-        let denominator = U256::from(u128::from(self.reserve_1))
-            .checked_mul(fee_base)
+        let discriminant = b
+            .checked_mul(b)
             .unwrap()
-            .checked_add(amount_in_with_fee)
+            .checked_add(
+                U256::from(4u128)
+                    .checked_mul(fee_multiplier)
+                    .unwrap()
+                    .checked_mul(fee_base)
+                    .unwrap()
+                    .checked_mul(U256::from(u128::from(amount_in)))
+                    .unwrap()
+                    .checked_mul(reserve_in)
+                    .unwrap(),
+            )
             .unwrap();
 
         Ok(Amount::from_attos(
-            numerator
-                .checked_div(denominator)
+            discriminant
+                .integer_sqrt()
+                .checked_sub(b)
                 .unwrap_or(U256::from(0))
-                .as_u128(),
+                .checked_div(U256::from(2u128).checked_mul(fee_multiplier).unwrap())
+                .unwrap()
+                .as_u128()
+                .min(u128::from(amount_in)),
         ))
     }
 
+    pub fn calculate_zap_in_swap_amount_0(&self, amount_0_in: Amount) -> Result<Amount, PoolError> {
+        self.calculate_zap_in_swap_amount(amount_0_in, self.reserve_0)
+    }
+
+    pub fn calculate_zap_in_swap_amount_1(&self, amount_1_in: Amount) -> Result<Amount, PoolError> {
+        self.calculate_zap_in_swap_amount(amount_1_in, self.reserve_1)
+    }
+
+    pub fn calculate_swap_quote(
+        &self,
+        amount_0_in: Option<Amount>,
+        amount_1_in: Option<Amount>,
+    ) -> Result<SwapQuote, PoolError> {
+        let (amount_0_in, amount_1_in, amount_0_out, amount_1_out) =
+            match (amount_0_in, amount_1_in) {
+                (Some(amount_0_in), None) => (
+                    amount_0_in,
+                    Amount::ZERO,
+                    Amount::ZERO,
+                    self.calculate_swap_amount_1(amount_0_in)?,
+                ),
+                (None, Some(amount_1_in)) => (
+                    Amount::ZERO,
+                    amount_1_in,
+                    self.calculate_swap_amount_0(amount_1_in)?,
+                    Amount::ZERO,
+                ),
+                _ => return Err(PoolError::InvalidAmount),
+            };
+        if amount_0_out == Amount::ZERO && amount_1_out == Amount::ZERO {
+            return Err(PoolError::InvalidAmount);
+        }
+        self.validate_swap_invariant(amount_0_in, amount_1_in, amount_0_out, amount_1_out)?;
+
+        let (amount_in, amount_out, reserve_in, reserve_out) = if amount_0_in > Amount::ZERO {
+            (amount_0_in, amount_1_out, self.reserve_0, self.reserve_1)
+        } else {
+            (amount_1_in, amount_0_out, self.reserve_1, self.reserve_0)
+        };
+
+        let (lp_fee, protocol_fee) = self.swap_fees(amount_in);
+
+        let amount_in = amount_to_decimal(amount_in);
+        let amount_out = amount_to_decimal(amount_out);
+        let reserve_in = amount_to_decimal(reserve_in);
+        let reserve_out = amount_to_decimal(reserve_out);
+        let fee_multiplier = Decimal::ONE
+            .checked_sub(Decimal::new(self.fee_percent_mul_100() as i64, 4))
+            .unwrap();
+
+        let execution_price = amount_out.checked_div(amount_in).unwrap();
+        let spot_price_before = reserve_out.checked_div(reserve_in).unwrap();
+        let spot_price_after = reserve_out
+            .checked_sub(amount_out)
+            .unwrap()
+            .checked_div(reserve_in.checked_add(amount_in).unwrap())
+            .unwrap();
+        let price_impact_percent = Decimal::ONE
+            .checked_sub(
+                execution_price
+                    .checked_div(spot_price_before.checked_mul(fee_multiplier).unwrap())
+                    .unwrap(),
+            )
+            .unwrap()
+            .checked_mul(Decimal::ONE_HUNDRED)
+            .unwrap();
+
+        Ok(SwapQuote {
+            amount_0_in,
+            amount_1_in,
+            amount_0_out,
+            amount_1_out,
+            fee_percent_mul_100: self.fee_percent_mul_100(),
+            lp_fee,
+            protocol_fee,
+            execution_price: decimal_to_amount(execution_price),
+            spot_price_before: decimal_to_amount(spot_price_before),
+            spot_price_after: decimal_to_amount(spot_price_after),
+            price_impact_percent: decimal_to_amount(price_impact_percent),
+        })
+    }
+
+    // Quote the input needed for the desired output, the returned output may exceed it
+    // slightly because of rounding
+    pub fn calculate_swap_quote_exact_out(
+        &self,
+        amount_0_out: Option<Amount>,
+        amount_1_out: Option<Amount>,
+    ) -> Result<SwapQuote, PoolError> {
+        match (amount_0_out, amount_1_out) {
+            (Some(amount_0_out), None) => self
+                .calculate_swap_quote(None, Some(self.calculate_swap_amount_1_in(amount_0_out)?)),
+            (None, Some(amount_1_out)) => self
+                .calculate_swap_quote(Some(self.calculate_swap_amount_0_in(amount_1_out)?), None),
+            _ => Err(PoolError::InvalidAmount),
+        }
+    }
+
     pub fn validate_swap_invariant(
         &self,
         amount_0_in: Amount,
@@ -621,7 +1660,7 @@ impl Pool {
             .unwrap()
             .checked_sub(
                 U256::from(u128::from(amount_0_in))
-                    .checked_mul(U256::from(self.pool_fee_percent_mul_100))
+                    .checked_mul(U256::from(self.fee_percent_mul_100()))
                     .unwrap(),
             )
             .unwrap();
@@ -630,7 +1669,7 @@ impl Pool {
             .unwrap()
             .checked_sub(
                 U256::from(u128::from(amount_1_in))
-                    .checked_mul(U256::from(self.pool_fee_percent_mul_100))
+                    .checked_mul(U256::from(self.fee_percent_mul_100()))
                     .unwrap(),
             )
             .unwrap();
@@ -745,6 +1784,66 @@ impl Pool {
         Ok((amount_0, amount_1))
     }
 
+    pub fn observe(&self, timestamp: Timestamp) -> PriceObservation {
+        let time_elapsed = u128::from(
+            timestamp
+                .delta_since(self.block_timestamp)
+                .as_duration()
+                .as_secs(),
+        );
+        let (price_0_cumulative, price_1_cumulative) =
+            self.calculate_price_cumulative_pair(time_elapsed);
+
+        PriceObservation {
+            timestamp,
+            price_0_cumulative,
+            price_1_cumulative,
+        }
+    }
+
+    // Observations must be ordered by timestamp. The window has to be covered by them,
+    // otherwise the average would silently fall back to a shorter window.
+    pub fn calculate_twap(
+        &self,
+        observations: &[PriceObservation],
+        now: Timestamp,
+        window_seconds: u64,
+    ) -> Result<PoolTwap, PoolError> {
+        let window_micros = window_seconds
+            .checked_mul(1_000_000)
+            .ok_or(PoolError::InvalidWindow)?;
+        if window_micros == 0 || window_micros > now.micros() {
+            return Err(PoolError::InvalidWindow);
+        }
+
+        let start = Timestamp::from(now.micros() - window_micros);
+        let current = self.observe(now);
+        let index = observations
+            .iter()
+            .rposition(|observation| observation.timestamp <= start)
+            .ok_or(PoolError::InsufficientObservations)?;
+        let next = observations.get(index + 1).unwrap_or(&current);
+        let start = observations[index].interpolate(next, start);
+
+        let average = |from: Decimal, to: Decimal| {
+            Amount::from_str(
+                &to.checked_sub(from)
+                    .unwrap()
+                    .checked_div(Decimal::from(window_seconds))
+                    .unwrap()
+                    .round_dp(Amount::DECIMAL_PLACES as u32)
+                    .to_string(),
+            )
+            .unwrap()
+        };
+
+        Ok(PoolTwap {
+            window_seconds,
+            price_0: average(start.price_0_cumulative, current.price_0_cumulative),
+            price_1: average(start.price_1_cumulative, current.price_1_cumulative),
+        })
+    }
+
     pub fn calculate_price_pair(&self) -> (Amount, Amount) {
         let time_elapsed = 1000;
         let (price_0_cumulative, price_1_cumulative) =
@@ -780,7 +1879,7 @@ mod tests {
     use rust_decimal::prelude::*;
     use std::str::FromStr;
 
-    use super::Pool;
+    use super::{DynamicFee, LaunchProtection, Pool, PoolError, MAX_LAUNCH_DURATION_MINUTES};
 
     #[test]
     fn test_pool_with_virtual_initial_liquidity() {
@@ -969,4 +2068,336 @@ mod tests {
             Amount::from_str("2.978723404255319148").unwrap(),
         );
     }
+
+    #[test]
+    fn test_pool_mint_fee_follows_protocol_fee_share() {
+        let token_0 = ApplicationId::from_str(
+            "b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bad",
+        )
+        .unwrap();
+        let token_1 = ApplicationId::from_str(
+            "b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bae",
+        )
+        .unwrap();
+        let owner = AccountOwner::from_str(
+            "0x5279b3ae14d3b38e14b65a74aefe44824ea88b25c7841836e9ec77d991a5bc7f",
+        )
+        .unwrap();
+        let chain_id =
+            ChainId::from_str("aee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfe8")
+                .unwrap();
+        let creator = Account { chain_id, owner };
+
+        let mut pool = Pool::create(token_0, Some(token_1), 30, creator, 0.into());
+        pool.reserve_0 = Amount::from_tokens(121);
+        pool.reserve_1 = Amount::from_tokens(121);
+        pool.k_last = Amount::from_tokens(100);
+        pool.protocol_fee_share_denominator = 4;
+
+        assert_eq!(
+            pool.mint_fee(Amount::from_tokens(100)),
+            Amount::from_str("4.535637149028077753").unwrap(),
+        );
+
+        pool.set_protocol_fee_share_denominator(0);
+        assert_eq!(pool.k_last, Amount::ZERO);
+        assert_eq!(pool.mint_fee(Amount::from_tokens(100)), Amount::ZERO);
+
+        // Growth while off is not charged once the protocol fee is turned back on
+        pool.reserve_0 = Amount::from_tokens(144);
+        pool.reserve_1 = Amount::from_tokens(144);
+        pool.update_k_last();
+        assert_eq!(pool.k_last, Amount::ZERO);
+
+        pool.set_protocol_fee_share_denominator(6);
+        assert_eq!(pool.k_last, Amount::from_tokens(144));
+        assert_eq!(pool.mint_fee(Amount::from_tokens(100)), Amount::ZERO);
+
+        let quote = pool.calculate_swap_quote(None, Some(Amount::ONE)).unwrap();
+        assert_eq!(quote.protocol_fee, Amount::from_str("0.0005").unwrap());
+
+        pool.set_protocol_fee_share_denominator(0);
+        let quote = pool.calculate_swap_quote(None, Some(Amount::ONE)).unwrap();
+        assert_eq!(quote.protocol_fee, Amount::ZERO);
+    }
+
+    #[test]
+    fn test_pool_twap_interpolates_observations() {
+        let token_0 = ApplicationId::from_str(
+            "b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bad",
+        )
+        .unwrap();
+        let token_1 = ApplicationId::from_str(
+            "b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bae",
+        )
+        .unwrap();
+        let owner = AccountOwner::from_str(
+            "0x5279b3ae14d3b38e14b65a74aefe44824ea88b25c7841836e9ec77d991a5bc7f",
+        )
+        .unwrap();
+        let chain_id =
+            ChainId::from_str("aee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfe8")
+                .unwrap();
+        let creator = Account { chain_id, owner };
+
+        let mut pool = Pool::create(token_0, Some(token_1), 30, creator, 0.into());
+        pool.liquid(Amount::from_tokens(1), Amount::from_tokens(2), 0.into());
+        let mut observations = vec![pool.observe(0.into())];
+        pool.liquid(
+            Amount::from_tokens(2),
+            Amount::from_tokens(1),
+            10_000_000.into(),
+        );
+        observations.push(pool.observe(10_000_000.into()));
+        assert_eq!(
+            observations[1].price_0_cumulative,
+            Decimal::from_str("20").unwrap()
+        );
+
+        let now = 20_000_000.into();
+        let twap = pool.calculate_twap(&observations, now, 20).unwrap();
+        assert_eq!(twap.price_0, Amount::from_str("1.25").unwrap());
+        assert_eq!(twap.price_1, Amount::from_str("1.25").unwrap());
+
+        let twap = pool.calculate_twap(&observations, now, 15).unwrap();
+        assert_eq!(twap.price_0, Amount::ONE);
+        assert_eq!(twap.price_1, Amount::from_str("1.5").unwrap());
+
+        assert!(matches!(
+            pool.calculate_twap(&observations, now, 25),
+            Err(PoolError::InvalidWindow)
+        ));
+        assert!(matches!(
+            pool.calculate_twap(&observations[1..], now, 15),
+            Err(PoolError::InsufficientObservations)
+        ));
+    }
+
+    #[test]
+    fn test_pool_swap_quote_matches_swap_math() {
+        let token_0 = ApplicationId::from_str(
+            "b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bad",
+        )
+        .unwrap();
+        let token_1 = ApplicationId::from_str(
+            "b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bae",
+        )
+        .unwrap();
+        let owner = AccountOwner::from_str(
+            "0x5279b3ae14d3b38e14b65a74aefe44824ea88b25c7841836e9ec77d991a5bc7f",
+        )
+        .unwrap();
+        let chain_id =
+            ChainId::from_str("aee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfe8")
+                .unwrap();
+        let creator = Account { chain_id, owner };
+
+        let mut pool = Pool::create(token_0, Some(token_1), 30, creator, 0.into());
+        pool.reserve_0 = Amount::from_tokens(1000);
+        pool.reserve_1 = Amount::from_tokens(10);
+
+        let quote = pool.calculate_swap_quote(None, Some(Amount::ONE)).unwrap();
+        assert_eq!(
+            quote.amount_0_out,
+            pool.calculate_swap_amount_0(Amount::ONE).unwrap()
+        );
+        assert_eq!(
+            quote.amount_0_out,
+            Amount::from_str("90.661089388014913158").unwrap()
+        );
+        assert_eq!(quote.amount_1_out, Amount::ZERO);
+        assert_eq!(quote.lp_fee, Amount::from_str("0.003").unwrap());
+        assert_eq!(quote.protocol_fee, Amount::from_str("0.0005").unwrap());
+        assert_eq!(quote.execution_price, quote.amount_0_out);
+        assert_eq!(quote.spot_price_before, Amount::from_tokens(100));
+        assert_eq!(
+            quote.spot_price_after,
+            Amount::from_str("82.667173691998644258").unwrap()
+        );
+        assert!(quote.price_impact_percent > Amount::from_str("9.066").unwrap());
+        assert!(quote.price_impact_percent < Amount::from_str("9.067").unwrap());
+
+        let quote = pool
+            .calculate_swap_quote_exact_out(Some(Amount::from_tokens(50)), None)
+            .unwrap();
+        assert_eq!(
+            quote.amount_1_in,
+            Amount::from_str("0.527899487937496701").unwrap()
+        );
+        assert!(quote.amount_0_out >= Amount::from_tokens(50));
+
+        assert!(pool.calculate_swap_quote(None, None).is_err());
+        assert!(pool
+            .calculate_swap_quote_exact_out(Some(Amount::from_tokens(1000)), None)
+            .is_err());
+    }
+
+    #[test]
+    fn test_pool_swap_amount_in_overflow_is_insufficient_liquidity() {
+        let token_0 = ApplicationId::from_str(
+            "b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bad",
+        )
+        .unwrap();
+        let owner = AccountOwner::from_str(
+            "0x5279b3ae14d3b38e14b65a74aefe44824ea88b25c7841836e9ec77d991a5bc7f",
+        )
+        .unwrap();
+        let chain_id =
+            ChainId::from_str("aee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfe8")
+                .unwrap();
+        let creator = Account { chain_id, owner };
+
+        let mut pool = Pool::create(token_0, None, 30, creator, 0.into());
+        pool.reserve_0 = Amount::from_tokens(1_000_000_000);
+        pool.reserve_1 = Amount::ONE;
+
+        // All but one atto of the reserve needs more input than an amount holds
+        let amount_1_out = Amount::ONE.try_sub(Amount::from_attos(1)).unwrap();
+        assert!(matches!(
+            pool.calculate_swap_amount_0_in(amount_1_out),
+            Err(PoolError::InsufficientLiquidity)
+        ));
+        assert!(pool
+            .calculate_swap_amount_0_in(Amount::from_str("0.5").unwrap())
+            .is_ok());
+    }
+
+    #[test]
+    fn test_pool_zap_in_swap_amount_balances_remainder() {
+        let token_0 = ApplicationId::from_str(
+            "b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bad",
+        )
+        .unwrap();
+        let token_1 = ApplicationId::from_str(
+            "b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bae",
+        )
+        .unwrap();
+        let owner = AccountOwner::from_str(
+            "0x5279b3ae14d3b38e14b65a74aefe44824ea88b25c7841836e9ec77d991a5bc7f",
+        )
+        .unwrap();
+        let chain_id =
+            ChainId::from_str("aee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfe8")
+                .unwrap();
+        let creator = Account { chain_id, owner };
+
+        let mut pool = Pool::create(token_0, Some(token_1), 30, creator, 0.into());
+        pool.reserve_0 = Amount::from_tokens(1000);
+        pool.reserve_1 = Amount::from_tokens(10);
+
+        let amount_0_in = Amount::from_tokens(100);
+        let swap_amount = pool.calculate_zap_in_swap_amount_0(amount_0_in).unwrap();
+        assert_eq!(
+            swap_amount,
+            Amount::from_str("48.882173994193580692").unwrap()
+        );
+        let amount_1_out = pool.calculate_swap_amount_1(swap_amount).unwrap();
+        pool.validate_swap_invariant(swap_amount, Amount::ZERO, Amount::ZERO, amount_1_out)
+            .unwrap();
+
+        pool.reserve_0 = pool.reserve_0.saturating_add(swap_amount);
+        pool.reserve_1 = pool.reserve_1.saturating_sub(amount_1_out);
+        let (amount_0, amount_1) = pool
+            .try_calculate_swap_amount_pair(
+                amount_0_in.saturating_sub(swap_amount),
+                amount_1_out,
+                None,
+                None,
+            )
+            .unwrap();
+        assert!(
+            amount_0_in
+                .saturating_sub(swap_amount)
+                .saturating_sub(amount_0)
+                <= Amount::from_attos(1000)
+        );
+        assert_eq!(amount_1, amount_1_out);
+
+        assert!(pool.calculate_zap_in_swap_amount_1(Amount::ZERO).is_err());
+    }
+
+    #[test]
+    fn test_pool_dynamic_fee_follows_realized_volatility() {
+        let token_0 = ApplicationId::from_str(
+            "b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bad",
+        )
+        .unwrap();
+        let token_1 = ApplicationId::from_str(
+            "b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bae",
+        )
+        .unwrap();
+        let owner = AccountOwner::from_str(
+            "0x5279b3ae14d3b38e14b65a74aefe44824ea88b25c7841836e9ec77d991a5bc7f",
+        )
+        .unwrap();
+        let chain_id =
+            ChainId::from_str("aee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfe8")
+                .unwrap();
+        let creator = Account { chain_id, owner };
+
+        let mut pool = Pool::create(token_0, Some(token_1), 30, creator, 0.into());
+        pool.reserve_0 = Amount::from_tokens(1000);
+        pool.reserve_1 = Amount::from_tokens(10);
+        pool.set_dynamic_fee(Some(DynamicFee {
+            min_fee_percent_mul_100: 10,
+            max_fee_percent_mul_100: 100,
+            max_volatility_percent_mul_100: 1000,
+            window_seconds: 600,
+        }));
+        assert_eq!(pool.fee_percent_mul_100(), 10);
+
+        // Price 0 moves 5%, half of the max volatility
+        pool.liquid(
+            Amount::from_tokens(1000),
+            Amount::from_str("10.5").unwrap(),
+            60_000_000.into(),
+        );
+        assert_eq!(pool.fee_percent_mul_100(), 55);
+        let quote = pool.calculate_swap_quote(Some(Amount::ONE), None).unwrap();
+        assert_eq!(quote.fee_percent_mul_100, 55);
+        assert_eq!(quote.lp_fee, Amount::from_str("0.0055").unwrap());
+
+        // Moves older than the window no longer count
+        pool.liquid(
+            Amount::from_tokens(1000),
+            Amount::from_str("10.5").unwrap(),
+            660_000_000.into(),
+        );
+        assert_eq!(pool.fee_percent_mul_100(), 10);
+
+        pool.set_dynamic_fee(None);
+        assert_eq!(pool.fee_percent_mul_100(), 30);
+    }
+
+    #[test]
+    fn test_launch_protection_validate_bounds_guards() {
+        LaunchProtection::default().validate();
+
+        let mut protection = LaunchProtection::default();
+        protection.duration_minutes = MAX_LAUNCH_DURATION_MINUTES + 1;
+        assert!(std::panic::catch_unwind(|| protection.validate()).is_err());
+
+        let mut protection = LaunchProtection::default();
+        protection.max_trade_percent_mul_100 = Some(0);
+        assert!(std::panic::catch_unwind(|| protection.validate()).is_err());
+
+        let mut protection = LaunchProtection::default();
+        protection.extra_fee_percent_mul_100 = Some(10000);
+        assert!(std::panic::catch_unwind(|| protection.validate()).is_err());
+    }
+
+    #[test]
+    fn test_launch_protection_amount_in_covers_extra_fee() {
+        let protection = LaunchProtection::default();
+        let amount_in = Amount::from_str("1.2345").unwrap();
+
+        let amount_in_with_extra_fee =
+            protection.amount_in_with_extra_fee(amount_in, 0.into(), 0.into());
+        let extra_fee = protection.extra_fee(amount_in_with_extra_fee, 0.into(), 0.into());
+        assert_eq!(
+            amount_in_with_extra_fee,
+            Amount::from_str("1.371666666666666667").unwrap()
+        );
+        assert!(amount_in_with_extra_fee.try_sub(extra_fee).unwrap() >= amount_in);
+    }
 }
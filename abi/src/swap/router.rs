@@ -1,7 +1,7 @@
 // Copyright (c) Zefchain Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::swap::pool::BootstrapPolicy;
+use crate::swap::pool::{BootstrapPolicy, LaunchProtection, PoolStats, PoolStatus};
 use async_graphql::{scalar, InputObject, Request, Response, SimpleObject};
 use linera_sdk::{
     graphql::GraphQLMutationRoot,
@@ -41,6 +41,8 @@ pub enum SwapOperation {
         // Only for creator to initialize pool
         virtual_liquidity: bool,
         to: Option<Account>,
+        // Chosen by the meme creator, None launches the pool without protection
+        launch_protection: Option<LaunchProtection>,
     },
     // User-reachable create-pool entry for the missing-pair add-liquidity flow.
     // This operation is create-with-initial-liquidity only.
@@ -52,6 +54,8 @@ pub enum SwapOperation {
         amount_0: Amount,
         amount_1: Amount,
         to: Option<Account>,
+        // One of POOL_FEE_TIERS, default tier if not set
+        pool_fee_percent_mul_100: Option<u16>,
     },
     // Notify swap of new transaction, called from pool chain
     UpdatePool {
@@ -62,6 +66,62 @@ pub enum SwapOperation {
         token_1_price: Amount,
         reserve_0: Amount,
         reserve_1: Amount,
+        stats: PoolStats,
+    },
+    // Multi-hop swap along `path`, e.g. [Some(MEME_A), None, Some(MEME_B)] for
+    // MEME_A -> native -> MEME_B. Only the last hop is checked against `amount_out_min`.
+    Swap {
+        path: Vec<Option<ApplicationId>>,
+        amount_in: Amount,
+        amount_out_min: Option<Amount>,
+        deadline: Option<Timestamp>,
+    },
+    // Continue a routed swap after a hop output is delivered, called from pool chain
+    ContinueSwap {
+        origin: Account,
+        route: SwapRoute,
+        amount_in: Amount,
+    },
+    // Operators of the swap creator chain pause or resume a pool
+    SetPoolStatus {
+        pool_application: Account,
+        status: PoolStatus,
+    },
+    // Notify swap of pool status change, called from pool chain
+    UpdatePoolStatus {
+        token_0: ApplicationId,
+        token_1: Option<ApplicationId>,
+        status: PoolStatus,
+    },
+    // Claim all claimable balances of caller from the given catalogued pools, at most
+    // MAX_CLAIM_ALL_POOLS at once
+    ClaimAll {
+        pool_applications: Vec<Account>,
+    },
+    // Operators of the swap creator chain upgrade the bytecode of pools created from now on
+    SetPoolBytecodeId {
+        pool_bytecode_id: ModuleId,
+    },
+    // Continue a liquidity migration after the old pool delivered the underlying to origin,
+    // called from pool chain. Slippage minimums and deadline of the migration also bound the
+    // deposit into the new pool version
+    MigrateLiquidity {
+        origin: Account,
+        token_0: ApplicationId,
+        token_1: Option<ApplicationId>,
+        pool_fee_percent_mul_100: u16,
+        amount_0: Amount,
+        amount_1: Amount,
+        amount_0_out_min: Option<Amount>,
+        amount_1_out_min: Option<Amount>,
+        deadline: Option<Timestamp>,
+    },
+    // Operators of the swap creator chain re-point the catalog entry of the pair to its
+    // waiting new version, once enough liquidity migrated
+    PromotePoolVersion {
+        token_0: ApplicationId,
+        token_1: Option<ApplicationId>,
+        pool_fee_percent_mul_100: u16,
     },
 }
 
@@ -82,6 +142,7 @@ pub enum SwapMessage {
         // Only for creator to initialize pool
         virtual_liquidity: bool,
         to: Option<Account>,
+        launch_protection: Option<LaunchProtection>,
     },
     CreatePool {
         creator: Account,
@@ -92,6 +153,9 @@ pub enum SwapMessage {
         amount_1: Amount,
         bootstrap_policy: BootstrapPolicy,
         to: Option<Account>,
+        pool_fee_percent_mul_100: u16,
+        // Only meme pools are launched with protection
+        launch_protection: Option<LaunchProtection>,
     },
     PoolCreated {
         creator: Account,
@@ -102,6 +166,7 @@ pub enum SwapMessage {
         amount_1: Amount,
         bootstrap_policy: BootstrapPolicy,
         to: Option<Account>,
+        pool_fee_percent_mul_100: u16,
     },
     // Execute on swap creation chain
     CreateUserPool {
@@ -114,6 +179,7 @@ pub enum SwapMessage {
         amount_0: Amount,
         amount_1: Amount,
         to: Option<Account>,
+        pool_fee_percent_mul_100: u16,
     },
     // Execute on user caller chain
     UserPoolCreated {
@@ -132,9 +198,120 @@ pub enum SwapMessage {
         token_1_price: Amount,
         reserve_0: Amount,
         reserve_1: Amount,
+        stats: PoolStats,
+    },
+    // Execute on swap creation chain to resolve pools of each hop
+    Swap {
+        origin: Account,
+        path: Vec<Option<ApplicationId>>,
+        amount_in: Amount,
+        amount_out_min: Option<Amount>,
+        deadline: Option<Timestamp>,
+    },
+    // Execute on user caller chain to run the next hop of the route
+    ExecuteSwapRoute {
+        origin: Account,
+        route: SwapRoute,
+        amount_in: Amount,
+    },
+    UpdatePoolStatus {
+        token_0: ApplicationId,
+        token_1: Option<ApplicationId>,
+        status: PoolStatus,
+    },
+    // Execute on swap creation chain to drop pools which are not catalogued
+    ClaimAll {
+        origin: Account,
+        pool_applications: Vec<Account>,
+    },
+    // Execute on user caller chain to claim from each pool
+    ExecuteClaimAll {
+        origin: Account,
+        pool_applications: Vec<Account>,
+    },
+    // Execute on swap creator chain to resolve the latest pool version of the pair
+    MigrateLiquidity {
+        origin: Account,
+        pool_application: Account,
+        token_0: ApplicationId,
+        token_1: Option<ApplicationId>,
+        pool_fee_percent_mul_100: u16,
+        amount_0: Amount,
+        amount_1: Amount,
+        amount_0_out_min: Option<Amount>,
+        amount_1_out_min: Option<Amount>,
+        deadline: Option<Timestamp>,
+    },
+    // Execute on user caller chain to deposit the migrated underlying
+    ExecuteMigrateLiquidity {
+        origin: Account,
+        pool_application: Account,
+        amount_0: Amount,
+        amount_1: Amount,
+        amount_0_out_min: Option<Amount>,
+        amount_1_out_min: Option<Amount>,
+        deadline: Option<Timestamp>,
+    },
+    // Execute on swap creator chain to check the remaining hops against the catalog
+    ContinueSwap {
+        origin: Account,
+        pool_application: Account,
+        route: SwapRoute,
+        amount_in: Amount,
     },
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq)]
+pub struct SwapRouteHop {
+    pub pool_application: Account,
+    pub token_0: ApplicationId,
+    pub token_1: Option<ApplicationId>,
+    pub token_in: Option<ApplicationId>,
+}
+
+impl SwapRouteHop {
+    pub fn token_0_in(&self) -> bool {
+        self.token_in == Some(self.token_0)
+    }
+
+    pub fn token_out(&self) -> Option<ApplicationId> {
+        if self.token_0_in() {
+            self.token_1
+        } else {
+            Some(self.token_0)
+        }
+    }
+}
+
+// Remaining hops of a routed swap. The route travels with the hop input so each
+// pool knows whether to settle its output to the owner or hand it to the next hop.
+#[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq)]
+pub struct SwapRoute {
+    pub hops: Vec<SwapRouteHop>,
+    pub amount_out_min: Option<Amount>,
+    pub deadline: Option<Timestamp>,
+}
+
+scalar!(SwapRoute);
+
+impl SwapRoute {
+    pub fn expired(&self, now: Timestamp) -> bool {
+        self.deadline.is_some_and(|deadline| now > deadline)
+    }
+}
+
+// Liquidity migration waiting for the new pool version to be created, amounts are in the
+// token order of the catalogued pool
+#[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq)]
+pub struct PendingMigration {
+    pub origin: Account,
+    pub amount_0: Amount,
+    pub amount_1: Amount,
+    pub amount_0_out_min: Option<Amount>,
+    pub amount_1_out_min: Option<Amount>,
+    pub deadline: Option<Timestamp>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, SimpleObject)]
 #[serde(rename_all = "camelCase")]
 pub struct Pool {
@@ -148,8 +325,24 @@ pub struct Pool {
     pub reserve_0: Option<Amount>,
     pub reserve_1: Option<Amount>,
     pub created_at: Timestamp,
+    pub pool_fee_percent_mul_100: u16,
+    pub status: PoolStatus,
+    // Cumulative figures reported by the pool with each transaction
+    pub volume_0: Amount,
+    pub volume_1: Amount,
+    pub fees_0: Amount,
+    pub fees_1: Amount,
+    pub swap_count: u64,
+    pub last_trade_at: Option<Timestamp>,
 }
 
+// Pools of the same pair are distinguished by fee tier: 0.05%, 0.3% and 1%
+pub const POOL_FEE_TIERS: [u16; 3] = [5, 30, 100];
+pub const DEFAULT_POOL_FEE_PERCENT_MUL_100: u16 = 30;
+
+// Each claimed pool is one application call in the block of the claimer
+pub const MAX_CLAIM_ALL_POOLS: usize = 16;
+
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, InputObject)]
 pub struct InstantiationArgument {
     pub pool_bytecode_id: ModuleId,
@@ -24,6 +24,8 @@ pub struct Transaction {
     pub amount_1_in: Option<Amount>,
     pub amount_1_out: Option<Amount>,
     pub liquidity: Option<Amount>,
+    // Swap fee charged on the input, None for liquidity changes
+    pub fee_percent_mul_100: Option<u16>,
     pub created_at: Timestamp,
 }
 
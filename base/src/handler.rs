@@ -1,12 +1,11 @@
 use async_trait::async_trait;
 use linera_sdk::{
-    linera_base_types::{ArithmeticError, ChainId},
+    linera_base_types::{ArithmeticError, ChainId, StreamName},
     views::ViewError,
 };
-use serde::Serialize;
+use serde::{de::DeserializeOwned, Serialize};
 use thiserror::Error;
 
-// TODO: support send to stream
 #[derive(Debug)]
 pub struct HandlerMessage<M: Serialize> {
     destination: ChainId,
@@ -36,9 +35,34 @@ impl<M: Serialize> HandlerMessage<M> {
     }
 }
 
+// Value is kept serialized since event value type is decided by the contract, not the handler
+#[derive(Debug)]
+pub struct HandlerEvent {
+    stream_name: StreamName,
+    value: Vec<u8>,
+}
+
+impl HandlerEvent {
+    pub fn new<E: Serialize>(stream_name: StreamName, value: &E) -> Self {
+        Self {
+            stream_name,
+            value: bcs::to_bytes(value).expect("Failed serialize event"),
+        }
+    }
+
+    pub fn stream_name(&self) -> &StreamName {
+        &self.stream_name
+    }
+
+    pub fn value<E: DeserializeOwned>(&self) -> E {
+        bcs::from_bytes(&self.value).expect("Failed deserialize event")
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct HandlerOutcome<M: Serialize, R: Serialize> {
     pub messages: Vec<HandlerMessage<M>>,
+    pub events: Vec<HandlerEvent>,
     pub response: Option<R>,
 }
 
@@ -46,6 +70,7 @@ impl<M: Serialize, R: Serialize> HandlerOutcome<M, R> {
     pub fn new() -> Self {
         Self {
             messages: Vec::new(),
+            events: Vec::new(),
             response: None,
         }
     }
@@ -56,6 +81,11 @@ impl<M: Serialize, R: Serialize> HandlerOutcome<M, R> {
         self
     }
 
+    pub fn with_event<E: Serialize>(&mut self, stream_name: StreamName, value: &E) -> &mut Self {
+        self.events.push(HandlerEvent::new(stream_name, value));
+        self
+    }
+
     pub fn with_response(&mut self, response: R) -> &mut Self {
         self.response = Some(response);
         self
@@ -96,6 +126,9 @@ pub enum HandlerError {
 
     #[error("Invalid application response")]
     InvalidApplicationResponse,
+
+    #[error("Deadline exceeded")]
+    DeadlineExceeded,
 }
 
 #[async_trait(?Send)]
@@ -44,6 +44,7 @@ impl<
         log::info!("DEBUG MEME:MSG liquidity funded");
 
         let virtual_liquidity = self.runtime.borrow_mut().virtual_initial_liquidity();
+        let launch_protection = self.runtime.borrow_mut().launch_protection();
         // Liquidity will be adjusted when we have mining so we use state liquidity
         // let Some(liquidity) = self.runtime.borrow_mut().initial_liquidity() else {
         let Some(liquidity) = self.state.borrow()._initial_liquidity() else {
@@ -69,6 +70,7 @@ impl<
             amount_1: liquidity.native_amount,
             virtual_liquidity,
             to: None,
+            launch_protection,
         };
         let _ = self
             .runtime
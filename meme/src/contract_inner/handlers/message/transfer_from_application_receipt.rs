@@ -114,7 +114,14 @@ impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
 
         match self.receipt.purpose {
             TransferFromApplicationReceiptPurpose::PoolClaim => {
-                assert!(self.receipt.payload.is_none(), "Invalid receipt payload");
+                // Claims issued for a routed swap hop carry the remaining route
+                let route = match self.receipt.payload.clone() {
+                    None => None,
+                    Some(TransferFromApplicationReceiptPayload::PoolClaim(payload)) => {
+                        Some(payload.route)
+                    }
+                    Some(_) => panic!("Invalid receipt payload"),
+                };
 
                 let AccountOwner::Address32(application_description_hash) = self.caller.owner
                 else {
@@ -128,6 +135,7 @@ impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
                         token: self.receipt.token,
                         amount: self.receipt.amount,
                         result,
+                        route,
                     },
                 };
                 let _ = self
@@ -200,9 +208,11 @@ impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
                     self.receipt.amount, payload.request.amount_in,
                     "Invalid receipt amount"
                 );
-                assert_eq!(
-                    payload.request.fund_type,
-                    FundType::Swap,
+                assert!(
+                    matches!(
+                        payload.request.fund_type,
+                        FundType::Swap | FundType::LimitOrder | FundType::ZapIn
+                    ),
                     "Invalid fund type"
                 );
 
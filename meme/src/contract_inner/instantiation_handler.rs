@@ -171,6 +171,10 @@ impl<
             "Invalid owner"
         );
 
+        if let Some(launch_protection) = self.runtime.borrow_mut().launch_protection() {
+            launch_protection.validate();
+        }
+
         let creator = self.runtime.borrow_mut().creator();
         let application = self.runtime.borrow_mut().application_account();
 
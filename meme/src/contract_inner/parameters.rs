@@ -1,5 +1,8 @@
 use crate::interfaces::parameters::ParametersInterface;
-use abi::meme::{Liquidity, MemeParameters};
+use abi::{
+    meme::{Liquidity, MemeParameters},
+    swap::pool::LaunchProtection,
+};
 use linera_sdk::{
     linera_base_types::{Account, AccountOwner, Amount, ChainId},
     Contract,
@@ -35,6 +38,10 @@ impl ParametersInterface for MemeParameters {
     fn mining_supply(&mut self) -> Option<Amount> {
         self.mining_supply
     }
+
+    fn launch_protection(&mut self) -> Option<LaunchProtection> {
+        self.launch_protection.clone()
+    }
 }
 
 impl<T, M> ParametersInterface for ContractRuntimeAdapter<T, M>
@@ -69,4 +76,8 @@ where
     fn mining_supply(&mut self) -> Option<Amount> {
         self.application_parameters().mining_supply()
     }
+
+    fn launch_protection(&mut self) -> Option<LaunchProtection> {
+        self.application_parameters().launch_protection()
+    }
 }
@@ -10,9 +10,13 @@ use abi::{
     },
     proxy::ProxyResponse,
     store_type::StoreType,
-    swap::pool::{
-        AddLiquidityTransferReceiptPayload, ClaimTransferReceipt, FundRequest, FundType,
-        PoolInitializeLiquidityCall, PoolOperation, PoolResponse, SwapTransferReceiptPayload,
+    swap::{
+        pool::{
+            AddLiquidityTransferReceiptPayload, ClaimTransferReceipt, ClaimTransferReceiptPayload,
+            FundRequest, FundType, PoolInitializeLiquidityCall, PoolOperation, PoolResponse,
+            SwapTransferReceiptPayload,
+        },
+        router::{SwapRoute, SwapRouteHop},
     },
 };
 use futures::FutureExt as _;
@@ -1042,6 +1046,7 @@ async fn message_transfer_from_application_receipt_dispatches_pool_claim_success
                 token: receipt_token,
                 amount: receipt_amount,
                 result: Ok(()),
+                route: None,
             },
         } if receipt_owner == owner
             && receipt_token == receipt.token
@@ -1086,6 +1091,59 @@ async fn message_transfer_from_application_receipt_dispatches_pool_claim_fail()
     ));
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn message_transfer_from_application_receipt_dispatches_pool_claim_route() {
+    let mut meme = create_and_instantiate_meme(false, None).await;
+    let caller = pool_application_account();
+    let route = SwapRoute {
+        hops: vec![SwapRouteHop {
+            pool_application: caller,
+            token_0: ApplicationId::from_str(
+                "b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bad",
+            )
+            .unwrap(),
+            token_1: None,
+            token_in: None,
+        }],
+        amount_out_min: Some(Amount::ONE),
+        deadline: None,
+    };
+    let receipt = TransferFromApplicationReceipt {
+        payload: Some(TransferFromApplicationReceiptPayload::PoolClaim(
+            ClaimTransferReceiptPayload {
+                route: route.clone(),
+            },
+        )),
+        ..pool_claim_receipt(Some(Ok(())))
+    };
+
+    meme.runtime.borrow_mut().set_message_is_bouncing(false);
+
+    let captured = std::rc::Rc::new(std::cell::RefCell::new(None));
+    let captured_for_handler = captured.clone();
+    meme.runtime.borrow_mut().set_call_application_handler(
+        move |_authenticated, application_id, operation| {
+            *captured_for_handler.borrow_mut() = Some((application_id, operation));
+            bcs::to_bytes(&PoolResponse::Ok).unwrap()
+        },
+    );
+
+    meme.execute_message(MemeMessage::TransferFromApplicationReceipt { caller, receipt })
+        .await;
+
+    let (_application_id, operation) = captured.borrow().clone().unwrap();
+    assert!(matches!(
+        bcs::from_bytes::<PoolOperation>(&operation).unwrap(),
+        PoolOperation::ClaimTransferReceipt {
+            receipt: ClaimTransferReceipt {
+                result: Ok(()),
+                route: Some(receipt_route),
+                ..
+            },
+        } if receipt_route == route
+    ));
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn message_transfer_from_application_receipt_dispatches_pool_add_liquidity_success() {
     let mut meme = create_and_instantiate_meme(false, None).await;
@@ -1553,6 +1611,7 @@ async fn create_and_instantiate_meme_with_authenticated_caller(
         swap_creator_chain_id: chain_id,
 
         enable_mining,
+        launch_protection: None,
         mining_supply,
     };
     let mut runtime = ContractRuntime::new()
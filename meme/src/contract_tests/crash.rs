@@ -625,6 +625,7 @@ async fn create_and_instantiate_meme(
         swap_creator_chain_id: chain_id,
 
         enable_mining,
+        launch_protection: None,
         mining_supply,
     };
     let runtime = ContractRuntime::new()
@@ -1,4 +1,4 @@
-use abi::meme::Liquidity;
+use abi::{meme::Liquidity, swap::pool::LaunchProtection};
 use linera_sdk::linera_base_types::{Account, AccountOwner, Amount, ChainId};
 
 pub trait ParametersInterface {
@@ -9,4 +9,5 @@ pub trait ParametersInterface {
     fn swap_creator_chain_id(&mut self) -> ChainId;
     fn enable_mining(&mut self) -> bool;
     fn mining_supply(&mut self) -> Option<Amount>;
+    fn launch_protection(&mut self) -> Option<LaunchProtection>;
 }
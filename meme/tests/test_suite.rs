@@ -162,6 +162,7 @@ impl TestSuite {
             swap_creator_chain_id: self.swap_chain.id(),
 
             enable_mining,
+            launch_protection: None,
             mining_supply,
         };
 
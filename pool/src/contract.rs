@@ -2,7 +2,9 @@
 
 use std::{cell::RefCell, rc::Rc};
 
-use abi::swap::pool::{InstantiationArgument, PoolAbi, PoolMessage, PoolOperation, PoolParameters};
+use abi::swap::pool::{
+    InstantiationArgument, PoolAbi, PoolEvent, PoolMessage, PoolOperation, PoolParameters,
+};
 
 use linera_sdk::{
     linera_base_types::WithContractAbi,
@@ -26,7 +28,7 @@ impl Contract for PoolContract {
     type Message = PoolMessage;
     type InstantiationArgument = InstantiationArgument;
     type Parameters = PoolParameters;
-    type EventValue = ();
+    type EventValue = PoolEvent;
 
     async fn load(runtime: ContractRuntime<Self>) -> Self {
         let state = PoolState::load(runtime.root_view_storage_context())
@@ -59,9 +59,16 @@ impl PoolContract {
             );
         }
 
-        // TODO: process event / stream
+        // Event indexes follow the emit order so keep the handler order here
+        for event in outcome.events.iter() {
+            log::debug!("DEBUG OP:POOL: emitting event {:?} ", event);
 
-        PoolResponse::Ok
+            runtime_context
+                .borrow_mut()
+                .emit(event.stream_name().clone(), &event.value());
+        }
+
+        outcome.response.unwrap_or(PoolResponse::Ok)
     }
 
     pub async fn on_message(&mut self, msg: &PoolMessage) {
@@ -95,6 +102,13 @@ impl PoolContract {
             );
         }
 
-        // TODO: process event / stream
+        // Event indexes follow the emit order so keep the handler order here
+        for event in outcome.events.iter() {
+            log::debug!("DEBUG MSG:POOL: emitting event {:?} ", event);
+
+            runtime_context
+                .borrow_mut()
+                .emit(event.stream_name().clone(), &event.value());
+        }
     }
 }
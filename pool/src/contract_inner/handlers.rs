@@ -1,6 +1,12 @@
+pub mod continue_swap_route;
+pub mod deliver_claimable;
+pub mod fill_limit_orders;
+pub mod forward_swap_route;
 pub mod fund_pool_application_creation_chain;
+pub mod launch_protection;
 pub mod message;
 pub mod operation;
+pub mod record_failed_request;
 pub mod refund;
 pub mod request_meme_fund;
 pub mod transfer_meme_from_application;
@@ -11,29 +17,56 @@ use base::handler::{Handler, HandlerError};
 use message::{
     add_liquidity::AddLiquidityHandler as MessageAddLiquidityHandler,
     add_liquidity_transfer_receipt::AddLiquidityTransferReceiptHandler as MessageAddLiquidityTransferReceiptHandler,
+    approve_liquidity::ApproveLiquidityHandler as MessageApproveLiquidityHandler,
+    cancel_limit_order::CancelLimitOrderHandler as MessageCancelLimitOrderHandler,
     claim::ClaimHandler as MessageClaimHandler,
+    claim_all::ClaimAllHandler as MessageClaimAllHandler,
     claim_transfer_receipt::ClaimTransferReceiptHandler as MessageClaimTransferReceiptHandler,
     fund_result::FundResultHandler as MessageFundResultHandler,
     initialize_liquidity::InitializeLiquidityHandler as MessageInitializeLiquidityHandler,
+    migrate_liquidity::MigrateLiquidityHandler as MessageMigrateLiquidityHandler,
     new_transaction::NewTransactionHandler as MessageNewTransactionHandler,
+    place_limit_order::PlaceLimitOrderHandler as MessagePlaceLimitOrderHandler,
     remove_liquidity::RemoveLiquidityHandler as MessageRemoveLiquidityHandler,
+    request_failed::RequestFailedHandler as MessageRequestFailedHandler,
     request_fund::RequestFundHandler as MessageRequestFundHandler,
+    set_dynamic_fee::SetDynamicFeeHandler as MessageSetDynamicFeeHandler,
     set_fee_to::SetFeeToHandler as MessageSetFeeToHandler,
     set_fee_to_setter::SetFeeToSetterHandler as MessageSetFeeToSetterHandler,
-    swap::SwapHandler as MessageSwapHandler,
+    set_pool_status::SetPoolStatusHandler as MessageSetPoolStatusHandler,
+    set_protocol_fee_share::SetProtocolFeeShareHandler as MessageSetProtocolFeeShareHandler,
+    skim::SkimHandler as MessageSkimHandler, swap::SwapHandler as MessageSwapHandler,
+    swap_settled::SwapSettledHandler as MessageSwapSettledHandler,
     swap_transfer_receipt::SwapTransferReceiptHandler as MessageSwapTransferReceiptHandler,
+    sync::SyncHandler as MessageSyncHandler,
+    transfer_liquidity::TransferLiquidityHandler as MessageTransferLiquidityHandler,
+    transfer_liquidity_from::TransferLiquidityFromHandler as MessageTransferLiquidityFromHandler,
+    zap_in::ZapInHandler as MessageZapInHandler, zap_out::ZapOutHandler as MessageZapOutHandler,
 };
 use operation::{
     add_liquidity::AddLiquidityHandler as OperationAddLiquidityHandler,
     add_liquidity_transfer_receipt::AddLiquidityTransferReceiptHandler as OperationAddLiquidityTransferReceiptHandler,
+    approve_liquidity::ApproveLiquidityHandler as OperationApproveLiquidityHandler,
+    cancel_limit_order::CancelLimitOrderHandler as OperationCancelLimitOrderHandler,
     claim::ClaimHandler as OperationClaimHandler,
+    claim_all::ClaimAllHandler as OperationClaimAllHandler,
     claim_transfer_receipt::ClaimTransferReceiptHandler as OperationClaimTransferReceiptHandler,
     initialize_liquidity::InitializeLiquidityHandler as OperationInitializeLiquidityHandler,
+    migrate_liquidity::MigrateLiquidityHandler as OperationMigrateLiquidityHandler,
+    place_limit_order::PlaceLimitOrderHandler as OperationPlaceLimitOrderHandler,
     remove_liquidity::RemoveLiquidityHandler as OperationRemoveLiquidityHandler,
+    set_dynamic_fee::SetDynamicFeeHandler as OperationSetDynamicFeeHandler,
     set_fee_to::SetFeeToHandler as OperationSetFeeToHandler,
     set_fee_to_setter::SetFeeToSetterHandler as OperationSetFeeToSetterHandler,
-    swap::SwapHandler as OperationSwapHandler,
+    set_pool_status::SetPoolStatusHandler as OperationSetPoolStatusHandler,
+    set_protocol_fee_share::SetProtocolFeeShareHandler as OperationSetProtocolFeeShareHandler,
+    skim::SkimHandler as OperationSkimHandler, swap::SwapHandler as OperationSwapHandler,
     swap_transfer_receipt::SwapTransferReceiptHandler as OperationSwapTransferReceiptHandler,
+    sync::SyncHandler as OperationSyncHandler,
+    transfer_liquidity::TransferLiquidityHandler as OperationTransferLiquidityHandler,
+    transfer_liquidity_from::TransferLiquidityFromHandler as OperationTransferLiquidityFromHandler,
+    twap::TwapHandler as OperationTwapHandler, zap_in::ZapInHandler as OperationZapInHandler,
+    zap_out::ZapOutHandler as OperationZapOutHandler,
 };
 use runtime::interfaces::{
     access_control::AccessControl, contract::ContractRuntimeContext, meme::MemeRuntimeContext,
@@ -83,6 +116,46 @@ impl HandlerFactory {
             PoolOperation::SwapTransferReceipt { .. } => {
                 Box::new(OperationSwapTransferReceiptHandler::new(runtime, state, op))
             }
+            PoolOperation::Twap { .. } => Box::new(OperationTwapHandler::new(runtime, state, op)),
+            PoolOperation::SwapExactOut { .. } => {
+                Box::new(OperationSwapHandler::new(runtime, state, op))
+            }
+            PoolOperation::SetProtocolFeeShare { .. } => {
+                Box::new(OperationSetProtocolFeeShareHandler::new(runtime, state, op))
+            }
+            PoolOperation::TransferLiquidity { .. } => {
+                Box::new(OperationTransferLiquidityHandler::new(runtime, state, op))
+            }
+            PoolOperation::ApproveLiquidity { .. } => {
+                Box::new(OperationApproveLiquidityHandler::new(runtime, state, op))
+            }
+            PoolOperation::TransferLiquidityFrom { .. } => Box::new(
+                OperationTransferLiquidityFromHandler::new(runtime, state, op),
+            ),
+            PoolOperation::PlaceLimitOrder { .. } => {
+                Box::new(OperationPlaceLimitOrderHandler::new(runtime, state, op))
+            }
+            PoolOperation::CancelLimitOrder { .. } => {
+                Box::new(OperationCancelLimitOrderHandler::new(runtime, state, op))
+            }
+            PoolOperation::SetPoolStatus { .. } => {
+                Box::new(OperationSetPoolStatusHandler::new(runtime, state, op))
+            }
+            PoolOperation::ClaimAll { .. } => {
+                Box::new(OperationClaimAllHandler::new(runtime, state, op))
+            }
+            PoolOperation::ZapIn { .. } => Box::new(OperationZapInHandler::new(runtime, state, op)),
+            PoolOperation::ZapOut { .. } => {
+                Box::new(OperationZapOutHandler::new(runtime, state, op))
+            }
+            PoolOperation::Sync => Box::new(OperationSyncHandler::new(runtime, state, op)),
+            PoolOperation::Skim { .. } => Box::new(OperationSkimHandler::new(runtime, state, op)),
+            PoolOperation::SetDynamicFee { .. } => {
+                Box::new(OperationSetDynamicFeeHandler::new(runtime, state, op))
+            }
+            PoolOperation::MigrateLiquidity { .. } => {
+                Box::new(OperationMigrateLiquidityHandler::new(runtime, state, op))
+            }
         }
     }
 
@@ -135,6 +208,46 @@ impl HandlerFactory {
                 Box::new(MessageNewTransactionHandler::new(runtime, state, msg))
             }
             PoolMessage::Claim { .. } => Box::new(MessageClaimHandler::new(runtime, state, msg)),
+            PoolMessage::SetProtocolFeeShare { .. } => {
+                Box::new(MessageSetProtocolFeeShareHandler::new(runtime, state, msg))
+            }
+            PoolMessage::TransferLiquidity { .. } => {
+                Box::new(MessageTransferLiquidityHandler::new(runtime, state, msg))
+            }
+            PoolMessage::ApproveLiquidity { .. } => {
+                Box::new(MessageApproveLiquidityHandler::new(runtime, state, msg))
+            }
+            PoolMessage::TransferLiquidityFrom { .. } => Box::new(
+                MessageTransferLiquidityFromHandler::new(runtime, state, msg),
+            ),
+            PoolMessage::PlaceLimitOrder { .. } => {
+                Box::new(MessagePlaceLimitOrderHandler::new(runtime, state, msg))
+            }
+            PoolMessage::CancelLimitOrder { .. } => {
+                Box::new(MessageCancelLimitOrderHandler::new(runtime, state, msg))
+            }
+            PoolMessage::SetPoolStatus { .. } => {
+                Box::new(MessageSetPoolStatusHandler::new(runtime, state, msg))
+            }
+            PoolMessage::ClaimAll { .. } => {
+                Box::new(MessageClaimAllHandler::new(runtime, state, msg))
+            }
+            PoolMessage::ZapIn { .. } => Box::new(MessageZapInHandler::new(runtime, state, msg)),
+            PoolMessage::ZapOut { .. } => Box::new(MessageZapOutHandler::new(runtime, state, msg)),
+            PoolMessage::Sync { .. } => Box::new(MessageSyncHandler::new(runtime, state, msg)),
+            PoolMessage::Skim { .. } => Box::new(MessageSkimHandler::new(runtime, state, msg)),
+            PoolMessage::SetDynamicFee { .. } => {
+                Box::new(MessageSetDynamicFeeHandler::new(runtime, state, msg))
+            }
+            PoolMessage::MigrateLiquidity { .. } => {
+                Box::new(MessageMigrateLiquidityHandler::new(runtime, state, msg))
+            }
+            PoolMessage::RequestFailed { .. } => {
+                Box::new(MessageRequestFailedHandler::new(runtime, state, msg))
+            }
+            PoolMessage::SwapSettled { .. } => {
+                Box::new(MessageSwapSettledHandler::new(runtime, state, msg))
+            }
         }
     }
 
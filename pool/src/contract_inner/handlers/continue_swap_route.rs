@@ -0,0 +1,63 @@
+use crate::interfaces::state::StateInterface;
+use abi::swap::{
+    pool::{PoolMessage, PoolResponse},
+    router::{SwapAbi, SwapOperation, SwapRoute},
+};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{Account, Amount};
+use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
+use std::{cell::RefCell, rc::Rc};
+
+// Hand the settled hop output back to router to run the next hop on origin chain
+
+pub struct ContinueSwapRouteHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: Rc<RefCell<R>>,
+    state: Rc<RefCell<S>>,
+
+    origin: Account,
+    route: SwapRoute,
+    amount: Amount,
+}
+
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> ContinueSwapRouteHandler<R, S> {
+    pub fn new(
+        runtime: Rc<RefCell<R>>,
+        state: Rc<RefCell<S>>,
+        origin: Account,
+        route: SwapRoute,
+        amount: Amount,
+    ) -> Self {
+        Self {
+            runtime,
+            state,
+
+            origin,
+            route,
+            amount,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    Handler<PoolMessage, PoolResponse> for ContinueSwapRouteHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        let router_application_id = self.state.borrow().router_application_id();
+        let call = SwapOperation::ContinueSwap {
+            origin: self.origin,
+            route: self.route.clone(),
+            amount_in: self.amount,
+        };
+
+        let _ = self
+            .runtime
+            .borrow_mut()
+            .call_application(router_application_id.with_abi::<SwapAbi>(), &call);
+
+        Ok(None)
+    }
+}
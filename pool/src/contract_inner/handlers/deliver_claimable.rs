@@ -0,0 +1,104 @@
+use crate::interfaces::state::StateInterface;
+use abi::{
+    meme::{
+        MemeAbi, MemeOperation, TransferFromApplicationReceipt,
+        TransferFromApplicationReceiptPurpose,
+    },
+    meme_token::MemeToken,
+    swap::pool::{PoolMessage, PoolResponse},
+};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{Account, AccountOwner, Amount, ApplicationId};
+use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
+use std::{cell::RefCell, rc::Rc};
+
+// Deliver claimable balance of owner through the claim path. Native is settled at once,
+// meme is settled when the claim transfer receipt comes back, and returns to claimable
+// balance if the transfer fails.
+
+pub struct DeliverClaimableHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: Rc<RefCell<R>>,
+    state: Rc<RefCell<S>>,
+
+    owner: Account,
+    token: MemeToken,
+    amount: Amount,
+}
+
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> DeliverClaimableHandler<R, S> {
+    pub fn new(
+        runtime: Rc<RefCell<R>>,
+        state: Rc<RefCell<S>>,
+        owner: Account,
+        token: MemeToken,
+        amount: Amount,
+    ) -> Self {
+        Self {
+            runtime,
+            state,
+
+            owner,
+            token,
+            amount,
+        }
+    }
+
+    fn transfer_native(&mut self) {
+        let source = AccountOwner::from(self.runtime.borrow_mut().application_id());
+        self.runtime
+            .borrow_mut()
+            .transfer(source, self.owner, self.amount);
+    }
+
+    fn transfer_fungible(&mut self, token: ApplicationId) {
+        let call = MemeOperation::TransferFromApplicationWithReceipt {
+            to: self.owner,
+            amount: self.amount,
+            receipt: TransferFromApplicationReceipt {
+                purpose: TransferFromApplicationReceiptPurpose::PoolClaim,
+                owner: self.owner,
+                token,
+                amount: self.amount,
+                result: None,
+                payload: None,
+            },
+        };
+
+        let _ = self
+            .runtime
+            .borrow_mut()
+            .call_application(token.with_abi::<MemeAbi>(), &call);
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    Handler<PoolMessage, PoolResponse> for DeliverClaimableHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        self.state
+            .borrow_mut()
+            .claim(self.token, self.owner, self.amount)
+            .await
+            .map_err(Into::into)?;
+
+        match self.token {
+            MemeToken::Native => {
+                self.transfer_native();
+                self.state
+                    .borrow_mut()
+                    .claim_success(self.token, self.owner, self.amount)
+                    .await
+                    .map_err(Into::into)?;
+            }
+            MemeToken::Fungible(application_id) => {
+                self.transfer_fungible(application_id);
+            }
+        }
+
+        Ok(None)
+    }
+}
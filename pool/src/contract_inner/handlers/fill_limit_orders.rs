@@ -0,0 +1,196 @@
+use crate::{
+    contract_inner::handlers::launch_protection::launch_protected,
+    interfaces::{parameters::ParametersInterface, state::StateInterface},
+};
+use abi::{
+    meme_token::MemeToken,
+    swap::pool::{LimitOrder, PoolMessage, PoolResponse},
+};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{AccountOwner, Amount};
+use runtime::interfaces::{
+    access_control::AccessControl, contract::ContractRuntimeContext, meme::MemeRuntimeContext,
+};
+use std::{cell::RefCell, rc::Rc};
+
+// Bound the work a single swap pays for
+const MAX_LIMIT_ORDER_FILLS: usize = 16;
+
+// Fill resting orders of one side against the pool, best limit price first. Each order is
+// filled as a whole once the pool pays at least its amount_out_min, so filling stops at the
+// first order the current price does not reach.
+
+pub struct FillLimitOrdersHandler<
+    R: ContractRuntimeContext + AccessControl + MemeRuntimeContext + ParametersInterface,
+    S: StateInterface,
+> {
+    runtime: Rc<RefCell<R>>,
+    state: Rc<RefCell<S>>,
+
+    token_0_in: bool,
+}
+
+impl<
+        R: ContractRuntimeContext + AccessControl + MemeRuntimeContext + ParametersInterface,
+        S: StateInterface,
+    > FillLimitOrdersHandler<R, S>
+{
+    pub fn new(runtime: Rc<RefCell<R>>, state: Rc<RefCell<S>>, token_0_in: bool) -> Self {
+        Self {
+            runtime,
+            state,
+
+            token_0_in,
+        }
+    }
+
+    fn calculate_amount_out(&self, order: &LimitOrder) -> Option<Amount> {
+        let pool = self.state.borrow().pool();
+        let (amount_0_in, amount_1_in) = (
+            order.amount_0_in.unwrap_or(Amount::ZERO),
+            order.amount_1_in.unwrap_or(Amount::ZERO),
+        );
+        let (amount_0_out, amount_1_out) = if self.token_0_in {
+            (
+                Amount::ZERO,
+                pool.calculate_swap_amount_1(amount_0_in).ok()?,
+            )
+        } else {
+            (
+                pool.calculate_swap_amount_0(amount_1_in).ok()?,
+                Amount::ZERO,
+            )
+        };
+        pool.validate_swap_invariant(amount_0_in, amount_1_in, amount_0_out, amount_1_out)
+            .ok()?;
+
+        Some(if self.token_0_in {
+            amount_1_out
+        } else {
+            amount_0_out
+        })
+    }
+
+    fn native_balance_insufficient(&mut self, amount_out: Amount) -> bool {
+        if !self.token_0_in || self.runtime.borrow_mut().token_1().is_some() {
+            return false;
+        }
+        let application =
+            AccountOwner::from(self.runtime.borrow_mut().application_id().forget_abi());
+        self.runtime.borrow_mut().owner_balance(application) < amount_out
+    }
+
+    async fn fill(
+        &mut self,
+        order: &LimitOrder,
+        amount_out: Amount,
+    ) -> Result<PoolMessage, HandlerError> {
+        let (amount_0_out, amount_1_out) = if self.token_0_in {
+            (Amount::ZERO, amount_out)
+        } else {
+            (amount_out, Amount::ZERO)
+        };
+        let timestamp = self.runtime.borrow_mut().system_time();
+
+        // Fills settle like any other swap, so they grow LP fees and count in the stats
+        let fee_percent_mul_100 = self
+            .state
+            .borrow_mut()
+            .commit_swap(
+                order.amount_0_in.unwrap_or(Amount::ZERO),
+                order.amount_1_in.unwrap_or(Amount::ZERO),
+                amount_0_out,
+                amount_1_out,
+                timestamp,
+            )
+            .map_err(Into::into)?;
+        self.state
+            .borrow_mut()
+            .remove_limit_order(order.order_id)
+            .await
+            .map_err(Into::into)?;
+
+        let token_out = if self.token_0_in {
+            self.runtime.borrow_mut().token_1()
+        } else {
+            Some(self.runtime.borrow_mut().token_0())
+        };
+        self.state
+            .borrow_mut()
+            .credit(
+                MemeToken::from(token_out),
+                order.to.unwrap_or(order.owner),
+                amount_out,
+            )
+            .await
+            .map_err(Into::into)?;
+
+        log::info!(
+            "Filled limit order {} of {} amount in {} amount out {}",
+            order.order_id,
+            order.owner,
+            order.amount_in(),
+            amount_out
+        );
+
+        let mut transaction = self.state.borrow_mut().build_transaction(
+            order.owner,
+            order.amount_0_in,
+            order.amount_1_in,
+            if self.token_0_in {
+                None
+            } else {
+                Some(amount_0_out)
+            },
+            if self.token_0_in {
+                Some(amount_1_out)
+            } else {
+                None
+            },
+            None,
+            timestamp,
+        );
+        transaction.fee_percent_mul_100 = Some(fee_percent_mul_100);
+        Ok(PoolMessage::NewTransaction { transaction })
+    }
+}
+
+#[async_trait(?Send)]
+impl<
+        R: ContractRuntimeContext + AccessControl + MemeRuntimeContext + ParametersInterface,
+        S: StateInterface,
+    > Handler<PoolMessage, PoolResponse> for FillLimitOrdersHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        if launch_protected(&self.runtime, &self.state) {
+            return Ok(None);
+        }
+
+        let orders = self
+            .state
+            .borrow()
+            .limit_orders(self.token_0_in, MAX_LIMIT_ORDER_FILLS)
+            .await
+            .map_err(Into::into)?;
+        // We already on creator chain
+        let destination = self.runtime.borrow_mut().chain_id();
+        let mut outcome = HandlerOutcome::new();
+
+        for order in orders.iter() {
+            let Some(amount_out) = self.calculate_amount_out(order) else {
+                break;
+            };
+            if amount_out < order.amount_out_min || self.native_balance_insufficient(amount_out) {
+                break;
+            }
+
+            let message = self.fill(order, amount_out).await?;
+            outcome.with_message(destination, message, false);
+        }
+
+        Ok(Some(outcome))
+    }
+}
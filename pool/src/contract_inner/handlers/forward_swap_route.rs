@@ -0,0 +1,126 @@
+use crate::{
+    contract_inner::handlers::continue_swap_route::ContinueSwapRouteHandler,
+    interfaces::state::StateInterface,
+};
+use abi::{
+    meme::{
+        MemeAbi, MemeOperation, TransferFromApplicationReceipt,
+        TransferFromApplicationReceiptPayload, TransferFromApplicationReceiptPurpose,
+    },
+    meme_token::MemeToken,
+    swap::{
+        pool::{ClaimTransferReceiptPayload, PoolMessage, PoolResponse},
+        router::SwapRoute,
+    },
+};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{Account, AccountOwner, Amount, ApplicationId};
+use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
+use std::{cell::RefCell, rc::Rc};
+
+// Deliver an intermediate hop output to origin through the claim path. The next hop
+// only starts after the output is settled, so native output continues immediately
+// while meme output continues when the claim transfer receipt comes back.
+
+pub struct ForwardSwapRouteHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: Rc<RefCell<R>>,
+    state: Rc<RefCell<S>>,
+
+    origin: Account,
+    token: MemeToken,
+    amount: Amount,
+    route: SwapRoute,
+}
+
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> ForwardSwapRouteHandler<R, S> {
+    pub fn new(
+        runtime: Rc<RefCell<R>>,
+        state: Rc<RefCell<S>>,
+        origin: Account,
+        token: MemeToken,
+        amount: Amount,
+        route: SwapRoute,
+    ) -> Self {
+        Self {
+            runtime,
+            state,
+
+            origin,
+            token,
+            amount,
+            route,
+        }
+    }
+
+    fn transfer_native(&mut self) {
+        let source = AccountOwner::from(self.runtime.borrow_mut().application_id());
+        self.runtime
+            .borrow_mut()
+            .transfer(source, self.origin, self.amount);
+    }
+
+    fn transfer_fungible(&mut self, token: ApplicationId) {
+        let call = MemeOperation::TransferFromApplicationWithReceipt {
+            to: self.origin,
+            amount: self.amount,
+            receipt: TransferFromApplicationReceipt {
+                purpose: TransferFromApplicationReceiptPurpose::PoolClaim,
+                owner: self.origin,
+                token,
+                amount: self.amount,
+                result: None,
+                payload: Some(TransferFromApplicationReceiptPayload::PoolClaim(
+                    ClaimTransferReceiptPayload {
+                        route: self.route.clone(),
+                    },
+                )),
+            },
+        };
+
+        let _ = self
+            .runtime
+            .borrow_mut()
+            .call_application(token.with_abi::<MemeAbi>(), &call);
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    Handler<PoolMessage, PoolResponse> for ForwardSwapRouteHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        self.state
+            .borrow_mut()
+            .claim(self.token, self.origin, self.amount)
+            .await
+            .map_err(Into::into)?;
+
+        match self.token {
+            MemeToken::Native => {
+                self.transfer_native();
+                self.state
+                    .borrow_mut()
+                    .claim_success(self.token, self.origin, self.amount)
+                    .await
+                    .map_err(Into::into)?;
+
+                ContinueSwapRouteHandler::new(
+                    self.runtime.clone(),
+                    self.state.clone(),
+                    self.origin,
+                    self.route.clone(),
+                    self.amount,
+                )
+                .handle()
+                .await
+            }
+            MemeToken::Fungible(application_id) => {
+                self.transfer_fungible(application_id);
+                Ok(None)
+            }
+        }
+    }
+}
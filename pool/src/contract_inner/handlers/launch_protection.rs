@@ -0,0 +1,19 @@
+use crate::interfaces::{parameters::ParametersInterface, state::StateInterface};
+use runtime::interfaces::contract::ContractRuntimeContext;
+use std::{cell::RefCell, rc::Rc};
+
+// Only direct swaps can apply the per account launch guards. Zaps and limit order fills
+// swap inside the pool without them, so they wait until the launch phase ends
+pub fn launch_protected<R: ContractRuntimeContext + ParametersInterface, S: StateInterface>(
+    runtime: &Rc<RefCell<R>>,
+    state: &Rc<RefCell<S>>,
+) -> bool {
+    let Some(protection) = runtime.borrow_mut().launch_protection() else {
+        return false;
+    };
+    let Some(launched_at) = state.borrow().launched_at() else {
+        return false;
+    };
+    let now = runtime.borrow_mut().system_time();
+    protection.active(launched_at, now)
+}
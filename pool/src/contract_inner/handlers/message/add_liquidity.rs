@@ -1,7 +1,10 @@
-use crate::interfaces::{parameters::ParametersInterface, state::StateInterface};
+use crate::{
+    contract_inner::handlers::record_failed_request::{failure_reason, RecordFailedRequestHandler},
+    interfaces::{parameters::ParametersInterface, state::StateInterface},
+};
 use abi::{
     meme_token::MemeToken,
-    swap::pool::{PoolMessage, PoolResponse},
+    swap::pool::{FailedRequestKind, FailureReason, PoolMessage, PoolResponse},
 };
 use async_trait::async_trait;
 use base::handler::{Handler, HandlerError, HandlerOutcome};
@@ -25,6 +28,7 @@ pub struct AddLiquidityHandler<
     amount_1_out_min: Option<Amount>,
     to: Option<Account>,
     block_timestamp: Option<Timestamp>,
+    deadline: Option<Timestamp>,
 }
 
 impl<
@@ -41,6 +45,7 @@ impl<
             amount_1_out_min,
             to,
             block_timestamp,
+            deadline,
         } = msg
         else {
             panic!("Invalid message");
@@ -57,6 +62,7 @@ impl<
             amount_1_out_min: *amount_1_out_min,
             to: *to,
             block_timestamp: *block_timestamp,
+            deadline: *deadline,
         }
     }
 
@@ -83,7 +89,26 @@ impl<
         self.credit(MemeToken::from(token_1), amount_1).await
     }
 
-    async fn calculate_amount_pair(&mut self) -> Result<Option<(Amount, Amount)>, HandlerError> {
+    async fn record_failed_request(
+        &mut self,
+        reason: FailureReason,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        RecordFailedRequestHandler::new(
+            self.runtime.clone(),
+            self.state.clone(),
+            self.origin,
+            FailedRequestKind::AddLiquidity,
+            self.amount_0_in,
+            self.amount_1_in,
+            reason,
+        )
+        .handle()
+        .await
+    }
+
+    async fn calculate_amount_pair(
+        &mut self,
+    ) -> Result<Result<(Amount, Amount), FailureReason>, HandlerError> {
         let amount_pair_result = {
             self.state.borrow().try_calculate_swap_amount_pair(
                 self.amount_0_in,
@@ -95,20 +120,20 @@ impl<
 
         let (amount_0, amount_1) = match amount_pair_result {
             Ok(amounts) => amounts,
-            Err(_) => {
+            Err(err) => {
                 self.credit_amount_pair(self.amount_0_in, self.amount_1_in)
                     .await?;
-                return Ok(None);
+                return Ok(Err(failure_reason(&err.into())));
             }
         };
 
         if amount_0 == Amount::ZERO || amount_1 == Amount::ZERO {
             self.credit_amount_pair(self.amount_0_in, self.amount_1_in)
                 .await?;
-            return Ok(None);
+            return Ok(Err(FailureReason::InvalidAmount));
         }
 
-        Ok(Some((amount_0, amount_1)))
+        Ok(Ok((amount_0, amount_1)))
     }
 
     async fn credit_excess(
@@ -146,12 +171,32 @@ impl<
         &mut self,
     ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
         // We already receive all funds here
-        let Some((amount_0, amount_1)) = self.calculate_amount_pair().await? else {
-            return Ok(None);
+        let timestamp = self.runtime.borrow_mut().system_time();
+        if self.deadline.is_some_and(|deadline| timestamp > deadline) {
+            self.credit_amount_pair(self.amount_0_in, self.amount_1_in)
+                .await?;
+            log::warn!(
+                "Failed add liquidity: {} at {}",
+                HandlerError::DeadlineExceeded,
+                timestamp
+            );
+            return self
+                .record_failed_request(FailureReason::DeadlineExceeded)
+                .await;
+        }
+        if !self.state.borrow().pool().status.deposits_enabled() {
+            self.credit_amount_pair(self.amount_0_in, self.amount_1_in)
+                .await?;
+            log::warn!("Failed add liquidity: {}", HandlerError::NotEnabled);
+            return self.record_failed_request(FailureReason::NotEnabled).await;
+        }
+
+        let (amount_0, amount_1) = match self.calculate_amount_pair().await? {
+            Ok(amounts) => amounts,
+            Err(reason) => return self.record_failed_request(reason).await,
         };
 
         let to = self.to.unwrap_or(self.origin);
-        let timestamp = self.runtime.borrow_mut().system_time();
         let liquidity_result = {
             self.state
                 .borrow_mut()
@@ -169,7 +214,9 @@ impl<
                 self.credit_amount_pair(self.amount_0_in, self.amount_1_in)
                     .await?;
                 log::warn!("Failed add liquidity after custody: {}", err);
-                return Ok(None);
+                return self
+                    .record_failed_request(failure_reason(&err.into()))
+                    .await;
             }
         };
 
@@ -70,6 +70,7 @@ where
                 amount_1_out_min: request.counterparty_amount_out_min,
                 to: request.to,
                 block_timestamp: request.block_timestamp,
+                deadline: request.deadline,
             }
         } else {
             PoolMessage::AddLiquidity {
@@ -80,6 +81,7 @@ where
                 amount_1_out_min: request.amount_out_min,
                 to: request.to,
                 block_timestamp: request.block_timestamp,
+                deadline: request.deadline,
             }
         }
     }
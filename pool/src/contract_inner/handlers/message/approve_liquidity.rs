@@ -0,0 +1,59 @@
+use crate::interfaces::state::StateInterface;
+use abi::swap::pool::{PoolMessage, PoolResponse};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{Account, Amount};
+use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct ApproveLiquidityHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    _runtime: Rc<RefCell<R>>,
+    state: S,
+
+    origin: Account,
+    spender: Account,
+    liquidity: Amount,
+}
+
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> ApproveLiquidityHandler<R, S> {
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, msg: &PoolMessage) -> Self {
+        let PoolMessage::ApproveLiquidity {
+            origin,
+            spender,
+            liquidity,
+        } = msg
+        else {
+            panic!("Invalid message");
+        };
+
+        Self {
+            state,
+            _runtime: runtime,
+
+            origin: *origin,
+            spender: *spender,
+            liquidity: *liquidity,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    Handler<PoolMessage, PoolResponse> for ApproveLiquidityHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        // Nothing moves until the spender transfers, so revoking works while the pool is paused
+        if self.liquidity > Amount::ZERO && !self.state.pool().status.withdrawals_enabled() {
+            log::warn!("Failed approve liquidity: {}", HandlerError::NotEnabled);
+            return Ok(None);
+        }
+
+        self.state
+            .approve_liquidity(self.origin, self.spender, self.liquidity)
+            .await
+            .map_err(Into::into)?;
+        Ok(None)
+    }
+}
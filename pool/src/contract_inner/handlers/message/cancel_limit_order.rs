@@ -0,0 +1,74 @@
+use crate::interfaces::state::StateInterface;
+use abi::{
+    meme_token::MemeToken,
+    swap::pool::{PoolMessage, PoolResponse},
+};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::Account;
+use runtime::interfaces::{
+    access_control::AccessControl, contract::ContractRuntimeContext, meme::MemeRuntimeContext,
+};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct CancelLimitOrderHandler<
+    R: ContractRuntimeContext + AccessControl + MemeRuntimeContext,
+    S: StateInterface,
+> {
+    runtime: Rc<RefCell<R>>,
+    state: S,
+
+    origin: Account,
+    order_id: u64,
+}
+
+impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInterface>
+    CancelLimitOrderHandler<R, S>
+{
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, msg: &PoolMessage) -> Self {
+        let PoolMessage::CancelLimitOrder { origin, order_id } = msg else {
+            panic!("Invalid message");
+        };
+
+        Self {
+            state,
+            runtime,
+
+            origin: *origin,
+            order_id: *order_id,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInterface>
+    Handler<PoolMessage, PoolResponse> for CancelLimitOrderHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        if !self.state.pool().status.withdrawals_enabled() {
+            log::warn!("Failed cancel limit order: {}", HandlerError::NotEnabled);
+            return Ok(None);
+        }
+
+        let order = self
+            .state
+            .cancel_limit_order(self.origin, self.order_id)
+            .await
+            .map_err(Into::into)?;
+
+        // Escrowed input goes back to the owner as claimable balance
+        let token = if order.amount_0_in.is_some() {
+            MemeToken::from(self.runtime.borrow_mut().token_0())
+        } else {
+            MemeToken::from(self.runtime.borrow_mut().token_1())
+        };
+        self.state
+            .credit(token, order.owner, order.amount_in())
+            .await
+            .map_err(Into::into)?;
+
+        Ok(None)
+    }
+}
@@ -5,11 +5,11 @@ use abi::{
         TransferFromApplicationReceiptPurpose,
     },
     meme_token::MemeToken,
-    swap::pool::{PoolMessage, PoolResponse},
+    swap::pool::{PoolEvent, PoolMessage, PoolResponse, POOL_EVENT_STREAM},
 };
 use async_trait::async_trait;
 use base::handler::{Handler, HandlerError, HandlerOutcome};
-use linera_sdk::linera_base_types::{Account, AccountOwner, Amount, ApplicationId};
+use linera_sdk::linera_base_types::{Account, AccountOwner, Amount, ApplicationId, StreamName};
 use runtime::interfaces::{
     access_control::AccessControl, contract::ContractRuntimeContext, meme::MemeRuntimeContext,
 };
@@ -110,10 +110,25 @@ impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInt
         assert!(self.amount > Amount::ZERO, "Invalid amount");
 
         self.state.pool().validate_token(self.token);
+        if !self.state.pool().status.withdrawals_enabled() {
+            log::warn!("Failed claim: {}", HandlerError::NotEnabled);
+            return Ok(None);
+        }
 
         let token = MemeToken::from(self.token);
         self.claim(token).await?;
 
-        Ok(None)
+        let mut outcome = HandlerOutcome::new();
+        outcome.with_event(
+            StreamName::from(POOL_EVENT_STREAM),
+            &PoolEvent::Claim {
+                owner: self.origin,
+                token: self.token,
+                amount: self.amount,
+                created_at: self.runtime.borrow_mut().system_time(),
+            },
+        );
+
+        Ok(Some(outcome))
     }
 }
@@ -0,0 +1,105 @@
+use crate::{
+    contract_inner::handlers::deliver_claimable::DeliverClaimableHandler,
+    interfaces::state::StateInterface,
+};
+use abi::{
+    meme_token::MemeToken,
+    swap::pool::{PoolEvent, PoolMessage, PoolResponse, POOL_EVENT_STREAM},
+};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{Account, Amount, ApplicationId, StreamName};
+use runtime::interfaces::{
+    access_control::AccessControl, contract::ContractRuntimeContext, meme::MemeRuntimeContext,
+};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct ClaimAllHandler<
+    R: ContractRuntimeContext + AccessControl + MemeRuntimeContext,
+    S: StateInterface,
+> {
+    runtime: Rc<RefCell<R>>,
+    state: Rc<RefCell<S>>,
+
+    origin: Account,
+    tokens: Option<Vec<Option<ApplicationId>>>,
+}
+
+impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInterface>
+    ClaimAllHandler<R, S>
+{
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, msg: &PoolMessage) -> Self {
+        let PoolMessage::ClaimAll { origin, tokens } = msg else {
+            panic!("Invalid message");
+        };
+
+        Self {
+            runtime,
+            state: Rc::new(RefCell::new(state)),
+
+            origin: *origin,
+            tokens: tokens.clone(),
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInterface>
+    Handler<PoolMessage, PoolResponse> for ClaimAllHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        let pool = self.state.borrow().pool();
+        if !pool.status.withdrawals_enabled() {
+            log::warn!("Failed claim all: {}", HandlerError::NotEnabled);
+            return Ok(None);
+        }
+
+        let tokens = self
+            .tokens
+            .clone()
+            .unwrap_or_else(|| vec![Some(pool.token_0), pool.token_1]);
+
+        let created_at = self.runtime.borrow_mut().system_time();
+        let mut outcome = HandlerOutcome::new();
+
+        for token_id in tokens {
+            pool.validate_token(token_id);
+
+            // Duplicated tokens are already drained by the first claim
+            let token = MemeToken::from(token_id);
+            let amount = self
+                .state
+                .borrow()
+                .claimable_balance(token, self.origin)
+                .await
+                .map_err(Into::into)?;
+            if amount == Amount::ZERO {
+                continue;
+            }
+
+            DeliverClaimableHandler::new(
+                self.runtime.clone(),
+                self.state.clone(),
+                self.origin,
+                token,
+                amount,
+            )
+            .handle()
+            .await?;
+
+            outcome.with_event(
+                StreamName::from(POOL_EVENT_STREAM),
+                &PoolEvent::Claim {
+                    owner: self.origin,
+                    token: token_id,
+                    amount,
+                    created_at,
+                },
+            );
+        }
+
+        Ok(Some(outcome))
+    }
+}
@@ -1,4 +1,7 @@
-use crate::interfaces::state::StateInterface;
+use crate::{
+    contract_inner::handlers::continue_swap_route::ContinueSwapRouteHandler,
+    interfaces::state::StateInterface,
+};
 use abi::{
     meme_token::MemeToken,
     swap::pool::{ClaimTransferReceipt, PoolMessage, PoolResponse},
@@ -11,7 +14,7 @@ use std::{cell::RefCell, rc::Rc};
 pub struct ClaimTransferReceiptHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface>
 {
     runtime: Rc<RefCell<R>>,
-    state: S,
+    state: Rc<RefCell<S>>,
     receipt: ClaimTransferReceipt,
 }
 
@@ -24,7 +27,7 @@ impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
         };
         Self {
             runtime,
-            state,
+            state: Rc::new(RefCell::new(state)),
             receipt: receipt.clone(),
         }
     }
@@ -48,24 +51,43 @@ impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
             self.receipt.amount > linera_sdk::linera_base_types::Amount::ZERO,
             "Invalid amount"
         );
-        self.state.pool().validate_token(Some(self.receipt.token));
+        self.state
+            .borrow()
+            .pool()
+            .validate_token(Some(self.receipt.token));
 
         let token = MemeToken::Fungible(self.receipt.token);
         match &self.receipt.result {
             Ok(()) => {
                 self.state
+                    .borrow_mut()
                     .claim_success(token, self.receipt.owner, self.receipt.amount)
                     .await
                     .map_err(Into::into)?;
             }
             Err(_) => {
+                // A failed routed hop stays claimable for the owner and the route stops here
                 self.state
+                    .borrow_mut()
                     .claim_fail(token, self.receipt.owner, self.receipt.amount)
                     .await
                     .map_err(Into::into)?;
+                return Ok(None);
             }
         }
 
-        Ok(None)
+        let Some(route) = self.receipt.route.clone() else {
+            return Ok(None);
+        };
+
+        ContinueSwapRouteHandler::new(
+            self.runtime.clone(),
+            self.state.clone(),
+            self.receipt.owner,
+            route,
+            self.receipt.amount,
+        )
+        .handle()
+        .await
     }
 }
@@ -1,4 +1,7 @@
-use crate::interfaces::{parameters::ParametersInterface, state::StateInterface};
+use crate::{
+    contract_inner::handlers::record_failed_request::RecordFailedRequestHandler,
+    interfaces::{parameters::ParametersInterface, state::StateInterface},
+};
 use abi::{
     meme::{
         MemeAbi, MemeOperation, TransferFromApplicationReceipt,
@@ -6,8 +9,8 @@ use abi::{
     },
     meme_token::MemeToken,
     swap::pool::{
-        AddLiquidityTransferReceiptPayload, FundRequest, FundType, PoolMessage, PoolResponse,
-        SwapTransferReceiptPayload,
+        AddLiquidityTransferReceiptPayload, FailedRequestKind, FailureReason, FundRequest,
+        FundType, PoolMessage, PoolResponse, SwapTransferReceiptPayload,
     },
 };
 use async_trait::async_trait;
@@ -102,12 +105,35 @@ where
             .map_err(Into::into)
     }
 
-    async fn settle_failed_transfer(&mut self) -> Result<(), HandlerError> {
+    // Liquidity migrations deposit right after the old pool delivers the underlying, so an
+    // input not settled on origin yet must not fail silently
+    async fn settle_failed_transfer(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
         if let Some(prev) = self.prev.clone() {
             self.credit_request(&prev).await?;
         }
 
-        Ok(())
+        let token_0 = self.runtime.borrow_mut().token_0();
+        let amount_in = self.request.amount_in;
+        let counterparty_amount_in = self.request.counterparty_amount_in.unwrap_or(Amount::ZERO);
+        let (amount_0, amount_1) = if self.request.token == Some(token_0) {
+            (amount_in, counterparty_amount_in)
+        } else {
+            (counterparty_amount_in, amount_in)
+        };
+
+        RecordFailedRequestHandler::new(
+            self.runtime.clone(),
+            self.state.clone(),
+            self.request.from,
+            FailedRequestKind::AddLiquidity,
+            amount_0,
+            amount_1,
+            FailureReason::TransferFailed,
+        )
+        .handle()
+        .await
     }
 
     fn fund_pool_chain_for_add_liquidity(&mut self) {
@@ -190,14 +216,16 @@ where
 
         if self.result.is_err() {
             if self.request.fund_type == FundType::AddLiquidity {
-                self.settle_failed_transfer().await?;
+                return self.settle_failed_transfer().await;
             }
             return Ok(None);
         }
 
         match self.request.fund_type {
             FundType::AddLiquidity => self.fund_pool_chain_for_add_liquidity(),
-            FundType::Swap => self.fund_pool_chain_for_swap(),
+            FundType::Swap | FundType::LimitOrder | FundType::ZapIn => {
+                self.fund_pool_chain_for_swap()
+            }
             FundType::InitializeLiquidity => {
                 panic!("FundRequest is not enabled for InitializeLiquidity")
             }
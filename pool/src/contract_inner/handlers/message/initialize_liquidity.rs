@@ -54,7 +54,7 @@ impl<R: ContractRuntimeContext + AccessControl + ParametersInterface, S: StateIn
         &mut self,
     ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
         let BootstrapPolicy::MemeInitializeLiquidity {
-            virtual_initial_liquidity: _,
+            virtual_initial_liquidity,
         } = self.runtime.borrow_mut().bootstrap_policy()
         else {
             panic!("InitializeLiquidity message is only valid for meme initialization");
@@ -71,6 +71,12 @@ impl<R: ContractRuntimeContext + AccessControl + ParametersInterface, S: StateIn
             .initialize_liquidity(self.amount_0_in, self.amount_1_in, to, timestamp)
             .await
             .map_err(Into::into)?;
+        // Meme creator chain never funds the native side of virtual initial liquidity
+        if virtual_initial_liquidity && self.runtime.borrow_mut().token_1().is_none() {
+            self.state
+                .borrow_mut()
+                .set_virtual_reserve_1(self.amount_1_in);
+        }
 
         let transaction = self.state.borrow_mut().build_transaction(
             self.origin,
@@ -0,0 +1,178 @@
+use crate::{
+    contract_inner::handlers::deliver_claimable::DeliverClaimableHandler,
+    interfaces::{parameters::ParametersInterface, state::StateInterface},
+};
+use abi::{
+    meme_token::MemeToken,
+    swap::{
+        pool::{PoolMessage, PoolResponse},
+        router::{SwapAbi, SwapOperation},
+    },
+};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{Account, Amount, Timestamp};
+use runtime::interfaces::{
+    access_control::AccessControl, contract::ContractRuntimeContext, meme::MemeRuntimeContext,
+};
+use std::{cell::RefCell, rc::Rc};
+
+// Burn liquidity like RemoveLiquidity, deliver the underlying back to origin and let router
+// deposit it into the latest pool version of the pair from there, as routed swap hops do
+
+pub struct MigrateLiquidityHandler<
+    R: ContractRuntimeContext + AccessControl + ParametersInterface + MemeRuntimeContext,
+    S: StateInterface,
+> {
+    runtime: Rc<RefCell<R>>,
+    state: Rc<RefCell<S>>,
+
+    origin: Account,
+    liquidity: Amount,
+    amount_0_out_min: Option<Amount>,
+    amount_1_out_min: Option<Amount>,
+    deadline: Option<Timestamp>,
+}
+
+impl<
+        R: ContractRuntimeContext + AccessControl + ParametersInterface + MemeRuntimeContext,
+        S: StateInterface,
+    > MigrateLiquidityHandler<R, S>
+{
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, msg: &PoolMessage) -> Self {
+        let PoolMessage::MigrateLiquidity {
+            origin,
+            liquidity,
+            amount_0_out_min,
+            amount_1_out_min,
+            deadline,
+        } = msg
+        else {
+            panic!("Invalid message");
+        };
+
+        Self {
+            state: Rc::new(RefCell::new(state)),
+            runtime,
+
+            origin: *origin,
+            liquidity: *liquidity,
+            amount_0_out_min: *amount_0_out_min,
+            amount_1_out_min: *amount_1_out_min,
+            deadline: *deadline,
+        }
+    }
+
+    async fn deliver(&mut self, token: MemeToken, amount: Amount) -> Result<(), HandlerError> {
+        if amount == Amount::ZERO {
+            return Ok(());
+        }
+
+        self.state
+            .borrow_mut()
+            .credit(token, self.origin, amount)
+            .await
+            .map_err(Into::into)?;
+        DeliverClaimableHandler::new(
+            self.runtime.clone(),
+            self.state.clone(),
+            self.origin,
+            token,
+            amount,
+        )
+        .handle()
+        .await?;
+        Ok(())
+    }
+}
+
+#[async_trait(?Send)]
+impl<
+        R: ContractRuntimeContext + AccessControl + ParametersInterface + MemeRuntimeContext,
+        S: StateInterface,
+    > Handler<PoolMessage, PoolResponse> for MigrateLiquidityHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        assert!(
+            self.state.borrow().has_finalized_reserve_share_facts(),
+            "Pool is not ready"
+        );
+
+        // Liquidity is only burnt here, so an expired migration leaves the shares untouched
+        let now = self.runtime.borrow_mut().system_time();
+        if self.deadline.is_some_and(|deadline| now > deadline) {
+            log::warn!(
+                "Failed migrate liquidity: {} at {}",
+                HandlerError::DeadlineExceeded,
+                now
+            );
+            return Ok(None);
+        }
+        if !self.state.borrow().pool().status.withdrawals_enabled() {
+            log::warn!("Failed migrate liquidity: {}", HandlerError::NotEnabled);
+            return Ok(None);
+        }
+
+        let (amount_0, amount_1) = self
+            .state
+            .borrow_mut()
+            .remove_liquidity(
+                self.origin,
+                self.liquidity,
+                self.amount_0_out_min,
+                self.amount_1_out_min,
+                now,
+            )
+            .await
+            .map_err(Into::into)?;
+
+        let token_0 = self.runtime.borrow_mut().token_0();
+        let token_1 = self.runtime.borrow_mut().token_1();
+        self.deliver(MemeToken::from(token_0), amount_0).await?;
+        self.deliver(MemeToken::from(token_1), amount_1).await?;
+
+        // The deposit is funded by origin. If the underlying is not settled there yet the
+        // funding fails, origin keeps the underlying and records a TransferFailed request
+        if amount_0 > Amount::ZERO && amount_1 > Amount::ZERO {
+            let call = SwapOperation::MigrateLiquidity {
+                origin: self.origin,
+                token_0,
+                token_1,
+                pool_fee_percent_mul_100: self.state.borrow().pool().pool_fee_percent_mul_100,
+                amount_0,
+                amount_1,
+                amount_0_out_min: self.amount_0_out_min,
+                amount_1_out_min: self.amount_1_out_min,
+                deadline: self.deadline,
+            };
+            let router_application_id = self.state.borrow().router_application_id();
+            let _ = self
+                .runtime
+                .borrow_mut()
+                .call_application(router_application_id.with_abi::<SwapAbi>(), &call);
+        }
+
+        let transaction = self.state.borrow_mut().build_transaction(
+            self.origin,
+            None,
+            None,
+            Some(amount_0),
+            Some(amount_1),
+            Some(self.liquidity),
+            now,
+        );
+
+        let destination = self.runtime.borrow_mut().chain_id();
+        let mut outcome = HandlerOutcome::new();
+
+        outcome.with_message(
+            destination,
+            PoolMessage::NewTransaction { transaction },
+            false,
+        );
+
+        Ok(Some(outcome))
+    }
+}
@@ -1,11 +1,12 @@
 use crate::interfaces::{parameters::ParametersInterface, state::StateInterface};
 use abi::swap::{
-    pool::{PoolMessage, PoolResponse},
+    pool::{PoolEvent, PoolMessage, PoolResponse, POOL_EVENT_STREAM},
     router::{SwapAbi, SwapOperation},
-    transaction::Transaction,
+    transaction::{Transaction, TransactionType},
 };
 use async_trait::async_trait;
 use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::StreamName;
 use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
 use std::{cell::RefCell, rc::Rc};
 
@@ -34,6 +35,32 @@ impl<R: ContractRuntimeContext + AccessControl + ParametersInterface, S: StateIn
             transaction: *transaction,
         }
     }
+
+    fn event(&self) -> PoolEvent {
+        let (token_0_price, token_1_price) = self.state.calculate_price_pair();
+        let reserve_0 = self.state.reserve_0();
+        let reserve_1 = self.state.reserve_1();
+
+        match self.transaction.transaction_type {
+            TransactionType::BuyToken0 | TransactionType::SellToken0 => PoolEvent::Swap {
+                transaction: self.transaction,
+                token_0_price,
+                token_1_price,
+                reserve_0,
+                reserve_1,
+            },
+            TransactionType::AddLiquidity => PoolEvent::AddLiquidity {
+                transaction: self.transaction,
+                reserve_0,
+                reserve_1,
+            },
+            TransactionType::RemoveLiquidity => PoolEvent::RemoveLiquidity {
+                transaction: self.transaction,
+                reserve_0,
+                reserve_1,
+            },
+        }
+    }
 }
 
 #[async_trait(?Send)]
@@ -47,6 +74,8 @@ impl<R: ContractRuntimeContext + AccessControl + ParametersInterface, S: StateIn
         let reserve_0 = self.state.reserve_0();
         let reserve_1 = self.state.reserve_1();
 
+        // Contracts could only read events of their own application, so router still needs the
+        // call to keep its pool catalog
         let token_0 = self.runtime.borrow_mut().token_0();
         let token_1 = self.runtime.borrow_mut().token_1();
 
@@ -58,11 +87,16 @@ impl<R: ContractRuntimeContext + AccessControl + ParametersInterface, S: StateIn
             token_1_price,
             reserve_0,
             reserve_1,
+            stats: self.state.stats(),
         };
         let _ = self.runtime.borrow_mut().call_application(
             self.state.router_application_id().with_abi::<SwapAbi>(),
             &call,
         );
-        Ok(None)
+
+        let mut outcome = HandlerOutcome::new();
+        outcome.with_event(StreamName::from(POOL_EVENT_STREAM), &self.event());
+
+        Ok(Some(outcome))
     }
 }
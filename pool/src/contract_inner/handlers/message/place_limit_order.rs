@@ -0,0 +1,126 @@
+use crate::{
+    contract_inner::handlers::fill_limit_orders::FillLimitOrdersHandler,
+    interfaces::{parameters::ParametersInterface, state::StateInterface},
+};
+use abi::{
+    meme_token::MemeToken,
+    swap::pool::{PoolMessage, PoolResponse},
+};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{Account, Amount};
+use runtime::interfaces::{
+    access_control::AccessControl, contract::ContractRuntimeContext, meme::MemeRuntimeContext,
+};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct PlaceLimitOrderHandler<
+    R: ContractRuntimeContext + AccessControl + MemeRuntimeContext + ParametersInterface,
+    S: StateInterface,
+> {
+    runtime: Rc<RefCell<R>>,
+    state: Rc<RefCell<S>>,
+
+    origin: Account,
+    amount_0_in: Option<Amount>,
+    amount_1_in: Option<Amount>,
+    amount_out_min: Amount,
+    to: Option<Account>,
+}
+
+impl<
+        R: ContractRuntimeContext + AccessControl + MemeRuntimeContext + ParametersInterface,
+        S: StateInterface,
+    > PlaceLimitOrderHandler<R, S>
+{
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, msg: &PoolMessage) -> Self {
+        let PoolMessage::PlaceLimitOrder {
+            origin,
+            amount_0_in,
+            amount_1_in,
+            amount_out_min,
+            to,
+        } = msg
+        else {
+            panic!("Invalid message");
+        };
+
+        Self {
+            state: Rc::new(RefCell::new(state)),
+            runtime,
+
+            origin: *origin,
+            amount_0_in: *amount_0_in,
+            amount_1_in: *amount_1_in,
+            amount_out_min: *amount_out_min,
+            to: *to,
+        }
+    }
+
+    async fn refund(&mut self) -> Result<(), HandlerError> {
+        let (token, amount) = match (self.amount_0_in, self.amount_1_in) {
+            (Some(amount), _) => (MemeToken::from(self.runtime.borrow_mut().token_0()), amount),
+            (None, Some(amount)) => (MemeToken::from(self.runtime.borrow_mut().token_1()), amount),
+            (None, None) => return Ok(()),
+        };
+
+        self.state
+            .borrow_mut()
+            .credit(token, self.origin, amount)
+            .await
+            .map_err(Into::into)
+    }
+}
+
+#[async_trait(?Send)]
+impl<
+        R: ContractRuntimeContext + AccessControl + MemeRuntimeContext + ParametersInterface,
+        S: StateInterface,
+    > Handler<PoolMessage, PoolResponse> for PlaceLimitOrderHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        // Input is already funded, so refund instead of failing
+        if !self.state.borrow().has_finalized_reserve_share_facts()
+            || !self.state.borrow().pool().status.swaps_enabled()
+            || self.amount_0_in.is_some() == self.amount_1_in.is_some()
+            || self.amount_out_min == Amount::ZERO
+        {
+            self.refund().await?;
+            return Ok(None);
+        }
+
+        let timestamp = self.runtime.borrow_mut().system_time();
+        let order = self
+            .state
+            .borrow_mut()
+            .place_limit_order(
+                self.origin,
+                self.amount_0_in,
+                self.amount_1_in,
+                self.amount_out_min,
+                self.to,
+                timestamp,
+            )
+            .await
+            .map_err(Into::into)?;
+
+        log::info!(
+            "Placed limit order {} of {} amount in {} amount out min {}",
+            order.order_id,
+            order.owner,
+            order.amount_in(),
+            order.amount_out_min
+        );
+
+        // The order may already be marketable at the current price
+        FillLimitOrdersHandler::new(
+            self.runtime.clone(),
+            self.state.clone(),
+            order.amount_0_in.is_some(),
+        )
+        .handle()
+        .await
+    }
+}
@@ -22,6 +22,7 @@ pub struct RemoveLiquidityHandler<
     amount_1_out_min: Option<Amount>,
     to: Option<Account>,
     block_timestamp: Option<Timestamp>,
+    deadline: Option<Timestamp>,
 }
 
 impl<
@@ -37,6 +38,7 @@ impl<
             amount_1_out_min,
             to,
             block_timestamp,
+            deadline,
         } = msg
         else {
             panic!("Invalid message");
@@ -52,6 +54,7 @@ impl<
             amount_1_out_min: *amount_1_out_min,
             to: *to,
             block_timestamp: *block_timestamp,
+            deadline: *deadline,
         }
     }
 
@@ -87,9 +90,22 @@ impl<
             "Pool is not ready"
         );
 
-        let timestamp = self
-            .block_timestamp
-            .unwrap_or(self.runtime.borrow_mut().system_time());
+        // Liquidity is only burnt here, so an expired removal leaves the shares untouched
+        let now = self.runtime.borrow_mut().system_time();
+        if self.deadline.is_some_and(|deadline| now > deadline) {
+            log::warn!(
+                "Failed remove liquidity: {} at {}",
+                HandlerError::DeadlineExceeded,
+                now
+            );
+            return Ok(None);
+        }
+        if !self.state.borrow().pool().status.withdrawals_enabled() {
+            log::warn!("Failed remove liquidity: {}", HandlerError::NotEnabled);
+            return Ok(None);
+        }
+
+        let timestamp = self.block_timestamp.unwrap_or(now);
         let (amount_0, amount_1) = self
             .state
             .borrow_mut()
@@ -0,0 +1,56 @@
+use crate::interfaces::state::StateInterface;
+use abi::swap::pool::{FailedRequest, PoolMessage, PoolResponse};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::Account;
+use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct RequestFailedHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: Rc<RefCell<R>>,
+    state: S,
+
+    origin: Account,
+    request: FailedRequest,
+}
+
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> RequestFailedHandler<R, S> {
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, msg: &PoolMessage) -> Self {
+        let PoolMessage::RequestFailed { origin, request } = msg else {
+            panic!("Invalid message");
+        };
+
+        Self {
+            state,
+            runtime,
+
+            origin: *origin,
+            request: request.clone(),
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    Handler<PoolMessage, PoolResponse> for RequestFailedHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        let creator_chain_id = self.runtime.borrow_mut().application_creator_chain_id();
+        assert_eq!(
+            self.runtime.borrow_mut().message_origin_chain_id(),
+            Some(creator_chain_id),
+            "Invalid request failed origin"
+        );
+        let chain_id = self.runtime.borrow_mut().chain_id();
+        assert_eq!(chain_id, self.origin.chain_id, "Invalid request origin");
+
+        self.state
+            .record_failed_request(self.origin, self.request.clone())
+            .await
+            .map_err(Into::into)?;
+
+        Ok(None)
+    }
+}
@@ -0,0 +1,66 @@
+use crate::interfaces::{parameters::ParametersInterface, state::StateInterface};
+use abi::swap::{
+    pool::{PoolMessage, PoolResponse, PoolStatus},
+    router::{SwapAbi, SwapOperation},
+};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::Account;
+use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct SetPoolStatusHandler<
+    R: ContractRuntimeContext + AccessControl + ParametersInterface,
+    S: StateInterface,
+> {
+    runtime: Rc<RefCell<R>>,
+    state: S,
+
+    operator: Account,
+    status: PoolStatus,
+}
+
+impl<R: ContractRuntimeContext + AccessControl + ParametersInterface, S: StateInterface>
+    SetPoolStatusHandler<R, S>
+{
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, msg: &PoolMessage) -> Self {
+        let PoolMessage::SetPoolStatus { operator, status } = msg else {
+            panic!("Invalid message");
+        };
+
+        Self {
+            state,
+            runtime,
+
+            operator: *operator,
+            status: *status,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl + ParametersInterface, S: StateInterface>
+    Handler<PoolMessage, PoolResponse> for SetPoolStatusHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        self.state.set_pool_status(self.operator, self.status);
+
+        log::info!("Pool status set to {:?} by {}", self.status, self.operator);
+
+        // Keep swap catalog in sync so routes avoid paused pools
+        let token_0 = self.runtime.borrow_mut().token_0();
+        let token_1 = self.runtime.borrow_mut().token_1();
+        let call = SwapOperation::UpdatePoolStatus {
+            token_0,
+            token_1,
+            status: self.status,
+        };
+        let _ = self.runtime.borrow_mut().call_application(
+            self.state.router_application_id().with_abi::<SwapAbi>(),
+            &call,
+        );
+        Ok(None)
+    }
+}
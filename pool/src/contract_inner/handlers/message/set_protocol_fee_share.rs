@@ -0,0 +1,67 @@
+use crate::interfaces::state::StateInterface;
+use abi::swap::pool::{PoolEvent, PoolMessage, PoolResponse, POOL_EVENT_STREAM};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{Account, StreamName};
+use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct SetProtocolFeeShareHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+{
+    runtime: Rc<RefCell<R>>,
+    state: S,
+
+    operator: Account,
+    protocol_fee_share_denominator: u16,
+}
+
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    SetProtocolFeeShareHandler<R, S>
+{
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, msg: &PoolMessage) -> Self {
+        let PoolMessage::SetProtocolFeeShare {
+            operator,
+            protocol_fee_share_denominator,
+        } = msg
+        else {
+            panic!("Invalid message");
+        };
+
+        Self {
+            state,
+            runtime,
+
+            operator: *operator,
+            protocol_fee_share_denominator: *protocol_fee_share_denominator,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    Handler<PoolMessage, PoolResponse> for SetProtocolFeeShareHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        self.state
+            .set_protocol_fee_share(self.operator, self.protocol_fee_share_denominator)
+            .await
+            .map_err(Into::into)?;
+
+        let pool = self.state.pool();
+        let mut outcome = HandlerOutcome::new();
+        outcome.with_event(
+            StreamName::from(POOL_EVENT_STREAM),
+            &PoolEvent::FeeChange {
+                operator: self.operator,
+                pool_fee_percent_mul_100: pool.pool_fee_percent_mul_100,
+                protocol_fee_share_denominator: pool.protocol_fee_share_denominator,
+                dynamic_fee: pool.dynamic_fee,
+                created_at: self.runtime.borrow_mut().system_time(),
+            },
+        );
+
+        Ok(Some(outcome))
+    }
+}
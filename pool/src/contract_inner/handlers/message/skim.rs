@@ -0,0 +1,75 @@
+use crate::interfaces::state::StateInterface;
+use abi::{
+    meme_token::MemeToken,
+    swap::pool::{PoolMessage, PoolResponse},
+};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{Account, AccountOwner, Amount};
+use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct SkimHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: Rc<RefCell<R>>,
+    state: S,
+
+    operator: Account,
+    to: Account,
+}
+
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> SkimHandler<R, S> {
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, msg: &PoolMessage) -> Self {
+        let PoolMessage::Skim { operator, to } = msg else {
+            panic!("Invalid message");
+        };
+
+        Self {
+            state,
+            runtime,
+
+            operator: *operator,
+            to: *to,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    Handler<PoolMessage, PoolResponse> for SkimHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        let pool = self.state.pool();
+        assert!(pool.fee_to_setter == self.operator, "Invalid operator");
+
+        // Meme ledgers live on the meme creator chain, only native holdings are known here
+        if pool.token_1.is_some() {
+            return Err(HandlerError::NotEnabled);
+        }
+
+        let application = AccountOwner::from(self.runtime.borrow_mut().application_id());
+        let balance = self.runtime.borrow_mut().owner_balance(application);
+        let obligations = self
+            .state
+            .obligations(MemeToken::Native)
+            .await
+            .map_err(Into::into)?;
+        // Virtual initial liquidity is counted in the reserve but never held
+        let held_reserve_1 = pool
+            .reserve_1
+            .saturating_sub(self.state.virtual_reserve_1());
+        let surplus = balance.saturating_sub(held_reserve_1.try_add(obligations)?);
+        if surplus == Amount::ZERO {
+            return Ok(None);
+        }
+
+        log::info!("Skimmed {} to {} by {}", surplus, self.to, self.operator);
+
+        self.runtime
+            .borrow_mut()
+            .transfer(application, self.to, surplus);
+
+        Ok(None)
+    }
+}
@@ -1,7 +1,22 @@
-use crate::interfaces::{parameters::ParametersInterface, state::StateInterface};
+use crate::{
+    contract_inner::handlers::{
+        deliver_claimable::DeliverClaimableHandler,
+        fill_limit_orders::FillLimitOrdersHandler,
+        forward_swap_route::ForwardSwapRouteHandler,
+        record_failed_request::{failure_reason, RecordFailedRequestHandler},
+    },
+    interfaces::{parameters::ParametersInterface, state::StateInterface},
+};
 use abi::{
     meme_token::MemeToken,
-    swap::pool::{PoolError, PoolMessage, PoolResponse},
+    swap::{
+        pool::{
+            FailedRequestKind, FailureReason, LaunchProtection, PoolError, PoolMessage,
+            PoolResponse, SwapSettlement, SwapSettlementStatus,
+        },
+        router::SwapRoute,
+        transaction::Transaction,
+    },
 };
 use async_trait::async_trait;
 use base::handler::{Handler, HandlerError, HandlerOutcome};
@@ -25,6 +40,11 @@ pub struct SwapHandler<
     amount_1_out_min: Option<Amount>,
     to: Option<Account>,
     block_timestamp: Option<Timestamp>,
+    route: Option<SwapRoute>,
+    amount_0_out: Option<Amount>,
+    amount_1_out: Option<Amount>,
+    deadline: Option<Timestamp>,
+    auto_claim: bool,
 }
 
 impl<
@@ -41,6 +61,11 @@ impl<
             amount_1_out_min,
             to,
             block_timestamp,
+            route,
+            amount_0_out,
+            amount_1_out,
+            deadline,
+            auto_claim,
         } = msg
         else {
             panic!("Invalid message");
@@ -57,6 +82,11 @@ impl<
             amount_1_out_min: *amount_1_out_min,
             to: *to,
             block_timestamp: *block_timestamp,
+            route: route.clone(),
+            amount_0_out: *amount_0_out,
+            amount_1_out: *amount_1_out,
+            deadline: *deadline,
+            auto_claim: *auto_claim,
         }
     }
 
@@ -115,6 +145,150 @@ impl<
             .await
     }
 
+    // Exact output only consumes the input it needs including the launch extra fee, the rest
+    // of the funded maximum goes back to the origin right away
+    async fn consume_exact_out_amount_in(
+        &mut self,
+        origin: Account,
+        amount_0_in: Option<Amount>,
+        amount_1_in: Option<Amount>,
+        now: Timestamp,
+    ) -> Result<(Option<Amount>, Option<Amount>), HandlerError> {
+        let pool = self.state.borrow().pool();
+        let (amount_in_max, amount_in) = match (
+            self.amount_0_out,
+            self.amount_1_out,
+            amount_0_in,
+            amount_1_in,
+        ) {
+            (None, None, _, _) => return Ok((amount_0_in, amount_1_in)),
+            (Some(amount_0_out), None, None, Some(amount_1_in_max)) => (
+                amount_1_in_max,
+                pool.calculate_swap_amount_1_in(amount_0_out),
+            ),
+            (None, Some(amount_1_out), Some(amount_0_in_max), None) => (
+                amount_0_in_max,
+                pool.calculate_swap_amount_0_in(amount_1_out),
+            ),
+            _ => {
+                self.credit_amount_in(origin, amount_0_in, amount_1_in)
+                    .await?;
+                return Err(HandlerError::InvalidAmount);
+            }
+        };
+
+        let launch_phase = self.launch_phase(now);
+        let amount_in = amount_in.map(|amount_in| match &launch_phase {
+            Some((protection, launched_at)) => {
+                protection.amount_in_with_extra_fee(amount_in, *launched_at, now)
+            }
+            None => amount_in,
+        });
+        let amount_in = match amount_in {
+            Ok(amount_in) if amount_in <= amount_in_max => amount_in,
+            Ok(amount_in) => {
+                self.credit_amount_in(origin, amount_0_in, amount_1_in)
+                    .await?;
+                log::warn!(
+                    "DEBUG POOL: Amount in {} exceeds maximum {}",
+                    amount_in,
+                    amount_in_max
+                );
+                return Err(HandlerError::InvalidAmount);
+            }
+            Err(err) => {
+                self.credit_amount_in(origin, amount_0_in, amount_1_in)
+                    .await?;
+                return Err(err.into());
+            }
+        };
+
+        let remainder = amount_in_max.try_sub(amount_in)?;
+        if amount_0_in.is_some() {
+            self.credit_amount_in(origin, Some(remainder), None).await?;
+            Ok((Some(amount_in), None))
+        } else {
+            self.credit_amount_in(origin, None, Some(remainder)).await?;
+            Ok((None, Some(amount_in)))
+        }
+    }
+
+    fn launch_phase(&mut self, now: Timestamp) -> Option<(LaunchProtection, Timestamp)> {
+        let protection = self.runtime.borrow_mut().launch_protection()?;
+        let launched_at = self.state.borrow().launched_at()?;
+        protection
+            .active(launched_at, now)
+            .then_some((protection, launched_at))
+    }
+
+    // Meme pools may reject swaps of the launch phase, or keep an extra fee of the input in
+    // the reserves
+    async fn launch_extra_fee(
+        &mut self,
+        origin: Account,
+        amount_0_in: Option<Amount>,
+        amount_1_in: Option<Amount>,
+        now: Timestamp,
+    ) -> Result<Amount, HandlerError> {
+        let Some((protection, launched_at)) = self.launch_phase(now) else {
+            return Ok(Amount::ZERO);
+        };
+
+        let (amount_in, reserve_in) = match amount_0_in {
+            Some(amount_0_in) => (amount_0_in, self.state.borrow().reserve_0()),
+            None => (
+                amount_1_in.unwrap_or(Amount::ZERO),
+                self.state.borrow().reserve_1(),
+            ),
+        };
+        if let Some(max_trade) = protection.max_trade(reserve_in) {
+            if amount_in > max_trade {
+                self.credit_amount_in(origin, amount_0_in, amount_1_in)
+                    .await?;
+                log::warn!(
+                    "DEBUG POOL: Amount in {} exceeds launch max trade {}",
+                    amount_in,
+                    max_trade
+                );
+                return Err(HandlerError::InvalidAmount);
+            }
+        }
+
+        let last_swap_at = self
+            .state
+            .borrow()
+            .last_swap_at(origin)
+            .await
+            .map_err(Into::into)?;
+        if let Some(last_swap_at) = last_swap_at {
+            if now < protection.cooldown_ends_at(last_swap_at) {
+                self.credit_amount_in(origin, amount_0_in, amount_1_in)
+                    .await?;
+                log::warn!("DEBUG POOL: Account {} swaps in launch cooldown", origin);
+                return Err(HandlerError::NotAllowed);
+            }
+        }
+
+        Ok(protection.extra_fee(amount_in, launched_at, now))
+    }
+
+    // Only swaps committed in the launch phase start a cooldown
+    fn record_launch_swap(&mut self, origin: Account, now: Timestamp) -> Result<(), HandlerError> {
+        let Some(protection) = self.runtime.borrow_mut().launch_protection() else {
+            return Ok(());
+        };
+        let Some(launched_at) = self.state.borrow().launched_at() else {
+            return Ok(());
+        };
+        if protection.cooldown_secs.is_none() || !protection.active(launched_at, now) {
+            return Ok(());
+        }
+        self.state
+            .borrow_mut()
+            .record_swap(origin, now)
+            .map_err(Into::into)
+    }
+
     // Always be run on creation chain
     async fn do_swap(
         &mut self,
@@ -126,10 +300,29 @@ impl<
         to: Option<Account>,
         _block_timestamp: Option<Timestamp>,
     ) -> Result<HandlerOutcome<PoolMessage, PoolResponse>, HandlerError> {
-        // Here we already funded
+        // Here we already funded, but the pool state may have moved a lot since then
+        let now = self.runtime.borrow_mut().system_time();
+        if self.deadline.is_some_and(|deadline| now > deadline) {
+            self.credit_amount_in(origin, amount_0_in, amount_1_in)
+                .await?;
+            return Err(HandlerError::DeadlineExceeded);
+        }
+
+        let (amount_0_in, amount_1_in) = self
+            .consume_exact_out_amount_in(origin, amount_0_in, amount_1_in, now)
+            .await?;
+        // Extra fee stays in the reserves, exact output already paid it with the input
+        let extra_fee = self
+            .launch_extra_fee(origin, amount_0_in, amount_1_in, now)
+            .await?;
+
         // 1: Calculate pair token amount
         let amount_0_out = if let Some(amount_1_in) = amount_1_in {
-            let calculated = { self.state.borrow().calculate_swap_amount_0(amount_1_in) };
+            let calculated = {
+                self.state
+                    .borrow()
+                    .calculate_swap_amount_0(amount_1_in.saturating_sub(extra_fee))
+            };
             match calculated {
                 Ok(amount) => amount,
                 Err(err) => {
@@ -141,6 +334,10 @@ impl<
         } else {
             Amount::ZERO
         };
+        // Exact output leaves the rounding dust of the required input in the pool
+        let amount_0_out = self
+            .amount_0_out
+            .map_or(amount_0_out, |amount| amount.min(amount_0_out));
         if let Some(amount_0_out_min) = amount_0_out_min {
             if amount_0_out < amount_0_out_min {
                 self.credit_amount_in(origin, amount_0_in, amount_1_in)
@@ -155,7 +352,11 @@ impl<
         }
 
         let amount_1_out = if let Some(amount_0_in) = amount_0_in {
-            let calculated = { self.state.borrow().calculate_swap_amount_1(amount_0_in) };
+            let calculated = {
+                self.state
+                    .borrow()
+                    .calculate_swap_amount_1(amount_0_in.saturating_sub(extra_fee))
+            };
             match calculated {
                 Ok(amount) => amount,
                 Err(err) => {
@@ -167,6 +368,9 @@ impl<
         } else {
             Amount::ZERO
         };
+        let amount_1_out = self
+            .amount_1_out
+            .map_or(amount_1_out, |amount| amount.min(amount_1_out));
         if let Some(amount_1_out_min) = amount_1_out_min {
             if amount_1_out < amount_1_out_min {
                 self.credit_amount_in(origin, amount_0_in, amount_1_in)
@@ -225,7 +429,9 @@ impl<
                     amount_1_out
                 );
                 return Err(match err {
-                    PoolError::InvalidAmount => HandlerError::InvalidAmount,
+                    PoolError::InvalidAmount
+                    | PoolError::InvalidWindow
+                    | PoolError::InsufficientObservations => HandlerError::InvalidAmount,
                     PoolError::BrokenK | PoolError::InsufficientLiquidity => {
                         HandlerError::InsufficientFunds
                     }
@@ -262,43 +468,39 @@ impl<
         // the input asset has already been locked to the pool, the output amounts
         // are final, and the reserves have been updated. Output delivery is
         // represented as claimable balance after the transaction is fixed.
-        let balance_0_result = {
-            self.state
-                .borrow()
-                .reserve_0()
-                .try_sub(amount_0_out)
-                .and_then(|amount| amount.try_add(amount_0_in.unwrap_or(Amount::ZERO)))
-        };
-        let balance_0 = match balance_0_result {
-            Ok(balance) => balance,
-            Err(err) => {
-                self.credit_amount_in(origin, amount_0_in, amount_1_in)
-                    .await?;
-                return Err(err.into());
-            }
+        let timestamp = self.runtime.borrow_mut().system_time();
+        let (extra_fee_0, extra_fee_1) = if amount_0_in.is_some() {
+            (extra_fee, Amount::ZERO)
+        } else {
+            (Amount::ZERO, extra_fee)
         };
-        let balance_1_result = {
-            self.state
-                .borrow()
-                .reserve_1()
-                .try_sub(amount_1_out)
-                .and_then(|amount| amount.try_add(amount_1_in.unwrap_or(Amount::ZERO)))
+        self.state
+            .borrow_mut()
+            .accrue_launch_fee(extra_fee_0, extra_fee_1);
+        let commit_result = {
+            self.state.borrow_mut().commit_swap(
+                amount_0_in.unwrap_or(Amount::ZERO),
+                amount_1_in.unwrap_or(Amount::ZERO),
+                amount_0_out,
+                amount_1_out,
+                timestamp,
+            )
         };
-        let balance_1 = match balance_1_result {
-            Ok(balance) => balance,
+        let fee_percent_mul_100 = match commit_result {
+            Ok(fee_percent_mul_100) => fee_percent_mul_100,
             Err(err) => {
                 self.credit_amount_in(origin, amount_0_in, amount_1_in)
                     .await?;
                 return Err(err.into());
             }
         };
-        let timestamp = self.runtime.borrow_mut().system_time();
-
-        self.state
-            .borrow_mut()
-            .liquid(balance_0, balance_1, timestamp);
+        // Reserves are committed from here, later failures are logged and only change how
+        // the output is delivered, the swap itself stands
+        if let Err(err) = self.record_launch_swap(origin, now) {
+            log::warn!("Failed record launch swap of {}: {}", origin, err);
+        }
 
-        let transaction = self.state.borrow_mut().build_transaction(
+        let mut transaction = self.state.borrow_mut().build_transaction(
             origin,
             amount_0_in,
             amount_1_in,
@@ -315,6 +517,7 @@ impl<
             None,
             timestamp,
         );
+        transaction.fee_percent_mul_100 = Some(fee_percent_mul_100);
         // We already on creator chain
         let destination = self.runtime.borrow_mut().chain_id();
         let mut outcome = HandlerOutcome::new();
@@ -337,12 +540,220 @@ impl<
 
         // 5: Output delivery is represented as claimable balance. This keeps
         // swap settlement local to the pool creator chain; the user exits funds
-        // through the unified Claim path.
-        self.credit_amount_out(to, amount_0_out, amount_1_out)
-            .await?;
+        // through the unified Claim path, or right away with auto_claim.
+        let credited = match self.credit_amount_out(to, amount_0_out, amount_1_out).await {
+            Ok(_) => true,
+            Err(err) => {
+                log::warn!("Failed credit amount out to {}: {}", to, err);
+                false
+            }
+        };
+        if !credited {
+            self.record_credit_failed(origin, amount_0_out, amount_1_out, &mut outcome)
+                .await;
+        }
+
+        // 6: Selling token_0 moves the price towards orders selling token_1 and vice versa
+        if let Err(err) = self
+            .fill_limit_orders(amount_0_in.is_none(), &mut outcome)
+            .await
+        {
+            log::warn!("Failed fill limit orders: {}", err);
+        }
+
+        // 7: An intermediate hop of a routed swap claims its output back to the
+        // origin right away, then the router continues with the next hop.
+        let status = match self.route.clone() {
+            Some(route) if credited => {
+                match self
+                    .forward_swap_route(to, amount_0_out, amount_1_out, route)
+                    .await
+                {
+                    Ok(_) => SwapSettlementStatus::Routed,
+                    Err(err) => {
+                        log::warn!("Failed forward swap route: {}", err);
+                        SwapSettlementStatus::Claimable
+                    }
+                }
+            }
+            None if credited && self.auto_claim => {
+                match self
+                    .deliver_amount_out(to, amount_0_out, amount_1_out)
+                    .await
+                {
+                    Ok(_) => SwapSettlementStatus::Delivering,
+                    Err(err) => {
+                        log::warn!("Failed deliver amount out: {}", err);
+                        SwapSettlementStatus::Claimable
+                    }
+                }
+            }
+            _ if !credited => SwapSettlementStatus::Failed,
+            _ => SwapSettlementStatus::Claimable,
+        };
+
+        // 8: Let the origin chain know how the swap settled
+        self.settle(origin, transaction, status, &mut outcome);
 
         Ok(outcome)
     }
+
+    // Tracked so that a rejected receipt bounces back instead of being lost silently
+    fn settle(
+        &mut self,
+        origin: Account,
+        transaction: Transaction,
+        status: SwapSettlementStatus,
+        outcome: &mut HandlerOutcome<PoolMessage, PoolResponse>,
+    ) {
+        if origin.chain_id == self.runtime.borrow_mut().chain_id() {
+            self.state
+                .borrow_mut()
+                .record_swap_settlement(SwapSettlement {
+                    transaction,
+                    status,
+                });
+            return;
+        }
+
+        outcome.with_message(
+            origin.chain_id,
+            PoolMessage::SwapSettled {
+                transaction,
+                status,
+            },
+            true,
+        );
+    }
+
+    async fn deliver_amount_out(
+        &mut self,
+        owner: Account,
+        amount_0_out: Amount,
+        amount_1_out: Amount,
+    ) -> Result<(), HandlerError> {
+        let token_0 = self.runtime.borrow_mut().token_0();
+        let token_1 = self.runtime.borrow_mut().token_1();
+
+        for (token, amount) in [
+            (MemeToken::from(token_0), amount_0_out),
+            (MemeToken::from(token_1), amount_1_out),
+        ] {
+            if amount == Amount::ZERO {
+                continue;
+            }
+            DeliverClaimableHandler::new(
+                self.runtime.clone(),
+                self.state.clone(),
+                owner,
+                token,
+                amount,
+            )
+            .handle()
+            .await?;
+        }
+        Ok(())
+    }
+
+    async fn fill_limit_orders(
+        &mut self,
+        token_0_in: bool,
+        outcome: &mut HandlerOutcome<PoolMessage, PoolResponse>,
+    ) -> Result<(), HandlerError> {
+        if let Some(filled) =
+            FillLimitOrdersHandler::new(self.runtime.clone(), self.state.clone(), token_0_in)
+                .handle()
+                .await?
+        {
+            outcome.messages.extend(filled.messages);
+        }
+        Ok(())
+    }
+
+    async fn record_failed_request(
+        &mut self,
+        reason: FailureReason,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        RecordFailedRequestHandler::new(
+            self.runtime.clone(),
+            self.state.clone(),
+            self.origin,
+            FailedRequestKind::Swap,
+            self.amount_0_in.unwrap_or(Amount::ZERO),
+            self.amount_1_in.unwrap_or(Amount::ZERO),
+            reason,
+        )
+        .handle()
+        .await
+    }
+
+    // Swap already stands, so the failure is only logged if it cannot be recorded either
+    async fn record_credit_failed(
+        &mut self,
+        origin: Account,
+        amount_0_out: Amount,
+        amount_1_out: Amount,
+        outcome: &mut HandlerOutcome<PoolMessage, PoolResponse>,
+    ) {
+        match RecordFailedRequestHandler::new(
+            self.runtime.clone(),
+            self.state.clone(),
+            origin,
+            FailedRequestKind::Swap,
+            amount_0_out,
+            amount_1_out,
+            FailureReason::CreditFailed,
+        )
+        .handle()
+        .await
+        {
+            Ok(Some(recorded)) => outcome.messages.extend(recorded.messages),
+            Ok(None) => {}
+            Err(err) => log::warn!("Failed record credit failure of {}: {}", origin, err),
+        }
+    }
+
+    async fn forward_swap_route(
+        &mut self,
+        owner: Account,
+        amount_0_out: Amount,
+        amount_1_out: Amount,
+        route: SwapRoute,
+    ) -> Result<(), HandlerError> {
+        let (token_out, amount_out) = if amount_1_out > Amount::ZERO {
+            (
+                MemeToken::from(self.runtime.borrow_mut().token_1()),
+                amount_1_out,
+            )
+        } else {
+            (
+                MemeToken::from(self.runtime.borrow_mut().token_0()),
+                amount_0_out,
+            )
+        };
+
+        let Some(hop) = route.hops.first() else {
+            return Ok(());
+        };
+        if MemeToken::from(hop.token_in) != token_out {
+            // Output stays claimable, the user can still exit through Claim
+            log::warn!("Invalid route hop token in {:?}", hop.token_in);
+            return Ok(());
+        }
+
+        ForwardSwapRouteHandler::new(
+            self.runtime.clone(),
+            self.state.clone(),
+            owner,
+            token_out,
+            amount_out,
+            route,
+        )
+        .handle()
+        .await?;
+
+        Ok(())
+    }
 }
 
 #[async_trait(?Send)]
@@ -357,10 +768,16 @@ impl<
         if !self.state.borrow().has_finalized_reserve_share_facts() {
             self.credit_amount_in(self.origin, self.amount_0_in, self.amount_1_in)
                 .await?;
-            return Ok(None);
+            return self.record_failed_request(FailureReason::NotReady).await;
+        }
+        if !self.state.borrow().pool().status.swaps_enabled() {
+            self.credit_amount_in(self.origin, self.amount_0_in, self.amount_1_in)
+                .await?;
+            log::warn!("Failed swap: {}", HandlerError::NotEnabled);
+            return self.record_failed_request(FailureReason::NotEnabled).await;
         }
 
-        // We just return OK to refund the failed balance here
+        // We just return OK to refund the failed balance here, with the reason recorded
         match self
             .do_swap(
                 self.origin,
@@ -376,7 +793,7 @@ impl<
             Ok(outcome) => Ok(Some(outcome)),
             Err(err) => {
                 log::warn!("Failed swap: {}", err);
-                Ok(None)
+                self.record_failed_request(failure_reason(&err)).await
             }
         }
     }
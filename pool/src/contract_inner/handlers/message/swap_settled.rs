@@ -0,0 +1,80 @@
+use crate::interfaces::state::StateInterface;
+use abi::swap::{
+    pool::{PoolMessage, PoolResponse, SwapSettlement, SwapSettlementStatus},
+    transaction::Transaction,
+};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct SwapSettledHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: Rc<RefCell<R>>,
+    state: S,
+
+    transaction: Transaction,
+    status: SwapSettlementStatus,
+}
+
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> SwapSettledHandler<R, S> {
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, msg: &PoolMessage) -> Self {
+        let PoolMessage::SwapSettled {
+            transaction,
+            status,
+        } = msg
+        else {
+            panic!("Invalid message");
+        };
+
+        Self {
+            state,
+            runtime,
+
+            transaction: *transaction,
+            status: *status,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    Handler<PoolMessage, PoolResponse> for SwapSettledHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        // The swap itself is already settled, a bounced receipt has nothing to revert
+        if self
+            .runtime
+            .borrow_mut()
+            .message_is_bouncing()
+            .unwrap_or(false)
+        {
+            log::warn!(
+                "Swap settlement {:?} of {} bounced",
+                self.transaction.transaction_id,
+                self.transaction.from
+            );
+            return Ok(None);
+        }
+
+        let creator_chain_id = self.runtime.borrow_mut().application_creator_chain_id();
+        assert_eq!(
+            self.runtime.borrow_mut().message_origin_chain_id(),
+            Some(creator_chain_id),
+            "Invalid swap settlement origin"
+        );
+        let chain_id = self.runtime.borrow_mut().chain_id();
+        assert_eq!(
+            chain_id, self.transaction.from.chain_id,
+            "Invalid swap settlement chain"
+        );
+
+        self.state.record_swap_settlement(SwapSettlement {
+            transaction: self.transaction,
+            status: self.status,
+        });
+
+        Ok(None)
+    }
+}
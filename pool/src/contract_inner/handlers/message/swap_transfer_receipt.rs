@@ -49,7 +49,13 @@ where
 
     fn validate_request(&self, request: &FundRequest) {
         assert!(request.amount_in > Amount::ZERO, "Invalid amount");
-        assert_eq!(request.fund_type, FundType::Swap, "Invalid fund type");
+        assert!(
+            matches!(
+                request.fund_type,
+                FundType::Swap | FundType::LimitOrder | FundType::ZapIn
+            ),
+            "Invalid fund type"
+        );
 
         let token = request.token.expect("Invalid fund token");
         self.state.borrow().pool().validate_token(Some(token));
@@ -59,6 +65,41 @@ where
         let token = request.token.expect("Invalid fund token");
         let token_0 = self.runtime.borrow_mut().token_0();
 
+        if request.fund_type == FundType::LimitOrder {
+            let amount_out_min = request
+                .counterparty_amount_out_min
+                .expect("Invalid amount out min");
+            let (amount_0_in, amount_1_in) = if token == token_0 {
+                (Some(request.amount_in), None)
+            } else {
+                (None, Some(request.amount_in))
+            };
+            return PoolMessage::PlaceLimitOrder {
+                origin: request.from,
+                amount_0_in,
+                amount_1_in,
+                amount_out_min,
+                to: request.to,
+            };
+        }
+
+        if request.fund_type == FundType::ZapIn {
+            let (amount_0_in, amount_1_in) = if token == token_0 {
+                (Some(request.amount_in), None)
+            } else {
+                (None, Some(request.amount_in))
+            };
+            return PoolMessage::ZapIn {
+                origin: request.from,
+                amount_0_in,
+                amount_1_in,
+                min_liquidity: request.amount_out_min,
+                to: request.to,
+                block_timestamp: request.block_timestamp,
+                deadline: request.deadline,
+            };
+        }
+
         if token == token_0 {
             PoolMessage::Swap {
                 origin: request.from,
@@ -68,6 +109,11 @@ where
                 amount_1_out_min: request.counterparty_amount_out_min,
                 to: request.to,
                 block_timestamp: request.block_timestamp,
+                route: request.route.clone(),
+                amount_0_out: None,
+                amount_1_out: request.counterparty_amount_out,
+                deadline: request.deadline,
+                auto_claim: request.auto_claim,
             }
         } else {
             PoolMessage::Swap {
@@ -78,6 +124,11 @@ where
                 amount_1_out_min: None,
                 to: request.to,
                 block_timestamp: request.block_timestamp,
+                route: request.route.clone(),
+                amount_0_out: request.counterparty_amount_out,
+                amount_1_out: None,
+                deadline: request.deadline,
+                auto_claim: request.auto_claim,
             }
         }
     }
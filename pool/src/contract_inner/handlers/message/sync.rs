@@ -0,0 +1,87 @@
+use crate::interfaces::state::StateInterface;
+use abi::{
+    meme_token::MemeToken,
+    swap::pool::{PoolMessage, PoolResponse},
+};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{Account, AccountOwner, Amount};
+use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct SyncHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: Rc<RefCell<R>>,
+    state: S,
+
+    operator: Account,
+}
+
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> SyncHandler<R, S> {
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, msg: &PoolMessage) -> Self {
+        let PoolMessage::Sync { operator } = msg else {
+            panic!("Invalid message");
+        };
+
+        Self {
+            state,
+            runtime,
+
+            operator: *operator,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    Handler<PoolMessage, PoolResponse> for SyncHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        let pool = self.state.pool();
+        assert!(pool.fee_to_setter == self.operator, "Invalid operator");
+        assert!(
+            self.state.has_finalized_reserve_share_facts(),
+            "Pool is not ready"
+        );
+
+        // Meme ledgers live on the meme creator chain, only native holdings are known here
+        if pool.token_1.is_some() {
+            return Err(HandlerError::NotEnabled);
+        }
+
+        let application = AccountOwner::from(self.runtime.borrow_mut().application_id());
+        let balance = self.runtime.borrow_mut().owner_balance(application);
+        let obligations = self
+            .state
+            .obligations(MemeToken::Native)
+            .await
+            .map_err(Into::into)?;
+        // Virtual initial liquidity is counted in the reserve but never held
+        let virtual_reserve_1 = self.state.virtual_reserve_1();
+        let reserve_1 = balance
+            .saturating_sub(obligations)
+            .saturating_add(virtual_reserve_1);
+        if reserve_1 == virtual_reserve_1 {
+            log::warn!(
+                "Failed sync: {}, balance {} obligations {}",
+                HandlerError::InsufficientFunds,
+                balance,
+                obligations
+            );
+            return Ok(None);
+        }
+
+        log::info!(
+            "Synced reserve 1 from {} to {} by {}",
+            pool.reserve_1,
+            reserve_1,
+            self.operator
+        );
+
+        let timestamp = self.runtime.borrow_mut().system_time();
+        self.state.liquid(pool.reserve_0, reserve_1, timestamp);
+
+        Ok(None)
+    }
+}
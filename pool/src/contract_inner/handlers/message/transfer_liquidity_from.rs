@@ -0,0 +1,69 @@
+use crate::interfaces::state::StateInterface;
+use abi::swap::pool::{PoolMessage, PoolResponse};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{Account, Amount};
+use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct TransferLiquidityFromHandler<
+    R: ContractRuntimeContext + AccessControl,
+    S: StateInterface,
+> {
+    _runtime: Rc<RefCell<R>>,
+    state: S,
+
+    origin: Account,
+    from: Account,
+    to: Account,
+    liquidity: Amount,
+}
+
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    TransferLiquidityFromHandler<R, S>
+{
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, msg: &PoolMessage) -> Self {
+        let PoolMessage::TransferLiquidityFrom {
+            origin,
+            from,
+            to,
+            liquidity,
+        } = msg
+        else {
+            panic!("Invalid message");
+        };
+
+        Self {
+            state,
+            _runtime: runtime,
+
+            origin: *origin,
+            from: *from,
+            to: *to,
+            liquidity: *liquidity,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    Handler<PoolMessage, PoolResponse> for TransferLiquidityFromHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        if !self.state.pool().status.withdrawals_enabled() {
+            log::warn!(
+                "Failed transfer liquidity from: {}",
+                HandlerError::NotEnabled
+            );
+            return Ok(None);
+        }
+
+        self.state
+            .transfer_liquidity_from(self.origin, self.from, self.to, self.liquidity)
+            .await
+            .map_err(Into::into)?;
+        Ok(None)
+    }
+}
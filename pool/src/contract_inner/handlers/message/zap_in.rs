@@ -0,0 +1,364 @@
+use crate::{
+    contract_inner::handlers::{
+        launch_protection::launch_protected, record_failed_request::RecordFailedRequestHandler,
+    },
+    interfaces::{parameters::ParametersInterface, state::StateInterface},
+};
+use abi::{
+    meme_token::MemeToken,
+    swap::pool::{FailedRequestKind, FailureReason, PoolError, PoolMessage, PoolResponse},
+};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{Account, Amount, Timestamp};
+use runtime::interfaces::{
+    access_control::AccessControl, contract::ContractRuntimeContext, meme::MemeRuntimeContext,
+};
+use std::{cell::RefCell, rc::Rc};
+
+struct ZapInPlan {
+    amount_0_swap_in: Amount,
+    amount_1_swap_in: Amount,
+    amount_0_swap_out: Amount,
+    amount_1_swap_out: Amount,
+    amount_0: Amount,
+    amount_1: Amount,
+}
+
+pub struct ZapInHandler<
+    R: ContractRuntimeContext + AccessControl + ParametersInterface + MemeRuntimeContext,
+    S: StateInterface,
+> {
+    runtime: Rc<RefCell<R>>,
+    state: Rc<RefCell<S>>,
+
+    origin: Account,
+    amount_0_in: Amount,
+    amount_1_in: Amount,
+    min_liquidity: Option<Amount>,
+    to: Option<Account>,
+    block_timestamp: Option<Timestamp>,
+    deadline: Option<Timestamp>,
+}
+
+impl<
+        R: ContractRuntimeContext + AccessControl + ParametersInterface + MemeRuntimeContext,
+        S: StateInterface,
+    > ZapInHandler<R, S>
+{
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, msg: &PoolMessage) -> Self {
+        let PoolMessage::ZapIn {
+            origin,
+            amount_0_in,
+            amount_1_in,
+            min_liquidity,
+            to,
+            block_timestamp,
+            deadline,
+        } = msg
+        else {
+            panic!("Invalid message");
+        };
+
+        assert!(
+            amount_0_in.is_some() != amount_1_in.is_some(),
+            "Invalid amount"
+        );
+
+        Self {
+            state: Rc::new(RefCell::new(state)),
+            runtime,
+
+            origin: *origin,
+            amount_0_in: amount_0_in.unwrap_or(Amount::ZERO),
+            amount_1_in: amount_1_in.unwrap_or(Amount::ZERO),
+            min_liquidity: *min_liquidity,
+            to: *to,
+            block_timestamp: *block_timestamp,
+            deadline: *deadline,
+        }
+    }
+
+    async fn refund(&mut self) -> Result<(), HandlerError> {
+        let (token, amount) = if self.amount_0_in > Amount::ZERO {
+            (
+                MemeToken::from(self.runtime.borrow_mut().token_0()),
+                self.amount_0_in,
+            )
+        } else {
+            (
+                MemeToken::from(self.runtime.borrow_mut().token_1()),
+                self.amount_1_in,
+            )
+        };
+
+        self.state
+            .borrow_mut()
+            .credit(token, self.origin, amount)
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn record_failed_request(
+        &mut self,
+        reason: FailureReason,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        RecordFailedRequestHandler::new(
+            self.runtime.clone(),
+            self.state.clone(),
+            self.origin,
+            FailedRequestKind::ZapIn,
+            self.amount_0_in,
+            self.amount_1_in,
+            reason,
+        )
+        .handle()
+        .await
+    }
+
+    // Everything is calculated on a copy of the pool first, so that a failed zap refunds the
+    // whole deposit without touching reserves or shares
+    fn plan(&self, timestamp: Timestamp) -> Result<ZapInPlan, PoolError> {
+        let mut pool = self.state.borrow().pool();
+        let total_supply = self.state.borrow().total_supply();
+
+        let (amount_0_swap_in, amount_1_swap_in, amount_0_swap_out, amount_1_swap_out) =
+            if self.amount_0_in > Amount::ZERO {
+                let amount_0_swap_in = pool.calculate_zap_in_swap_amount_0(self.amount_0_in)?;
+                let amount_1_swap_out = pool.calculate_swap_amount_1(amount_0_swap_in)?;
+                (
+                    amount_0_swap_in,
+                    Amount::ZERO,
+                    Amount::ZERO,
+                    amount_1_swap_out,
+                )
+            } else {
+                let amount_1_swap_in = pool.calculate_zap_in_swap_amount_1(self.amount_1_in)?;
+                let amount_0_swap_out = pool.calculate_swap_amount_0(amount_1_swap_in)?;
+                (
+                    Amount::ZERO,
+                    amount_1_swap_in,
+                    amount_0_swap_out,
+                    Amount::ZERO,
+                )
+            };
+        if amount_0_swap_out == Amount::ZERO && amount_1_swap_out == Amount::ZERO {
+            return Err(PoolError::InvalidAmount);
+        }
+        pool.validate_swap_invariant(
+            amount_0_swap_in,
+            amount_1_swap_in,
+            amount_0_swap_out,
+            amount_1_swap_out,
+        )?;
+
+        pool.liquid(
+            pool.reserve_0
+                .saturating_add(amount_0_swap_in)
+                .saturating_sub(amount_0_swap_out),
+            pool.reserve_1
+                .saturating_add(amount_1_swap_in)
+                .saturating_sub(amount_1_swap_out),
+            timestamp,
+        );
+
+        let (amount_0, amount_1) = pool.try_calculate_swap_amount_pair(
+            self.amount_0_in
+                .saturating_sub(amount_0_swap_in)
+                .saturating_add(amount_0_swap_out),
+            self.amount_1_in
+                .saturating_sub(amount_1_swap_in)
+                .saturating_add(amount_1_swap_out),
+            None,
+            None,
+        )?;
+        if amount_0 == Amount::ZERO || amount_1 == Amount::ZERO {
+            return Err(PoolError::InvalidAmount);
+        }
+
+        // Same as mint_shares(), the protocol fee is minted before the new shares
+        let total_supply = total_supply.saturating_add(pool.mint_fee(total_supply));
+        let liquidity = pool.calculate_liquidity(total_supply, amount_0, amount_1);
+        if liquidity == Amount::ZERO
+            || self
+                .min_liquidity
+                .is_some_and(|min_liquidity| liquidity < min_liquidity)
+        {
+            return Err(PoolError::InvalidAmount);
+        }
+
+        Ok(ZapInPlan {
+            amount_0_swap_in,
+            amount_1_swap_in,
+            amount_0_swap_out,
+            amount_1_swap_out,
+            amount_0,
+            amount_1,
+        })
+    }
+
+    async fn credit_excess(&mut self, plan: &ZapInPlan) -> Result<(), HandlerError> {
+        let excess_0 = self
+            .amount_0_in
+            .try_sub(plan.amount_0_swap_in)?
+            .try_add(plan.amount_0_swap_out)?
+            .try_sub(plan.amount_0)?;
+        if excess_0 > Amount::ZERO {
+            let token_0 = self.runtime.borrow_mut().token_0();
+            self.state
+                .borrow_mut()
+                .credit(MemeToken::from(token_0), self.origin, excess_0)
+                .await
+                .map_err(Into::into)?;
+        }
+
+        let excess_1 = self
+            .amount_1_in
+            .try_sub(plan.amount_1_swap_in)?
+            .try_add(plan.amount_1_swap_out)?
+            .try_sub(plan.amount_1)?;
+        if excess_1 > Amount::ZERO {
+            let token_1 = self.runtime.borrow_mut().token_1();
+            self.state
+                .borrow_mut()
+                .credit(MemeToken::from(token_1), self.origin, excess_1)
+                .await
+                .map_err(Into::into)?;
+        }
+        Ok(())
+    }
+}
+
+fn non_zero(amount: Amount) -> Option<Amount> {
+    (amount > Amount::ZERO).then_some(amount)
+}
+
+#[async_trait(?Send)]
+impl<
+        R: ContractRuntimeContext + AccessControl + ParametersInterface + MemeRuntimeContext,
+        S: StateInterface,
+    > Handler<PoolMessage, PoolResponse> for ZapInHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        // We already receive all funds here
+        let now = self.runtime.borrow_mut().system_time();
+        if self.deadline.is_some_and(|deadline| now > deadline) {
+            self.refund().await?;
+            log::warn!(
+                "Failed zap in: {} at {}",
+                HandlerError::DeadlineExceeded,
+                now
+            );
+            return self
+                .record_failed_request(FailureReason::DeadlineExceeded)
+                .await;
+        }
+        let status = self.state.borrow().pool().status;
+        if !status.swaps_enabled() || !status.deposits_enabled() {
+            self.refund().await?;
+            log::warn!("Failed zap in: {}", HandlerError::NotEnabled);
+            return self.record_failed_request(FailureReason::NotEnabled).await;
+        }
+        if !self.state.borrow().has_finalized_reserve_share_facts() {
+            self.refund().await?;
+            log::warn!("Failed zap in: pool is not ready");
+            return self.record_failed_request(FailureReason::NotReady).await;
+        }
+        if launch_protected(&self.runtime, &self.state) {
+            self.refund().await?;
+            log::warn!("Failed zap in: pool is in launch phase");
+            return self.record_failed_request(FailureReason::NotEnabled).await;
+        }
+
+        let timestamp = self.block_timestamp.unwrap_or(now);
+        let plan = match self.plan(timestamp) {
+            Ok(plan) => plan,
+            Err(err) => {
+                self.refund().await?;
+                log::warn!("Failed zap in: {}", err);
+                return self.record_failed_request(FailureReason::from(&err)).await;
+            }
+        };
+
+        // 1: Swap the optimal portion inside the pool
+        let balance_0 = self
+            .state
+            .borrow()
+            .reserve_0()
+            .try_add(plan.amount_0_swap_in)?
+            .try_sub(plan.amount_0_swap_out)?;
+        let balance_1 = self
+            .state
+            .borrow()
+            .reserve_1()
+            .try_add(plan.amount_1_swap_in)?
+            .try_sub(plan.amount_1_swap_out)?;
+        let fee_percent_mul_100 = self
+            .state
+            .borrow_mut()
+            .accrue_swap_fee(plan.amount_0_swap_in, plan.amount_1_swap_in);
+        self.state.borrow_mut().record_swap_stats(
+            plan.amount_0_swap_in,
+            plan.amount_1_swap_in,
+            plan.amount_0_swap_out,
+            plan.amount_1_swap_out,
+            timestamp,
+        );
+        self.state
+            .borrow_mut()
+            .liquid(balance_0, balance_1, timestamp);
+        let mut swap_transaction = self.state.borrow_mut().build_transaction(
+            self.origin,
+            non_zero(plan.amount_0_swap_in),
+            non_zero(plan.amount_1_swap_in),
+            non_zero(plan.amount_0_swap_out),
+            non_zero(plan.amount_1_swap_out),
+            None,
+            timestamp,
+        );
+        swap_transaction.fee_percent_mul_100 = Some(fee_percent_mul_100);
+
+        // 2: Add the rest as liquidity
+        let to = self.to.unwrap_or(self.origin);
+        let liquidity = self
+            .state
+            .borrow_mut()
+            .add_liquidity(plan.amount_0, plan.amount_1, to, timestamp)
+            .await
+            .map_err(Into::into)?;
+        self.credit_excess(&plan).await?;
+
+        let liquidity_transaction = self.state.borrow_mut().build_transaction(
+            self.origin,
+            Some(plan.amount_0),
+            Some(plan.amount_1),
+            None,
+            None,
+            Some(liquidity),
+            timestamp,
+        );
+
+        let destination = self.runtime.borrow_mut().chain_id();
+        let mut outcome = HandlerOutcome::new();
+
+        outcome
+            .with_message(
+                destination,
+                PoolMessage::NewTransaction {
+                    transaction: swap_transaction,
+                },
+                false,
+            )
+            .with_message(
+                destination,
+                PoolMessage::NewTransaction {
+                    transaction: liquidity_transaction,
+                },
+                false,
+            );
+
+        Ok(Some(outcome))
+    }
+}
@@ -0,0 +1,300 @@
+use crate::{
+    contract_inner::handlers::launch_protection::launch_protected,
+    interfaces::{parameters::ParametersInterface, state::StateInterface},
+};
+use abi::{
+    meme_token::MemeToken,
+    swap::pool::{PoolError, PoolMessage, PoolResponse},
+};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{Account, Amount, ApplicationId, Timestamp};
+use runtime::interfaces::{
+    access_control::AccessControl, contract::ContractRuntimeContext, meme::MemeRuntimeContext,
+};
+use std::{cell::RefCell, rc::Rc};
+
+struct ZapOutPlan {
+    amount_0: Amount,
+    amount_1: Amount,
+    amount_0_swap_out: Amount,
+    amount_1_swap_out: Amount,
+}
+
+pub struct ZapOutHandler<
+    R: ContractRuntimeContext + AccessControl + ParametersInterface + MemeRuntimeContext,
+    S: StateInterface,
+> {
+    runtime: Rc<RefCell<R>>,
+    state: Rc<RefCell<S>>,
+
+    origin: Account,
+    liquidity: Amount,
+    token: Option<ApplicationId>,
+    amount_out_min: Option<Amount>,
+    to: Option<Account>,
+    block_timestamp: Option<Timestamp>,
+    deadline: Option<Timestamp>,
+}
+
+impl<
+        R: ContractRuntimeContext + AccessControl + ParametersInterface + MemeRuntimeContext,
+        S: StateInterface,
+    > ZapOutHandler<R, S>
+{
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, msg: &PoolMessage) -> Self {
+        let PoolMessage::ZapOut {
+            origin,
+            liquidity,
+            token,
+            amount_out_min,
+            to,
+            block_timestamp,
+            deadline,
+        } = msg
+        else {
+            panic!("Invalid message");
+        };
+
+        Self {
+            state: Rc::new(RefCell::new(state)),
+            runtime,
+
+            origin: *origin,
+            liquidity: *liquidity,
+            token: *token,
+            amount_out_min: *amount_out_min,
+            to: *to,
+            block_timestamp: *block_timestamp,
+            deadline: *deadline,
+        }
+    }
+
+    // Calculated on a copy of the pool, so that a failed zap leaves the shares untouched
+    fn plan(&self, token_0_out: bool) -> Result<ZapOutPlan, PoolError> {
+        let mut pool = self.state.borrow().pool();
+        let total_supply = self.state.borrow().total_supply();
+
+        // Same as remove_liquidity(), the protocol fee is minted before the shares are burnt
+        let total_supply = total_supply.saturating_add(pool.mint_fee(total_supply));
+        let (amount_0, amount_1) =
+            pool.try_calculate_liquidity_amount_pair(self.liquidity, total_supply, None, None)?;
+
+        pool.reserve_0 = pool.reserve_0.saturating_sub(amount_0);
+        pool.reserve_1 = pool.reserve_1.saturating_sub(amount_1);
+
+        let (amount_0_swap_out, amount_1_swap_out) = if token_0_out {
+            (pool.calculate_swap_amount_0(amount_1)?, Amount::ZERO)
+        } else {
+            (Amount::ZERO, pool.calculate_swap_amount_1(amount_0)?)
+        };
+        let (amount_0_swap_in, amount_1_swap_in) = if token_0_out {
+            (Amount::ZERO, amount_1)
+        } else {
+            (amount_0, Amount::ZERO)
+        };
+        if amount_0_swap_out == Amount::ZERO && amount_1_swap_out == Amount::ZERO {
+            return Err(PoolError::InvalidAmount);
+        }
+        pool.validate_swap_invariant(
+            amount_0_swap_in,
+            amount_1_swap_in,
+            amount_0_swap_out,
+            amount_1_swap_out,
+        )?;
+
+        let amount_out = if token_0_out {
+            amount_0.saturating_add(amount_0_swap_out)
+        } else {
+            amount_1.saturating_add(amount_1_swap_out)
+        };
+        if self
+            .amount_out_min
+            .is_some_and(|amount_out_min| amount_out < amount_out_min)
+        {
+            return Err(PoolError::InvalidAmount);
+        }
+
+        Ok(ZapOutPlan {
+            amount_0,
+            amount_1,
+            amount_0_swap_out,
+            amount_1_swap_out,
+        })
+    }
+}
+
+#[async_trait(?Send)]
+impl<
+        R: ContractRuntimeContext + AccessControl + ParametersInterface + MemeRuntimeContext,
+        S: StateInterface,
+    > Handler<PoolMessage, PoolResponse> for ZapOutHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        assert!(
+            self.state.borrow().has_finalized_reserve_share_facts(),
+            "Pool is not ready"
+        );
+        self.state.borrow().pool().validate_token(self.token);
+
+        // Liquidity is only burnt here, so a failed zap leaves the shares untouched
+        let now = self.runtime.borrow_mut().system_time();
+        if self.deadline.is_some_and(|deadline| now > deadline) {
+            log::warn!(
+                "Failed zap out: {} at {}",
+                HandlerError::DeadlineExceeded,
+                now
+            );
+            return Ok(None);
+        }
+        let status = self.state.borrow().pool().status;
+        if !status.swaps_enabled() || !status.withdrawals_enabled() {
+            log::warn!("Failed zap out: {}", HandlerError::NotEnabled);
+            return Ok(None);
+        }
+        if launch_protected(&self.runtime, &self.state) {
+            log::warn!("Failed zap out: pool is in launch phase");
+            return Ok(None);
+        }
+
+        let token_0 = self.runtime.borrow_mut().token_0();
+        let token_1 = self.runtime.borrow_mut().token_1();
+        let token_0_out = self.token == Some(token_0);
+        let plan = match self.plan(token_0_out) {
+            Ok(plan) => plan,
+            Err(err) => {
+                log::warn!("Failed zap out: {}", err);
+                return Ok(None);
+            }
+        };
+
+        // 1: Remove liquidity
+        let timestamp = self.block_timestamp.unwrap_or(now);
+        let (amount_0, amount_1) = self
+            .state
+            .borrow_mut()
+            .remove_liquidity(
+                self.origin,
+                self.liquidity,
+                Some(plan.amount_0),
+                Some(plan.amount_1),
+                timestamp,
+            )
+            .await
+            .map_err(Into::into)?;
+        let liquidity_transaction = self.state.borrow_mut().build_transaction(
+            self.origin,
+            None,
+            None,
+            Some(amount_0),
+            Some(amount_1),
+            Some(self.liquidity),
+            timestamp,
+        );
+
+        // 2: Swap the other token back into the pool
+        let (token, amount_out, swap_transaction) = if token_0_out {
+            let balance_0 = self
+                .state
+                .borrow()
+                .reserve_0()
+                .try_sub(plan.amount_0_swap_out)?;
+            let balance_1 = self.state.borrow().reserve_1().try_add(amount_1)?;
+            let fee_percent_mul_100 = self
+                .state
+                .borrow_mut()
+                .accrue_swap_fee(Amount::ZERO, amount_1);
+            self.state.borrow_mut().record_swap_stats(
+                Amount::ZERO,
+                amount_1,
+                plan.amount_0_swap_out,
+                Amount::ZERO,
+                timestamp,
+            );
+            self.state
+                .borrow_mut()
+                .liquid(balance_0, balance_1, timestamp);
+            let mut transaction = self.state.borrow_mut().build_transaction(
+                self.origin,
+                None,
+                Some(amount_1),
+                Some(plan.amount_0_swap_out),
+                None,
+                None,
+                timestamp,
+            );
+            transaction.fee_percent_mul_100 = Some(fee_percent_mul_100);
+            (
+                MemeToken::from(token_0),
+                amount_0.try_add(plan.amount_0_swap_out)?,
+                transaction,
+            )
+        } else {
+            let balance_0 = self.state.borrow().reserve_0().try_add(amount_0)?;
+            let balance_1 = self
+                .state
+                .borrow()
+                .reserve_1()
+                .try_sub(plan.amount_1_swap_out)?;
+            let fee_percent_mul_100 = self
+                .state
+                .borrow_mut()
+                .accrue_swap_fee(amount_0, Amount::ZERO);
+            self.state.borrow_mut().record_swap_stats(
+                amount_0,
+                Amount::ZERO,
+                Amount::ZERO,
+                plan.amount_1_swap_out,
+                timestamp,
+            );
+            self.state
+                .borrow_mut()
+                .liquid(balance_0, balance_1, timestamp);
+            let mut transaction = self.state.borrow_mut().build_transaction(
+                self.origin,
+                Some(amount_0),
+                None,
+                None,
+                Some(plan.amount_1_swap_out),
+                None,
+                timestamp,
+            );
+            transaction.fee_percent_mul_100 = Some(fee_percent_mul_100);
+            (
+                MemeToken::from(token_1),
+                amount_1.try_add(plan.amount_1_swap_out)?,
+                transaction,
+            )
+        };
+
+        let to = self.to.unwrap_or(self.origin);
+        self.state
+            .borrow_mut()
+            .credit(token, to, amount_out)
+            .await
+            .map_err(Into::into)?;
+
+        let destination = self.runtime.borrow_mut().chain_id();
+        let mut outcome = HandlerOutcome::new();
+
+        outcome
+            .with_message(
+                destination,
+                PoolMessage::NewTransaction {
+                    transaction: liquidity_transaction,
+                },
+                false,
+            )
+            .with_message(
+                destination,
+                PoolMessage::NewTransaction {
+                    transaction: swap_transaction,
+                },
+                false,
+            );
+
+        Ok(Some(outcome))
+    }
+}
@@ -1,10 +1,25 @@
 pub mod add_liquidity;
 pub mod add_liquidity_transfer_receipt;
+pub mod approve_liquidity;
+pub mod cancel_limit_order;
 pub mod claim;
+pub mod claim_all;
 pub mod claim_transfer_receipt;
 pub mod initialize_liquidity;
+pub mod migrate_liquidity;
+pub mod place_limit_order;
 pub mod remove_liquidity;
+pub mod set_dynamic_fee;
 pub mod set_fee_to;
 pub mod set_fee_to_setter;
+pub mod set_pool_status;
+pub mod set_protocol_fee_share;
+pub mod skim;
 pub mod swap;
 pub mod swap_transfer_receipt;
+pub mod sync;
+pub mod transfer_liquidity;
+pub mod transfer_liquidity_from;
+pub mod twap;
+pub mod zap_in;
+pub mod zap_out;
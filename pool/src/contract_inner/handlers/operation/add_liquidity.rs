@@ -24,6 +24,7 @@ pub struct AddLiquidityHandler<
     amount_1_out_min: Option<Amount>,
     to: Option<Account>,
     block_timestamp: Option<Timestamp>,
+    deadline: Option<Timestamp>,
 }
 
 impl<
@@ -39,6 +40,7 @@ impl<
             amount_1_out_min,
             to,
             block_timestamp,
+            deadline,
         } = op
         else {
             panic!("Invalid operation");
@@ -54,6 +56,7 @@ impl<
             amount_1_out_min: *amount_1_out_min,
             to: *to,
             block_timestamp: *block_timestamp,
+            deadline: *deadline,
         }
     }
 }
@@ -88,6 +91,7 @@ impl<
         .counterparty_amount_out_min(self.amount_1_out_min)
         .to(self.to)
         .block_timestamp(self.block_timestamp)
+        .deadline(self.deadline)
         .build();
 
         let fund_request_1 =
@@ -98,6 +102,7 @@ impl<
                 .counterparty_amount_out_min(self.amount_0_out_min)
                 .to(self.to)
                 .block_timestamp(self.block_timestamp)
+                .deadline(self.deadline)
                 .build();
 
         let mut handler = RequestMemeFundHandler::new(
@@ -0,0 +1,64 @@
+use crate::interfaces::state::StateInterface;
+use abi::swap::pool::{PoolMessage, PoolOperation, PoolResponse};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{Account, Amount};
+use runtime::interfaces::{
+    access_control::AccessControl, contract::ContractRuntimeContext, meme::MemeRuntimeContext,
+};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct ApproveLiquidityHandler<
+    R: ContractRuntimeContext + AccessControl + MemeRuntimeContext,
+    S: StateInterface,
+> {
+    runtime: Rc<RefCell<R>>,
+    _state: S,
+
+    spender: Account,
+    liquidity: Amount,
+}
+
+impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInterface>
+    ApproveLiquidityHandler<R, S>
+{
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, op: &PoolOperation) -> Self {
+        let PoolOperation::ApproveLiquidity { spender, liquidity } = op else {
+            panic!("Invalid operation");
+        };
+
+        Self {
+            _state: state,
+            runtime,
+
+            spender: *spender,
+            liquidity: *liquidity,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInterface>
+    Handler<PoolMessage, PoolResponse> for ApproveLiquidityHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        let origin = self.runtime.borrow_mut().authenticated_account();
+
+        let destination = self.runtime.borrow_mut().application_creator_chain_id();
+        let mut outcome = HandlerOutcome::new();
+
+        outcome.with_message(
+            destination,
+            PoolMessage::ApproveLiquidity {
+                origin,
+                spender: self.spender,
+                liquidity: self.liquidity,
+            },
+            false,
+        );
+
+        Ok(Some(outcome))
+    }
+}
@@ -0,0 +1,60 @@
+use crate::interfaces::state::StateInterface;
+use abi::swap::pool::{PoolMessage, PoolOperation, PoolResponse};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use runtime::interfaces::{
+    access_control::AccessControl, contract::ContractRuntimeContext, meme::MemeRuntimeContext,
+};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct CancelLimitOrderHandler<
+    R: ContractRuntimeContext + AccessControl + MemeRuntimeContext,
+    S: StateInterface,
+> {
+    runtime: Rc<RefCell<R>>,
+    _state: S,
+
+    order_id: u64,
+}
+
+impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInterface>
+    CancelLimitOrderHandler<R, S>
+{
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, op: &PoolOperation) -> Self {
+        let PoolOperation::CancelLimitOrder { order_id } = op else {
+            panic!("Invalid operation");
+        };
+
+        Self {
+            _state: state,
+            runtime,
+
+            order_id: *order_id,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInterface>
+    Handler<PoolMessage, PoolResponse> for CancelLimitOrderHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        let origin = self.runtime.borrow_mut().authenticated_account();
+
+        let destination = self.runtime.borrow_mut().application_creator_chain_id();
+        let mut outcome = HandlerOutcome::new();
+
+        outcome.with_message(
+            destination,
+            PoolMessage::CancelLimitOrder {
+                origin,
+                order_id: self.order_id,
+            },
+            false,
+        );
+
+        Ok(Some(outcome))
+    }
+}
@@ -0,0 +1,64 @@
+use crate::interfaces::state::StateInterface;
+use abi::swap::pool::{PoolMessage, PoolOperation, PoolResponse};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::ApplicationId;
+use runtime::interfaces::{
+    access_control::AccessControl, contract::ContractRuntimeContext, meme::MemeRuntimeContext,
+};
+use std::{cell::RefCell, marker::PhantomData, rc::Rc};
+
+pub struct ClaimAllHandler<
+    R: ContractRuntimeContext + AccessControl + MemeRuntimeContext,
+    S: StateInterface,
+> {
+    runtime: Rc<RefCell<R>>,
+
+    tokens: Option<Vec<Option<ApplicationId>>>,
+    _state: PhantomData<S>,
+}
+
+impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInterface>
+    ClaimAllHandler<R, S>
+{
+    pub fn new(runtime: Rc<RefCell<R>>, _state: S, op: &PoolOperation) -> Self {
+        let PoolOperation::ClaimAll { tokens } = op else {
+            panic!("Invalid operation");
+        };
+
+        Self {
+            runtime,
+
+            tokens: tokens.clone(),
+            _state: PhantomData,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInterface>
+    Handler<PoolMessage, PoolResponse> for ClaimAllHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        assert!(
+            self.tokens.as_ref().is_none_or(|tokens| !tokens.is_empty()),
+            "Invalid tokens"
+        );
+
+        let origin = self.runtime.borrow_mut().authenticated_account();
+        let destination = self.runtime.borrow_mut().application_creator_chain_id();
+        let mut outcome = HandlerOutcome::new();
+        outcome.with_message(
+            destination,
+            PoolMessage::ClaimAll {
+                origin,
+                tokens: self.tokens.clone(),
+            },
+            false,
+        );
+
+        Ok(Some(outcome))
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{
+    contract_inner::handlers::{
+        fund_pool_application_creation_chain::FundPoolApplicationCreationChainHandler,
+        request_meme_fund::RequestMemeFundHandler,
+    },
+    interfaces::{parameters::ParametersInterface, state::StateInterface},
+};
+use abi::swap::pool::{FundRequest, FundType, PoolMessage, PoolOperation, PoolResponse};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{Account, Amount};
+use runtime::interfaces::{
+    access_control::AccessControl, contract::ContractRuntimeContext, meme::MemeRuntimeContext,
+};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct PlaceLimitOrderHandler<
+    R: ContractRuntimeContext + AccessControl + MemeRuntimeContext + ParametersInterface,
+    S: StateInterface,
+> {
+    runtime: Rc<RefCell<R>>,
+    state: Rc<RefCell<S>>,
+
+    amount_0_in: Option<Amount>,
+    amount_1_in: Option<Amount>,
+    amount_out_min: Amount,
+    to: Option<Account>,
+}
+
+impl<
+        R: ContractRuntimeContext + AccessControl + MemeRuntimeContext + ParametersInterface,
+        S: StateInterface,
+    > PlaceLimitOrderHandler<R, S>
+{
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, op: &PoolOperation) -> Self {
+        let PoolOperation::PlaceLimitOrder {
+            amount_0_in,
+            amount_1_in,
+            amount_out_min,
+            to,
+        } = op
+        else {
+            panic!("Invalid operation");
+        };
+
+        Self {
+            state: Rc::new(RefCell::new(state)),
+            runtime,
+
+            amount_0_in: *amount_0_in,
+            amount_1_in: *amount_1_in,
+            amount_out_min: *amount_out_min,
+            to: *to,
+        }
+    }
+
+    async fn fund_pool_application_creation_chain(&mut self, amount: Amount) {
+        let _ = FundPoolApplicationCreationChainHandler::new(
+            self.runtime.clone(),
+            self.state.clone(),
+            amount,
+        )
+        .handle()
+        .await;
+    }
+}
+
+#[async_trait(?Send)]
+impl<
+        R: ContractRuntimeContext + AccessControl + MemeRuntimeContext + ParametersInterface,
+        S: StateInterface,
+    > Handler<PoolMessage, PoolResponse> for PlaceLimitOrderHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        assert!(
+            self.amount_0_in.is_some() != self.amount_1_in.is_some(),
+            "Invalid amount"
+        );
+        assert!(self.amount_out_min > Amount::ZERO, "Invalid amount");
+
+        let origin = self.runtime.borrow_mut().authenticated_account();
+        let token_0 = self.runtime.borrow_mut().token_0();
+        let token_1 = self.runtime.borrow_mut().token_1();
+
+        let (token_in, counterparty_token, amount) = match (self.amount_0_in, self.amount_1_in) {
+            (Some(amount), None) => (Some(token_0), token_1, amount),
+            (None, Some(amount)) => (token_1, Some(token_0), amount),
+            _ => panic!("Invalid amount"),
+        };
+        assert!(amount > Amount::ZERO, "Invalid amount");
+
+        if token_in.is_some() {
+            let fund_request = FundRequest::builder(origin, token_in, amount, FundType::LimitOrder)
+                .counterparty_token(counterparty_token)
+                .counterparty_amount_out_min(Some(self.amount_out_min))
+                .to(self.to)
+                .build();
+
+            let mut handler = RequestMemeFundHandler::new(
+                self.runtime.clone(),
+                self.state.clone(),
+                None,
+                fund_request,
+                None,
+            );
+            return handler.handle().await;
+        }
+
+        self.fund_pool_application_creation_chain(amount).await;
+
+        let destination = self.runtime.borrow_mut().application_creator_chain_id();
+        let mut outcome = HandlerOutcome::new();
+
+        outcome.with_message(
+            destination,
+            PoolMessage::PlaceLimitOrder {
+                origin,
+                amount_0_in: self.amount_0_in,
+                amount_1_in: self.amount_1_in,
+                amount_out_min: self.amount_out_min,
+                to: self.to,
+            },
+            false,
+        );
+
+        Ok(Some(outcome))
+    }
+}
@@ -15,6 +15,7 @@ pub struct RemoveLiquidityHandler<R: ContractRuntimeContext + AccessControl, S:
     amount_1_out_min: Option<Amount>,
     to: Option<Account>,
     block_timestamp: Option<Timestamp>,
+    deadline: Option<Timestamp>,
 }
 
 impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> RemoveLiquidityHandler<R, S> {
@@ -25,6 +26,7 @@ impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> RemoveLiquidi
             amount_1_out_min,
             to,
             block_timestamp,
+            deadline,
         } = op
         else {
             panic!("Invalid operation");
@@ -39,6 +41,7 @@ impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> RemoveLiquidi
             amount_1_out_min: *amount_1_out_min,
             to: *to,
             block_timestamp: *block_timestamp,
+            deadline: *deadline,
         }
     }
 }
@@ -66,6 +69,7 @@ impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
                 amount_1_out_min: self.amount_1_out_min,
                 to: self.to,
                 block_timestamp: self.block_timestamp,
+                deadline: self.deadline,
             },
             false,
         );
@@ -0,0 +1,64 @@
+use crate::interfaces::state::StateInterface;
+use abi::swap::pool::{DynamicFee, PoolMessage, PoolOperation, PoolResponse};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use runtime::interfaces::{
+    access_control::AccessControl, contract::ContractRuntimeContext, meme::MemeRuntimeContext,
+};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct SetDynamicFeeHandler<
+    R: ContractRuntimeContext + AccessControl + MemeRuntimeContext,
+    S: StateInterface,
+> {
+    runtime: Rc<RefCell<R>>,
+    _state: S,
+
+    dynamic_fee: Option<DynamicFee>,
+}
+
+impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInterface>
+    SetDynamicFeeHandler<R, S>
+{
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, op: &PoolOperation) -> Self {
+        let PoolOperation::SetDynamicFee { dynamic_fee } = op else {
+            panic!("Invalid operation");
+        };
+
+        Self {
+            _state: state,
+            runtime,
+
+            dynamic_fee: dynamic_fee.clone(),
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInterface>
+    Handler<PoolMessage, PoolResponse> for SetDynamicFeeHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        if let Some(dynamic_fee) = &self.dynamic_fee {
+            dynamic_fee.validate();
+        }
+
+        let operator = self.runtime.borrow_mut().authenticated_account();
+
+        let destination = self.runtime.borrow_mut().application_creator_chain_id();
+        let mut outcome = HandlerOutcome::new();
+
+        outcome.with_message(
+            destination,
+            PoolMessage::SetDynamicFee {
+                operator,
+                dynamic_fee: self.dynamic_fee.clone(),
+            },
+            false,
+        );
+
+        Ok(Some(outcome))
+    }
+}
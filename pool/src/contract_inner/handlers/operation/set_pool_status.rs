@@ -0,0 +1,72 @@
+use crate::interfaces::state::StateInterface;
+use abi::swap::pool::{PoolMessage, PoolOperation, PoolResponse, PoolStatus};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{Account, AccountOwner};
+use runtime::interfaces::{
+    access_control::AccessControl, contract::ContractRuntimeContext, meme::MemeRuntimeContext,
+};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct SetPoolStatusHandler<
+    R: ContractRuntimeContext + AccessControl + MemeRuntimeContext,
+    S: StateInterface,
+> {
+    runtime: Rc<RefCell<R>>,
+    _state: S,
+
+    status: PoolStatus,
+}
+
+impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInterface>
+    SetPoolStatusHandler<R, S>
+{
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, op: &PoolOperation) -> Self {
+        let PoolOperation::SetPoolStatus { status } = op else {
+            panic!("Invalid operation");
+        };
+
+        Self {
+            _state: state,
+            runtime,
+
+            status: *status,
+        }
+    }
+
+    // Router is checked on the pool creator chain, where its application id is known
+    fn operator(&mut self) -> Account {
+        match self.runtime.borrow_mut().authenticated_caller_id() {
+            Some(caller_id) => Account {
+                chain_id: self.runtime.borrow_mut().chain_id(),
+                owner: AccountOwner::from(caller_id),
+            },
+            None => self.runtime.borrow_mut().authenticated_account(),
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInterface>
+    Handler<PoolMessage, PoolResponse> for SetPoolStatusHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        let operator = self.operator();
+
+        let destination = self.runtime.borrow_mut().application_creator_chain_id();
+        let mut outcome = HandlerOutcome::new();
+
+        outcome.with_message(
+            destination,
+            PoolMessage::SetPoolStatus {
+                operator,
+                status: self.status,
+            },
+            false,
+        );
+
+        Ok(Some(outcome))
+    }
+}
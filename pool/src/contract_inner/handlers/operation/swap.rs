@@ -5,7 +5,10 @@ use crate::{
     },
     interfaces::{parameters::ParametersInterface, state::StateInterface},
 };
-use abi::swap::pool::{FundRequest, FundType, PoolMessage, PoolOperation, PoolResponse};
+use abi::swap::{
+    pool::{FundRequest, FundType, PoolMessage, PoolOperation, PoolResponse},
+    router::SwapRoute,
+};
 use async_trait::async_trait;
 use base::handler::{Handler, HandlerError, HandlerOutcome};
 use linera_sdk::linera_base_types::{Account, Amount, Timestamp};
@@ -27,6 +30,11 @@ pub struct SwapHandler<
     amount_1_out_min: Option<Amount>,
     to: Option<Account>,
     block_timestamp: Option<Timestamp>,
+    route: Option<SwapRoute>,
+    amount_0_out: Option<Amount>,
+    amount_1_out: Option<Amount>,
+    deadline: Option<Timestamp>,
+    auto_claim: bool,
 }
 
 impl<
@@ -35,28 +43,66 @@ impl<
     > SwapHandler<R, S>
 {
     pub fn new(runtime: Rc<RefCell<R>>, state: S, op: &PoolOperation) -> Self {
-        let PoolOperation::Swap {
-            amount_0_in,
-            amount_1_in,
-            amount_0_out_min,
-            amount_1_out_min,
-            to,
-            block_timestamp,
-        } = op
-        else {
-            panic!("Invalid operation");
-        };
-
-        Self {
-            state: Rc::new(RefCell::new(state)),
-            runtime,
-
-            amount_0_in: *amount_0_in,
-            amount_1_in: *amount_1_in,
-            amount_0_out_min: *amount_0_out_min,
-            amount_1_out_min: *amount_1_out_min,
-            to: *to,
-            block_timestamp: *block_timestamp,
+        match op {
+            PoolOperation::Swap {
+                amount_0_in,
+                amount_1_in,
+                amount_0_out_min,
+                amount_1_out_min,
+                to,
+                block_timestamp,
+                route,
+                deadline,
+                auto_claim,
+            } => Self {
+                state: Rc::new(RefCell::new(state)),
+                runtime,
+
+                amount_0_in: *amount_0_in,
+                amount_1_in: *amount_1_in,
+                amount_0_out_min: *amount_0_out_min,
+                amount_1_out_min: *amount_1_out_min,
+                to: *to,
+                block_timestamp: *block_timestamp,
+                route: route.clone(),
+                amount_0_out: None,
+                amount_1_out: None,
+                deadline: *deadline,
+                auto_claim: *auto_claim,
+            },
+            // Exact output is funded like an exact input swap of amount_in_max
+            PoolOperation::SwapExactOut {
+                amount_0_out,
+                amount_1_out,
+                amount_in_max,
+                to,
+                block_timestamp,
+                deadline,
+                auto_claim,
+            } => {
+                assert!(
+                    amount_0_out.is_some() != amount_1_out.is_some(),
+                    "Invalid amount"
+                );
+
+                Self {
+                    state: Rc::new(RefCell::new(state)),
+                    runtime,
+
+                    amount_0_in: amount_1_out.map(|_| *amount_in_max),
+                    amount_1_in: amount_0_out.map(|_| *amount_in_max),
+                    amount_0_out_min: None,
+                    amount_1_out_min: None,
+                    to: *to,
+                    block_timestamp: *block_timestamp,
+                    route: None,
+                    amount_0_out: *amount_0_out,
+                    amount_1_out: *amount_1_out,
+                    deadline: *deadline,
+                    auto_claim: *auto_claim,
+                }
+            }
+            _ => panic!("Invalid operation"),
         }
     }
 
@@ -85,6 +131,11 @@ impl<
                 && (self.amount_0_in.is_some() || self.amount_1_in.is_some()),
             "Invalid amount"
         );
+        // Intermediate hop output is always handed back to the origin
+        assert!(
+            self.route.is_none() || self.to.is_none(),
+            "Invalid route recipient"
+        );
 
         let origin = self.runtime.borrow_mut().authenticated_account();
         let token_0 = self.runtime.borrow_mut().token_0();
@@ -106,6 +157,10 @@ impl<
                     .counterparty_amount_out_min(self.amount_1_out_min)
                     .to(self.to)
                     .block_timestamp(self.block_timestamp)
+                    .route(self.route.clone())
+                    .counterparty_amount_out(self.amount_1_out)
+                    .deadline(self.deadline)
+                    .auto_claim(self.auto_claim)
                     .build();
 
             let mut handler = RequestMemeFundHandler::new(
@@ -131,6 +186,10 @@ impl<
                 .counterparty_amount_out_min(self.amount_0_out_min)
                 .to(self.to)
                 .block_timestamp(self.block_timestamp)
+                .route(self.route.clone())
+                .counterparty_amount_out(self.amount_0_out)
+                .deadline(self.deadline)
+                .auto_claim(self.auto_claim)
                 .build();
 
             let mut handler = RequestMemeFundHandler::new(
@@ -158,6 +217,11 @@ impl<
                 amount_1_out_min: self.amount_1_out_min,
                 to: self.to,
                 block_timestamp: self.block_timestamp,
+                route: self.route.clone(),
+                amount_0_out: self.amount_0_out,
+                amount_1_out: self.amount_1_out,
+                deadline: self.deadline,
+                auto_claim: self.auto_claim,
             },
             false,
         );
@@ -52,7 +52,13 @@ where
 
     fn validate_request(&self, request: &FundRequest) {
         assert!(request.amount_in > Amount::ZERO, "Invalid amount");
-        assert_eq!(request.fund_type, FundType::Swap, "Invalid fund type");
+        assert!(
+            matches!(
+                request.fund_type,
+                FundType::Swap | FundType::LimitOrder | FundType::ZapIn
+            ),
+            "Invalid fund type"
+        );
     }
 }
 
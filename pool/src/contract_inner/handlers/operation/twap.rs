@@ -0,0 +1,62 @@
+use crate::interfaces::state::StateInterface;
+use abi::swap::pool::{PoolMessage, PoolOperation, PoolResponse};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use runtime::interfaces::{
+    access_control::AccessControl, contract::ContractRuntimeContext, meme::MemeRuntimeContext,
+};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct TwapHandler<
+    R: ContractRuntimeContext + AccessControl + MemeRuntimeContext,
+    S: StateInterface,
+> {
+    runtime: Rc<RefCell<R>>,
+    state: S,
+
+    window_seconds: u64,
+}
+
+impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInterface>
+    TwapHandler<R, S>
+{
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, op: &PoolOperation) -> Self {
+        let PoolOperation::Twap { window_seconds } = op else {
+            panic!("Invalid operation");
+        };
+
+        Self {
+            state,
+            runtime,
+
+            window_seconds: *window_seconds,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInterface>
+    Handler<PoolMessage, PoolResponse> for TwapHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        // Observations only exist where reserves are maintained
+        self.runtime
+            .borrow_mut()
+            .only_application_creator()
+            .expect("Invalid twap chain");
+
+        let now = self.runtime.borrow_mut().system_time();
+        let twap = self
+            .state
+            .calculate_twap(now, self.window_seconds)
+            .await
+            .map_err(Into::into)?;
+
+        let mut outcome = HandlerOutcome::new();
+        outcome.with_response(PoolResponse::Twap(twap));
+
+        Ok(Some(outcome))
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{
+    contract_inner::handlers::{
+        fund_pool_application_creation_chain::FundPoolApplicationCreationChainHandler,
+        request_meme_fund::RequestMemeFundHandler,
+    },
+    interfaces::{parameters::ParametersInterface, state::StateInterface},
+};
+use abi::swap::pool::{FundRequest, FundType, PoolMessage, PoolOperation, PoolResponse};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{Account, Amount, ApplicationId, Timestamp};
+use runtime::interfaces::{
+    access_control::AccessControl, contract::ContractRuntimeContext, meme::MemeRuntimeContext,
+};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct ZapInHandler<
+    R: ContractRuntimeContext + AccessControl + MemeRuntimeContext + ParametersInterface,
+    S: StateInterface,
+> {
+    runtime: Rc<RefCell<R>>,
+    state: Rc<RefCell<S>>,
+
+    token: Option<ApplicationId>,
+    amount_in: Amount,
+    min_liquidity: Option<Amount>,
+    to: Option<Account>,
+    block_timestamp: Option<Timestamp>,
+    deadline: Option<Timestamp>,
+}
+
+impl<
+        R: ContractRuntimeContext + AccessControl + MemeRuntimeContext + ParametersInterface,
+        S: StateInterface,
+    > ZapInHandler<R, S>
+{
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, op: &PoolOperation) -> Self {
+        let PoolOperation::ZapIn {
+            token,
+            amount_in,
+            min_liquidity,
+            to,
+            block_timestamp,
+            deadline,
+        } = op
+        else {
+            panic!("Invalid operation");
+        };
+
+        Self {
+            state: Rc::new(RefCell::new(state)),
+            runtime,
+
+            token: *token,
+            amount_in: *amount_in,
+            min_liquidity: *min_liquidity,
+            to: *to,
+            block_timestamp: *block_timestamp,
+            deadline: *deadline,
+        }
+    }
+
+    async fn fund_pool_application_creation_chain(&mut self, amount: Amount) {
+        let _ = FundPoolApplicationCreationChainHandler::new(
+            self.runtime.clone(),
+            self.state.clone(),
+            amount,
+        )
+        .handle()
+        .await;
+    }
+}
+
+#[async_trait(?Send)]
+impl<
+        R: ContractRuntimeContext + AccessControl + MemeRuntimeContext + ParametersInterface,
+        S: StateInterface,
+    > Handler<PoolMessage, PoolResponse> for ZapInHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        assert!(self.amount_in > Amount::ZERO, "Invalid amount");
+        self.state.borrow().pool().validate_token(self.token);
+
+        let origin = self.runtime.borrow_mut().authenticated_account();
+
+        if self.token.is_some() {
+            let fund_request =
+                FundRequest::builder(origin, self.token, self.amount_in, FundType::ZapIn)
+                    .amount_out_min(self.min_liquidity)
+                    .to(self.to)
+                    .block_timestamp(self.block_timestamp)
+                    .deadline(self.deadline)
+                    .build();
+
+            let mut handler = RequestMemeFundHandler::new(
+                self.runtime.clone(),
+                self.state.clone(),
+                None,
+                fund_request,
+                None,
+            );
+            return handler.handle().await;
+        }
+
+        self.fund_pool_application_creation_chain(self.amount_in)
+            .await;
+
+        let destination = self.runtime.borrow_mut().application_creator_chain_id();
+        let mut outcome = HandlerOutcome::new();
+
+        // Native token is always token_1
+        outcome.with_message(
+            destination,
+            PoolMessage::ZapIn {
+                origin,
+                amount_0_in: None,
+                amount_1_in: Some(self.amount_in),
+                min_liquidity: self.min_liquidity,
+                to: self.to,
+                block_timestamp: self.block_timestamp,
+                deadline: self.deadline,
+            },
+            false,
+        );
+
+        Ok(Some(outcome))
+    }
+}
@@ -0,0 +1,80 @@
+use crate::interfaces::state::StateInterface;
+use abi::swap::pool::{PoolMessage, PoolOperation, PoolResponse};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{Account, Amount, ApplicationId, Timestamp};
+use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct ZapOutHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: Rc<RefCell<R>>,
+    state: S,
+
+    liquidity: Amount,
+    token: Option<ApplicationId>,
+    amount_out_min: Option<Amount>,
+    to: Option<Account>,
+    block_timestamp: Option<Timestamp>,
+    deadline: Option<Timestamp>,
+}
+
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> ZapOutHandler<R, S> {
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, op: &PoolOperation) -> Self {
+        let PoolOperation::ZapOut {
+            liquidity,
+            token,
+            amount_out_min,
+            to,
+            block_timestamp,
+            deadline,
+        } = op
+        else {
+            panic!("Invalid operation");
+        };
+
+        Self {
+            state,
+            runtime,
+
+            liquidity: *liquidity,
+            token: *token,
+            amount_out_min: *amount_out_min,
+            to: *to,
+            block_timestamp: *block_timestamp,
+            deadline: *deadline,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    Handler<PoolMessage, PoolResponse> for ZapOutHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        assert!(self.liquidity > Amount::ZERO, "Invalid amount");
+        self.state.pool().validate_token(self.token);
+
+        let origin = self.runtime.borrow_mut().authenticated_account();
+
+        let destination = self.runtime.borrow_mut().application_creator_chain_id();
+        let mut outcome = HandlerOutcome::new();
+
+        outcome.with_message(
+            destination,
+            PoolMessage::ZapOut {
+                origin,
+                liquidity: self.liquidity,
+                token: self.token,
+                amount_out_min: self.amount_out_min,
+                to: self.to,
+                block_timestamp: self.block_timestamp,
+                deadline: self.deadline,
+            },
+            false,
+        );
+
+        Ok(Some(outcome))
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{interfaces::state::StateInterface, state::errors::StateError};
+use abi::swap::pool::{FailedRequest, FailedRequestKind, FailureReason, PoolMessage, PoolResponse};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{Account, Amount};
+use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
+use std::{cell::RefCell, rc::Rc};
+
+// Pool errors are boxed into ProcessError by state, unwrap them to keep the reason precise
+pub fn failure_reason(err: &HandlerError) -> FailureReason {
+    match err {
+        HandlerError::NotEnabled => FailureReason::NotEnabled,
+        HandlerError::DeadlineExceeded => FailureReason::DeadlineExceeded,
+        HandlerError::InvalidAmount | HandlerError::ArithmeticError(_) => {
+            FailureReason::InvalidAmount
+        }
+        HandlerError::InsufficientFunds => FailureReason::InsufficientLiquidity,
+        HandlerError::ProcessError(err) => match err.downcast_ref::<StateError>() {
+            Some(StateError::PoolError(err)) => FailureReason::from(err),
+            Some(StateError::ArithmeticError(_)) => FailureReason::InvalidAmount,
+            _ => FailureReason::Other,
+        },
+        _ => FailureReason::Other,
+    }
+}
+
+// Record a request refunded to origin as claimable balance so the user could find out why
+// funds came back. Origin on another chain also gets the record there.
+
+pub struct RecordFailedRequestHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+{
+    runtime: Rc<RefCell<R>>,
+    state: Rc<RefCell<S>>,
+
+    origin: Account,
+    kind: FailedRequestKind,
+    amount_0: Amount,
+    amount_1: Amount,
+    reason: FailureReason,
+}
+
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    RecordFailedRequestHandler<R, S>
+{
+    pub fn new(
+        runtime: Rc<RefCell<R>>,
+        state: Rc<RefCell<S>>,
+        origin: Account,
+        kind: FailedRequestKind,
+        amount_0: Amount,
+        amount_1: Amount,
+        reason: FailureReason,
+    ) -> Self {
+        Self {
+            runtime,
+            state,
+
+            origin,
+            kind,
+            amount_0,
+            amount_1,
+            reason,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    Handler<PoolMessage, PoolResponse> for RecordFailedRequestHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<PoolMessage, PoolResponse>>, HandlerError> {
+        let request = FailedRequest {
+            kind: self.kind,
+            amount_0: self.amount_0,
+            amount_1: self.amount_1,
+            reason: self.reason,
+            created_at: self.runtime.borrow_mut().system_time(),
+        };
+        log::warn!(
+            "Failed {:?} of {}: {:?}",
+            self.kind,
+            self.origin,
+            self.reason
+        );
+
+        self.state
+            .borrow_mut()
+            .record_failed_request(self.origin, request.clone())
+            .await
+            .map_err(Into::into)?;
+
+        if self.origin.chain_id == self.runtime.borrow_mut().chain_id() {
+            return Ok(None);
+        }
+
+        let mut outcome = HandlerOutcome::new();
+        outcome.with_message(
+            self.origin.chain_id,
+            PoolMessage::RequestFailed {
+                origin: self.origin,
+                request,
+            },
+            false,
+        );
+
+        Ok(Some(outcome))
+    }
+}
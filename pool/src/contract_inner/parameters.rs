@@ -1,5 +1,5 @@
 use crate::interfaces::parameters::ParametersInterface;
-use abi::swap::pool::{BootstrapPolicy, PoolParameters};
+use abi::swap::pool::{BootstrapPolicy, LaunchProtection, PoolParameters};
 use linera_sdk::{
     linera_base_types::{Account, ApplicationId},
     Contract,
@@ -22,6 +22,10 @@ impl ParametersInterface for PoolParameters {
     fn bootstrap_policy(&mut self) -> BootstrapPolicy {
         self.bootstrap_policy.clone()
     }
+
+    fn launch_protection(&mut self) -> Option<LaunchProtection> {
+        self.launch_protection.clone()
+    }
 }
 
 impl<T, M> ParametersInterface for ContractRuntimeAdapter<T, M>
@@ -44,4 +48,8 @@ where
     fn bootstrap_policy(&mut self) -> BootstrapPolicy {
         self.application_parameters().bootstrap_policy()
     }
+
+    fn launch_protection(&mut self) -> Option<LaunchProtection> {
+        self.application_parameters().launch_protection()
+    }
 }
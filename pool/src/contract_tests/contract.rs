@@ -6,10 +6,15 @@ use abi::{
         TransferFromApplicationReceiptPurpose,
     },
     meme_token::MemeToken,
-    swap::pool::{
-        AddLiquidityTransferReceipt, BootstrapPolicy, ClaimTransferReceipt, FundRequest, FundType,
-        InstantiationArgument, PoolAbi, PoolMessage, PoolOperation, PoolParameters, PoolResponse,
-        SwapTransferReceipt,
+    swap::{
+        pool::{
+            AddLiquidityTransferReceipt, BootstrapPolicy, ClaimTransferReceipt, DynamicFee,
+            FailedRequestKind, FailureReason, FundRequest, FundType, InstantiationArgument,
+            LaunchProtection, PoolAbi, PoolMessage, PoolOperation, PoolParameters, PoolResponse,
+            PoolStats, PoolStatus, SwapSettlementStatus, SwapTransferReceipt,
+            DEFAULT_PROTOCOL_FEE_SHARE_DENOMINATOR, MAX_FAILED_REQUESTS,
+        },
+        router::{SwapOperation, SwapResponse, SwapRoute, SwapRouteHop},
     },
 };
 use futures::FutureExt as _;
@@ -199,6 +204,9 @@ async fn operation_swap() {
             amount_1_out_min: None,
             to: None,
             block_timestamp: None,
+            route: None,
+            deadline: None,
+            auto_claim: false,
         })
         .now_or_never()
         .expect("Execution of meme operation should not await anything");
@@ -222,6 +230,9 @@ async fn operation_swap_meme_input_queues_message_carried_funding_without_persis
             amount_1_out_min: Some(Amount::from_attos(1)),
             to: None,
             block_timestamp: None,
+            route: None,
+            deadline: None,
+            auto_claim: false,
         })
         .await;
 
@@ -247,209 +258,81 @@ async fn operation_swap_meme_input_queues_message_carried_funding_without_persis
 }
 
 #[tokio::test(flavor = "multi_thread")]
-async fn message_swap_rejects_without_finalized_reserve_share_facts() {
-    let mut pool = create_and_instantiate_pool_with_amounts(false).await;
+async fn operation_swap_exact_out_funds_maximum_input() {
+    let mut pool = create_and_initialize_pool(true).await;
     let origin = authenticated_account(&pool);
-    let reserve_0_before = pool.state.borrow().reserve_0();
-    let reserve_1_before = pool.state.borrow().reserve_1();
-    let total_supply_before = total_supply(&pool);
-    let request_count_before = pool.runtime.borrow().created_send_message_requests().len();
-
-    pool.execute_message(PoolMessage::Swap {
-        origin,
-        amount_0_in: Some(Amount::ONE),
-        amount_1_in: None,
-        amount_0_out_min: None,
-        amount_1_out_min: None,
-        to: None,
-        block_timestamp: None,
-    })
-    .await;
-
-    assert_eq!(
-        pool.state
-            .borrow()
-            .claimable_balance(
-                MemeToken::Fungible(pool.runtime.borrow_mut().application_parameters().token_0),
-                origin
-            )
-            .await
-            .unwrap(),
-        Amount::ONE
-    );
-    assert_eq!(pool.state.borrow().reserve_0(), reserve_0_before);
-    assert_eq!(pool.state.borrow().reserve_1(), reserve_1_before);
-    assert_eq!(total_supply(&pool), total_supply_before);
-    assert_eq!(
-        pool.runtime.borrow().created_send_message_requests().len(),
-        request_count_before
-    );
-}
-
-#[tokio::test(flavor = "multi_thread")]
-async fn operation_add_liquidity() {
-    let mut pool = create_and_instantiate_pool(true).await;
+    let token_0 = pool.runtime.borrow_mut().application_parameters().token_0;
+    let message_count_before = pool.runtime.borrow().created_send_message_requests().len();
 
     let response = pool
-        .execute_operation(PoolOperation::AddLiquidity {
-            amount_0_in: Amount::ONE,
-            amount_1_in: Amount::from_tokens(20),
-            amount_0_out_min: None,
-            amount_1_out_min: None,
+        .execute_operation(PoolOperation::SwapExactOut {
+            amount_0_out: None,
+            amount_1_out: Some(Amount::ONE),
+            amount_in_max: Amount::from_tokens(200),
             to: None,
             block_timestamp: None,
+            deadline: None,
+            auto_claim: false,
         })
-        .now_or_never()
-        .expect("Execution of meme operation should not await anything");
-
-    assert!(matches!(response, PoolResponse::Ok));
-}
-
-#[tokio::test(flavor = "multi_thread")]
-async fn message_remove_liquidity_rejects_without_finalized_reserve_share_facts() {
-    let mut pool = create_and_instantiate_pool_with_amounts(false).await;
-    let origin = authenticated_account(&pool);
-    let reserve_0_before = pool.state.borrow().reserve_0();
-    let reserve_1_before = pool.state.borrow().reserve_1();
-    let total_supply_before = total_supply(&pool);
-    let request_count_before = pool.runtime.borrow().created_send_message_requests().len();
-
-    let result = std::panic::AssertUnwindSafe(pool.execute_message(PoolMessage::RemoveLiquidity {
-        origin,
-        liquidity: Amount::ONE,
-        amount_0_out_min: None,
-        amount_1_out_min: None,
-        to: None,
-        block_timestamp: None,
-    }))
-    .catch_unwind()
-    .await;
-
-    assert!(result.is_err());
-    assert_eq!(pool.state.borrow().reserve_0(), reserve_0_before);
-    assert_eq!(pool.state.borrow().reserve_1(), reserve_1_before);
-    assert_eq!(total_supply(&pool), total_supply_before);
-    assert_eq!(
-        pool.runtime.borrow().created_send_message_requests().len(),
-        request_count_before
-    );
-}
-
-#[tokio::test(flavor = "multi_thread")]
-async fn message_set_fee_to_updates_fee_receiver_for_current_fee_to_setter() {
-    let mut pool = create_and_instantiate_pool(true).await;
-    let operator = authenticated_account(&pool);
-    let account = Account {
-        chain_id: operator.chain_id,
-        owner: AccountOwner::from_str(
-            "0x8b0f4d4320f64d5cf5fd742f5a7d6a51a8c3dbd9d6c6c23f73e3b0f8fbb04f11",
-        )
-        .unwrap(),
-    };
-
-    pool.execute_message(PoolMessage::SetFeeTo { operator, account })
         .await;
 
-    let pool_state = pool.state.borrow().pool();
-    assert_eq!(pool_state.fee_to, account);
-    assert_eq!(pool_state.fee_to_setter, operator);
+    assert!(matches!(response, PoolResponse::Ok));
+    let runtime = pool.runtime.borrow();
+    let requests = &runtime.created_send_message_requests()[message_count_before..];
+    assert_eq!(requests.len(), 1);
+    assert!(matches!(
+        &requests[0].message,
+        PoolMessage::RequestFund {
+            prev: None,
+            request,
+            next: None,
+        } if request.from == origin
+            && request.token == Some(token_0)
+            && request.amount_in == Amount::from_tokens(200)
+            && request.counterparty_amount_out == Some(Amount::ONE)
+            && request.fund_type == FundType::Swap
+    ));
 }
 
 #[tokio::test(flavor = "multi_thread")]
-async fn message_set_fee_to_setter_rotates_operator_and_invalidates_old_operator() {
-    let mut pool = create_and_instantiate_pool(true).await;
-    let old_operator = authenticated_account(&pool);
-    let new_operator = Account {
-        chain_id: old_operator.chain_id,
-        owner: AccountOwner::from_str(
-            "0x3f3c6f7fbc833f18d48f3b9d8552cf16e491061b85d34ed2a5b3720d9f2f4c31",
-        )
-        .unwrap(),
-    };
-    let target_fee_to = Account {
-        chain_id: old_operator.chain_id,
-        owner: AccountOwner::from_str(
-            "0x3447565f8a4f3db39c46fc92f6fa5700d6c74a585cd49007daa4619052f5e91b",
-        )
-        .unwrap(),
-    };
-
-    pool.execute_message(PoolMessage::SetFeeToSetter {
-        operator: old_operator,
-        account: new_operator,
-    })
-    .await;
-    assert_eq!(pool.state.borrow().pool().fee_to_setter, new_operator);
+async fn operation_swap_exact_out_rejects_both_outputs() {
+    let mut pool = create_and_initialize_pool(true).await;
 
-    let old_operator_attempt =
-        std::panic::AssertUnwindSafe(pool.execute_message(PoolMessage::SetFeeTo {
-            operator: old_operator,
-            account: target_fee_to,
+    let result =
+        std::panic::AssertUnwindSafe(pool.execute_operation(PoolOperation::SwapExactOut {
+            amount_0_out: Some(Amount::ONE),
+            amount_1_out: Some(Amount::ONE),
+            amount_in_max: Amount::ONE,
+            to: None,
+            block_timestamp: None,
+            deadline: None,
+            auto_claim: false,
         }))
         .catch_unwind()
         .await;
-    assert!(old_operator_attempt.is_err());
-
-    pool.execute_message(PoolMessage::SetFeeTo {
-        operator: new_operator,
-        account: target_fee_to,
-    })
-    .await;
-
-    let pool_state = pool.state.borrow().pool();
-    assert_eq!(pool_state.fee_to_setter, new_operator);
-    assert_eq!(pool_state.fee_to, target_fee_to);
-}
-
-#[tokio::test(flavor = "multi_thread")]
-async fn message_set_fee_to_rejects_non_operator() {
-    let mut pool = create_and_instantiate_pool(true).await;
-    let current_operator = authenticated_account(&pool);
-    let invalid_operator = Account {
-        chain_id: current_operator.chain_id,
-        owner: AccountOwner::from_str(
-            "0x61f6a31f937dfb8a5e47f6d471b1e40f949e8ddfb66914318e403d315f0dce21",
-        )
-        .unwrap(),
-    };
-    let target_fee_to = Account {
-        chain_id: current_operator.chain_id,
-        owner: AccountOwner::from_str(
-            "0x216bd78c27e4abfef4e1a6b4af2f14f4dd35df621d8f21891cf9d33d6535f1a1",
-        )
-        .unwrap(),
-    };
-
-    let result = std::panic::AssertUnwindSafe(pool.execute_message(PoolMessage::SetFeeTo {
-        operator: invalid_operator,
-        account: target_fee_to,
-    }))
-    .catch_unwind()
-    .await;
 
     assert!(result.is_err());
-    let pool_state = pool.state.borrow().pool();
-    assert_eq!(pool_state.fee_to_setter, current_operator);
-    assert_eq!(pool_state.fee_to, current_operator);
 }
 
 #[tokio::test(flavor = "multi_thread")]
-async fn message_swap() {
+async fn message_swap_exact_out_credits_unused_input() {
     let mut pool = create_and_initialize_pool(true).await;
-    let mut runtime_context = ContractRuntimeAdapter::new(pool.runtime.clone());
-
-    let owner = Account {
-        chain_id: runtime_context.chain_id(),
-        owner: runtime_context.authenticated_signer().unwrap(),
-    };
-
+    let owner = authenticated_account(&pool);
+    let token_0 = pool.runtime.borrow_mut().application_parameters().token_0;
+    let token_1 = pool.runtime.borrow_mut().application_parameters().token_1;
     let reserve_0 = pool.state.borrow().reserve_0();
     let reserve_1 = pool.state.borrow().reserve_1();
-    let swap_amount_0 = pool
+    let amount_0_out = Amount::from_tokens(50);
+    let amount_1_in = pool
         .state
         .borrow()
-        .calculate_swap_amount_0(Amount::ONE)
+        .pool()
+        .calculate_swap_amount_1_in(amount_0_out)
         .unwrap();
+    assert_eq!(
+        amount_1_in,
+        Amount::from_str("0.527899487937496701").unwrap()
+    );
 
     pool.execute_message(PoolMessage::Swap {
         origin: owner,
@@ -459,218 +342,2641 @@ async fn message_swap() {
         amount_1_out_min: None,
         to: None,
         block_timestamp: None,
+        route: None,
+        amount_0_out: Some(amount_0_out),
+        amount_1_out: None,
+        deadline: None,
+        auto_claim: false,
     })
     .await;
 
     assert_eq!(
-        reserve_0.try_sub(swap_amount_0).unwrap(),
-        pool.state.borrow().reserve_0()
+        pool.state.borrow().reserve_0(),
+        reserve_0.try_sub(amount_0_out).unwrap()
     );
     assert_eq!(
-        reserve_1.try_add(Amount::ONE).unwrap(),
-        pool.state.borrow().reserve_1()
+        pool.state.borrow().reserve_1(),
+        reserve_1.try_add(amount_1_in).unwrap()
     );
     assert_eq!(
         pool.state
             .borrow()
-            .claimable_balance(MemeToken::Fungible(runtime_context.token_0()), owner)
+            .claimable_balance(MemeToken::from(Some(token_0)), owner)
             .await
             .unwrap(),
-        swap_amount_0
+        amount_0_out
+    );
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claimable_balance(MemeToken::from(token_1), owner)
+            .await
+            .unwrap(),
+        Amount::ONE.try_sub(amount_1_in).unwrap()
     );
 }
 
 #[tokio::test(flavor = "multi_thread")]
-async fn message_swap_min_amount_boundary() {
+async fn message_swap_exact_out_refunds_when_input_exceeds_maximum() {
     let mut pool = create_and_initialize_pool(true).await;
     let owner = authenticated_account(&pool);
-    let amount_1_in = Amount::ONE;
-    let exact_amount_0_out = pool
-        .state
-        .borrow()
-        .calculate_swap_amount_0(amount_1_in)
-        .unwrap();
+    let token_0 = pool.runtime.borrow_mut().application_parameters().token_0;
+    let token_1 = pool.runtime.borrow_mut().application_parameters().token_1;
+    let reserve_0 = pool.state.borrow().reserve_0();
+    let reserve_1 = pool.state.borrow().reserve_1();
+    let amount_1_in_max = Amount::from_str("0.5").unwrap();
 
     pool.execute_message(PoolMessage::Swap {
         origin: owner,
         amount_0_in: None,
-        amount_1_in: Some(amount_1_in),
-        amount_0_out_min: Some(exact_amount_0_out),
+        amount_1_in: Some(amount_1_in_max),
+        amount_0_out_min: None,
         amount_1_out_min: None,
         to: None,
         block_timestamp: None,
+        route: None,
+        amount_0_out: Some(Amount::from_tokens(50)),
+        amount_1_out: None,
+        deadline: None,
+        auto_claim: false,
     })
     .await;
 
+    assert_eq!(pool.state.borrow().reserve_0(), reserve_0);
+    assert_eq!(pool.state.borrow().reserve_1(), reserve_1);
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claimable_balance(MemeToken::from(Some(token_0)), owner)
+            .await
+            .unwrap(),
+        Amount::ZERO
+    );
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claimable_balance(MemeToken::from(token_1), owner)
+            .await
+            .unwrap(),
+        amount_1_in_max
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_swap_refunds_after_deadline() {
     let mut pool = create_and_initialize_pool(true).await;
     let owner = authenticated_account(&pool);
+    let token_0 = pool.runtime.borrow_mut().application_parameters().token_0;
+    let token_1 = pool.runtime.borrow_mut().application_parameters().token_1;
     let reserve_0 = pool.state.borrow().reserve_0();
     let reserve_1 = pool.state.borrow().reserve_1();
+    pool.runtime.borrow_mut().set_system_time(10_000_000.into());
+
     pool.execute_message(PoolMessage::Swap {
         origin: owner,
-        amount_0_in: None,
-        amount_1_in: Some(amount_1_in),
-        amount_0_out_min: Some(exact_amount_0_out.try_add(Amount::from_attos(1)).unwrap()),
-        amount_1_out_min: None,
+        amount_0_in: Some(Amount::ONE),
+        amount_1_in: None,
+        amount_0_out_min: None,
+        amount_1_out_min: None,
         to: None,
         block_timestamp: None,
+        route: None,
+        amount_0_out: None,
+        amount_1_out: None,
+        deadline: Some(9_999_999.into()),
+        auto_claim: false,
     })
     .await;
+
     assert_eq!(pool.state.borrow().reserve_0(), reserve_0);
     assert_eq!(pool.state.borrow().reserve_1(), reserve_1);
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claimable_balance(MemeToken::from(Some(token_0)), owner)
+            .await
+            .unwrap(),
+        Amount::ONE
+    );
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claimable_balance(MemeToken::from(token_1), owner)
+            .await
+            .unwrap(),
+        Amount::ZERO
+    );
 }
 
 #[tokio::test(flavor = "multi_thread")]
-async fn message_add_liquidity() {
+async fn message_swap_after_deadline_records_failed_request() {
     let mut pool = create_and_initialize_pool(true).await;
     let owner = authenticated_account(&pool);
+    pool.runtime.borrow_mut().set_system_time(10_000_000.into());
 
-    pool.execute_message(PoolMessage::AddLiquidity {
+    pool.execute_message(PoolMessage::Swap {
         origin: owner,
-        amount_0_in: Amount::ONE,
-        amount_1_in: Amount::from_tokens(10),
+        amount_0_in: Some(Amount::ONE),
+        amount_1_in: None,
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        to: None,
+        block_timestamp: None,
+        route: None,
+        amount_0_out: None,
+        amount_1_out: None,
+        deadline: Some(9_999_999.into()),
+        auto_claim: false,
+    })
+    .await;
+
+    let failed_requests = pool.state.borrow().failed_requests(owner).await.unwrap();
+    assert_eq!(failed_requests.len(), 1);
+    assert_eq!(failed_requests[0].kind, FailedRequestKind::Swap);
+    assert_eq!(failed_requests[0].amount_0, Amount::ONE);
+    assert_eq!(failed_requests[0].amount_1, Amount::ZERO);
+    assert_eq!(failed_requests[0].reason, FailureReason::DeadlineExceeded);
+    assert_eq!(failed_requests[0].created_at, 10_000_000.into());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_failed_requests_keep_latest_only() {
+    let mut pool = create_and_initialize_pool(true).await;
+    let owner = authenticated_account(&pool);
+    pool.runtime.borrow_mut().set_system_time(10_000_000.into());
+
+    for attos in 1..=MAX_FAILED_REQUESTS as u128 + 2 {
+        pool.execute_message(PoolMessage::Swap {
+            origin: owner,
+            amount_0_in: Some(Amount::from_attos(attos)),
+            amount_1_in: None,
+            amount_0_out_min: None,
+            amount_1_out_min: None,
+            to: None,
+            block_timestamp: None,
+            route: None,
+            amount_0_out: None,
+            amount_1_out: None,
+            deadline: Some(9_999_999.into()),
+            auto_claim: false,
+        })
+        .await;
+    }
+
+    let failed_requests = pool.state.borrow().failed_requests(owner).await.unwrap();
+    assert_eq!(failed_requests.len(), MAX_FAILED_REQUESTS);
+    assert_eq!(failed_requests[0].amount_0, Amount::from_attos(3));
+    assert_eq!(
+        failed_requests[MAX_FAILED_REQUESTS - 1].amount_0,
+        Amount::from_attos(MAX_FAILED_REQUESTS as u128 + 2)
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_swap_records_settlement_of_local_origin() {
+    let mut pool = create_and_initialize_pool(true).await;
+    let owner = authenticated_account(&pool);
+
+    pool.execute_message(PoolMessage::Swap {
+        origin: owner,
+        amount_0_in: Some(Amount::ONE),
+        amount_1_in: None,
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        to: None,
+        block_timestamp: None,
+        route: None,
+        amount_0_out: None,
+        amount_1_out: None,
+        deadline: None,
+        auto_claim: false,
+    })
+    .await;
+
+    let settlements = pool.state.borrow().swap_settlements().await.unwrap();
+    assert_eq!(settlements.len(), 1);
+    assert_eq!(settlements[0].status, SwapSettlementStatus::Claimable);
+    assert_eq!(settlements[0].transaction.from, owner);
+    assert_eq!(settlements[0].transaction.amount_0_in, Some(Amount::ONE));
+    assert!(settlements[0].transaction.amount_1_out.is_some());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_swap_records_failed_credit_of_output() {
+    let mut pool = create_and_initialize_pool(true).await;
+    let owner = authenticated_account(&pool);
+    let token_1 = pool.runtime.borrow_mut().application_parameters().token_1;
+    let receiver = alternate_account(owner.chain_id);
+    // Output credit to the receiver overflows
+    pool.state
+        .borrow_mut()
+        .credit(MemeToken::from(token_1), receiver, Amount::MAX)
+        .await
+        .unwrap();
+
+    pool.execute_message(PoolMessage::Swap {
+        origin: owner,
+        amount_0_in: Some(Amount::ONE),
+        amount_1_in: None,
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        to: Some(receiver),
+        block_timestamp: None,
+        route: None,
+        amount_0_out: None,
+        amount_1_out: None,
+        deadline: None,
+        auto_claim: false,
+    })
+    .await;
+
+    let settlements = pool.state.borrow().swap_settlements().await.unwrap();
+    assert_eq!(settlements.len(), 1);
+    assert_eq!(settlements[0].status, SwapSettlementStatus::Failed);
+    let failed_requests = pool.state.borrow().failed_requests(owner).await.unwrap();
+    assert_eq!(failed_requests.len(), 1);
+    assert_eq!(failed_requests[0].kind, FailedRequestKind::Swap);
+    assert_eq!(failed_requests[0].reason, FailureReason::CreditFailed);
+    assert_eq!(
+        Some(failed_requests[0].amount_1),
+        settlements[0].transaction.amount_1_out
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_swap_sends_tracked_settlement_to_origin_chain() {
+    let mut pool = create_and_initialize_pool(true).await;
+    let owner = authenticated_account(&pool);
+    let user_chain_id =
+        ChainId::from_str("bee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfe8")
+            .unwrap();
+    let origin = Account {
+        chain_id: user_chain_id,
+        owner: owner.owner,
+    };
+
+    pool.execute_message(PoolMessage::Swap {
+        origin,
+        amount_0_in: Some(Amount::ONE),
+        amount_1_in: None,
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        to: None,
+        block_timestamp: None,
+        route: None,
+        amount_0_out: None,
+        amount_1_out: None,
+        deadline: None,
+        auto_claim: false,
+    })
+    .await;
+
+    assert!(pool
+        .state
+        .borrow()
+        .swap_settlements()
+        .await
+        .unwrap()
+        .is_empty());
+    let runtime = pool.runtime.borrow();
+    let request = runtime
+        .created_send_message_requests()
+        .iter()
+        .find(|request| matches!(request.message, PoolMessage::SwapSettled { .. }))
+        .unwrap();
+    assert_eq!(request.destination, user_chain_id);
+    assert!(request.is_tracked);
+    assert!(matches!(
+        &request.message,
+        PoolMessage::SwapSettled { transaction, status }
+            if transaction.from == origin && *status == SwapSettlementStatus::Claimable
+    ));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_swap_settled_records_settlement_on_origin_chain() {
+    let mut pool = create_and_instantiate_pool(true).await;
+    let owner = authenticated_account(&pool);
+    let user_chain_id =
+        ChainId::from_str("bee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfe8")
+            .unwrap();
+    let creator_chain_id = pool.runtime.borrow_mut().application_creator_chain_id();
+    let origin = Account {
+        chain_id: user_chain_id,
+        owner: owner.owner,
+    };
+    let transaction = pool.state.borrow_mut().build_transaction(
+        origin,
+        Some(Amount::ONE),
+        None,
+        None,
+        Some(Amount::from_str("0.00997").unwrap()),
+        None,
+        1.into(),
+    );
+
+    pool.runtime.borrow_mut().set_chain_id(user_chain_id);
+    pool.runtime
+        .borrow_mut()
+        .set_message_origin_chain_id(creator_chain_id);
+    pool.execute_message(PoolMessage::SwapSettled {
+        transaction,
+        status: SwapSettlementStatus::Delivering,
+    })
+    .await;
+
+    let settlements = pool.state.borrow().swap_settlements().await.unwrap();
+    assert_eq!(settlements.len(), 1);
+    assert_eq!(settlements[0].transaction, transaction);
+    assert_eq!(settlements[0].status, SwapSettlementStatus::Delivering);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_swap_settled_bounce_is_ignored() {
+    let mut pool = create_and_instantiate_pool(true).await;
+    let owner = authenticated_account(&pool);
+    let user_chain_id =
+        ChainId::from_str("bee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfe8")
+            .unwrap();
+    let transaction = pool.state.borrow_mut().build_transaction(
+        Account {
+            chain_id: user_chain_id,
+            owner: owner.owner,
+        },
+        Some(Amount::ONE),
+        None,
+        None,
+        Some(Amount::from_str("0.00997").unwrap()),
+        None,
+        1.into(),
+    );
+
+    pool.runtime.borrow_mut().set_message_is_bouncing(true);
+    pool.runtime
+        .borrow_mut()
+        .set_message_origin_chain_id(user_chain_id);
+    pool.execute_message(PoolMessage::SwapSettled {
+        transaction,
+        status: SwapSettlementStatus::Claimable,
+    })
+    .await;
+
+    assert!(pool
+        .state
+        .borrow()
+        .swap_settlements()
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_swap_executes_at_deadline() {
+    let mut pool = create_and_initialize_pool(true).await;
+    let owner = authenticated_account(&pool);
+    let token_1 = pool.runtime.borrow_mut().application_parameters().token_1;
+    let reserve_0 = pool.state.borrow().reserve_0();
+    pool.runtime.borrow_mut().set_system_time(10_000_000.into());
+
+    pool.execute_message(PoolMessage::Swap {
+        origin: owner,
+        amount_0_in: Some(Amount::ONE),
+        amount_1_in: None,
         amount_0_out_min: None,
         amount_1_out_min: None,
         to: None,
         block_timestamp: None,
+        route: None,
+        amount_0_out: None,
+        amount_1_out: None,
+        deadline: Some(10_000_000.into()),
+        auto_claim: false,
     })
     .await;
 
     assert_eq!(
-        pool.state.borrow().liquidity(owner).await.unwrap(),
-        Amount::from_str("100.1").unwrap()
+        pool.state.borrow().reserve_0(),
+        reserve_0.try_add(Amount::ONE).unwrap()
+    );
+    assert!(
+        pool.state
+            .borrow()
+            .claimable_balance(MemeToken::from(token_1), owner)
+            .await
+            .unwrap()
+            > Amount::ZERO
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_swap_accrues_earned_fees_by_liquidity_share() {
+    let mut pool = create_and_initialize_pool(true).await;
+    let owner = authenticated_account(&pool);
+    let other = alternate_account(owner.chain_id);
+    let liquidity = pool.state.borrow().liquidity(owner).await.unwrap();
+    assert_eq!(liquidity, total_supply(&pool));
+
+    for (amount_0_in, amount_1_in) in [(Some(Amount::ONE), None), (None, Some(Amount::ONE))] {
+        if amount_1_in.is_some() {
+            pool.state
+                .borrow_mut()
+                .transfer_liquidity(owner, other, Amount::from_attos(u128::from(liquidity) / 2))
+                .await
+                .unwrap();
+        }
+
+        pool.execute_message(PoolMessage::Swap {
+            origin: owner,
+            amount_0_in,
+            amount_1_in,
+            amount_0_out_min: None,
+            amount_1_out_min: None,
+            to: None,
+            block_timestamp: None,
+            route: None,
+            amount_0_out: None,
+            amount_1_out: None,
+            deadline: None,
+            auto_claim: false,
+        })
+        .await;
+    }
+
+    let (lp_fee, protocol_fee) = pool.state.borrow().pool().swap_fees(Amount::ONE);
+    let earned = lp_fee.try_sub(protocol_fee).unwrap();
+    let half = Amount::from_attos(u128::from(earned) / 2);
+
+    assert_eq!(
+        pool.state.borrow().earned_fees(owner).await.unwrap(),
+        (earned, half)
+    );
+    assert_eq!(
+        pool.state.borrow().earned_fees(other).await.unwrap(),
+        (Amount::ZERO, half)
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_swap_accumulates_pool_stats() {
+    let mut pool = create_and_initialize_pool(true).await;
+    let owner = authenticated_account(&pool);
+    assert_eq!(pool.state.borrow().stats(), PoolStats::default());
+
+    for (amount_0_in, amount_1_in) in [(Some(Amount::ONE), None), (None, Some(Amount::ONE))] {
+        pool.execute_message(PoolMessage::Swap {
+            origin: owner,
+            amount_0_in,
+            amount_1_in,
+            amount_0_out_min: None,
+            amount_1_out_min: None,
+            to: None,
+            block_timestamp: None,
+            route: None,
+            amount_0_out: None,
+            amount_1_out: None,
+            deadline: None,
+            auto_claim: false,
+        })
+        .await;
+    }
+
+    let (fee, _) = pool.state.borrow().pool().swap_fees(Amount::ONE);
+    let stats = pool.state.borrow().stats();
+    assert_eq!(stats.swap_count, 2);
+    assert_eq!(stats.fees_0, fee);
+    assert_eq!(stats.fees_1, fee);
+    assert!(stats.volume_0 > Amount::ONE);
+    assert!(stats.volume_1 > Amount::ONE);
+    assert_eq!(
+        stats.last_trade_at,
+        Some(pool.runtime.borrow_mut().system_time())
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_swap_enforces_launch_protection() {
+    let mut pool = create_and_initialize_launch_protected_pool().await;
+    let owner = authenticated_account(&pool);
+    let token_0 = pool.runtime.borrow_mut().application_parameters().token_0;
+    let token_1 = pool.runtime.borrow_mut().application_parameters().token_1;
+    let swap = |amount_0_in: Amount| PoolMessage::Swap {
+        origin: owner,
+        amount_0_in: Some(amount_0_in),
+        amount_1_in: None,
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        to: None,
+        block_timestamp: None,
+        route: None,
+        amount_0_out: None,
+        amount_1_out: None,
+        deadline: None,
+        auto_claim: false,
+    };
+    let reserve_0 = pool.state.borrow().reserve_0();
+
+    // 2% of reserve 0 at most
+    pool.execute_message(swap(Amount::from_tokens(21))).await;
+    assert_eq!(pool.state.borrow().reserve_0(), reserve_0);
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claimable_balance(MemeToken::from(token_0), owner)
+            .await
+            .unwrap(),
+        Amount::from_tokens(21)
+    );
+
+    // Full extra fee right after initialization
+    let amount_1_out = pool
+        .state
+        .borrow()
+        .calculate_swap_amount_1(Amount::from_str("0.9").unwrap())
+        .unwrap();
+    pool.execute_message(swap(Amount::ONE)).await;
+    assert_eq!(
+        pool.state.borrow().reserve_0(),
+        reserve_0.try_add(Amount::ONE).unwrap()
+    );
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claimable_balance(MemeToken::from(token_1), owner)
+            .await
+            .unwrap(),
+        amount_1_out
+    );
+
+    pool.runtime.borrow_mut().set_system_time(10_000_000.into());
+    pool.execute_message(swap(Amount::ONE)).await;
+    assert_eq!(
+        pool.state.borrow().reserve_0(),
+        reserve_0.try_add(Amount::ONE).unwrap()
+    );
+
+    pool.runtime.borrow_mut().set_system_time(30_000_000.into());
+    pool.execute_message(swap(Amount::ONE)).await;
+    assert_eq!(
+        pool.state.borrow().reserve_0(),
+        reserve_0.try_add(Amount::from_tokens(2)).unwrap()
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_swap_exact_out_pays_launch_extra_fee_with_input() {
+    let mut pool = create_and_initialize_launch_protected_pool().await;
+    let owner = authenticated_account(&pool);
+    let token_0 = pool.runtime.borrow_mut().application_parameters().token_0;
+    let token_1 = pool.runtime.borrow_mut().application_parameters().token_1;
+    let reserve_1 = pool.state.borrow().reserve_1();
+    let amount_0_out = Amount::from_tokens(10);
+    let amount_1_in_max = Amount::from_str("0.2").unwrap();
+
+    let amount_1_in = LaunchProtection::default().amount_in_with_extra_fee(
+        pool.state
+            .borrow()
+            .pool()
+            .calculate_swap_amount_1_in(amount_0_out)
+            .unwrap(),
+        0.into(),
+        0.into(),
+    );
+
+    pool.execute_message(PoolMessage::Swap {
+        origin: owner,
+        amount_0_in: None,
+        amount_1_in: Some(amount_1_in_max),
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        to: None,
+        block_timestamp: None,
+        route: None,
+        amount_0_out: Some(amount_0_out),
+        amount_1_out: None,
+        deadline: None,
+        auto_claim: false,
+    })
+    .await;
+
+    assert_eq!(
+        pool.state.borrow().reserve_1(),
+        reserve_1.try_add(amount_1_in).unwrap()
+    );
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claimable_balance(MemeToken::from(token_0), owner)
+            .await
+            .unwrap(),
+        amount_0_out
+    );
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claimable_balance(MemeToken::from(token_1), owner)
+            .await
+            .unwrap(),
+        amount_1_in_max.try_sub(amount_1_in).unwrap()
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_swap_accrues_launch_extra_fee() {
+    let mut pool = create_and_initialize_launch_protected_pool().await;
+    let owner = authenticated_account(&pool);
+    assert_eq!(
+        pool.state.borrow().liquidity(owner).await.unwrap(),
+        total_supply(&pool)
+    );
+
+    let pool_state = pool.state.borrow().pool();
+    let extra_fee = LaunchProtection::default().extra_fee(Amount::ONE, 0.into(), 0.into());
+    let (lp_fee, protocol_fee) = pool_state.swap_fees(Amount::ONE);
+    let (extra_base_fee, _) = pool_state.swap_fees(extra_fee);
+    let launch_fee = extra_fee.try_sub(extra_base_fee).unwrap();
+    let earned = lp_fee
+        .try_sub(protocol_fee)
+        .unwrap()
+        .try_add(
+            launch_fee
+                .try_sub(pool_state.protocol_fee(launch_fee))
+                .unwrap(),
+        )
+        .unwrap();
+
+    pool.execute_message(PoolMessage::Swap {
+        origin: owner,
+        amount_0_in: Some(Amount::ONE),
+        amount_1_in: None,
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        to: None,
+        block_timestamp: None,
+        route: None,
+        amount_0_out: None,
+        amount_1_out: None,
+        deadline: None,
+        auto_claim: false,
+    })
+    .await;
+
+    // Fee per share accrues twice, allow its rounding
+    let (earned_0, earned_1) = pool.state.borrow().earned_fees(owner).await.unwrap();
+    assert!(earned_0.saturating_sub(earned) <= Amount::from_attos(1));
+    assert!(earned.saturating_sub(earned_0) <= Amount::from_attos(1));
+    assert_eq!(earned_1, Amount::ZERO);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_add_liquidity_refunds_after_deadline() {
+    let mut pool = create_and_initialize_pool(true).await;
+    let owner = authenticated_account(&pool);
+    let parameters = pool.runtime.borrow_mut().application_parameters();
+    let reserve_0 = pool.state.borrow().reserve_0();
+    let total_supply_before = total_supply(&pool);
+    pool.runtime.borrow_mut().set_system_time(10_000_000.into());
+
+    pool.execute_message(PoolMessage::AddLiquidity {
+        origin: owner,
+        amount_0_in: Amount::ONE,
+        amount_1_in: Amount::from_tokens(10),
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        to: None,
+        block_timestamp: None,
+        deadline: Some(9_999_999.into()),
+    })
+    .await;
+
+    assert_eq!(pool.state.borrow().reserve_0(), reserve_0);
+    assert_eq!(total_supply(&pool), total_supply_before);
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claimable_balance(MemeToken::from(parameters.token_0), owner)
+            .await
+            .unwrap(),
+        Amount::ONE
+    );
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claimable_balance(MemeToken::from(parameters.token_1), owner)
+            .await
+            .unwrap(),
+        Amount::from_tokens(10)
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_remove_liquidity_keeps_liquidity_after_deadline() {
+    let mut pool = create_and_initialize_pool(true).await;
+    let owner = authenticated_account(&pool);
+    let liquidity = pool.state.borrow().liquidity(owner).await.unwrap();
+    let reserve_0 = pool.state.borrow().reserve_0();
+    pool.runtime.borrow_mut().set_system_time(10_000_000.into());
+
+    pool.execute_message(PoolMessage::RemoveLiquidity {
+        origin: owner,
+        liquidity: Amount::ONE,
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        to: None,
+        block_timestamp: None,
+        deadline: Some(9_999_999.into()),
+    })
+    .await;
+
+    assert_eq!(
+        pool.state.borrow().liquidity(owner).await.unwrap(),
+        liquidity
+    );
+    assert_eq!(pool.state.borrow().reserve_0(), reserve_0);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_swap_rejects_without_finalized_reserve_share_facts() {
+    let mut pool = create_and_instantiate_pool_with_amounts(false).await;
+    let origin = authenticated_account(&pool);
+    let reserve_0_before = pool.state.borrow().reserve_0();
+    let reserve_1_before = pool.state.borrow().reserve_1();
+    let total_supply_before = total_supply(&pool);
+    let request_count_before = pool.runtime.borrow().created_send_message_requests().len();
+
+    pool.execute_message(PoolMessage::Swap {
+        origin,
+        amount_0_in: Some(Amount::ONE),
+        amount_1_in: None,
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        to: None,
+        block_timestamp: None,
+        route: None,
+        amount_0_out: None,
+        amount_1_out: None,
+        deadline: None,
+        auto_claim: false,
+    })
+    .await;
+
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claimable_balance(
+                MemeToken::Fungible(pool.runtime.borrow_mut().application_parameters().token_0),
+                origin
+            )
+            .await
+            .unwrap(),
+        Amount::ONE
+    );
+    assert_eq!(pool.state.borrow().reserve_0(), reserve_0_before);
+    assert_eq!(pool.state.borrow().reserve_1(), reserve_1_before);
+    assert_eq!(total_supply(&pool), total_supply_before);
+    assert_eq!(
+        pool.runtime.borrow().created_send_message_requests().len(),
+        request_count_before
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn operation_add_liquidity() {
+    let mut pool = create_and_instantiate_pool(true).await;
+
+    let response = pool
+        .execute_operation(PoolOperation::AddLiquidity {
+            amount_0_in: Amount::ONE,
+            amount_1_in: Amount::from_tokens(20),
+            amount_0_out_min: None,
+            amount_1_out_min: None,
+            to: None,
+            block_timestamp: None,
+            deadline: None,
+        })
+        .now_or_never()
+        .expect("Execution of meme operation should not await anything");
+
+    assert!(matches!(response, PoolResponse::Ok));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_remove_liquidity_rejects_without_finalized_reserve_share_facts() {
+    let mut pool = create_and_instantiate_pool_with_amounts(false).await;
+    let origin = authenticated_account(&pool);
+    let reserve_0_before = pool.state.borrow().reserve_0();
+    let reserve_1_before = pool.state.borrow().reserve_1();
+    let total_supply_before = total_supply(&pool);
+    let request_count_before = pool.runtime.borrow().created_send_message_requests().len();
+
+    let result = std::panic::AssertUnwindSafe(pool.execute_message(PoolMessage::RemoveLiquidity {
+        origin,
+        liquidity: Amount::ONE,
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        to: None,
+        block_timestamp: None,
+        deadline: None,
+    }))
+    .catch_unwind()
+    .await;
+
+    assert!(result.is_err());
+    assert_eq!(pool.state.borrow().reserve_0(), reserve_0_before);
+    assert_eq!(pool.state.borrow().reserve_1(), reserve_1_before);
+    assert_eq!(total_supply(&pool), total_supply_before);
+    assert_eq!(
+        pool.runtime.borrow().created_send_message_requests().len(),
+        request_count_before
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_set_fee_to_updates_fee_receiver_for_current_fee_to_setter() {
+    let mut pool = create_and_instantiate_pool(true).await;
+    let operator = authenticated_account(&pool);
+    let account = Account {
+        chain_id: operator.chain_id,
+        owner: AccountOwner::from_str(
+            "0x8b0f4d4320f64d5cf5fd742f5a7d6a51a8c3dbd9d6c6c23f73e3b0f8fbb04f11",
+        )
+        .unwrap(),
+    };
+
+    pool.execute_message(PoolMessage::SetFeeTo { operator, account })
+        .await;
+
+    let pool_state = pool.state.borrow().pool();
+    assert_eq!(pool_state.fee_to, account);
+    assert_eq!(pool_state.fee_to_setter, operator);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_set_fee_to_setter_rotates_operator_and_invalidates_old_operator() {
+    let mut pool = create_and_instantiate_pool(true).await;
+    let old_operator = authenticated_account(&pool);
+    let new_operator = Account {
+        chain_id: old_operator.chain_id,
+        owner: AccountOwner::from_str(
+            "0x3f3c6f7fbc833f18d48f3b9d8552cf16e491061b85d34ed2a5b3720d9f2f4c31",
+        )
+        .unwrap(),
+    };
+    let target_fee_to = Account {
+        chain_id: old_operator.chain_id,
+        owner: AccountOwner::from_str(
+            "0x3447565f8a4f3db39c46fc92f6fa5700d6c74a585cd49007daa4619052f5e91b",
+        )
+        .unwrap(),
+    };
+
+    pool.execute_message(PoolMessage::SetFeeToSetter {
+        operator: old_operator,
+        account: new_operator,
+    })
+    .await;
+    assert_eq!(pool.state.borrow().pool().fee_to_setter, new_operator);
+
+    let old_operator_attempt =
+        std::panic::AssertUnwindSafe(pool.execute_message(PoolMessage::SetFeeTo {
+            operator: old_operator,
+            account: target_fee_to,
+        }))
+        .catch_unwind()
+        .await;
+    assert!(old_operator_attempt.is_err());
+
+    pool.execute_message(PoolMessage::SetFeeTo {
+        operator: new_operator,
+        account: target_fee_to,
+    })
+    .await;
+
+    let pool_state = pool.state.borrow().pool();
+    assert_eq!(pool_state.fee_to_setter, new_operator);
+    assert_eq!(pool_state.fee_to, target_fee_to);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_set_fee_to_rejects_non_operator() {
+    let mut pool = create_and_instantiate_pool(true).await;
+    let current_operator = authenticated_account(&pool);
+    let invalid_operator = Account {
+        chain_id: current_operator.chain_id,
+        owner: AccountOwner::from_str(
+            "0x61f6a31f937dfb8a5e47f6d471b1e40f949e8ddfb66914318e403d315f0dce21",
+        )
+        .unwrap(),
+    };
+    let target_fee_to = Account {
+        chain_id: current_operator.chain_id,
+        owner: AccountOwner::from_str(
+            "0x216bd78c27e4abfef4e1a6b4af2f14f4dd35df621d8f21891cf9d33d6535f1a1",
+        )
+        .unwrap(),
+    };
+
+    let result = std::panic::AssertUnwindSafe(pool.execute_message(PoolMessage::SetFeeTo {
+        operator: invalid_operator,
+        account: target_fee_to,
+    }))
+    .catch_unwind()
+    .await;
+
+    assert!(result.is_err());
+    let pool_state = pool.state.borrow().pool();
+    assert_eq!(pool_state.fee_to_setter, current_operator);
+    assert_eq!(pool_state.fee_to, current_operator);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_sync_sets_native_reserve_to_holding_minus_obligations() {
+    let mut pool = create_and_instantiate_native_pool(false).await;
+    let operator = authenticated_account(&pool);
+    pool.execute_message(PoolMessage::InitializeLiquidity {
+        origin: operator,
+        amount_0_in: Amount::from_tokens(1000),
+        amount_1_in: Amount::from_tokens(10),
+        to: None,
+        block_timestamp: None,
+    })
+    .await;
+    pool.state
+        .borrow_mut()
+        .credit(MemeToken::Native, operator, Amount::ONE)
+        .await
+        .unwrap();
+
+    // Native tokens sent to the pool outside of the funding flow
+    let application_owner =
+        AccountOwner::from(pool.runtime.borrow_mut().application_id().forget_abi());
+    pool.runtime
+        .borrow_mut()
+        .set_owner_balance(application_owner, Amount::from_tokens(15));
+
+    pool.execute_message(PoolMessage::Sync { operator }).await;
+
+    assert_eq!(pool.state.borrow().reserve_0(), Amount::from_tokens(1000));
+    assert_eq!(pool.state.borrow().reserve_1(), Amount::from_tokens(14));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_skim_transfers_native_surplus_and_rejects_non_operator() {
+    let mut pool = create_and_instantiate_native_pool(false).await;
+    let operator = authenticated_account(&pool);
+    pool.execute_message(PoolMessage::InitializeLiquidity {
+        origin: operator,
+        amount_0_in: Amount::from_tokens(1000),
+        amount_1_in: Amount::from_tokens(10),
+        to: None,
+        block_timestamp: None,
+    })
+    .await;
+    pool.state
+        .borrow_mut()
+        .credit(MemeToken::Native, operator, Amount::ONE)
+        .await
+        .unwrap();
+
+    let application_owner =
+        AccountOwner::from(pool.runtime.borrow_mut().application_id().forget_abi());
+    pool.runtime
+        .borrow_mut()
+        .set_owner_balance(application_owner, Amount::from_tokens(15));
+    let to = Account {
+        chain_id: operator.chain_id,
+        owner: AccountOwner::from_str(
+            "0x216bd78c27e4abfef4e1a6b4af2f14f4dd35df621d8f21891cf9d33d6535f1a1",
+        )
+        .unwrap(),
+    };
+
+    let result =
+        std::panic::AssertUnwindSafe(pool.execute_message(PoolMessage::Skim { operator: to, to }))
+            .catch_unwind()
+            .await;
+    assert!(result.is_err());
+
+    pool.execute_message(PoolMessage::Skim { operator, to })
+        .await;
+
+    assert_eq!(pool.state.borrow().reserve_1(), Amount::from_tokens(10));
+    assert_eq!(
+        pool.runtime.borrow_mut().owner_balance(to.owner),
+        Amount::from_tokens(4)
+    );
+    assert_eq!(
+        pool.runtime.borrow_mut().owner_balance(application_owner),
+        Amount::from_tokens(11)
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_sync_keeps_virtual_initial_liquidity_in_native_reserve() {
+    let mut pool = create_and_instantiate_native_pool(true).await;
+    let operator = authenticated_account(&pool);
+    pool.execute_message(PoolMessage::InitializeLiquidity {
+        origin: operator,
+        amount_0_in: Amount::from_tokens(1000),
+        amount_1_in: Amount::from_tokens(10),
+        to: None,
+        block_timestamp: None,
+    })
+    .await;
+    pool.state
+        .borrow_mut()
+        .credit(MemeToken::Native, operator, Amount::ONE)
+        .await
+        .unwrap();
+
+    // Virtual reserve is never funded, only later native inputs are held
+    let application_owner =
+        AccountOwner::from(pool.runtime.borrow_mut().application_id().forget_abi());
+    pool.runtime
+        .borrow_mut()
+        .set_owner_balance(application_owner, Amount::from_tokens(5));
+
+    pool.execute_message(PoolMessage::Sync { operator }).await;
+
+    assert_eq!(pool.state.borrow().reserve_0(), Amount::from_tokens(1000));
+    assert_eq!(pool.state.borrow().reserve_1(), Amount::from_tokens(14));
+
+    let to = alternate_account(operator.chain_id);
+    pool.execute_message(PoolMessage::Skim { operator, to })
+        .await;
+
+    assert_eq!(
+        pool.runtime.borrow_mut().owner_balance(to.owner),
+        Amount::ZERO
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn sync_and_skim_reject_meme_pools() {
+    let mut pool = create_and_initialize_pool(false).await;
+    let operator = authenticated_account(&pool);
+
+    let result = std::panic::AssertUnwindSafe(pool.execute_operation(PoolOperation::Sync))
+        .catch_unwind()
+        .await;
+    assert!(result.is_err());
+
+    let result =
+        std::panic::AssertUnwindSafe(pool.execute_operation(PoolOperation::Skim { to: operator }))
+            .catch_unwind()
+            .await;
+    assert!(result.is_err());
+
+    let result = std::panic::AssertUnwindSafe(pool.execute_message(PoolMessage::Sync { operator }))
+        .catch_unwind()
+        .await;
+    assert!(result.is_err());
+    assert_eq!(pool.state.borrow().reserve_1(), Amount::from_tokens(10));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_set_protocol_fee_share_mints_accrued_fee_before_switching() {
+    let mut pool = create_and_initialize_pool(false).await;
+    let operator = authenticated_account(&pool);
+    let fee_to = alternate_account(operator.chain_id);
+
+    pool.execute_message(PoolMessage::SetFeeTo {
+        operator,
+        account: fee_to,
+    })
+    .await;
+
+    let swap = PoolMessage::Swap {
+        origin: operator,
+        amount_0_in: None,
+        amount_1_in: Some(Amount::ONE),
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        to: None,
+        block_timestamp: None,
+        route: None,
+        amount_0_out: None,
+        amount_1_out: None,
+        deadline: None,
+        auto_claim: false,
+    };
+    pool.execute_message(swap.clone()).await;
+
+    let accrued_fee = pool.state.borrow().pool().mint_fee(total_supply(&pool));
+    assert!(accrued_fee > Amount::ZERO);
+
+    pool.execute_message(PoolMessage::SetProtocolFeeShare {
+        operator,
+        protocol_fee_share_denominator: 0,
+    })
+    .await;
+
+    let pool_state = pool.state.borrow().pool();
+    assert_eq!(pool_state.protocol_fee_share_denominator, 0);
+    assert_eq!(pool_state.k_last, Amount::ZERO);
+    assert_eq!(
+        pool.state.borrow().liquidity(fee_to).await.unwrap(),
+        accrued_fee
+    );
+
+    pool.execute_message(swap).await;
+    pool.execute_message(PoolMessage::AddLiquidity {
+        origin: operator,
+        amount_0_in: Amount::ONE,
+        amount_1_in: Amount::from_tokens(10),
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        to: None,
+        block_timestamp: None,
+        deadline: None,
+    })
+    .await;
+
+    assert_eq!(
+        pool.state.borrow().liquidity(fee_to).await.unwrap(),
+        accrued_fee
+    );
+    assert_eq!(pool.state.borrow().pool().k_last, Amount::ZERO);
+
+    pool.execute_message(PoolMessage::SetProtocolFeeShare {
+        operator,
+        protocol_fee_share_denominator: 4,
+    })
+    .await;
+
+    let pool_state = pool.state.borrow().pool();
+    assert_eq!(pool_state.protocol_fee_share_denominator, 4);
+    assert!(pool_state.k_last > Amount::ZERO);
+    assert_eq!(pool_state.mint_fee(total_supply(&pool)), Amount::ZERO);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_set_protocol_fee_share_rejects_non_operator() {
+    let mut pool = create_and_initialize_pool(false).await;
+    let operator = authenticated_account(&pool);
+
+    let result =
+        std::panic::AssertUnwindSafe(pool.execute_message(PoolMessage::SetProtocolFeeShare {
+            operator: alternate_account(operator.chain_id),
+            protocol_fee_share_denominator: 0,
+        }))
+        .catch_unwind()
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(
+        pool.state.borrow().pool().protocol_fee_share_denominator,
+        DEFAULT_PROTOCOL_FEE_SHARE_DENOMINATOR
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_swap_reports_dynamic_fee_in_transaction() {
+    let mut pool = create_and_initialize_pool(true).await;
+    let operator = authenticated_account(&pool);
+    let dynamic_fee = DynamicFee {
+        min_fee_percent_mul_100: 10,
+        max_fee_percent_mul_100: 100,
+        max_volatility_percent_mul_100: 100,
+        window_seconds: 600,
+    };
+
+    let result = std::panic::AssertUnwindSafe(pool.execute_message(PoolMessage::SetDynamicFee {
+        operator: alternate_account(operator.chain_id),
+        dynamic_fee: Some(dynamic_fee.clone()),
+    }))
+    .catch_unwind()
+    .await;
+    assert!(result.is_err());
+    assert_eq!(pool.state.borrow().pool().fee_percent_mul_100(), 30);
+
+    pool.execute_message(PoolMessage::SetDynamicFee {
+        operator,
+        dynamic_fee: Some(dynamic_fee),
+    })
+    .await;
+    assert_eq!(pool.state.borrow().pool().fee_percent_mul_100(), 10);
+
+    pool.execute_message(PoolMessage::Swap {
+        origin: operator,
+        amount_0_in: Some(Amount::ONE),
+        amount_1_in: None,
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        to: None,
+        block_timestamp: None,
+        route: None,
+        amount_0_out: None,
+        amount_1_out: None,
+        deadline: None,
+        auto_claim: false,
+    })
+    .await;
+
+    let transaction = pool
+        .runtime
+        .borrow()
+        .created_send_message_requests()
+        .iter()
+        .rev()
+        .find_map(|request| match &request.message {
+            PoolMessage::NewTransaction { transaction } => Some(*transaction),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(transaction.fee_percent_mul_100, Some(10));
+    // The swap itself moved the price, so the next one pays more
+    assert!(pool.state.borrow().pool().fee_percent_mul_100() > 10);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_transfer_liquidity_moves_shares_without_changing_supply() {
+    let mut pool = create_and_initialize_pool(true).await;
+    let owner = authenticated_account(&pool);
+    let to = alternate_account(owner.chain_id);
+    let share_before = pool.state.borrow().liquidity(owner).await.unwrap();
+    let total_supply_before = total_supply(&pool);
+
+    pool.execute_message(PoolMessage::TransferLiquidity {
+        origin: owner,
+        to,
+        liquidity: Amount::from_tokens(40),
+    })
+    .await;
+
+    assert_eq!(
+        pool.state.borrow().liquidity(owner).await.unwrap(),
+        share_before.try_sub(Amount::from_tokens(40)).unwrap()
+    );
+    assert_eq!(
+        pool.state.borrow().liquidity(to).await.unwrap(),
+        Amount::from_tokens(40)
+    );
+    assert_eq!(total_supply(&pool), total_supply_before);
+
+    let result =
+        std::panic::AssertUnwindSafe(pool.execute_message(PoolMessage::TransferLiquidity {
+            origin: to,
+            to: owner,
+            liquidity: Amount::from_tokens(41),
+        }))
+        .catch_unwind()
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(
+        pool.state.borrow().liquidity(to).await.unwrap(),
+        Amount::from_tokens(40)
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_transfer_liquidity_from_spends_allowance() {
+    let mut pool = create_and_initialize_pool(true).await;
+    let owner = authenticated_account(&pool);
+    let spender = alternate_account(owner.chain_id);
+    let to = Account {
+        chain_id: owner.chain_id,
+        owner: AccountOwner::from_str(
+            "0x3447565f8a4f3db39c46fc92f6fa5700d6c74a585cd49007daa4619052f5e91b",
+        )
+        .unwrap(),
+    };
+    let share_before = pool.state.borrow().liquidity(owner).await.unwrap();
+
+    pool.execute_message(PoolMessage::ApproveLiquidity {
+        origin: owner,
+        spender,
+        liquidity: Amount::from_tokens(30),
+    })
+    .await;
+
+    // Approval doesn't move shares
+    assert_eq!(
+        pool.state.borrow().liquidity(owner).await.unwrap(),
+        share_before
+    );
+    assert_eq!(
+        pool.state
+            .borrow()
+            .liquidity_allowance(owner, spender)
+            .await
+            .unwrap(),
+        Amount::from_tokens(30)
+    );
+
+    pool.execute_message(PoolMessage::TransferLiquidityFrom {
+        origin: spender,
+        from: owner,
+        to,
+        liquidity: Amount::from_tokens(20),
+    })
+    .await;
+
+    assert_eq!(
+        pool.state.borrow().liquidity(owner).await.unwrap(),
+        share_before.try_sub(Amount::from_tokens(20)).unwrap()
+    );
+    assert_eq!(
+        pool.state.borrow().liquidity(to).await.unwrap(),
+        Amount::from_tokens(20)
+    );
+    assert_eq!(
+        pool.state
+            .borrow()
+            .liquidity_allowance(owner, spender)
+            .await
+            .unwrap(),
+        Amount::from_tokens(10)
+    );
+
+    let result =
+        std::panic::AssertUnwindSafe(pool.execute_message(PoolMessage::TransferLiquidityFrom {
+            origin: spender,
+            from: owner,
+            to,
+            liquidity: Amount::from_tokens(11),
+        }))
+        .catch_unwind()
+        .await;
+    assert!(result.is_err());
+
+    let result =
+        std::panic::AssertUnwindSafe(pool.execute_message(PoolMessage::TransferLiquidityFrom {
+            origin: to,
+            from: owner,
+            to,
+            liquidity: Amount::from_tokens(10),
+        }))
+        .catch_unwind()
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_approve_liquidity_replaces_and_revokes_allowance() {
+    let mut pool = create_and_initialize_pool(true).await;
+    let owner = authenticated_account(&pool);
+    let spender = alternate_account(owner.chain_id);
+    let share_before = pool.state.borrow().liquidity(owner).await.unwrap();
+
+    pool.execute_message(PoolMessage::ApproveLiquidity {
+        origin: owner,
+        spender,
+        liquidity: Amount::from_tokens(30),
+    })
+    .await;
+    pool.execute_message(PoolMessage::ApproveLiquidity {
+        origin: owner,
+        spender,
+        liquidity: Amount::from_tokens(5),
+    })
+    .await;
+
+    assert_eq!(
+        pool.state
+            .borrow()
+            .liquidity_allowance(owner, spender)
+            .await
+            .unwrap(),
+        Amount::from_tokens(5)
+    );
+
+    pool.execute_message(PoolMessage::ApproveLiquidity {
+        origin: owner,
+        spender,
+        liquidity: Amount::ZERO,
+    })
+    .await;
+
+    assert_eq!(
+        pool.state
+            .borrow()
+            .liquidity_allowance(owner, spender)
+            .await
+            .unwrap(),
+        Amount::ZERO
+    );
+    assert_eq!(
+        pool.state.borrow().liquidity(owner).await.unwrap(),
+        share_before
+    );
+
+    let result =
+        std::panic::AssertUnwindSafe(pool.execute_message(PoolMessage::TransferLiquidityFrom {
+            origin: spender,
+            from: owner,
+            to: spender,
+            liquidity: Amount::ONE,
+        }))
+        .catch_unwind()
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_transfer_liquidity_from_checks_owner_shares() {
+    let mut pool = create_and_initialize_pool(true).await;
+    let owner = authenticated_account(&pool);
+    let spender = alternate_account(owner.chain_id);
+    let share = pool.state.borrow().liquidity(owner).await.unwrap();
+
+    // Allowance may exceed the shares, the transfer is bounded by what the owner holds
+    pool.execute_message(PoolMessage::ApproveLiquidity {
+        origin: owner,
+        spender,
+        liquidity: share.try_add(Amount::ONE).unwrap(),
+    })
+    .await;
+
+    let result =
+        std::panic::AssertUnwindSafe(pool.execute_message(PoolMessage::TransferLiquidityFrom {
+            origin: spender,
+            from: owner,
+            to: spender,
+            liquidity: share.try_add(Amount::ONE).unwrap(),
+        }))
+        .catch_unwind()
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(pool.state.borrow().liquidity(owner).await.unwrap(), share);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn operation_transfer_liquidity_forwards_to_creator_chain() {
+    let mut pool = create_and_initialize_pool(true).await;
+    let owner = authenticated_account(&pool);
+    let to = alternate_account(owner.chain_id);
+    let creator_chain_id = pool.runtime.borrow_mut().application_creator_chain_id();
+    let request_count_before = pool.runtime.borrow().created_send_message_requests().len();
+
+    pool.execute_operation(PoolOperation::TransferLiquidity {
+        to,
+        liquidity: Amount::ONE,
+    })
+    .await;
+
+    let runtime = pool.runtime.borrow();
+    let requests = runtime.created_send_message_requests();
+    assert_eq!(requests.len(), request_count_before + 1);
+    assert_eq!(requests[request_count_before].destination, creator_chain_id);
+    assert!(matches!(
+        requests[request_count_before].message,
+        PoolMessage::TransferLiquidity {
+            origin,
+            to: message_to,
+            liquidity,
+        } if origin == owner && message_to == to && liquidity == Amount::ONE
+    ));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_swap_fills_crossed_limit_order_into_claimable_balance() {
+    let mut pool = create_and_initialize_pool(false).await;
+    let owner = authenticated_account(&pool);
+    let maker = alternate_account(owner.chain_id);
+    let token_0 = pool.runtime.borrow_mut().application_parameters().token_0;
+    let amount_1_in = Amount::ONE;
+    let amount_out_min = pool
+        .state
+        .borrow()
+        .calculate_swap_amount_0(amount_1_in)
+        .unwrap()
+        .try_add(Amount::from_tokens(1))
+        .unwrap();
+
+    pool.execute_message(PoolMessage::PlaceLimitOrder {
+        origin: maker,
+        amount_0_in: None,
+        amount_1_in: Some(amount_1_in),
+        amount_out_min,
+        to: None,
+    })
+    .await;
+
+    let orders = pool.state.borrow().limit_orders(false, 16).await.unwrap();
+    assert_eq!(orders.len(), 1);
+    assert_eq!(orders[0].owner, maker);
+
+    // Selling token_0 makes token_1 dearer, so the resting order becomes marketable
+    pool.execute_message(PoolMessage::Swap {
+        origin: owner,
+        amount_0_in: Some(Amount::from_tokens(100)),
+        amount_1_in: None,
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        to: None,
+        block_timestamp: None,
+        route: None,
+        amount_0_out: None,
+        amount_1_out: None,
+        deadline: None,
+        auto_claim: false,
+    })
+    .await;
+
+    assert!(pool
+        .state
+        .borrow()
+        .limit_orders(false, 16)
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(
+        pool.state
+            .borrow()
+            .claimable_balance(MemeToken::from(token_0), maker)
+            .await
+            .unwrap()
+            >= amount_out_min
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_swap_fill_settles_like_a_swap() {
+    let mut pool = create_and_initialize_pool(false).await;
+    let owner = authenticated_account(&pool);
+    let maker = alternate_account(owner.chain_id);
+    let amount_1_in = Amount::ONE;
+    let amount_out_min = pool
+        .state
+        .borrow()
+        .calculate_swap_amount_0(amount_1_in)
+        .unwrap()
+        .try_add(Amount::from_tokens(1))
+        .unwrap();
+
+    pool.execute_message(PoolMessage::PlaceLimitOrder {
+        origin: maker,
+        amount_0_in: None,
+        amount_1_in: Some(amount_1_in),
+        amount_out_min,
+        to: None,
+    })
+    .await;
+    let fee_growth_before = pool.state.borrow().fee_growth.get().clone();
+    pool.execute_message(PoolMessage::Swap {
+        origin: owner,
+        amount_0_in: Some(Amount::from_tokens(100)),
+        amount_1_in: None,
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        to: None,
+        block_timestamp: None,
+        route: None,
+        amount_0_out: None,
+        amount_1_out: None,
+        deadline: None,
+        auto_claim: false,
+    })
+    .await;
+
+    let stats = pool.state.borrow().stats();
+    assert_eq!(stats.swap_count, 2);
+    assert!(stats.fees_1 > Amount::ZERO);
+    // Only the fill paid a fee in token_1
+    assert!(
+        pool.state.borrow().fee_growth.get().fee_1_per_share > fee_growth_before.fee_1_per_share
+    );
+
+    let transaction = pool
+        .runtime
+        .borrow()
+        .created_send_message_requests()
+        .iter()
+        .find_map(|request| match &request.message {
+            PoolMessage::NewTransaction { transaction } if transaction.from == maker => {
+                Some(*transaction)
+            }
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(transaction.fee_percent_mul_100, Some(30));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_swap_keeps_limit_orders_resting_in_launch_phase() {
+    let mut pool = create_and_initialize_launch_protected_pool().await;
+    let owner = authenticated_account(&pool);
+    let maker = alternate_account(owner.chain_id);
+
+    pool.execute_message(PoolMessage::PlaceLimitOrder {
+        origin: maker,
+        amount_0_in: None,
+        amount_1_in: Some(Amount::from_str("0.01").unwrap()),
+        amount_out_min: Amount::from_str("0.0001").unwrap(),
+        to: None,
+    })
+    .await;
+    pool.execute_message(PoolMessage::Swap {
+        origin: owner,
+        amount_0_in: Some(Amount::from_tokens(10)),
+        amount_1_in: None,
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        to: None,
+        block_timestamp: None,
+        route: None,
+        amount_0_out: None,
+        amount_1_out: None,
+        deadline: None,
+        auto_claim: false,
+    })
+    .await;
+
+    assert_eq!(
+        pool.state
+            .borrow()
+            .limit_orders(false, 16)
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_place_limit_order_indexes_best_price_first() {
+    let mut pool = create_and_initialize_pool(false).await;
+    let owner = authenticated_account(&pool);
+    let place = |amount_out_min: Amount| PoolMessage::PlaceLimitOrder {
+        origin: owner,
+        amount_0_in: Some(Amount::from_tokens(10)),
+        amount_1_in: None,
+        amount_out_min,
+        to: None,
+    };
+
+    pool.execute_message(place(Amount::from_tokens(3))).await;
+    pool.execute_message(place(Amount::from_tokens(1))).await;
+    pool.execute_message(place(Amount::from_tokens(2))).await;
+    pool.execute_message(place(Amount::from_tokens(1))).await;
+
+    let orders = pool.state.borrow().limit_orders(true, 3).await.unwrap();
+    assert_eq!(
+        orders
+            .iter()
+            .map(|order| order.amount_out_min)
+            .collect::<Vec<_>>(),
+        vec![
+            Amount::from_tokens(1),
+            Amount::from_tokens(1),
+            Amount::from_tokens(2)
+        ]
+    );
+    // Same price is filled first in first out
+    assert!(orders[0].order_id < orders[1].order_id);
+
+    pool.execute_message(PoolMessage::CancelLimitOrder {
+        origin: owner,
+        order_id: orders[0].order_id,
+    })
+    .await;
+
+    let orders = pool.state.borrow().limit_orders(true, 16).await.unwrap();
+    assert_eq!(orders.len(), 3);
+    assert_eq!(orders[0].amount_out_min, Amount::from_tokens(1));
+    assert!(pool
+        .state
+        .borrow()
+        .limit_orders(false, 16)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_cancel_limit_order_refunds_escrowed_input() {
+    let mut pool = create_and_initialize_pool(false).await;
+    let owner = authenticated_account(&pool);
+    let token_0 = pool.runtime.borrow_mut().application_parameters().token_0;
+
+    pool.execute_message(PoolMessage::PlaceLimitOrder {
+        origin: owner,
+        amount_0_in: Some(Amount::from_tokens(10)),
+        amount_1_in: None,
+        amount_out_min: Amount::from_tokens(1),
+        to: None,
+    })
+    .await;
+
+    let order_id = pool.state.borrow().limit_orders(true, 16).await.unwrap()[0].order_id;
+    pool.execute_message(PoolMessage::CancelLimitOrder {
+        origin: owner,
+        order_id,
+    })
+    .await;
+
+    assert!(pool
+        .state
+        .borrow()
+        .limit_orders(true, 16)
+        .await
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claimable_balance(MemeToken::from(token_0), owner)
+            .await
+            .unwrap(),
+        Amount::from_tokens(10)
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_cancel_limit_order_rejects_non_owner() {
+    let mut pool = create_and_initialize_pool(false).await;
+    let owner = authenticated_account(&pool);
+    let other = alternate_account(owner.chain_id);
+
+    pool.execute_message(PoolMessage::PlaceLimitOrder {
+        origin: owner,
+        amount_0_in: Some(Amount::from_tokens(10)),
+        amount_1_in: None,
+        amount_out_min: Amount::from_tokens(1),
+        to: None,
+    })
+    .await;
+
+    let order_id = pool.state.borrow().limit_orders(true, 16).await.unwrap()[0].order_id;
+    let result =
+        std::panic::AssertUnwindSafe(pool.execute_message(PoolMessage::CancelLimitOrder {
+            origin: other,
+            order_id,
+        }))
+        .catch_unwind()
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(
+        pool.state
+            .borrow()
+            .limit_orders(true, 16)
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_swap_refunds_input_when_swaps_paused() {
+    let mut pool = create_and_initialize_pool(false).await;
+    let owner = authenticated_account(&pool);
+    let token_1 = pool.runtime.borrow_mut().application_parameters().token_1;
+    let reserve_0 = pool.state.borrow().reserve_0();
+    let reserve_1 = pool.state.borrow().reserve_1();
+
+    pool.execute_message(PoolMessage::SetPoolStatus {
+        operator: owner,
+        status: PoolStatus::SwapsPaused,
+    })
+    .await;
+    assert_eq!(pool.state.borrow().pool().status, PoolStatus::SwapsPaused);
+
+    pool.execute_message(PoolMessage::Swap {
+        origin: owner,
+        amount_0_in: None,
+        amount_1_in: Some(Amount::ONE),
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        to: None,
+        block_timestamp: None,
+        route: None,
+        amount_0_out: None,
+        amount_1_out: None,
+        deadline: None,
+        auto_claim: false,
+    })
+    .await;
+
+    assert_eq!(pool.state.borrow().reserve_0(), reserve_0);
+    assert_eq!(pool.state.borrow().reserve_1(), reserve_1);
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claimable_balance(MemeToken::from(token_1), owner)
+            .await
+            .unwrap(),
+        Amount::ONE
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_withdraw_only_refunds_deposits_and_allows_removal() {
+    let mut pool = create_and_initialize_pool(false).await;
+    let owner = authenticated_account(&pool);
+    let token_0 = pool.runtime.borrow_mut().application_parameters().token_0;
+    let total_supply_before = total_supply(&pool);
+    let liquidity_before = pool.state.borrow().liquidity(owner).await.unwrap();
+
+    pool.execute_message(PoolMessage::SetPoolStatus {
+        operator: owner,
+        status: PoolStatus::WithdrawOnly,
+    })
+    .await;
+
+    pool.execute_message(PoolMessage::AddLiquidity {
+        origin: owner,
+        amount_0_in: Amount::from_tokens(100),
+        amount_1_in: Amount::ONE,
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        to: None,
+        block_timestamp: None,
+        deadline: None,
+    })
+    .await;
+
+    assert_eq!(total_supply(&pool), total_supply_before);
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claimable_balance(MemeToken::from(token_0), owner)
+            .await
+            .unwrap(),
+        Amount::from_tokens(100)
+    );
+
+    pool.execute_message(PoolMessage::RemoveLiquidity {
+        origin: owner,
+        liquidity: Amount::ONE,
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        to: None,
+        block_timestamp: None,
+        deadline: None,
+    })
+    .await;
+
+    assert_eq!(
+        pool.state.borrow().liquidity(owner).await.unwrap(),
+        liquidity_before.try_sub(Amount::ONE).unwrap()
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_fully_paused_keeps_liquidity_shares() {
+    let mut pool = create_and_initialize_pool(false).await;
+    let owner = authenticated_account(&pool);
+    let liquidity_before = pool.state.borrow().liquidity(owner).await.unwrap();
+
+    pool.execute_message(PoolMessage::SetPoolStatus {
+        operator: owner,
+        status: PoolStatus::FullyPaused,
+    })
+    .await;
+
+    pool.execute_message(PoolMessage::RemoveLiquidity {
+        origin: owner,
+        liquidity: Amount::ONE,
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        to: None,
+        block_timestamp: None,
+        deadline: None,
+    })
+    .await;
+    pool.execute_message(PoolMessage::TransferLiquidity {
+        origin: owner,
+        to: alternate_account(owner.chain_id),
+        liquidity: Amount::ONE,
+    })
+    .await;
+
+    assert_eq!(
+        pool.state.borrow().liquidity(owner).await.unwrap(),
+        liquidity_before
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_set_pool_status_accepts_router_and_rejects_others() {
+    let mut pool = create_and_initialize_pool(false).await;
+    let owner = authenticated_account(&pool);
+    let router_application_id = pool.state.borrow().router_application_id();
+    let router = Account {
+        chain_id: mock_token_creator_chain_id(),
+        owner: AccountOwner::from(router_application_id),
+    };
+
+    pool.execute_message(PoolMessage::SetPoolStatus {
+        operator: router,
+        status: PoolStatus::FullyPaused,
+    })
+    .await;
+    assert_eq!(pool.state.borrow().pool().status, PoolStatus::FullyPaused);
+
+    let result = std::panic::AssertUnwindSafe(pool.execute_message(PoolMessage::SetPoolStatus {
+        operator: alternate_account(owner.chain_id),
+        status: PoolStatus::Active,
+    }))
+    .catch_unwind()
+    .await;
+
+    assert!(result.is_err());
+    assert_eq!(pool.state.borrow().pool().status, PoolStatus::FullyPaused);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn operation_twap_averages_observed_prices() {
+    let mut pool = create_and_initialize_pool(false).await;
+    pool.runtime.borrow_mut().set_system_time(10_000_000.into());
+
+    let response = pool
+        .execute_operation(PoolOperation::Twap { window_seconds: 10 })
+        .await;
+
+    let PoolResponse::Twap(twap) = response else {
+        panic!("Expected Twap");
+    };
+    assert_eq!(twap.window_seconds, 10);
+    assert_eq!(twap.price_0, Amount::from_str("0.01").unwrap());
+    assert_eq!(twap.price_1, Amount::from_str("100").unwrap());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn operation_twap_rejects_window_before_first_observation() {
+    let mut pool = create_and_initialize_pool(false).await;
+    pool.runtime.borrow_mut().set_system_time(10_000_000.into());
+
+    let result = std::panic::AssertUnwindSafe(
+        pool.execute_operation(PoolOperation::Twap { window_seconds: 11 }),
+    )
+    .catch_unwind()
+    .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_swap() {
+    let mut pool = create_and_initialize_pool(true).await;
+    let mut runtime_context = ContractRuntimeAdapter::new(pool.runtime.clone());
+
+    let owner = Account {
+        chain_id: runtime_context.chain_id(),
+        owner: runtime_context.authenticated_signer().unwrap(),
+    };
+
+    let reserve_0 = pool.state.borrow().reserve_0();
+    let reserve_1 = pool.state.borrow().reserve_1();
+    let swap_amount_0 = pool
+        .state
+        .borrow()
+        .calculate_swap_amount_0(Amount::ONE)
+        .unwrap();
+
+    pool.execute_message(PoolMessage::Swap {
+        origin: owner,
+        amount_0_in: None,
+        amount_1_in: Some(Amount::ONE),
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        to: None,
+        block_timestamp: None,
+        route: None,
+        amount_0_out: None,
+        amount_1_out: None,
+        deadline: None,
+        auto_claim: false,
+    })
+    .await;
+
+    assert_eq!(
+        reserve_0.try_sub(swap_amount_0).unwrap(),
+        pool.state.borrow().reserve_0()
+    );
+    assert_eq!(
+        reserve_1.try_add(Amount::ONE).unwrap(),
+        pool.state.borrow().reserve_1()
+    );
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claimable_balance(MemeToken::Fungible(runtime_context.token_0()), owner)
+            .await
+            .unwrap(),
+        swap_amount_0
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_swap_auto_claim_transfers_output_through_claim() {
+    let mut pool = create_and_initialize_pool(true).await;
+    let owner = authenticated_account(&pool);
+    let token_0 = pool.runtime.borrow_mut().application_parameters().token_0;
+    let swap_amount_0 = pool
+        .state
+        .borrow()
+        .calculate_swap_amount_0(Amount::ONE)
+        .unwrap();
+
+    let captured = std::rc::Rc::new(std::cell::RefCell::new(None));
+    let captured_for_handler = captured.clone();
+    pool.runtime.borrow_mut().set_call_application_handler(
+        move |_authenticated, application_id, operation| match bcs::from_bytes::<MemeOperation>(
+            &operation,
+        ) {
+            Ok(MemeOperation::CreatorChainId) => {
+                bcs::to_bytes(&MemeResponse::ChainId(mock_token_creator_chain_id())).unwrap()
+            }
+            _ => {
+                *captured_for_handler.borrow_mut() = Some((application_id, operation));
+                bcs::to_bytes(&MemeResponse::Ok).unwrap()
+            }
+        },
+    );
+
+    pool.execute_message(PoolMessage::Swap {
+        origin: owner,
+        amount_0_in: None,
+        amount_1_in: Some(Amount::ONE),
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        to: None,
+        block_timestamp: None,
+        route: None,
+        amount_0_out: None,
+        amount_1_out: None,
+        deadline: None,
+        auto_claim: true,
+    })
+    .await;
+
+    let (application_id, operation) = captured.borrow().clone().unwrap();
+    assert_eq!(application_id, token_0);
+    assert!(matches!(
+        bcs::from_bytes::<MemeOperation>(&operation).unwrap(),
+        MemeOperation::TransferFromApplicationWithReceipt {
+            to,
+            amount,
+            receipt,
+        } if to == owner
+            && amount == swap_amount_0
+            && receipt.purpose == TransferFromApplicationReceiptPurpose::PoolClaim
+            && receipt.payload.is_none()
+    ));
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claimable_balance(MemeToken::Fungible(token_0), owner)
+            .await
+            .unwrap(),
+        Amount::ZERO
+    );
+    // Settled by the claim transfer receipt, a failed transfer returns it to claimable
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claiming_balance(MemeToken::Fungible(token_0), owner)
+            .await
+            .unwrap(),
+        swap_amount_0
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_swap_min_amount_boundary() {
+    let mut pool = create_and_initialize_pool(true).await;
+    let owner = authenticated_account(&pool);
+    let amount_1_in = Amount::ONE;
+    let exact_amount_0_out = pool
+        .state
+        .borrow()
+        .calculate_swap_amount_0(amount_1_in)
+        .unwrap();
+
+    pool.execute_message(PoolMessage::Swap {
+        origin: owner,
+        amount_0_in: None,
+        amount_1_in: Some(amount_1_in),
+        amount_0_out_min: Some(exact_amount_0_out),
+        amount_1_out_min: None,
+        to: None,
+        block_timestamp: None,
+        route: None,
+        amount_0_out: None,
+        amount_1_out: None,
+        deadline: None,
+        auto_claim: false,
+    })
+    .await;
+
+    let mut pool = create_and_initialize_pool(true).await;
+    let owner = authenticated_account(&pool);
+    let reserve_0 = pool.state.borrow().reserve_0();
+    let reserve_1 = pool.state.borrow().reserve_1();
+    pool.execute_message(PoolMessage::Swap {
+        origin: owner,
+        amount_0_in: None,
+        amount_1_in: Some(amount_1_in),
+        amount_0_out_min: Some(exact_amount_0_out.try_add(Amount::from_attos(1)).unwrap()),
+        amount_1_out_min: None,
+        to: None,
+        block_timestamp: None,
+        route: None,
+        amount_0_out: None,
+        amount_1_out: None,
+        deadline: None,
+        auto_claim: false,
+    })
+    .await;
+    assert_eq!(pool.state.borrow().reserve_0(), reserve_0);
+    assert_eq!(pool.state.borrow().reserve_1(), reserve_1);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_add_liquidity() {
+    let mut pool = create_and_initialize_pool(true).await;
+    let owner = authenticated_account(&pool);
+
+    pool.execute_message(PoolMessage::AddLiquidity {
+        origin: owner,
+        amount_0_in: Amount::ONE,
+        amount_1_in: Amount::from_tokens(10),
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        to: None,
+        block_timestamp: None,
+        deadline: None,
+    })
+    .await;
+
+    assert_eq!(
+        pool.state.borrow().liquidity(owner).await.unwrap(),
+        Amount::from_str("100.1").unwrap()
+    );
+
+    let (amount_0_out, amount_1_out) = pool
+        .state
+        .borrow()
+        .try_calculate_liquidity_amount_pair(Amount::from_str("100.05").unwrap(), None, None)
+        .unwrap();
+    let parameters = pool.runtime.borrow_mut().application_parameters();
+    let claimable_0_before = pool
+        .state
+        .borrow()
+        .claimable_balance(MemeToken::from(parameters.token_0), owner)
+        .await
+        .unwrap();
+    let claimable_1_before = pool
+        .state
+        .borrow()
+        .claimable_balance(MemeToken::from(parameters.token_1), owner)
+        .await
+        .unwrap();
+
+    pool.execute_message(PoolMessage::RemoveLiquidity {
+        origin: owner,
+        liquidity: Amount::from_str("100.05").unwrap(),
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        to: None,
+        block_timestamp: None,
+        deadline: None,
+    })
+    .await;
+
+    assert_eq!(
+        pool.state.borrow().liquidity(owner).await.unwrap(),
+        Amount::from_str("0.05").unwrap()
+    );
+
+    assert!(amount_0_out > Amount::ZERO);
+    assert!(amount_1_out > Amount::ZERO);
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claimable_balance(MemeToken::from(parameters.token_0), owner)
+            .await
+            .unwrap(),
+        claimable_0_before.try_add(amount_0_out).unwrap()
+    );
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claimable_balance(MemeToken::from(parameters.token_1), owner)
+            .await
+            .unwrap(),
+        claimable_1_before.try_add(amount_1_out).unwrap()
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_remove_liquidity_native_output_claims_through_claim_path() {
+    let mut pool = create_and_instantiate_native_pool(false).await;
+    let owner = authenticated_account(&pool);
+    pool.execute_message(PoolMessage::InitializeLiquidity {
+        origin: owner,
+        amount_0_in: Amount::from_str("1000").unwrap(),
+        amount_1_in: Amount::from_str("10").unwrap(),
+        to: None,
+        block_timestamp: None,
+    })
+    .await;
+
+    let liquidity = Amount::from_str("10").unwrap();
+    let (amount_0_out, amount_1_out) = pool
+        .state
+        .borrow()
+        .try_calculate_liquidity_amount_pair(liquidity, None, None)
+        .unwrap();
+    let application_owner =
+        AccountOwner::from(pool.runtime.borrow_mut().application_id().forget_abi());
+
+    pool.runtime
+        .borrow_mut()
+        .set_owner_balance(application_owner, amount_1_out);
+    pool.runtime
+        .borrow_mut()
+        .set_owner_balance(owner.owner, Amount::ZERO);
+
+    pool.execute_message(PoolMessage::RemoveLiquidity {
+        origin: owner,
+        liquidity,
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        to: None,
+        block_timestamp: None,
+        deadline: None,
+    })
+    .await;
+
+    let token_0 = pool.runtime.borrow_mut().application_parameters().token_0;
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claimable_balance(MemeToken::from(token_0), owner)
+            .await
+            .unwrap(),
+        amount_0_out
+    );
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claimable_balance(MemeToken::Native, owner)
+            .await
+            .unwrap(),
+        amount_1_out
+    );
+
+    pool.execute_message(PoolMessage::Claim {
+        origin: owner,
+        token: None,
+        amount: amount_1_out,
+    })
+    .await;
+
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claimable_balance(MemeToken::Native, owner)
+            .await
+            .unwrap(),
+        Amount::ZERO
+    );
+    assert_eq!(
+        pool.runtime.borrow_mut().owner_balance(owner.owner),
+        amount_1_out
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_migrate_liquidity_delivers_underlying_and_continues_on_router() {
+    let mut pool = create_and_instantiate_native_pool(false).await;
+    let owner = authenticated_account(&pool);
+    pool.execute_message(PoolMessage::InitializeLiquidity {
+        origin: owner,
+        amount_0_in: Amount::from_str("1000").unwrap(),
+        amount_1_in: Amount::from_str("10").unwrap(),
+        to: None,
+        block_timestamp: None,
+    })
+    .await;
+
+    let liquidity = Amount::from_str("10").unwrap();
+    let (amount_0_out, amount_1_out) = pool
+        .state
+        .borrow()
+        .try_calculate_liquidity_amount_pair(liquidity, None, None)
+        .unwrap();
+    let application_owner =
+        AccountOwner::from(pool.runtime.borrow_mut().application_id().forget_abi());
+    pool.runtime
+        .borrow_mut()
+        .set_owner_balance(application_owner, amount_1_out);
+    pool.runtime
+        .borrow_mut()
+        .set_owner_balance(owner.owner, Amount::ZERO);
+    let router_application_id = pool.state.borrow().router_application_id();
+    let total_supply_before = total_supply(&pool);
+
+    let captured = Rc::new(RefCell::new(Vec::new()));
+    let captured_for_handler = captured.clone();
+    pool.runtime.borrow_mut().set_call_application_handler(
+        move |_authenticated, application_id, operation| {
+            captured_for_handler
+                .borrow_mut()
+                .push((application_id, operation));
+            bcs::to_bytes(&SwapResponse::Ok).unwrap()
+        },
+    );
+
+    pool.execute_message(PoolMessage::MigrateLiquidity {
+        origin: owner,
+        liquidity,
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        deadline: None,
+    })
+    .await;
+
+    assert_eq!(
+        total_supply(&pool),
+        total_supply_before.try_sub(liquidity).unwrap()
+    );
+    // Native is settled at once, meme is settled when its claim receipt comes back
+    assert_eq!(
+        pool.runtime.borrow_mut().owner_balance(owner.owner),
+        amount_1_out
+    );
+    let token_0 = pool.runtime.borrow_mut().application_parameters().token_0;
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claiming_balance(MemeToken::from(token_0), owner)
+            .await
+            .unwrap(),
+        amount_0_out
+    );
+
+    let captured = captured.borrow();
+    let (application_id, operation) = captured.last().unwrap();
+    assert_eq!(*application_id, router_application_id);
+    assert!(matches!(
+        bcs::from_bytes::<SwapOperation>(operation).unwrap(),
+        SwapOperation::MigrateLiquidity {
+            origin,
+            token_0: call_token_0,
+            token_1: None,
+            pool_fee_percent_mul_100: 30,
+            amount_0,
+            amount_1,
+            amount_0_out_min: None,
+            amount_1_out_min: None,
+            deadline: None,
+        } if origin == owner
+            && call_token_0 == token_0
+            && amount_0 == amount_0_out
+            && amount_1 == amount_1_out
+    ));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn operation_zap_in_meme_input_requests_zap_in_funding() {
+    let mut pool = create_and_initialize_pool(true).await;
+    let origin = authenticated_account(&pool);
+    let token_1 = pool.runtime.borrow_mut().application_parameters().token_1;
+    let message_count_before = pool.runtime.borrow().created_send_message_requests().len();
+
+    let response = pool
+        .execute_operation(PoolOperation::ZapIn {
+            token: token_1,
+            amount_in: Amount::ONE,
+            min_liquidity: Some(Amount::from_attos(1)),
+            to: None,
+            block_timestamp: None,
+            deadline: None,
+        })
+        .await;
+
+    assert!(matches!(response, PoolResponse::Ok));
+    let runtime = pool.runtime.borrow();
+    let requests = &runtime.created_send_message_requests()[message_count_before..];
+    assert_eq!(requests.len(), 1);
+    assert!(matches!(
+        &requests[0].message,
+        PoolMessage::RequestFund {
+            prev: None,
+            request,
+            next: None,
+        } if request.from == origin
+            && request.token == token_1
+            && request.amount_in == Amount::ONE
+            && request.amount_out_min == Some(Amount::from_attos(1))
+            && request.fund_type == FundType::ZapIn
+    ));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_zap_in_swaps_optimal_portion_and_mints_shares() {
+    let mut pool = create_and_initialize_pool(false).await;
+    let owner = authenticated_account(&pool);
+    let parameters = pool.runtime.borrow_mut().application_parameters();
+    let liquidity = pool.state.borrow().liquidity(owner).await.unwrap();
+    let reserve_0 = pool.state.borrow().reserve_0();
+    let reserve_1 = pool.state.borrow().reserve_1();
+    let amount_in = Amount::from_tokens(100);
+
+    pool.execute_message(PoolMessage::ZapIn {
+        origin: owner,
+        amount_0_in: Some(amount_in),
+        amount_1_in: None,
+        min_liquidity: Some(Amount::ONE),
+        to: None,
+        block_timestamp: None,
+        deadline: None,
+    })
+    .await;
+
+    assert!(
+        pool.state.borrow().liquidity(owner).await.unwrap() > liquidity.saturating_add(Amount::ONE)
+    );
+    // Output of the internal swap is added back as liquidity
+    assert_eq!(pool.state.borrow().reserve_1(), reserve_1);
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claimable_balance(MemeToken::from(parameters.token_1), owner)
+            .await
+            .unwrap(),
+        Amount::ZERO
+    );
+    let excess_0 = pool
+        .state
+        .borrow()
+        .claimable_balance(MemeToken::from(parameters.token_0), owner)
+        .await
+        .unwrap();
+    assert!(excess_0 <= Amount::from_attos(1000));
+    assert_eq!(
+        pool.state.borrow().reserve_0().saturating_add(excess_0),
+        reserve_0.saturating_add(amount_in)
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_zap_in_refunds_below_min_liquidity() {
+    let mut pool = create_and_initialize_pool(false).await;
+    let owner = authenticated_account(&pool);
+    let parameters = pool.runtime.borrow_mut().application_parameters();
+    let reserve_0 = pool.state.borrow().reserve_0();
+    let reserve_1 = pool.state.borrow().reserve_1();
+    let total_supply_before = total_supply(&pool);
+
+    pool.execute_message(PoolMessage::ZapIn {
+        origin: owner,
+        amount_0_in: None,
+        amount_1_in: Some(Amount::ONE),
+        min_liquidity: Some(Amount::from_tokens(1000)),
+        to: None,
+        block_timestamp: None,
+        deadline: None,
+    })
+    .await;
+
+    assert_eq!(pool.state.borrow().reserve_0(), reserve_0);
+    assert_eq!(pool.state.borrow().reserve_1(), reserve_1);
+    assert_eq!(total_supply(&pool), total_supply_before);
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claimable_balance(MemeToken::from(parameters.token_1), owner)
+            .await
+            .unwrap(),
+        Amount::ONE
     );
+}
 
-    let (amount_0_out, amount_1_out) = pool
-        .state
-        .borrow()
-        .try_calculate_liquidity_amount_pair(Amount::from_str("100.05").unwrap(), None, None)
-        .unwrap();
+#[tokio::test(flavor = "multi_thread")]
+async fn message_zap_out_pays_single_token() {
+    let mut pool = create_and_initialize_pool(false).await;
+    let owner = authenticated_account(&pool);
     let parameters = pool.runtime.borrow_mut().application_parameters();
-    let claimable_0_before = pool
-        .state
-        .borrow()
-        .claimable_balance(MemeToken::from(parameters.token_0), owner)
-        .await
-        .unwrap();
-    let claimable_1_before = pool
-        .state
-        .borrow()
-        .claimable_balance(MemeToken::from(parameters.token_1), owner)
-        .await
-        .unwrap();
+    let liquidity = pool.state.borrow().liquidity(owner).await.unwrap();
+    let reserve_0 = pool.state.borrow().reserve_0();
+    let reserve_1 = pool.state.borrow().reserve_1();
 
-    pool.execute_message(PoolMessage::RemoveLiquidity {
+    pool.execute_message(PoolMessage::ZapOut {
         origin: owner,
-        liquidity: Amount::from_str("100.05").unwrap(),
-        amount_0_out_min: None,
-        amount_1_out_min: None,
+        liquidity: Amount::from_tokens(10),
+        token: Some(parameters.token_0),
+        amount_out_min: Some(Amount::ONE),
         to: None,
         block_timestamp: None,
+        deadline: None,
     })
     .await;
 
     assert_eq!(
         pool.state.borrow().liquidity(owner).await.unwrap(),
-        Amount::from_str("0.05").unwrap()
+        liquidity.saturating_sub(Amount::from_tokens(10))
     );
-
-    assert!(amount_0_out > Amount::ZERO);
-    assert!(amount_1_out > Amount::ZERO);
+    assert_eq!(pool.state.borrow().reserve_1(), reserve_1);
     assert_eq!(
         pool.state
             .borrow()
-            .claimable_balance(MemeToken::from(parameters.token_0), owner)
+            .claimable_balance(MemeToken::from(parameters.token_1), owner)
             .await
             .unwrap(),
-        claimable_0_before.try_add(amount_0_out).unwrap()
+        Amount::ZERO
     );
+    let amount_out = pool
+        .state
+        .borrow()
+        .claimable_balance(MemeToken::from(parameters.token_0), owner)
+        .await
+        .unwrap();
+    assert!(amount_out > Amount::ONE);
     assert_eq!(
-        pool.state
-            .borrow()
-            .claimable_balance(MemeToken::from(parameters.token_1), owner)
-            .await
-            .unwrap(),
-        claimable_1_before.try_add(amount_1_out).unwrap()
+        pool.state.borrow().reserve_0().saturating_add(amount_out),
+        reserve_0
     );
 }
 
 #[tokio::test(flavor = "multi_thread")]
-async fn message_remove_liquidity_native_output_claims_through_claim_path() {
-    let mut pool = create_and_instantiate_native_pool(false).await;
+async fn message_zap_out_keeps_liquidity_below_amount_out_min() {
+    let mut pool = create_and_initialize_pool(false).await;
     let owner = authenticated_account(&pool);
-    pool.execute_message(PoolMessage::InitializeLiquidity {
+    let parameters = pool.runtime.borrow_mut().application_parameters();
+    let liquidity = pool.state.borrow().liquidity(owner).await.unwrap();
+    let reserve_1 = pool.state.borrow().reserve_1();
+
+    pool.execute_message(PoolMessage::ZapOut {
         origin: owner,
-        amount_0_in: Amount::from_str("1000").unwrap(),
-        amount_1_in: Amount::from_str("10").unwrap(),
+        liquidity: Amount::from_tokens(10),
+        token: Some(parameters.token_1),
+        amount_out_min: Some(Amount::from_tokens(10)),
         to: None,
         block_timestamp: None,
+        deadline: None,
     })
     .await;
 
-    let liquidity = Amount::from_str("10").unwrap();
-    let (amount_0_out, amount_1_out) = pool
-        .state
-        .borrow()
-        .try_calculate_liquidity_amount_pair(liquidity, None, None)
-        .unwrap();
-    let application_owner =
-        AccountOwner::from(pool.runtime.borrow_mut().application_id().forget_abi());
+    assert_eq!(
+        pool.state.borrow().liquidity(owner).await.unwrap(),
+        liquidity
+    );
+    assert_eq!(pool.state.borrow().reserve_1(), reserve_1);
+}
 
-    pool.runtime
-        .borrow_mut()
-        .set_owner_balance(application_owner, amount_1_out);
-    pool.runtime
-        .borrow_mut()
-        .set_owner_balance(owner.owner, Amount::ZERO);
+#[tokio::test(flavor = "multi_thread")]
+async fn message_zap_in_refunds_in_launch_phase() {
+    let mut pool = create_and_initialize_launch_protected_pool().await;
+    let owner = authenticated_account(&pool);
+    let parameters = pool.runtime.borrow_mut().application_parameters();
+    let reserve_0 = pool.state.borrow().reserve_0();
+    let total_supply_before = total_supply(&pool);
 
-    pool.execute_message(PoolMessage::RemoveLiquidity {
+    pool.execute_message(PoolMessage::ZapIn {
         origin: owner,
-        liquidity,
-        amount_0_out_min: None,
-        amount_1_out_min: None,
+        amount_0_in: Some(Amount::from_tokens(10)),
+        amount_1_in: None,
+        min_liquidity: None,
         to: None,
         block_timestamp: None,
+        deadline: None,
     })
     .await;
 
-    let token_0 = pool.runtime.borrow_mut().application_parameters().token_0;
-    assert_eq!(
-        pool.state
-            .borrow()
-            .claimable_balance(MemeToken::from(token_0), owner)
-            .await
-            .unwrap(),
-        amount_0_out
-    );
+    assert_eq!(pool.state.borrow().reserve_0(), reserve_0);
+    assert_eq!(total_supply(&pool), total_supply_before);
     assert_eq!(
         pool.state
             .borrow()
-            .claimable_balance(MemeToken::Native, owner)
+            .claimable_balance(MemeToken::from(parameters.token_0), owner)
             .await
             .unwrap(),
-        amount_1_out
+        Amount::from_tokens(10)
     );
+}
 
-    pool.execute_message(PoolMessage::Claim {
+#[tokio::test(flavor = "multi_thread")]
+async fn message_zap_out_keeps_liquidity_in_launch_phase() {
+    let mut pool = create_and_initialize_launch_protected_pool().await;
+    let owner = authenticated_account(&pool);
+    let parameters = pool.runtime.borrow_mut().application_parameters();
+    let liquidity = pool.state.borrow().liquidity(owner).await.unwrap();
+    let reserve_0 = pool.state.borrow().reserve_0();
+
+    pool.execute_message(PoolMessage::ZapOut {
         origin: owner,
-        token: None,
-        amount: amount_1_out,
+        liquidity: Amount::ONE,
+        token: Some(parameters.token_0),
+        amount_out_min: None,
+        to: None,
+        block_timestamp: None,
+        deadline: None,
     })
     .await;
 
     assert_eq!(
-        pool.state
-            .borrow()
-            .claimable_balance(MemeToken::Native, owner)
-            .await
-            .unwrap(),
-        Amount::ZERO
-    );
-    assert_eq!(
-        pool.runtime.borrow_mut().owner_balance(owner.owner),
-        amount_1_out
+        pool.state.borrow().liquidity(owner).await.unwrap(),
+        liquidity
     );
+    assert_eq!(pool.state.borrow().reserve_0(), reserve_0);
 }
 
 #[tokio::test(flavor = "multi_thread")]
@@ -693,6 +2999,7 @@ async fn message_add_liquidity_min_amount_boundary() {
         amount_1_out_min: Some(exact_amount_1),
         to: None,
         block_timestamp: None,
+        deadline: None,
     })
     .await;
 
@@ -706,6 +3013,7 @@ async fn message_add_liquidity_min_amount_boundary() {
         amount_1_out_min: Some(exact_amount_1.try_add(Amount::from_attos(1)).unwrap()),
         to: None,
         block_timestamp: None,
+        deadline: None,
     })
     .await;
     let parameters = pool.runtime.borrow_mut().application_parameters();
@@ -741,6 +3049,7 @@ async fn message_remove_liquidity_min_amount_boundary() {
         amount_1_out_min: None,
         to: None,
         block_timestamp: None,
+        deadline: None,
     })
     .await;
     let (exact_amount_0, exact_amount_1) = pool
@@ -756,6 +3065,7 @@ async fn message_remove_liquidity_min_amount_boundary() {
         amount_1_out_min: Some(exact_amount_1),
         to: None,
         block_timestamp: None,
+        deadline: None,
     })
     .await;
 
@@ -769,6 +3079,7 @@ async fn message_remove_liquidity_min_amount_boundary() {
         amount_1_out_min: None,
         to: None,
         block_timestamp: None,
+        deadline: None,
     })
     .await;
     let parameters = pool.runtime.borrow_mut().application_parameters();
@@ -797,6 +3108,7 @@ async fn message_remove_liquidity_min_amount_boundary() {
             amount_1_out_min: Some(exact_amount_1),
             to: None,
             block_timestamp: None,
+            deadline: None,
         }))
         .catch_unwind()
         .await;
@@ -852,6 +3164,11 @@ async fn message_add_liquidity_mints_fee_to_after_swap_growth() {
         amount_1_out_min: None,
         to: None,
         block_timestamp: None,
+        route: None,
+        amount_0_out: None,
+        amount_1_out: None,
+        deadline: None,
+        auto_claim: false,
     })
     .await;
 
@@ -868,6 +3185,7 @@ async fn message_add_liquidity_mints_fee_to_after_swap_growth() {
         amount_1_out_min: None,
         to: None,
         block_timestamp: None,
+        deadline: None,
     })
     .await;
 
@@ -894,6 +3212,11 @@ async fn message_remove_liquidity_mints_fee_to_and_updates_reserves_after_swap_g
         amount_1_out_min: None,
         to: None,
         block_timestamp: None,
+        route: None,
+        amount_0_out: None,
+        amount_1_out: None,
+        deadline: None,
+        auto_claim: false,
     })
     .await;
 
@@ -907,6 +3230,7 @@ async fn message_remove_liquidity_mints_fee_to_and_updates_reserves_after_swap_g
         amount_1_out_min: None,
         to: None,
         block_timestamp: None,
+        deadline: None,
     })
     .await;
 
@@ -928,6 +3252,7 @@ async fn message_remove_liquidity_mints_fee_to_and_updates_reserves_after_swap_g
         amount_1_out_min: None,
         to: None,
         block_timestamp: None,
+        deadline: None,
     })
     .await;
 
@@ -974,6 +3299,11 @@ async fn message_add_liquidity_conserves_total_supply_with_fee_dilution() {
         amount_1_out_min: None,
         to: None,
         block_timestamp: None,
+        route: None,
+        amount_0_out: None,
+        amount_1_out: None,
+        deadline: None,
+        auto_claim: false,
     })
     .await;
 
@@ -985,6 +3315,7 @@ async fn message_add_liquidity_conserves_total_supply_with_fee_dilution() {
         amount_1_out_min: None,
         to: None,
         block_timestamp: None,
+        deadline: None,
     })
     .await;
 
@@ -1027,6 +3358,11 @@ async fn message_remove_liquidity_conserves_total_supply_after_fee_mint() {
         amount_1_out_min: None,
         to: None,
         block_timestamp: None,
+        route: None,
+        amount_0_out: None,
+        amount_1_out: None,
+        deadline: None,
+        auto_claim: false,
     })
     .await;
 
@@ -1037,6 +3373,7 @@ async fn message_remove_liquidity_conserves_total_supply_after_fee_mint() {
         amount_1_out_min: None,
         to: None,
         block_timestamp: None,
+        deadline: None,
     })
     .await;
 
@@ -1238,34 +3575,161 @@ async fn message_claim_fungible_moves_to_claiming() {
     })
     .await;
 
-    let (application_id, operation) = captured.borrow().clone().unwrap();
-    assert_eq!(application_id, token_0);
-    assert!(matches!(
-        bcs::from_bytes::<MemeOperation>(&operation).unwrap(),
-        MemeOperation::TransferFromApplicationWithReceipt {
-            to,
-            amount: call_amount,
-            receipt,
-        } if to == owner
-            && call_amount == amount
-            && receipt.purpose == TransferFromApplicationReceiptPurpose::PoolClaim
-            && receipt.owner == owner
-            && receipt.token == token_0
-            && receipt.amount == amount
-            && receipt.result.is_none()
-    ));
-    assert_eq!(
-        pool.state
-            .borrow()
-            .claimable_balance(token, owner)
-            .await
-            .unwrap(),
-        Amount::ZERO
-    );
+    let (application_id, operation) = captured.borrow().clone().unwrap();
+    assert_eq!(application_id, token_0);
+    assert!(matches!(
+        bcs::from_bytes::<MemeOperation>(&operation).unwrap(),
+        MemeOperation::TransferFromApplicationWithReceipt {
+            to,
+            amount: call_amount,
+            receipt,
+        } if to == owner
+            && call_amount == amount
+            && receipt.purpose == TransferFromApplicationReceiptPurpose::PoolClaim
+            && receipt.owner == owner
+            && receipt.token == token_0
+            && receipt.amount == amount
+            && receipt.result.is_none()
+    ));
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claimable_balance(token, owner)
+            .await
+            .unwrap(),
+        Amount::ZERO
+    );
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claiming_balance(token, owner)
+            .await
+            .unwrap(),
+        amount
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_claim_all_drains_both_pool_tokens() {
+    let mut pool = create_and_instantiate_native_pool(false).await;
+    let owner = authenticated_account(&pool);
+    let token_0 = pool.runtime.borrow_mut().application_parameters().token_0;
+    let native_amount = Amount::from_tokens(5);
+    let fungible_amount = Amount::from_tokens(3);
+    let application_owner =
+        AccountOwner::from(pool.runtime.borrow_mut().application_id().forget_abi());
+
+    pool.runtime
+        .borrow_mut()
+        .set_owner_balance(application_owner, native_amount);
+    pool.runtime
+        .borrow_mut()
+        .set_owner_balance(owner.owner, Amount::ZERO);
+    pool.state
+        .borrow_mut()
+        .credit(MemeToken::Native, owner, native_amount)
+        .await
+        .unwrap();
+    pool.state
+        .borrow_mut()
+        .credit(MemeToken::Fungible(token_0), owner, fungible_amount)
+        .await
+        .unwrap();
+
+    let captured = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+    let captured_for_handler = captured.clone();
+    pool.runtime.borrow_mut().set_call_application_handler(
+        move |_authenticated, application_id, operation| match bcs::from_bytes::<MemeOperation>(
+            &operation,
+        ) {
+            Ok(MemeOperation::CreatorChainId) => {
+                bcs::to_bytes(&MemeResponse::ChainId(mock_token_creator_chain_id())).unwrap()
+            }
+            _ => {
+                captured_for_handler
+                    .borrow_mut()
+                    .push((application_id, operation));
+                bcs::to_bytes(&MemeResponse::Ok).unwrap()
+            }
+        },
+    );
+
+    pool.execute_message(PoolMessage::ClaimAll {
+        origin: owner,
+        tokens: None,
+    })
+    .await;
+
+    assert_eq!(
+        pool.runtime.borrow_mut().owner_balance(owner.owner),
+        native_amount
+    );
+    let captured = captured.borrow();
+    assert_eq!(captured.len(), 1);
+    assert_eq!(captured[0].0, token_0);
+    assert!(matches!(
+        bcs::from_bytes::<MemeOperation>(&captured[0].1).unwrap(),
+        MemeOperation::TransferFromApplicationWithReceipt { to, amount, .. }
+            if to == owner && amount == fungible_amount
+    ));
+    for token in [MemeToken::Native, MemeToken::Fungible(token_0)] {
+        assert_eq!(
+            pool.state
+                .borrow()
+                .claimable_balance(token, owner)
+                .await
+                .unwrap(),
+            Amount::ZERO
+        );
+    }
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claiming_balance(MemeToken::Fungible(token_0), owner)
+            .await
+            .unwrap(),
+        fungible_amount
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_claim_all_only_claims_selected_tokens() {
+    let mut pool = create_and_instantiate_native_pool(false).await;
+    let owner = authenticated_account(&pool);
+    let token_0 = pool.runtime.borrow_mut().application_parameters().token_0;
+    let amount = Amount::from_tokens(5);
+    let application_owner =
+        AccountOwner::from(pool.runtime.borrow_mut().application_id().forget_abi());
+
+    pool.runtime
+        .borrow_mut()
+        .set_owner_balance(application_owner, amount);
+    pool.runtime
+        .borrow_mut()
+        .set_owner_balance(owner.owner, Amount::ZERO);
+    pool.state
+        .borrow_mut()
+        .credit(MemeToken::Native, owner, amount)
+        .await
+        .unwrap();
+    pool.state
+        .borrow_mut()
+        .credit(MemeToken::Fungible(token_0), owner, amount)
+        .await
+        .unwrap();
+
+    // Claiming the same token twice claims nothing more
+    pool.execute_message(PoolMessage::ClaimAll {
+        origin: owner,
+        tokens: Some(vec![None, None]),
+    })
+    .await;
+
+    assert_eq!(pool.runtime.borrow_mut().owner_balance(owner.owner), amount);
     assert_eq!(
         pool.state
             .borrow()
-            .claiming_balance(token, owner)
+            .claimable_balance(MemeToken::Fungible(token_0), owner)
             .await
             .unwrap(),
         amount
@@ -1365,6 +3829,7 @@ async fn message_claim_fungible_keeps_claimable_and_claiming_exclusive() {
             token: token_0,
             amount,
             result: Err("transfer failed".to_string()),
+            route: None,
         },
     })
     .await;
@@ -1397,6 +3862,7 @@ async fn message_claim_fungible_keeps_claimable_and_claiming_exclusive() {
             token: token_0,
             amount,
             result: Ok(()),
+            route: None,
         },
     })
     .await;
@@ -1452,12 +3918,245 @@ async fn message_claim_native_rejects_meme_meme_pool() {
             .claiming_balance(MemeToken::Native, owner)
             .await
             .unwrap(),
-        Amount::ZERO
+        Amount::ZERO
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_claim_transfer_receipt_success_consumes_claiming() {
+    let mut pool = create_and_instantiate_pool(false).await;
+    let owner = authenticated_account(&pool);
+    let token_0 = pool.runtime.borrow_mut().application_parameters().token_0;
+    let token = MemeToken::Fungible(token_0);
+    let amount = Amount::from_tokens(5);
+
+    pool.runtime
+        .borrow_mut()
+        .set_authenticated_caller_id(token_0);
+    pool.state
+        .borrow_mut()
+        .credit(token, owner, amount)
+        .await
+        .unwrap();
+    pool.state
+        .borrow_mut()
+        .claim(token, owner, amount)
+        .await
+        .unwrap();
+
+    pool.execute_message(PoolMessage::ClaimTransferReceipt {
+        receipt: ClaimTransferReceipt {
+            owner,
+            token: token_0,
+            amount,
+            result: Ok(()),
+            route: None,
+        },
+    })
+    .await;
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claimable_balance(token, owner)
+            .await
+            .unwrap(),
+        Amount::ZERO
+    );
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claiming_balance(token, owner)
+            .await
+            .unwrap(),
+        Amount::ZERO
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_claim_transfer_receipt_rejects_duplicate_success_receipt() {
+    let mut pool = create_and_instantiate_pool(false).await;
+    let owner = authenticated_account(&pool);
+    let token_0 = pool.runtime.borrow_mut().application_parameters().token_0;
+    let token = MemeToken::Fungible(token_0);
+    let amount = Amount::from_tokens(5);
+
+    pool.runtime
+        .borrow_mut()
+        .set_authenticated_caller_id(token_0);
+    pool.state
+        .borrow_mut()
+        .credit(token, owner, amount)
+        .await
+        .unwrap();
+    pool.state
+        .borrow_mut()
+        .claim(token, owner, amount)
+        .await
+        .unwrap();
+
+    pool.execute_message(PoolMessage::ClaimTransferReceipt {
+        receipt: ClaimTransferReceipt {
+            owner,
+            token: token_0,
+            amount,
+            result: Ok(()),
+            route: None,
+        },
+    })
+    .await;
+
+    let result =
+        std::panic::AssertUnwindSafe(pool.execute_message(PoolMessage::ClaimTransferReceipt {
+            receipt: ClaimTransferReceipt {
+                owner,
+                token: token_0,
+                amount,
+                result: Ok(()),
+                route: None,
+            },
+        }))
+        .catch_unwind()
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claimable_balance(token, owner)
+            .await
+            .unwrap(),
+        Amount::ZERO
+    );
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claiming_balance(token, owner)
+            .await
+            .unwrap(),
+        Amount::ZERO
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn operation_claim_transfer_receipt_rejects_invalid_caller() {
+    let mut pool = create_and_instantiate_pool(false).await;
+    let owner = authenticated_account(&pool);
+    let token_0 = pool.runtime.borrow_mut().application_parameters().token_0;
+    let token_1 = pool
+        .runtime
+        .borrow_mut()
+        .application_parameters()
+        .token_1
+        .unwrap();
+    let token = MemeToken::Fungible(token_0);
+    let amount = Amount::from_tokens(5);
+
+    pool.runtime
+        .borrow_mut()
+        .set_authenticated_caller_id(token_1);
+    pool.state
+        .borrow_mut()
+        .credit(token, owner, amount)
+        .await
+        .unwrap();
+    pool.state
+        .borrow_mut()
+        .claim(token, owner, amount)
+        .await
+        .unwrap();
+
+    let result =
+        std::panic::AssertUnwindSafe(pool.execute_operation(PoolOperation::ClaimTransferReceipt {
+            receipt: ClaimTransferReceipt {
+                owner,
+                token: token_0,
+                amount,
+                result: Ok(()),
+                route: None,
+            },
+        }))
+        .catch_unwind()
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claimable_balance(token, owner)
+            .await
+            .unwrap(),
+        Amount::ZERO
+    );
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claiming_balance(token, owner)
+            .await
+            .unwrap(),
+        amount
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_claim_transfer_receipt_rejects_non_creator_chain() {
+    let mut pool = create_and_instantiate_pool(false).await;
+    let owner = authenticated_account(&pool);
+    let token_0 = pool.runtime.borrow_mut().application_parameters().token_0;
+    let token = MemeToken::Fungible(token_0);
+    let amount = Amount::from_tokens(5);
+    let user_chain_id =
+        ChainId::from_str("bee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfe8")
+            .unwrap();
+
+    pool.runtime
+        .borrow_mut()
+        .set_authenticated_caller_id(token_0);
+    pool.state
+        .borrow_mut()
+        .credit(token, owner, amount)
+        .await
+        .unwrap();
+    pool.state
+        .borrow_mut()
+        .claim(token, owner, amount)
+        .await
+        .unwrap();
+    pool.runtime.borrow_mut().set_chain_id(user_chain_id);
+
+    let result =
+        std::panic::AssertUnwindSafe(pool.execute_message(PoolMessage::ClaimTransferReceipt {
+            receipt: ClaimTransferReceipt {
+                owner,
+                token: token_0,
+                amount,
+                result: Ok(()),
+                route: None,
+            },
+        }))
+        .catch_unwind()
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claimable_balance(token, owner)
+            .await
+            .unwrap(),
+        Amount::ZERO
+    );
+    assert_eq!(
+        pool.state
+            .borrow()
+            .claiming_balance(token, owner)
+            .await
+            .unwrap(),
+        amount
     );
 }
 
 #[tokio::test(flavor = "multi_thread")]
-async fn message_claim_transfer_receipt_success_consumes_claiming() {
+async fn message_claim_transfer_receipt_fail_returns_to_claimable() {
     let mut pool = create_and_instantiate_pool(false).await;
     let owner = authenticated_account(&pool);
     let token_0 = pool.runtime.borrow_mut().application_parameters().token_0;
@@ -1467,6 +4166,7 @@ async fn message_claim_transfer_receipt_success_consumes_claiming() {
     pool.runtime
         .borrow_mut()
         .set_authenticated_caller_id(token_0);
+
     pool.state
         .borrow_mut()
         .credit(token, owner, amount)
@@ -1483,17 +4183,19 @@ async fn message_claim_transfer_receipt_success_consumes_claiming() {
             owner,
             token: token_0,
             amount,
-            result: Ok(()),
+            result: Err("transfer failed".to_string()),
+            route: None,
         },
     })
     .await;
+
     assert_eq!(
         pool.state
             .borrow()
             .claimable_balance(token, owner)
             .await
             .unwrap(),
-        Amount::ZERO
+        amount
     );
     assert_eq!(
         pool.state
@@ -1506,50 +4208,67 @@ async fn message_claim_transfer_receipt_success_consumes_claiming() {
 }
 
 #[tokio::test(flavor = "multi_thread")]
-async fn message_claim_transfer_receipt_rejects_duplicate_success_receipt() {
-    let mut pool = create_and_instantiate_pool(false).await;
+async fn message_swap_with_route_forwards_output_through_claim() {
+    let mut pool = create_and_initialize_pool(true).await;
     let owner = authenticated_account(&pool);
     let token_0 = pool.runtime.borrow_mut().application_parameters().token_0;
     let token = MemeToken::Fungible(token_0);
-    let amount = Amount::from_tokens(5);
-
-    pool.runtime
-        .borrow_mut()
-        .set_authenticated_caller_id(token_0);
-    pool.state
-        .borrow_mut()
-        .credit(token, owner, amount)
-        .await
-        .unwrap();
-    pool.state
-        .borrow_mut()
-        .claim(token, owner, amount)
-        .await
+    let swap_amount_0 = pool
+        .state
+        .borrow()
+        .calculate_swap_amount_0(Amount::ONE)
         .unwrap();
+    let route = route_for_tests(Some(token_0));
 
-    pool.execute_message(PoolMessage::ClaimTransferReceipt {
-        receipt: ClaimTransferReceipt {
-            owner,
-            token: token_0,
-            amount,
-            result: Ok(()),
+    let captured = std::rc::Rc::new(std::cell::RefCell::new(None));
+    let captured_for_handler = captured.clone();
+    pool.runtime.borrow_mut().set_call_application_handler(
+        move |_authenticated, application_id, operation| match bcs::from_bytes::<MemeOperation>(
+            &operation,
+        ) {
+            Ok(MemeOperation::CreatorChainId) => {
+                bcs::to_bytes(&MemeResponse::ChainId(mock_token_creator_chain_id())).unwrap()
+            }
+            _ => {
+                *captured_for_handler.borrow_mut() = Some((application_id, operation));
+                bcs::to_bytes(&MemeResponse::Ok).unwrap()
+            }
         },
+    );
+
+    pool.execute_message(PoolMessage::Swap {
+        origin: owner,
+        amount_0_in: None,
+        amount_1_in: Some(Amount::ONE),
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        to: None,
+        block_timestamp: None,
+        route: Some(route.clone()),
+        amount_0_out: None,
+        amount_1_out: None,
+        deadline: None,
+        auto_claim: false,
     })
     .await;
 
-    let result =
-        std::panic::AssertUnwindSafe(pool.execute_message(PoolMessage::ClaimTransferReceipt {
-            receipt: ClaimTransferReceipt {
-                owner,
-                token: token_0,
-                amount,
-                result: Ok(()),
-            },
-        }))
-        .catch_unwind()
-        .await;
-
-    assert!(result.is_err());
+    let (application_id, operation) = captured.borrow().clone().unwrap();
+    assert_eq!(application_id, token_0);
+    assert!(matches!(
+        bcs::from_bytes::<MemeOperation>(&operation).unwrap(),
+        MemeOperation::TransferFromApplicationWithReceipt {
+            to,
+            amount: call_amount,
+            receipt,
+        } if to == owner
+            && call_amount == swap_amount_0
+            && receipt.purpose == TransferFromApplicationReceiptPurpose::PoolClaim
+            && matches!(
+                &receipt.payload,
+                Some(TransferFromApplicationReceiptPayload::PoolClaim(payload))
+                    if payload.route == route
+            )
+    ));
     assert_eq!(
         pool.state
             .borrow()
@@ -1564,58 +4283,45 @@ async fn message_claim_transfer_receipt_rejects_duplicate_success_receipt() {
             .claiming_balance(token, owner)
             .await
             .unwrap(),
-        Amount::ZERO
+        swap_amount_0
     );
 }
 
 #[tokio::test(flavor = "multi_thread")]
-async fn operation_claim_transfer_receipt_rejects_invalid_caller() {
-    let mut pool = create_and_instantiate_pool(false).await;
+async fn message_swap_with_mismatched_route_keeps_output_claimable() {
+    let mut pool = create_and_initialize_pool(true).await;
     let owner = authenticated_account(&pool);
     let token_0 = pool.runtime.borrow_mut().application_parameters().token_0;
-    let token_1 = pool
-        .runtime
-        .borrow_mut()
-        .application_parameters()
-        .token_1
-        .unwrap();
     let token = MemeToken::Fungible(token_0);
-    let amount = Amount::from_tokens(5);
-
-    pool.runtime
-        .borrow_mut()
-        .set_authenticated_caller_id(token_1);
-    pool.state
-        .borrow_mut()
-        .credit(token, owner, amount)
-        .await
-        .unwrap();
-    pool.state
-        .borrow_mut()
-        .claim(token, owner, amount)
-        .await
+    let swap_amount_0 = pool
+        .state
+        .borrow()
+        .calculate_swap_amount_0(Amount::ONE)
         .unwrap();
 
-    let result =
-        std::panic::AssertUnwindSafe(pool.execute_operation(PoolOperation::ClaimTransferReceipt {
-            receipt: ClaimTransferReceipt {
-                owner,
-                token: token_0,
-                amount,
-                result: Ok(()),
-            },
-        }))
-        .catch_unwind()
-        .await;
+    pool.execute_message(PoolMessage::Swap {
+        origin: owner,
+        amount_0_in: None,
+        amount_1_in: Some(Amount::ONE),
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        to: None,
+        block_timestamp: None,
+        route: Some(route_for_tests(None)),
+        amount_0_out: None,
+        amount_1_out: None,
+        deadline: None,
+        auto_claim: false,
+    })
+    .await;
 
-    assert!(result.is_err());
     assert_eq!(
         pool.state
             .borrow()
             .claimable_balance(token, owner)
             .await
             .unwrap(),
-        Amount::ZERO
+        swap_amount_0
     );
     assert_eq!(
         pool.state
@@ -1623,21 +4329,28 @@ async fn operation_claim_transfer_receipt_rejects_invalid_caller() {
             .claiming_balance(token, owner)
             .await
             .unwrap(),
-        amount
+        Amount::ZERO
     );
 }
 
 #[tokio::test(flavor = "multi_thread")]
-async fn message_claim_transfer_receipt_rejects_non_creator_chain() {
+async fn message_claim_transfer_receipt_success_continues_route() {
     let mut pool = create_and_instantiate_pool(false).await;
     let owner = authenticated_account(&pool);
     let token_0 = pool.runtime.borrow_mut().application_parameters().token_0;
     let token = MemeToken::Fungible(token_0);
     let amount = Amount::from_tokens(5);
-    let user_chain_id =
-        ChainId::from_str("bee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfe8")
-            .unwrap();
+    let route = route_for_tests(Some(token_0));
+    let router_application_id = pool.state.borrow().router_application_id();
 
+    let captured = std::rc::Rc::new(std::cell::RefCell::new(None));
+    let captured_for_handler = captured.clone();
+    pool.runtime.borrow_mut().set_call_application_handler(
+        move |_authenticated, application_id, operation| {
+            *captured_for_handler.borrow_mut() = Some((application_id, operation));
+            bcs::to_bytes(&SwapResponse::Ok).unwrap()
+        },
+    );
     pool.runtime
         .borrow_mut()
         .set_authenticated_caller_id(token_0);
@@ -1651,51 +4364,57 @@ async fn message_claim_transfer_receipt_rejects_non_creator_chain() {
         .claim(token, owner, amount)
         .await
         .unwrap();
-    pool.runtime.borrow_mut().set_chain_id(user_chain_id);
 
-    let result =
-        std::panic::AssertUnwindSafe(pool.execute_message(PoolMessage::ClaimTransferReceipt {
-            receipt: ClaimTransferReceipt {
-                owner,
-                token: token_0,
-                amount,
-                result: Ok(()),
-            },
-        }))
-        .catch_unwind()
-        .await;
+    pool.execute_message(PoolMessage::ClaimTransferReceipt {
+        receipt: ClaimTransferReceipt {
+            owner,
+            token: token_0,
+            amount,
+            result: Ok(()),
+            route: Some(route.clone()),
+        },
+    })
+    .await;
 
-    assert!(result.is_err());
-    assert_eq!(
-        pool.state
-            .borrow()
-            .claimable_balance(token, owner)
-            .await
-            .unwrap(),
-        Amount::ZERO
-    );
+    let (application_id, operation) = captured.borrow().clone().unwrap();
+    assert_eq!(application_id, router_application_id);
+    assert!(matches!(
+        bcs::from_bytes::<SwapOperation>(&operation).unwrap(),
+        SwapOperation::ContinueSwap {
+            origin,
+            route: call_route,
+            amount_in,
+        } if origin == owner && call_route == route && amount_in == amount
+    ));
     assert_eq!(
         pool.state
             .borrow()
             .claiming_balance(token, owner)
             .await
             .unwrap(),
-        amount
+        Amount::ZERO
     );
 }
 
 #[tokio::test(flavor = "multi_thread")]
-async fn message_claim_transfer_receipt_fail_returns_to_claimable() {
+async fn message_claim_transfer_receipt_fail_stops_route() {
     let mut pool = create_and_instantiate_pool(false).await;
     let owner = authenticated_account(&pool);
     let token_0 = pool.runtime.borrow_mut().application_parameters().token_0;
     let token = MemeToken::Fungible(token_0);
     let amount = Amount::from_tokens(5);
 
+    let captured = std::rc::Rc::new(std::cell::RefCell::new(None));
+    let captured_for_handler = captured.clone();
+    pool.runtime.borrow_mut().set_call_application_handler(
+        move |_authenticated, application_id, operation| {
+            *captured_for_handler.borrow_mut() = Some((application_id, operation));
+            bcs::to_bytes(&SwapResponse::Ok).unwrap()
+        },
+    );
     pool.runtime
         .borrow_mut()
         .set_authenticated_caller_id(token_0);
-
     pool.state
         .borrow_mut()
         .credit(token, owner, amount)
@@ -1712,11 +4431,13 @@ async fn message_claim_transfer_receipt_fail_returns_to_claimable() {
             owner,
             token: token_0,
             amount,
-            result: Err("transfer failed".to_string()),
+            result: Err("Insufficient balance".to_string()),
+            route: Some(route_for_tests(Some(token_0))),
         },
     })
     .await;
 
+    assert!(captured.borrow().is_none());
     assert_eq!(
         pool.state
             .borrow()
@@ -1725,14 +4446,6 @@ async fn message_claim_transfer_receipt_fail_returns_to_claimable() {
             .unwrap(),
         amount
     );
-    assert_eq!(
-        pool.state
-            .borrow()
-            .claiming_balance(token, owner)
-            .await
-            .unwrap(),
-        Amount::ZERO
-    );
 }
 
 #[tokio::test(flavor = "multi_thread")]
@@ -1754,6 +4467,7 @@ async fn message_claim_transfer_receipt_rejects_insufficient_claiming() {
                 token: token_0,
                 amount,
                 result: Ok(()),
+                route: None,
             },
         }))
         .catch_unwind()
@@ -1790,6 +4504,7 @@ async fn message_claim_transfer_receipt_rejects_invalid_token() {
                 token: invalid_token,
                 amount,
                 result: Ok(()),
+                route: None,
             },
         }))
         .catch_unwind()
@@ -2565,6 +5280,34 @@ async fn message_fund_result_swap_fail_does_not_credit_or_update_reserves() {
     );
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn message_fund_result_add_liquidity_fail_records_failed_request() {
+    let mut pool = create_and_initialize_pool(false).await;
+    let token_0 = pool.runtime.borrow_mut().application_parameters().token_0;
+    let token_chain = mock_token_creator_chain_id();
+    let owner = authenticated_account(&pool);
+    let request = FundRequest::builder(owner, Some(token_0), Amount::ONE, FundType::AddLiquidity)
+        .counterparty_token(pool.runtime.borrow_mut().application_parameters().token_1)
+        .counterparty_amount_in(Some(Amount::from_tokens(2)))
+        .build();
+
+    configure_fund_result_source(&mut pool, token_chain, token_0, owner);
+    pool.execute_message(PoolMessage::FundResult {
+        prev: None,
+        request,
+        next: None,
+        result: Err("fund failed".to_string()),
+    })
+    .await;
+
+    let failed_requests = pool.state.borrow().failed_requests(owner).await.unwrap();
+    assert_eq!(failed_requests.len(), 1);
+    assert_eq!(failed_requests[0].kind, FailedRequestKind::AddLiquidity);
+    assert_eq!(failed_requests[0].reason, FailureReason::TransferFailed);
+    assert_eq!(failed_requests[0].amount_0, Amount::ONE);
+    assert_eq!(failed_requests[0].amount_1, Amount::from_tokens(2));
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn message_fund_result_swap_success_requests_pool_chain_custody_with_receipt() {
     let mut pool = create_and_initialize_pool(false).await;
@@ -2916,6 +5659,11 @@ async fn message_swap_slippage_after_custody_credits_input_claim_without_reserve
         amount_1_out_min: None,
         to: None,
         block_timestamp: None,
+        route: None,
+        amount_0_out: None,
+        amount_1_out: None,
+        deadline: None,
+        auto_claim: false,
     })
     .await;
 
@@ -3021,6 +5769,36 @@ fn mock_application_call(
     }
 }
 
+fn route_for_tests(token_in: Option<ApplicationId>) -> SwapRoute {
+    let pool_application = Account {
+        chain_id: ChainId::from_str(
+            "aee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfea",
+        )
+        .unwrap(),
+        owner: AccountOwner::from(
+            ApplicationId::from_str(
+                "b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bb0",
+            )
+            .unwrap(),
+        ),
+    };
+    let token_0 = token_in.unwrap_or(
+        ApplicationId::from_str("b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bad")
+            .unwrap(),
+    );
+
+    SwapRoute {
+        hops: vec![SwapRouteHop {
+            pool_application,
+            token_0,
+            token_1: None,
+            token_in,
+        }],
+        amount_out_min: None,
+        deadline: None,
+    }
+}
+
 fn authenticated_account(pool: &PoolContract) -> Account {
     let mut runtime_context = ContractRuntimeAdapter::new(pool.runtime.clone());
     Account {
@@ -3061,6 +5839,10 @@ fn add_liquidity_fund_request(
         to: None,
         block_timestamp: None,
         fund_type: FundType::AddLiquidity,
+        route: None,
+        counterparty_amount_out: None,
+        deadline: None,
+        auto_claim: false,
     }
 }
 
@@ -3144,6 +5926,27 @@ async fn create_and_initialize_pool(virtual_initial_liquidity: bool) -> PoolCont
     pool
 }
 
+async fn create_and_initialize_launch_protected_pool() -> PoolContract {
+    let mut pool = create_and_instantiate_pool(true).await;
+    let mut parameters = pool.runtime.borrow_mut().application_parameters();
+    parameters.launch_protection = Some(LaunchProtection::default());
+    pool.runtime
+        .borrow_mut()
+        .set_application_parameters(parameters);
+    let origin = authenticated_account(&pool);
+
+    pool.execute_message(PoolMessage::InitializeLiquidity {
+        origin,
+        amount_0_in: Amount::from_str("1000").unwrap(),
+        amount_1_in: Amount::from_str("10").unwrap(),
+        to: None,
+        block_timestamp: None,
+    })
+    .await;
+
+    pool
+}
+
 async fn create_and_instantiate_pool_with_amounts(virtual_initial_liquidity: bool) -> PoolContract {
     let _ = env_logger::builder().is_test(true).try_init();
 
@@ -3176,6 +5979,7 @@ async fn create_and_instantiate_pool_with_amounts(virtual_initial_liquidity: boo
             bootstrap_policy: BootstrapPolicy::MemeInitializeLiquidity {
                 virtual_initial_liquidity,
             },
+            launch_protection: None,
         })
         .with_chain_id(chain_id)
         .with_application_id(application_id)
@@ -1,4 +1,4 @@
-use abi::swap::pool::BootstrapPolicy;
+use abi::swap::pool::{BootstrapPolicy, LaunchProtection};
 use linera_sdk::linera_base_types::{Account, ApplicationId};
 
 pub trait ParametersInterface {
@@ -6,4 +6,5 @@ pub trait ParametersInterface {
     fn token_0(&mut self) -> ApplicationId;
     fn token_1(&mut self) -> Option<ApplicationId>;
     fn bootstrap_policy(&mut self) -> BootstrapPolicy;
+    fn launch_protection(&mut self) -> Option<LaunchProtection>;
 }
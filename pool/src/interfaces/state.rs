@@ -1,6 +1,9 @@
 use abi::meme_token::MemeToken;
 use abi::swap::{
-    pool::{InstantiationArgument, Pool, PoolParameters},
+    pool::{
+        DynamicFee, FailedRequest, InstantiationArgument, LimitOrder, Pool, PoolParameters,
+        PoolStats, PoolStatus, PoolTwap, SwapSettlement,
+    },
     transaction::Transaction,
 };
 use async_trait::async_trait;
@@ -48,6 +51,63 @@ pub trait StateInterface {
 
     fn liquid(&mut self, balance_0: Amount, balance_1: Amount, block_timestamp: Timestamp);
 
+    // Must run before liquid() of the swap, returns the fee rate charged
+    fn accrue_swap_fee(&mut self, amount_0_in: Amount, amount_1_in: Amount) -> u16;
+
+    // Launch extra fee taken from the swap input before quoting. accrue_swap_fee() already
+    // charged the base fee on it with the whole input, so only the rest is accrued. Must run
+    // before liquid() of the swap like accrue_swap_fee
+    fn accrue_launch_fee(&mut self, extra_fee_0: Amount, extra_fee_1: Amount);
+
+    fn stats(&self) -> PoolStats;
+
+    // Must run before liquid() of the swap like accrue_swap_fee
+    fn record_swap_stats(
+        &mut self,
+        amount_0_in: Amount,
+        amount_1_in: Amount,
+        amount_0_out: Amount,
+        amount_1_out: Amount,
+        timestamp: Timestamp,
+    );
+
+    // Settle reserves, fee growth and stats of a swap together, returns the fee rate charged.
+    // Nothing changes if the reserves can't take the amounts
+    fn commit_swap(
+        &mut self,
+        amount_0_in: Amount,
+        amount_1_in: Amount,
+        amount_0_out: Amount,
+        amount_1_out: Amount,
+        timestamp: Timestamp,
+    ) -> Result<u16, Self::Error>;
+
+    async fn earned_fees(&self, owner: Account) -> Result<(Amount, Amount), Self::Error>;
+
+    fn launched_at(&self) -> Option<Timestamp>;
+
+    fn virtual_reserve_1(&self) -> Amount;
+
+    fn set_virtual_reserve_1(&mut self, amount: Amount);
+
+    async fn last_swap_at(&self, owner: Account) -> Result<Option<Timestamp>, Self::Error>;
+
+    fn record_swap(&mut self, owner: Account, timestamp: Timestamp) -> Result<(), Self::Error>;
+
+    async fn failed_requests(&self, owner: Account) -> Result<Vec<FailedRequest>, Self::Error>;
+
+    // Only the latest MAX_FAILED_REQUESTS of each owner are kept
+    async fn record_failed_request(
+        &mut self,
+        owner: Account,
+        request: FailedRequest,
+    ) -> Result<(), Self::Error>;
+
+    async fn swap_settlements(&self) -> Result<Vec<SwapSettlement>, Self::Error>;
+
+    // Only the latest MAX_SWAP_SETTLEMENTS of the chain are kept
+    fn record_swap_settlement(&mut self, settlement: SwapSettlement);
+
     async fn add_liquidity(
         &mut self,
         amount_0: Amount,
@@ -87,6 +147,9 @@ pub trait StateInterface {
         owner: Account,
     ) -> Result<Amount, Self::Error>;
 
+    // Claimable, claiming and limit order escrow of token, which are held outside reserves
+    async fn obligations(&self, token: MemeToken) -> Result<Amount, Self::Error>;
+
     async fn credit(
         &mut self,
         token: MemeToken,
@@ -135,12 +198,82 @@ pub trait StateInterface {
 
     fn calculate_liquidity(&self, amount_0: Amount, amount_1: Amount) -> Amount;
 
+    async fn transfer_liquidity(
+        &mut self,
+        from: Account,
+        to: Account,
+        liquidity: Amount,
+    ) -> Result<(), Self::Error>;
+
+    async fn approve_liquidity(
+        &mut self,
+        owner: Account,
+        spender: Account,
+        liquidity: Amount,
+    ) -> Result<(), Self::Error>;
+
+    async fn transfer_liquidity_from(
+        &mut self,
+        spender: Account,
+        from: Account,
+        to: Account,
+        liquidity: Amount,
+    ) -> Result<(), Self::Error>;
+
+    async fn liquidity_allowance(
+        &self,
+        owner: Account,
+        spender: Account,
+    ) -> Result<Amount, Self::Error>;
+
     fn set_fee_to(&mut self, operator: Account, account: Account);
 
     fn set_fee_to_setter(&mut self, operator: Account, account: Account);
 
+    async fn set_protocol_fee_share(
+        &mut self,
+        operator: Account,
+        protocol_fee_share_denominator: u16,
+    ) -> Result<(), Self::Error>;
+
+    // Router application callers act as router operators
+    fn set_pool_status(&mut self, operator: Account, status: PoolStatus);
+
+    fn set_dynamic_fee(&mut self, operator: Account, dynamic_fee: Option<DynamicFee>);
+
     fn calculate_price_pair(&self) -> (Amount, Amount);
 
+    async fn place_limit_order(
+        &mut self,
+        owner: Account,
+        amount_0_in: Option<Amount>,
+        amount_1_in: Option<Amount>,
+        amount_out_min: Amount,
+        to: Option<Account>,
+        timestamp: Timestamp,
+    ) -> Result<LimitOrder, Self::Error>;
+
+    async fn cancel_limit_order(
+        &mut self,
+        owner: Account,
+        order_id: u64,
+    ) -> Result<LimitOrder, Self::Error>;
+
+    async fn remove_limit_order(&mut self, order_id: u64) -> Result<(), Self::Error>;
+
+    // At most limit open orders selling token_0 if token_0_in, best limit price first
+    async fn limit_orders(
+        &self,
+        token_0_in: bool,
+        limit: usize,
+    ) -> Result<Vec<LimitOrder>, Self::Error>;
+
+    async fn calculate_twap(
+        &self,
+        now: Timestamp,
+        window_seconds: u64,
+    ) -> Result<PoolTwap, Self::Error>;
+
     fn build_transaction(
         &mut self,
         owner: Account,
@@ -3,10 +3,10 @@ pub mod interfaces;
 pub mod state;
 
 pub use abi::swap::pool::FundType;
-use abi::swap::pool::PoolError as _PoolError;
+use abi::swap::pool::{LaunchProtection, PoolError as _PoolError};
 use async_graphql::SimpleObject;
 use linera_sdk::{
-    linera_base_types::{Amount, ArithmeticError},
+    linera_base_types::{Amount, ApplicationId, ArithmeticError, Timestamp},
     views::ViewError,
 };
 use serde::{Deserialize, Serialize};
@@ -38,3 +38,89 @@ pub struct LiquidityAmount {
     pub amount_0: Amount,
     pub amount_1: Amount,
 }
+
+// LP fees earned by a position since it first held shares, including fees already withdrawn
+// with the shares. Covers the swap fee at the rate charged and the launch extra fee, minus the
+// protocol share
+#[derive(Debug, Clone, Deserialize, Serialize, SimpleObject)]
+#[serde(rename_all = "camelCase")]
+pub struct EarnedFees {
+    pub amount_0: Amount,
+    pub amount_1: Amount,
+}
+
+// Launch protection of the pool as it applies right now
+#[derive(Debug, Clone, Deserialize, Serialize, SimpleObject)]
+#[serde(rename_all = "camelCase")]
+pub struct LaunchStatus {
+    pub protection: Option<LaunchProtection>,
+    // None until the pool is initialized
+    pub launched_at: Option<Timestamp>,
+    pub ends_at: Option<Timestamp>,
+    pub active: bool,
+    pub extra_fee_percent_mul_100: u16,
+    pub max_trade_0: Option<Amount>,
+    pub max_trade_1: Option<Amount>,
+    // When the queried owner may swap again
+    pub cooldown_ends_at: Option<Timestamp>,
+}
+
+// What the pool owes in one token against what the application actually holds
+#[derive(Debug, Clone, Deserialize, Serialize, SimpleObject)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenAudit {
+    // None is the native token
+    pub token: Option<ApplicationId>,
+    pub reserve: Amount,
+    // Part of the reserve from virtual initial liquidity, owed to nobody and never held
+    pub virtual_reserve: Amount,
+    pub claimable: Amount,
+    // Claim transfers not confirmed yet, they may already be gone from the holding
+    pub claiming: Amount,
+    // Input of resting limit orders
+    pub escrowed: Amount,
+    // Native holding is read from the chain balance. Meme holding is only known to the meme
+    // ledger on the meme creator chain, so it is always None and the meme side of a pool
+    // can't be audited, synced or skimmed
+    pub holding: Option<Amount>,
+    // None together with holding
+    pub surplus: Option<Amount>,
+    pub deficit: Option<Amount>,
+}
+
+impl TokenAudit {
+    pub fn new(
+        token: Option<ApplicationId>,
+        reserve: Amount,
+        virtual_reserve: Amount,
+        claimable: Amount,
+        claiming: Amount,
+        escrowed: Amount,
+        holding: Option<Amount>,
+    ) -> Self {
+        let liabilities = reserve
+            .saturating_sub(virtual_reserve)
+            .saturating_add(claimable)
+            .saturating_add(claiming)
+            .saturating_add(escrowed);
+
+        Self {
+            token,
+            reserve,
+            virtual_reserve,
+            claimable,
+            claiming,
+            escrowed,
+            holding,
+            surplus: holding.map(|holding| holding.saturating_sub(liabilities)),
+            deficit: holding.map(|holding| liabilities.saturating_sub(holding)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, SimpleObject)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolAudit {
+    pub token_0: TokenAudit,
+    pub token_1: TokenAudit,
+}
@@ -3,20 +3,23 @@
 
 #![cfg_attr(target_arch = "wasm32", no_main)]
 
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use abi::{
     meme_token::MemeToken,
-    swap::pool::{BootstrapPolicy, Pool, PoolAbi, PoolOperation, PoolParameters},
+    swap::pool::{
+        BootstrapPolicy, DynamicFee, FailedRequest, LimitOrder, Pool, PoolAbi, PoolOperation,
+        PoolParameters, PoolStats, PoolTwap, SwapQuote, SwapSettlement,
+    },
 };
 use async_graphql::{EmptySubscription, Object, Request, Response, Schema};
 use linera_sdk::{
-    linera_base_types::{Account, Amount, ApplicationId, Timestamp, WithServiceAbi},
+    linera_base_types::{Account, AccountOwner, Amount, ApplicationId, Timestamp, WithServiceAbi},
     views::View,
     Service, ServiceRuntime,
 };
 
-use pool::{state::PoolState, LiquidityAmount};
+use pool::{state::PoolState, EarnedFees, LaunchStatus, LiquidityAmount, PoolAudit, TokenAudit};
 
 #[derive(Clone)]
 pub struct PoolService {
@@ -69,12 +72,81 @@ impl PoolService {
             } => virtual_initial_liquidity,
         }
     }
+
+    fn application_account(&self) -> Account {
+        Account {
+            chain_id: self.runtime.chain_id(),
+            owner: AccountOwner::from(self.runtime.application_id().forget_abi()),
+        }
+    }
+
+    // Meme ledgers live on the meme creator chain, only the native holding is known here
+    fn holding(&self, token: Option<ApplicationId>) -> Option<Amount> {
+        token
+            .is_none()
+            .then(|| self.runtime.owner_balance(self.application_account().owner))
+    }
+
+    async fn escrowed_amounts(&self) -> (Amount, Amount) {
+        let mut escrowed_0 = Amount::ZERO;
+        let mut escrowed_1 = Amount::ZERO;
+        self.state
+            .limit_orders
+            .for_each_index_value(|_, order| {
+                escrowed_0 = escrowed_0.saturating_add(order.amount_0_in.unwrap_or(Amount::ZERO));
+                escrowed_1 = escrowed_1.saturating_add(order.amount_1_in.unwrap_or(Amount::ZERO));
+                Ok(())
+            })
+            .await
+            .expect("Failed to read limit orders");
+        (escrowed_0, escrowed_1)
+    }
+
+    async fn audit_token(
+        &self,
+        token: Option<ApplicationId>,
+        reserve: Amount,
+        virtual_reserve: Amount,
+        escrowed: Amount,
+    ) -> TokenAudit {
+        let token_key = MemeToken::from(token);
+        let claimable = self
+            .state
+            .claimable_balances
+            .get(&token_key)
+            .await
+            .expect("Failed to read claimable balances");
+        let claiming = self
+            .state
+            .claiming_balances
+            .get(&token_key)
+            .await
+            .expect("Failed to read claiming balances");
+
+        TokenAudit::new(
+            token,
+            reserve,
+            virtual_reserve,
+            balance_total(claimable),
+            balance_total(claiming),
+            escrowed,
+            self.holding(token),
+        )
+    }
 }
 
 fn effective_total_supply(pool: &Pool, total_supply: Amount) -> Amount {
     total_supply.try_add(pool.mint_fee(total_supply)).unwrap()
 }
 
+fn balance_total(balances: Option<HashMap<Account, Amount>>) -> Amount {
+    balances.map_or(Amount::ZERO, |balances| {
+        balances
+            .values()
+            .fold(Amount::ZERO, |total, amount| total.saturating_add(*amount))
+    })
+}
+
 fn query_liquidity_amounts(
     pool: &Pool,
     liquidity: Amount,
@@ -95,6 +167,10 @@ impl QueryRoot {
         self.service.state.pool.get().as_ref().unwrap().clone()
     }
 
+    async fn stats(&self) -> PoolStats {
+        *self.service.state.stats.get()
+    }
+
     async fn liquidity(&self, owner: Account) -> LiquidityAmount {
         // TODO: we have to access state directly instead of liquidity() right now due to `Send`
         let pool = self.service.state.pool.get().as_ref().unwrap().clone();
@@ -116,6 +192,47 @@ impl QueryRoot {
         }
     }
 
+    async fn earned_fees(&self, owner: Account) -> EarnedFees {
+        let checkpoint = self
+            .service
+            .state
+            .settled_fee_checkpoint(owner)
+            .await
+            .expect("Failed to settle fee checkpoint");
+
+        EarnedFees {
+            amount_0: checkpoint.earned_0,
+            amount_1: checkpoint.earned_1,
+        }
+    }
+
+    async fn liquidity_allowance(&self, owner: Account, spender: Account) -> Amount {
+        self.service
+            .state
+            .liquidity_allowances
+            .get(&owner)
+            .await
+            .expect("Failed to read liquidity allowance")
+            .and_then(|allowances| allowances.get(&spender).copied())
+            .unwrap_or(Amount::ZERO)
+    }
+
+    async fn limit_orders(&self, owner: Option<Account>) -> Vec<LimitOrder> {
+        let mut orders = Vec::new();
+        self.service
+            .state
+            .limit_orders
+            .for_each_index_value(|_, order| {
+                if owner.is_none_or(|owner| owner == order.owner) {
+                    orders.push(order.into_owned());
+                }
+                Ok(())
+            })
+            .await
+            .expect("Failed to read limit orders");
+        orders
+    }
+
     async fn claimable_balance(&self, token: Option<ApplicationId>, owner: Account) -> Amount {
         self.service
             .state
@@ -138,15 +255,172 @@ impl QueryRoot {
             .unwrap_or(Amount::ZERO)
     }
 
+    // Latest refunded requests of owner with failure reasons, oldest first
+    async fn failed_requests(&self, owner: Account) -> Vec<FailedRequest> {
+        self.service
+            .state
+            .failed_requests
+            .get(&owner)
+            .await
+            .expect("Failed to read failed requests")
+            .unwrap_or_default()
+    }
+
+    // Swaps originated from this chain, newest first
+    async fn swap_settlements(&self, owner: Option<Account>) -> Vec<SwapSettlement> {
+        let mut settlements = self
+            .service
+            .state
+            .swap_settlements
+            .elements()
+            .await
+            .expect("Failed to read swap settlements");
+        settlements
+            .retain(|settlement| owner.is_none_or(|owner| owner == settlement.transaction.from));
+        settlements.reverse();
+        settlements
+    }
+
     async fn total_supply(&self) -> Amount {
         let pool = self.service.state.pool.get().as_ref().unwrap().clone();
         effective_total_supply(&pool, *self.service.state.total_supply.get())
     }
 
+    // Liquidity mint_fee() would mint to fee_to on the next liquidity change
+    async fn accrued_protocol_fee(&self) -> LiquidityAmount {
+        let pool = self.service.state.pool.get().as_ref().unwrap().clone();
+        let total_supply = *self.service.state.total_supply.get();
+        let liquidity = pool.mint_fee(total_supply);
+        let (amount_0, amount_1) = query_liquidity_amounts(&pool, liquidity, total_supply);
+        LiquidityAmount {
+            liquidity,
+            amount_0,
+            amount_1,
+        }
+    }
+
     async fn virtual_initial_liquidity(&self) -> bool {
         self.service.virtual_initial_liquidity()
     }
 
+    // Only meaningful on the pool creator chain, where reserves and balances are kept
+    async fn audit(&self) -> PoolAudit {
+        let pool = self.service.state.pool.get().as_ref().unwrap().clone();
+        let (escrowed_0, escrowed_1) = self.service.escrowed_amounts().await;
+
+        PoolAudit {
+            token_0: self
+                .service
+                .audit_token(Some(pool.token_0), pool.reserve_0, Amount::ZERO, escrowed_0)
+                .await,
+            token_1: self
+                .service
+                .audit_token(
+                    pool.token_1,
+                    pool.reserve_1,
+                    *self.service.state.virtual_reserve_1.get(),
+                    escrowed_1,
+                )
+                .await,
+        }
+    }
+
+    async fn quote(&self, amount_0_in: Option<Amount>, amount_1_in: Option<Amount>) -> SwapQuote {
+        self.service
+            .state
+            .pool
+            .get()
+            .as_ref()
+            .unwrap()
+            .calculate_swap_quote(amount_0_in, amount_1_in)
+            .expect("Failed: quote")
+    }
+
+    async fn quote_exact_out(
+        &self,
+        amount_0_out: Option<Amount>,
+        amount_1_out: Option<Amount>,
+    ) -> SwapQuote {
+        self.service
+            .state
+            .pool
+            .get()
+            .as_ref()
+            .unwrap()
+            .calculate_swap_quote_exact_out(amount_0_out, amount_1_out)
+            .expect("Failed: quote exact out")
+    }
+
+    async fn twap(&self, window_seconds: u64) -> PoolTwap {
+        let pool = self.service.state.pool.get().as_ref().unwrap().clone();
+        let observations = self
+            .service
+            .state
+            .observations
+            .elements()
+            .await
+            .expect("Failed: observations");
+        pool.calculate_twap(
+            &observations,
+            self.service.runtime.system_time(),
+            window_seconds,
+        )
+        .expect("Failed: twap")
+    }
+
+    async fn launch(&self, owner: Option<Account>) -> LaunchStatus {
+        let protection = self
+            .service
+            .runtime
+            .application_parameters()
+            .launch_protection;
+        let launched_at = *self.service.state.launched_at.get();
+        let now = self.service.runtime.system_time();
+
+        let (Some(protection), Some(launched_at)) = (protection.clone(), launched_at) else {
+            return LaunchStatus {
+                protection,
+                launched_at,
+                ends_at: None,
+                active: false,
+                extra_fee_percent_mul_100: 0,
+                max_trade_0: None,
+                max_trade_1: None,
+                cooldown_ends_at: None,
+            };
+        };
+
+        let active = protection.active(launched_at, now);
+        let pool = self.service.state.pool.get().as_ref().unwrap().clone();
+        let last_swap_at = match owner {
+            Some(owner) => self
+                .service
+                .state
+                .last_swaps
+                .get(&owner)
+                .await
+                .expect("Failed to read last swap"),
+            None => None,
+        };
+
+        LaunchStatus {
+            ends_at: Some(protection.ends_at(launched_at)),
+            active,
+            extra_fee_percent_mul_100: protection.extra_fee_percent_mul_100(launched_at, now),
+            max_trade_0: active
+                .then(|| protection.max_trade(pool.reserve_0))
+                .flatten(),
+            max_trade_1: active
+                .then(|| protection.max_trade(pool.reserve_1))
+                .flatten(),
+            cooldown_ends_at: last_swap_at
+                .filter(|_| active && protection.cooldown_secs.is_some())
+                .map(|last_swap_at| protection.cooldown_ends_at(last_swap_at)),
+            protection: Some(protection),
+            launched_at: Some(launched_at),
+        }
+    }
+
     async fn calculate_amount_liquidity(
         &self,
         amount_0_desired: Option<Amount>,
@@ -203,6 +477,8 @@ impl MutationRoot {
         amount_1_out_min: Option<Amount>,
         to: Option<Account>,
         block_timestamp: Option<Timestamp>,
+        deadline: Option<Timestamp>,
+        auto_claim: Option<bool>,
     ) -> [u8; 0] {
         // Mutation should always be from other chain
         assert!(
@@ -219,6 +495,38 @@ impl MutationRoot {
                 amount_1_out_min,
                 to,
                 block_timestamp,
+                route: None,
+                deadline,
+                auto_claim: auto_claim.unwrap_or_default(),
+            });
+        []
+    }
+
+    async fn swap_exact_out(
+        &self,
+        amount_0_out: Option<Amount>,
+        amount_1_out: Option<Amount>,
+        amount_in_max: Amount,
+        to: Option<Account>,
+        block_timestamp: Option<Timestamp>,
+        deadline: Option<Timestamp>,
+        auto_claim: Option<bool>,
+    ) -> [u8; 0] {
+        assert!(
+            self.service.runtime.application_creator_chain_id() != self.service.runtime.chain_id(),
+            "Permission denied"
+        );
+
+        self.service
+            .runtime
+            .schedule_operation(&PoolOperation::SwapExactOut {
+                amount_0_out,
+                amount_1_out,
+                amount_in_max,
+                to,
+                block_timestamp,
+                deadline,
+                auto_claim: auto_claim.unwrap_or_default(),
             });
         []
     }
@@ -235,6 +543,18 @@ impl MutationRoot {
         []
     }
 
+    async fn claim_all(&self, tokens: Option<Vec<Option<ApplicationId>>>) -> [u8; 0] {
+        assert!(
+            self.service.runtime.application_creator_chain_id() != self.service.runtime.chain_id(),
+            "Permission denied"
+        );
+
+        self.service
+            .runtime
+            .schedule_operation(&PoolOperation::ClaimAll { tokens });
+        []
+    }
+
     async fn add_liquidity(
         &self,
         amount_0_in: Amount,
@@ -243,6 +563,7 @@ impl MutationRoot {
         amount_1_out_min: Option<Amount>,
         to: Option<Account>,
         block_timestamp: Option<Timestamp>,
+        deadline: Option<Timestamp>,
     ) -> [u8; 0] {
         assert!(
             self.service.runtime.application_creator_chain_id() != self.service.runtime.chain_id(),
@@ -258,6 +579,7 @@ impl MutationRoot {
                 amount_1_out_min,
                 to,
                 block_timestamp,
+                deadline,
             });
         []
     }
@@ -269,6 +591,7 @@ impl MutationRoot {
         amount_1_out_min: Option<Amount>,
         to: Option<Account>,
         block_timestamp: Option<Timestamp>,
+        deadline: Option<Timestamp>,
     ) -> [u8; 0] {
         assert!(
             self.service.runtime.application_creator_chain_id() != self.service.runtime.chain_id(),
@@ -283,6 +606,200 @@ impl MutationRoot {
                 amount_1_out_min,
                 to,
                 block_timestamp,
+                deadline,
+            });
+        []
+    }
+
+    async fn transfer_liquidity(&self, to: Account, liquidity: Amount) -> [u8; 0] {
+        assert!(
+            self.service.runtime.application_creator_chain_id() != self.service.runtime.chain_id(),
+            "Permission denied"
+        );
+
+        self.service
+            .runtime
+            .schedule_operation(&PoolOperation::TransferLiquidity { to, liquidity });
+        []
+    }
+
+    async fn approve_liquidity(&self, spender: Account, liquidity: Amount) -> [u8; 0] {
+        assert!(
+            self.service.runtime.application_creator_chain_id() != self.service.runtime.chain_id(),
+            "Permission denied"
+        );
+
+        self.service
+            .runtime
+            .schedule_operation(&PoolOperation::ApproveLiquidity { spender, liquidity });
+        []
+    }
+
+    async fn transfer_liquidity_from(
+        &self,
+        from: Account,
+        to: Account,
+        liquidity: Amount,
+    ) -> [u8; 0] {
+        assert!(
+            self.service.runtime.application_creator_chain_id() != self.service.runtime.chain_id(),
+            "Permission denied"
+        );
+
+        self.service
+            .runtime
+            .schedule_operation(&PoolOperation::TransferLiquidityFrom {
+                from,
+                to,
+                liquidity,
+            });
+        []
+    }
+
+    async fn place_limit_order(
+        &self,
+        amount_0_in: Option<Amount>,
+        amount_1_in: Option<Amount>,
+        amount_out_min: Amount,
+        to: Option<Account>,
+    ) -> [u8; 0] {
+        assert!(
+            self.service.runtime.application_creator_chain_id() != self.service.runtime.chain_id(),
+            "Permission denied"
+        );
+
+        self.service
+            .runtime
+            .schedule_operation(&PoolOperation::PlaceLimitOrder {
+                amount_0_in,
+                amount_1_in,
+                amount_out_min,
+                to,
+            });
+        []
+    }
+
+    async fn cancel_limit_order(&self, order_id: u64) -> [u8; 0] {
+        assert!(
+            self.service.runtime.application_creator_chain_id() != self.service.runtime.chain_id(),
+            "Permission denied"
+        );
+
+        self.service
+            .runtime
+            .schedule_operation(&PoolOperation::CancelLimitOrder { order_id });
+        []
+    }
+
+    async fn zap_in(
+        &self,
+        token: Option<ApplicationId>,
+        amount_in: Amount,
+        min_liquidity: Option<Amount>,
+        to: Option<Account>,
+        block_timestamp: Option<Timestamp>,
+        deadline: Option<Timestamp>,
+    ) -> [u8; 0] {
+        assert!(
+            self.service.runtime.application_creator_chain_id() != self.service.runtime.chain_id(),
+            "Permission denied"
+        );
+
+        self.service
+            .runtime
+            .schedule_operation(&PoolOperation::ZapIn {
+                token,
+                amount_in,
+                min_liquidity,
+                to,
+                block_timestamp,
+                deadline,
+            });
+        []
+    }
+
+    async fn zap_out(
+        &self,
+        liquidity: Amount,
+        token: Option<ApplicationId>,
+        amount_out_min: Option<Amount>,
+        to: Option<Account>,
+        block_timestamp: Option<Timestamp>,
+        deadline: Option<Timestamp>,
+    ) -> [u8; 0] {
+        assert!(
+            self.service.runtime.application_creator_chain_id() != self.service.runtime.chain_id(),
+            "Permission denied"
+        );
+
+        self.service
+            .runtime
+            .schedule_operation(&PoolOperation::ZapOut {
+                liquidity,
+                token,
+                amount_out_min,
+                to,
+                block_timestamp,
+                deadline,
+            });
+        []
+    }
+
+    async fn sync(&self) -> [u8; 0] {
+        assert!(
+            self.service.runtime.application_creator_chain_id() != self.service.runtime.chain_id(),
+            "Permission denied"
+        );
+
+        self.service
+            .runtime
+            .schedule_operation(&PoolOperation::Sync);
+        []
+    }
+
+    async fn skim(&self, to: Account) -> [u8; 0] {
+        assert!(
+            self.service.runtime.application_creator_chain_id() != self.service.runtime.chain_id(),
+            "Permission denied"
+        );
+
+        self.service
+            .runtime
+            .schedule_operation(&PoolOperation::Skim { to });
+        []
+    }
+
+    async fn set_dynamic_fee(&self, dynamic_fee: Option<DynamicFee>) -> [u8; 0] {
+        assert!(
+            self.service.runtime.application_creator_chain_id() != self.service.runtime.chain_id(),
+            "Permission denied"
+        );
+
+        self.service
+            .runtime
+            .schedule_operation(&PoolOperation::SetDynamicFee { dynamic_fee });
+        []
+    }
+
+    async fn migrate_liquidity(
+        &self,
+        liquidity: Amount,
+        amount_0_out_min: Option<Amount>,
+        amount_1_out_min: Option<Amount>,
+        deadline: Option<Timestamp>,
+    ) -> [u8; 0] {
+        assert!(
+            self.service.runtime.application_creator_chain_id() != self.service.runtime.chain_id(),
+            "Permission denied"
+        );
+
+        self.service
+            .runtime
+            .schedule_operation(&PoolOperation::MigrateLiquidity {
+                liquidity,
+                amount_0_out_min,
+                amount_1_out_min,
+                deadline,
             });
         []
     }
@@ -290,7 +807,7 @@ impl MutationRoot {
 
 #[cfg(test)]
 mod tests {
-    use super::{effective_total_supply, query_liquidity_amounts, PoolService};
+    use super::{balance_total, effective_total_supply, query_liquidity_amounts, PoolService};
     use abi::{meme_token::MemeToken, swap::pool::Pool};
     use async_graphql::{Request, Variables};
     use linera_sdk::{
@@ -298,7 +815,7 @@ mod tests {
         views::View,
         Service, ServiceRuntime,
     };
-    use pool::state::PoolState;
+    use pool::{state::PoolState, TokenAudit};
     use serde_json::{json, Value};
     use std::{
         collections::{BTreeSet, HashMap},
@@ -371,9 +888,22 @@ mod tests {
             .collect::<BTreeSet<_>>();
 
         assert!(names.contains("swap"));
+        assert!(names.contains("swapExactOut"));
         assert!(names.contains("claim"));
+        assert!(names.contains("claimAll"));
         assert!(names.contains("addLiquidity"));
         assert!(names.contains("removeLiquidity"));
+        assert!(names.contains("transferLiquidity"));
+        assert!(names.contains("approveLiquidity"));
+        assert!(names.contains("transferLiquidityFrom"));
+        assert!(names.contains("placeLimitOrder"));
+        assert!(names.contains("cancelLimitOrder"));
+        assert!(names.contains("zapIn"));
+        assert!(names.contains("zapOut"));
+        assert!(names.contains("sync"));
+        assert!(names.contains("skim"));
+        assert!(names.contains("setDynamicFee"));
+        assert!(names.contains("migrateLiquidity"));
     }
 
     #[tokio::test]
@@ -471,6 +1001,85 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn quote_query_uses_pool_swap_math() {
+        let runtime = Arc::new(ServiceRuntime::<PoolService>::new());
+        let mut state = PoolState::load(runtime.root_view_storage_context())
+            .await
+            .expect("Failed to read from mock key value store");
+        let (pool, _) = sample_pool_after_swap_growth();
+        state.pool.set(Some(pool.clone()));
+
+        let service = PoolService {
+            state: Arc::new(state),
+            runtime,
+        };
+
+        let response = service
+            .handle_query(Request::new(
+                r#"
+                query Quote {
+                    quote(amount0In: "1") {
+                        amount0Out
+                        amount1Out
+                        lpFee
+                    }
+                }
+                "#,
+            ))
+            .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+
+        let data = response.data.into_json().unwrap();
+        assert_eq!(
+            data["quote"]["amount1Out"],
+            json!(pool.calculate_swap_amount_1(Amount::ONE).unwrap())
+        );
+        assert_eq!(data["quote"]["amount0Out"], json!(Amount::ZERO));
+        assert_eq!(
+            data["quote"]["lpFee"],
+            json!(Amount::from_str("0.003").unwrap())
+        );
+    }
+
+    #[tokio::test]
+    async fn accrued_protocol_fee_query_follows_protocol_fee_share() {
+        let runtime = Arc::new(ServiceRuntime::<PoolService>::new());
+        let mut state = PoolState::load(runtime.root_view_storage_context())
+            .await
+            .expect("Failed to read from mock key value store");
+        let (mut pool, total_supply) = sample_pool_after_swap_growth();
+        state.pool.set(Some(pool.clone()));
+        state.total_supply.set(total_supply);
+
+        let service = PoolService {
+            state: Arc::new(state),
+            runtime: runtime.clone(),
+        };
+        let data = query_accrued_protocol_fee(&service).await;
+
+        let liquidity = Amount::from_str("0.002272933913650825").unwrap();
+        let (amount_0, amount_1) = query_liquidity_amounts(&pool, liquidity, total_supply);
+        assert_eq!(data["accruedProtocolFee"]["liquidity"], json!(liquidity));
+        assert_eq!(data["accruedProtocolFee"]["amount0"], json!(amount_0));
+        assert_eq!(data["accruedProtocolFee"]["amount1"], json!(amount_1));
+
+        let mut state = PoolState::load(runtime.root_view_storage_context())
+            .await
+            .expect("Failed to read from mock key value store");
+        pool.set_protocol_fee_share_denominator(0);
+        state.pool.set(Some(pool));
+        state.total_supply.set(total_supply);
+
+        let service = PoolService {
+            state: Arc::new(state),
+            runtime,
+        };
+        let data = query_accrued_protocol_fee(&service).await;
+
+        assert_eq!(data["accruedProtocolFee"]["liquidity"], json!(Amount::ZERO));
+    }
+
     #[test]
     fn total_supply_query_includes_pending_protocol_fee_dilution() {
         let (pool, total_supply) = sample_pool_after_swap_growth();
@@ -494,6 +1103,87 @@ mod tests {
         );
     }
 
+    #[test]
+    fn audit_compares_holding_with_all_liabilities() {
+        let owner = Account {
+            chain_id: ChainId::from_str(
+                "aee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfe8",
+            )
+            .unwrap(),
+            owner: AccountOwner::CHAIN,
+        };
+        let claimable = balance_total(Some(HashMap::from([(owner, Amount::from_tokens(2))])));
+        assert_eq!(claimable, Amount::from_tokens(2));
+        assert_eq!(balance_total(None), Amount::ZERO);
+
+        let audit = TokenAudit::new(
+            None,
+            Amount::from_tokens(100),
+            Amount::ZERO,
+            claimable,
+            Amount::ONE,
+            Amount::from_tokens(3),
+            Some(Amount::from_tokens(110)),
+        );
+        assert_eq!(audit.surplus, Some(Amount::from_tokens(4)));
+        assert_eq!(audit.deficit, Some(Amount::ZERO));
+
+        let audit = TokenAudit::new(
+            None,
+            Amount::from_tokens(100),
+            Amount::ZERO,
+            claimable,
+            Amount::ONE,
+            Amount::from_tokens(3),
+            Some(Amount::from_tokens(100)),
+        );
+        assert_eq!(audit.surplus, Some(Amount::ZERO));
+        assert_eq!(audit.deficit, Some(Amount::from_tokens(6)));
+
+        let audit = TokenAudit::new(
+            None,
+            Amount::from_tokens(100),
+            Amount::ZERO,
+            claimable,
+            Amount::ONE,
+            Amount::ZERO,
+            None,
+        );
+        assert_eq!(audit.surplus, None);
+        assert_eq!(audit.deficit, None);
+
+        // Virtual initial liquidity is part of the reserve but never held
+        let audit = TokenAudit::new(
+            None,
+            Amount::from_tokens(100),
+            Amount::from_tokens(90),
+            claimable,
+            Amount::ONE,
+            Amount::ZERO,
+            Some(Amount::from_tokens(13)),
+        );
+        assert_eq!(audit.surplus, Some(Amount::ZERO));
+        assert_eq!(audit.deficit, Some(Amount::ZERO));
+    }
+
+    async fn query_accrued_protocol_fee(service: &PoolService) -> Value {
+        let response = service
+            .handle_query(Request::new(
+                r#"
+                query AccruedProtocolFee {
+                    accruedProtocolFee {
+                        liquidity
+                        amount0
+                        amount1
+                    }
+                }
+                "#,
+            ))
+            .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        response.data.into_json().unwrap()
+    }
+
     async fn query_claim_balances(
         service: &PoolService,
         token: Option<ApplicationId>,
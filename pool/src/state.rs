@@ -2,10 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use abi::meme_token::MemeToken;
-use abi::swap::pool::Pool;
+use abi::swap::pool::{
+    FailedRequest, FeeCheckpoint, FeeGrowth, LimitOrder, Pool, PoolStats, PriceObservation,
+    SwapSettlement,
+};
 use linera_sdk::{
-    linera_base_types::{Account, Amount, ApplicationId},
-    views::{linera_views, MapView, RegisterView, RootView, ViewStorageContext},
+    linera_base_types::{Account, Amount, ApplicationId, Timestamp},
+    views::{
+        linera_views, CustomMapView, MapView, QueueView, RegisterView, RootView, ViewStorageContext,
+    },
 };
 use std::collections::HashMap;
 
@@ -18,11 +23,36 @@ pub struct PoolState {
 
     pub total_supply: RegisterView<Amount>,
     pub shares: MapView<Account, Amount>,
+    // Liquidity of owner which spender can transfer, checked against shares on transfer
+    pub liquidity_allowances: MapView<Account, HashMap<Account, Amount>>,
+    // LP fee per share grows with swaps, positions are settled against it when shares change
+    pub fee_growth: RegisterView<FeeGrowth>,
+    pub fee_checkpoints: MapView<Account, FeeCheckpoint>,
 
     pub claimable_balances: MapView<MemeToken, HashMap<Account, Amount>>,
     pub claiming_balances: MapView<MemeToken, HashMap<Account, Amount>>,
 
     pub transaction_id: RegisterView<u32>,
+    pub stats: RegisterView<PoolStats>,
+
+    pub observations: QueueView<PriceObservation>,
+
+    pub limit_orders: MapView<u64, LimitOrder>,
+    pub limit_order_id: RegisterView<u64>,
+    // Order ids of each side by limit price key, so fills only read the best orders
+    pub limit_order_book_0: CustomMapView<u128, Vec<u64>>,
+    pub limit_order_book_1: CustomMapView<u128, Vec<u64>>,
+
+    // Launch protection counts from the initialization of the pool
+    pub launched_at: RegisterView<Option<Timestamp>>,
+    // Native side of virtual initial liquidity, counted in reserve 1 but never funded
+    pub virtual_reserve_1: RegisterView<Amount>,
+    pub last_swaps: MapView<Account, Timestamp>,
+
+    // Latest refunded requests of each origin, oldest first
+    pub failed_requests: MapView<Account, Vec<FailedRequest>>,
+    // Settled swaps originated from this chain, oldest first
+    pub swap_settlements: QueueView<SwapSettlement>,
 }
 
 pub mod adapter;
@@ -4,7 +4,10 @@ use super::errors::StateError;
 use crate::{interfaces::state::StateInterface, state::PoolState};
 use abi::meme_token::MemeToken;
 use abi::swap::{
-    pool::{InstantiationArgument, Pool, PoolParameters},
+    pool::{
+        DynamicFee, FailedRequest, InstantiationArgument, LimitOrder, Pool, PoolParameters,
+        PoolStats, PoolStatus, PoolTwap, SwapSettlement,
+    },
     transaction::Transaction,
 };
 use async_trait::async_trait;
@@ -104,6 +107,103 @@ impl StateInterface for StateAdapter {
             .liquid(balance_0, balance_1, block_timestamp)
     }
 
+    fn accrue_swap_fee(&mut self, amount_0_in: Amount, amount_1_in: Amount) -> u16 {
+        self.state
+            .borrow_mut()
+            .accrue_swap_fee(amount_0_in, amount_1_in)
+    }
+
+    fn accrue_launch_fee(&mut self, extra_fee_0: Amount, extra_fee_1: Amount) {
+        self.state
+            .borrow_mut()
+            .accrue_launch_fee(extra_fee_0, extra_fee_1)
+    }
+
+    fn stats(&self) -> PoolStats {
+        self.state.borrow().stats()
+    }
+
+    fn record_swap_stats(
+        &mut self,
+        amount_0_in: Amount,
+        amount_1_in: Amount,
+        amount_0_out: Amount,
+        amount_1_out: Amount,
+        timestamp: Timestamp,
+    ) {
+        self.state.borrow_mut().record_swap_stats(
+            amount_0_in,
+            amount_1_in,
+            amount_0_out,
+            amount_1_out,
+            timestamp,
+        )
+    }
+
+    fn commit_swap(
+        &mut self,
+        amount_0_in: Amount,
+        amount_1_in: Amount,
+        amount_0_out: Amount,
+        amount_1_out: Amount,
+        timestamp: Timestamp,
+    ) -> Result<u16, Self::Error> {
+        self.state.borrow_mut().commit_swap(
+            amount_0_in,
+            amount_1_in,
+            amount_0_out,
+            amount_1_out,
+            timestamp,
+        )
+    }
+
+    async fn earned_fees(&self, owner: Account) -> Result<(Amount, Amount), Self::Error> {
+        self.state.borrow().earned_fees(owner).await
+    }
+
+    fn launched_at(&self) -> Option<Timestamp> {
+        self.state.borrow().launched_at()
+    }
+
+    fn virtual_reserve_1(&self) -> Amount {
+        self.state.borrow().virtual_reserve_1()
+    }
+
+    fn set_virtual_reserve_1(&mut self, amount: Amount) {
+        self.state.borrow_mut().set_virtual_reserve_1(amount)
+    }
+
+    async fn last_swap_at(&self, owner: Account) -> Result<Option<Timestamp>, Self::Error> {
+        self.state.borrow().last_swap_at(owner).await
+    }
+
+    fn record_swap(&mut self, owner: Account, timestamp: Timestamp) -> Result<(), Self::Error> {
+        self.state.borrow_mut().record_swap(owner, timestamp)
+    }
+
+    async fn failed_requests(&self, owner: Account) -> Result<Vec<FailedRequest>, Self::Error> {
+        self.state.borrow().failed_requests(owner).await
+    }
+
+    async fn record_failed_request(
+        &mut self,
+        owner: Account,
+        request: FailedRequest,
+    ) -> Result<(), Self::Error> {
+        self.state
+            .borrow_mut()
+            .record_failed_request(owner, request)
+            .await
+    }
+
+    async fn swap_settlements(&self) -> Result<Vec<SwapSettlement>, Self::Error> {
+        self.state.borrow().swap_settlements().await
+    }
+
+    fn record_swap_settlement(&mut self, settlement: SwapSettlement) {
+        self.state.borrow_mut().record_swap_settlement(settlement)
+    }
+
     async fn add_liquidity(
         &mut self,
         amount_0: Amount,
@@ -164,6 +264,10 @@ impl StateInterface for StateAdapter {
         self.state.borrow().claiming_balance(token, owner).await
     }
 
+    async fn obligations(&self, token: MemeToken) -> Result<Amount, Self::Error> {
+        self.state.borrow().obligations(token).await
+    }
+
     async fn credit(
         &mut self,
         token: MemeToken,
@@ -239,6 +343,54 @@ impl StateInterface for StateAdapter {
         self.state.borrow().calculate_liquidity(amount_0, amount_1)
     }
 
+    async fn transfer_liquidity(
+        &mut self,
+        from: Account,
+        to: Account,
+        liquidity: Amount,
+    ) -> Result<(), Self::Error> {
+        self.state
+            .borrow_mut()
+            .transfer_liquidity(from, to, liquidity)
+            .await
+    }
+
+    async fn approve_liquidity(
+        &mut self,
+        owner: Account,
+        spender: Account,
+        liquidity: Amount,
+    ) -> Result<(), Self::Error> {
+        self.state
+            .borrow_mut()
+            .approve_liquidity(owner, spender, liquidity)
+            .await
+    }
+
+    async fn transfer_liquidity_from(
+        &mut self,
+        spender: Account,
+        from: Account,
+        to: Account,
+        liquidity: Amount,
+    ) -> Result<(), Self::Error> {
+        self.state
+            .borrow_mut()
+            .transfer_liquidity_from(spender, from, to, liquidity)
+            .await
+    }
+
+    async fn liquidity_allowance(
+        &self,
+        owner: Account,
+        spender: Account,
+    ) -> Result<Amount, Self::Error> {
+        self.state
+            .borrow()
+            .liquidity_allowance(owner, spender)
+            .await
+    }
+
     fn set_fee_to(&mut self, operator: Account, account: Account) {
         self.state.borrow_mut().set_fee_to(operator, account)
     }
@@ -247,10 +399,87 @@ impl StateInterface for StateAdapter {
         self.state.borrow_mut().set_fee_to_setter(operator, account)
     }
 
+    async fn set_protocol_fee_share(
+        &mut self,
+        operator: Account,
+        protocol_fee_share_denominator: u16,
+    ) -> Result<(), Self::Error> {
+        self.state
+            .borrow_mut()
+            .set_protocol_fee_share(operator, protocol_fee_share_denominator)
+            .await
+    }
+
+    fn set_pool_status(&mut self, operator: Account, status: PoolStatus) {
+        self.state.borrow_mut().set_pool_status(operator, status)
+    }
+
+    fn set_dynamic_fee(&mut self, operator: Account, dynamic_fee: Option<DynamicFee>) {
+        self.state
+            .borrow_mut()
+            .set_dynamic_fee(operator, dynamic_fee)
+    }
+
     fn calculate_price_pair(&self) -> (Amount, Amount) {
         self.state.borrow().calculate_price_pair()
     }
 
+    async fn place_limit_order(
+        &mut self,
+        owner: Account,
+        amount_0_in: Option<Amount>,
+        amount_1_in: Option<Amount>,
+        amount_out_min: Amount,
+        to: Option<Account>,
+        timestamp: Timestamp,
+    ) -> Result<LimitOrder, Self::Error> {
+        self.state
+            .borrow_mut()
+            .place_limit_order(
+                owner,
+                amount_0_in,
+                amount_1_in,
+                amount_out_min,
+                to,
+                timestamp,
+            )
+            .await
+    }
+
+    async fn cancel_limit_order(
+        &mut self,
+        owner: Account,
+        order_id: u64,
+    ) -> Result<LimitOrder, Self::Error> {
+        self.state
+            .borrow_mut()
+            .cancel_limit_order(owner, order_id)
+            .await
+    }
+
+    async fn remove_limit_order(&mut self, order_id: u64) -> Result<(), Self::Error> {
+        self.state.borrow_mut().remove_limit_order(order_id).await
+    }
+
+    async fn limit_orders(
+        &self,
+        token_0_in: bool,
+        limit: usize,
+    ) -> Result<Vec<LimitOrder>, Self::Error> {
+        self.state.borrow().limit_orders(token_0_in, limit).await
+    }
+
+    async fn calculate_twap(
+        &self,
+        now: Timestamp,
+        window_seconds: u64,
+    ) -> Result<PoolTwap, Self::Error> {
+        self.state
+            .borrow()
+            .calculate_twap(now, window_seconds)
+            .await
+    }
+
     fn build_transaction(
         &mut self,
         owner: Account,
@@ -4,11 +4,17 @@ use crate::{
 };
 use abi::meme_token::MemeToken;
 use abi::swap::{
-    pool::{InstantiationArgument, Pool, PoolParameters},
+    pool::{
+        DynamicFee, FailedRequest, FeeCheckpoint, InstantiationArgument, LimitOrder, Pool,
+        PoolParameters, PoolStats, PoolStatus, PoolTwap, SwapSettlement, MAX_FAILED_REQUESTS,
+        MAX_SWAP_SETTLEMENTS,
+    },
     transaction::{Transaction, TransactionType},
 };
 use async_trait::async_trait;
-use linera_sdk::linera_base_types::{Account, Amount, ApplicationId, Timestamp};
+use linera_sdk::linera_base_types::{Account, AccountOwner, Amount, ApplicationId, Timestamp};
+
+const MAX_PRICE_OBSERVATIONS: usize = 256;
 
 #[async_trait(?Send)]
 impl StateInterface for PoolState {
@@ -102,7 +108,152 @@ impl StateInterface for PoolState {
     fn liquid(&mut self, balance_0: Amount, balance_1: Amount, block_timestamp: Timestamp) {
         let mut pool: Pool = self.pool();
         pool.liquid(balance_0, balance_1, block_timestamp);
-        self.pool.set(Some(pool));
+        self.update_pool(pool);
+    }
+
+    // Only the part of the fee left to LPs after the protocol fee grows fee per share
+    fn accrue_swap_fee(&mut self, amount_0_in: Amount, amount_1_in: Amount) -> u16 {
+        let pool = self.pool();
+        let (lp_fee_0, protocol_fee_0) = pool.swap_fees(amount_0_in);
+        let (lp_fee_1, protocol_fee_1) = pool.swap_fees(amount_1_in);
+
+        let mut fee_growth = self.fee_growth.get().clone();
+        fee_growth.accrue(
+            lp_fee_0.saturating_sub(protocol_fee_0),
+            lp_fee_1.saturating_sub(protocol_fee_1),
+            *self.total_supply.get(),
+        );
+        self.fee_growth.set(fee_growth);
+
+        pool.fee_percent_mul_100()
+    }
+
+    fn accrue_launch_fee(&mut self, extra_fee_0: Amount, extra_fee_1: Amount) {
+        let pool = self.pool();
+        let (base_fee_0, _) = pool.swap_fees(extra_fee_0);
+        let (base_fee_1, _) = pool.swap_fees(extra_fee_1);
+        let launch_fee_0 = extra_fee_0.saturating_sub(base_fee_0);
+        let launch_fee_1 = extra_fee_1.saturating_sub(base_fee_1);
+
+        let mut fee_growth = self.fee_growth.get().clone();
+        fee_growth.accrue(
+            launch_fee_0.saturating_sub(pool.protocol_fee(launch_fee_0)),
+            launch_fee_1.saturating_sub(pool.protocol_fee(launch_fee_1)),
+            *self.total_supply.get(),
+        );
+        self.fee_growth.set(fee_growth);
+    }
+
+    fn stats(&self) -> PoolStats {
+        *self.stats.get()
+    }
+
+    fn record_swap_stats(
+        &mut self,
+        amount_0_in: Amount,
+        amount_1_in: Amount,
+        amount_0_out: Amount,
+        amount_1_out: Amount,
+        timestamp: Timestamp,
+    ) {
+        let pool = self.pool();
+        let (fee_0, _) = pool.swap_fees(amount_0_in);
+        let (fee_1, _) = pool.swap_fees(amount_1_in);
+
+        let mut stats = *self.stats.get();
+        stats.record_swap(
+            amount_0_in,
+            amount_1_in,
+            amount_0_out,
+            amount_1_out,
+            fee_0,
+            fee_1,
+            timestamp,
+        );
+        self.stats.set(stats);
+    }
+
+    fn commit_swap(
+        &mut self,
+        amount_0_in: Amount,
+        amount_1_in: Amount,
+        amount_0_out: Amount,
+        amount_1_out: Amount,
+        timestamp: Timestamp,
+    ) -> Result<u16, Self::Error> {
+        let balance_0 = self
+            .reserve_0()
+            .try_sub(amount_0_out)?
+            .try_add(amount_0_in)?;
+        let balance_1 = self
+            .reserve_1()
+            .try_sub(amount_1_out)?
+            .try_add(amount_1_in)?;
+
+        let fee_percent_mul_100 = self.accrue_swap_fee(amount_0_in, amount_1_in);
+        self.record_swap_stats(
+            amount_0_in,
+            amount_1_in,
+            amount_0_out,
+            amount_1_out,
+            timestamp,
+        );
+        self.liquid(balance_0, balance_1, timestamp);
+
+        Ok(fee_percent_mul_100)
+    }
+
+    async fn earned_fees(&self, owner: Account) -> Result<(Amount, Amount), Self::Error> {
+        let checkpoint = self.settled_fee_checkpoint(owner).await?;
+        Ok((checkpoint.earned_0, checkpoint.earned_1))
+    }
+
+    fn launched_at(&self) -> Option<Timestamp> {
+        *self.launched_at.get()
+    }
+
+    fn virtual_reserve_1(&self) -> Amount {
+        *self.virtual_reserve_1.get()
+    }
+
+    fn set_virtual_reserve_1(&mut self, amount: Amount) {
+        self.virtual_reserve_1.set(amount);
+    }
+
+    async fn last_swap_at(&self, owner: Account) -> Result<Option<Timestamp>, Self::Error> {
+        Ok(self.last_swaps.get(&owner).await?)
+    }
+
+    fn record_swap(&mut self, owner: Account, timestamp: Timestamp) -> Result<(), Self::Error> {
+        Ok(self.last_swaps.insert(&owner, timestamp)?)
+    }
+
+    async fn failed_requests(&self, owner: Account) -> Result<Vec<FailedRequest>, Self::Error> {
+        Ok(self.failed_requests.get(&owner).await?.unwrap_or_default())
+    }
+
+    async fn record_failed_request(
+        &mut self,
+        owner: Account,
+        request: FailedRequest,
+    ) -> Result<(), Self::Error> {
+        let mut requests = self.failed_requests.get(&owner).await?.unwrap_or_default();
+        requests.push(request);
+        if requests.len() > MAX_FAILED_REQUESTS {
+            requests.drain(..requests.len() - MAX_FAILED_REQUESTS);
+        }
+        Ok(self.failed_requests.insert(&owner, requests)?)
+    }
+
+    async fn swap_settlements(&self) -> Result<Vec<SwapSettlement>, Self::Error> {
+        Ok(self.swap_settlements.elements().await?)
+    }
+
+    fn record_swap_settlement(&mut self, settlement: SwapSettlement) {
+        self.swap_settlements.push_back(settlement);
+        if self.swap_settlements.count() > MAX_SWAP_SETTLEMENTS {
+            self.swap_settlements.delete_front();
+        }
     }
 
     async fn add_liquidity(
@@ -121,7 +272,7 @@ impl StateInterface for PoolState {
         let mut pool: Pool = self.pool();
         pool.liquid(reserve_0, reserve_1, block_timestamp);
         pool.update_k_last();
-        self.pool.set(Some(pool));
+        self.update_pool(pool);
         Ok(liquidity)
     }
 
@@ -144,7 +295,8 @@ impl StateInterface for PoolState {
         let mut pool = self.pool();
         pool.liquid(amount_0, amount_1, block_timestamp);
         pool.update_k_last();
-        self.pool.set(Some(pool));
+        self.update_pool(pool);
+        self.launched_at.set(Some(block_timestamp));
 
         Ok(liquidity)
     }
@@ -173,7 +325,7 @@ impl StateInterface for PoolState {
             block_timestamp,
         );
         pool.update_k_last();
-        self.pool.set(Some(pool));
+        self.update_pool(pool);
 
         Ok((amount_0, amount_1))
     }
@@ -208,6 +360,41 @@ impl StateInterface for PoolState {
             .unwrap_or(Amount::ZERO))
     }
 
+    async fn obligations(&self, token: MemeToken) -> Result<Amount, Self::Error> {
+        let mut amount = Amount::ZERO;
+        for balances in [
+            self.claimable_balances.get(&token).await?,
+            self.claiming_balances.get(&token).await?,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            for balance in balances.values() {
+                amount.try_add_assign(*balance)?;
+            }
+        }
+
+        let pool = self.pool();
+        let (token_0, token_1) = (MemeToken::from(pool.token_0), MemeToken::from(pool.token_1));
+        let mut escrowed = Amount::ZERO;
+        self.limit_orders
+            .for_each_index_value(|_, order| {
+                let order_token = if order.amount_0_in.is_some() {
+                    token_0
+                } else {
+                    token_1
+                };
+                if order_token == token {
+                    escrowed = escrowed.saturating_add(order.amount_in());
+                }
+                Ok(())
+            })
+            .await?;
+        amount.try_add_assign(escrowed)?;
+
+        Ok(amount)
+    }
+
     async fn credit(
         &mut self,
         token: MemeToken,
@@ -305,6 +492,7 @@ impl StateInterface for PoolState {
     }
 
     async fn mint(&mut self, to: Account, amount: Amount) -> Result<(), Self::Error> {
+        self.settle_fees(to).await?;
         self.total_supply
             .set(self.total_supply.get().try_add(amount).unwrap());
 
@@ -313,6 +501,7 @@ impl StateInterface for PoolState {
     }
 
     async fn burn(&mut self, from: Account, liquidity: Amount) -> Result<(), Self::Error> {
+        self.settle_fees(from).await?;
         self.total_supply
             .set(self.total_supply.get().try_sub(liquidity)?);
 
@@ -348,6 +537,95 @@ impl StateInterface for PoolState {
             .calculate_liquidity(total_supply, amount_0, amount_1)
     }
 
+    async fn transfer_liquidity(
+        &mut self,
+        from: Account,
+        to: Account,
+        liquidity: Amount,
+    ) -> Result<(), Self::Error> {
+        assert!(liquidity > Amount::ZERO, "Invalid liquidity");
+        assert!(from != to, "Invalid account");
+
+        self.settle_fees(from).await?;
+        self.settle_fees(to).await?;
+
+        let share = self.liquidity(from).await?;
+        assert!(liquidity <= share, "Invalid liquidity");
+        self.shares.insert(&from, share.try_sub(liquidity)?)?;
+
+        let share = self.liquidity(to).await?;
+        Ok(self.shares.insert(&to, share.try_add(liquidity)?)?)
+    }
+
+    async fn approve_liquidity(
+        &mut self,
+        owner: Account,
+        spender: Account,
+        liquidity: Amount,
+    ) -> Result<(), Self::Error> {
+        assert!(owner != spender, "Invalid spender");
+
+        let mut allowances = self
+            .liquidity_allowances
+            .get(&owner)
+            .await?
+            .unwrap_or_default();
+        if liquidity == Amount::ZERO {
+            allowances.remove(&spender);
+        } else {
+            allowances.insert(spender, liquidity);
+        }
+
+        if allowances.is_empty() {
+            return Ok(self.liquidity_allowances.remove(&owner)?);
+        }
+        Ok(self.liquidity_allowances.insert(&owner, allowances)?)
+    }
+
+    async fn transfer_liquidity_from(
+        &mut self,
+        spender: Account,
+        from: Account,
+        to: Account,
+        liquidity: Amount,
+    ) -> Result<(), Self::Error> {
+        let current = self.liquidity_allowance(from, spender).await?;
+        assert!(current >= liquidity, "Insufficient allowance");
+
+        // Allowance is not escrowed, the owner shares are checked by the transfer itself
+        self.transfer_liquidity(from, to, liquidity).await?;
+
+        let mut allowances = self
+            .liquidity_allowances
+            .get(&from)
+            .await?
+            .unwrap_or_default();
+        let next = current.try_sub(liquidity)?;
+        if next == Amount::ZERO {
+            allowances.remove(&spender);
+        } else {
+            allowances.insert(spender, next);
+        }
+
+        if allowances.is_empty() {
+            return Ok(self.liquidity_allowances.remove(&from)?);
+        }
+        Ok(self.liquidity_allowances.insert(&from, allowances)?)
+    }
+
+    async fn liquidity_allowance(
+        &self,
+        owner: Account,
+        spender: Account,
+    ) -> Result<Amount, Self::Error> {
+        Ok(self
+            .liquidity_allowances
+            .get(&owner)
+            .await?
+            .and_then(|allowances| allowances.get(&spender).copied())
+            .unwrap_or(Amount::ZERO))
+    }
+
     fn set_fee_to(&mut self, operator: Account, account: Account) {
         let mut pool = self.pool();
 
@@ -366,10 +644,157 @@ impl StateInterface for PoolState {
         self.pool.set(Some(pool));
     }
 
+    async fn set_protocol_fee_share(
+        &mut self,
+        operator: Account,
+        protocol_fee_share_denominator: u16,
+    ) -> Result<(), Self::Error> {
+        let pool = self.pool();
+        assert!(pool.fee_to_setter == operator, "Invalid operator");
+
+        // Settle fee growth at the current share before switching
+        let fee_share = pool.mint_fee(*self.total_supply.get());
+        self.mint(pool.fee_to, fee_share).await?;
+
+        let mut pool = self.pool();
+        pool.set_protocol_fee_share_denominator(protocol_fee_share_denominator);
+        self.pool.set(Some(pool));
+
+        Ok(())
+    }
+
+    fn set_pool_status(&mut self, operator: Account, status: PoolStatus) {
+        let mut pool = self.pool();
+        let router = AccountOwner::from(self.router_application_id());
+
+        assert!(
+            pool.fee_to_setter == operator || operator.owner == router,
+            "Invalid operator"
+        );
+        pool.status = status;
+
+        self.pool.set(Some(pool));
+    }
+
+    fn set_dynamic_fee(&mut self, operator: Account, dynamic_fee: Option<DynamicFee>) {
+        let mut pool = self.pool();
+        assert!(pool.fee_to_setter == operator, "Invalid operator");
+
+        pool.set_dynamic_fee(dynamic_fee);
+        self.pool.set(Some(pool));
+    }
+
     fn calculate_price_pair(&self) -> (Amount, Amount) {
         self.pool().calculate_price_pair()
     }
 
+    async fn place_limit_order(
+        &mut self,
+        owner: Account,
+        amount_0_in: Option<Amount>,
+        amount_1_in: Option<Amount>,
+        amount_out_min: Amount,
+        to: Option<Account>,
+        timestamp: Timestamp,
+    ) -> Result<LimitOrder, Self::Error> {
+        assert!(
+            amount_0_in.is_some() != amount_1_in.is_some(),
+            "Invalid amount"
+        );
+        assert!(
+            amount_0_in.or(amount_1_in).unwrap() > Amount::ZERO,
+            "Invalid amount"
+        );
+        assert!(amount_out_min > Amount::ZERO, "Invalid amount");
+
+        let order_id = *self.limit_order_id.get();
+        self.limit_order_id.set(order_id + 1);
+
+        let order = LimitOrder {
+            order_id,
+            owner,
+            amount_0_in,
+            amount_1_in,
+            amount_out_min,
+            to,
+            created_at: timestamp,
+        };
+        self.limit_orders.insert(&order_id, order.clone())?;
+        self.index_limit_order(&order).await?;
+
+        Ok(order)
+    }
+
+    async fn cancel_limit_order(
+        &mut self,
+        owner: Account,
+        order_id: u64,
+    ) -> Result<LimitOrder, Self::Error> {
+        let order = self
+            .limit_orders
+            .get(&order_id)
+            .await?
+            .expect("Invalid order");
+        assert!(order.owner == owner, "Invalid owner");
+
+        self.limit_orders.remove(&order_id)?;
+        self.unindex_limit_order(&order).await?;
+        Ok(order)
+    }
+
+    async fn remove_limit_order(&mut self, order_id: u64) -> Result<(), Self::Error> {
+        let Some(order) = self.limit_orders.get(&order_id).await? else {
+            return Ok(());
+        };
+        self.limit_orders.remove(&order_id)?;
+        self.unindex_limit_order(&order).await
+    }
+
+    // Best price buckets first, orders of the same bucket first in first out
+    async fn limit_orders(
+        &self,
+        token_0_in: bool,
+        limit: usize,
+    ) -> Result<Vec<LimitOrder>, Self::Error> {
+        let book = if token_0_in {
+            &self.limit_order_book_0
+        } else {
+            &self.limit_order_book_1
+        };
+
+        // Each bucket holds at least one order
+        let mut keys = Vec::new();
+        book.for_each_index_while(|key| {
+            keys.push(key);
+            Ok(keys.len() < limit)
+        })
+        .await?;
+
+        let mut orders = Vec::new();
+        for key in keys {
+            for order_id in book.get(&key).await?.unwrap_or_default() {
+                if orders.len() >= limit {
+                    return Ok(orders);
+                }
+                if let Some(order) = self.limit_orders.get(&order_id).await? {
+                    orders.push(order);
+                }
+            }
+        }
+        Ok(orders)
+    }
+
+    async fn calculate_twap(
+        &self,
+        now: Timestamp,
+        window_seconds: u64,
+    ) -> Result<PoolTwap, Self::Error> {
+        let observations = self.observations.elements().await?;
+        Ok(self
+            .pool()
+            .calculate_twap(&observations, now, window_seconds)?)
+    }
+
     fn build_transaction(
         &mut self,
         owner: Account,
@@ -405,7 +830,74 @@ impl StateInterface for PoolState {
             amount_0_out,
             amount_1_out,
             liquidity,
+            fee_percent_mul_100: None,
             created_at: timestamp,
         }
     }
 }
+
+impl PoolState {
+    async fn index_limit_order(&mut self, order: &LimitOrder) -> Result<(), StateError> {
+        let book = if order.amount_0_in.is_some() {
+            &mut self.limit_order_book_0
+        } else {
+            &mut self.limit_order_book_1
+        };
+        let key = order.limit_price_key();
+        let mut order_ids = book.get(&key).await?.unwrap_or_default();
+        order_ids.push(order.order_id);
+        Ok(book.insert(&key, order_ids)?)
+    }
+
+    async fn unindex_limit_order(&mut self, order: &LimitOrder) -> Result<(), StateError> {
+        let book = if order.amount_0_in.is_some() {
+            &mut self.limit_order_book_0
+        } else {
+            &mut self.limit_order_book_1
+        };
+        let key = order.limit_price_key();
+        let mut order_ids = book.get(&key).await?.unwrap_or_default();
+        order_ids.retain(|order_id| *order_id != order.order_id);
+        if order_ids.is_empty() {
+            return Ok(book.remove(&key)?);
+        }
+        Ok(book.insert(&key, order_ids)?)
+    }
+
+    pub async fn settled_fee_checkpoint(
+        &self,
+        owner: Account,
+    ) -> Result<FeeCheckpoint, StateError> {
+        let shares = self.shares.get(&owner).await?.unwrap_or(Amount::ZERO);
+        Ok(self
+            .fee_checkpoints
+            .get(&owner)
+            .await?
+            .unwrap_or_default()
+            .settle(shares, self.fee_growth.get()))
+    }
+
+    // Must run before the fee earning shares of owner change
+    async fn settle_fees(&mut self, owner: Account) -> Result<(), StateError> {
+        let checkpoint = self.settled_fee_checkpoint(owner).await?;
+        Ok(self.fee_checkpoints.insert(&owner, checkpoint)?)
+    }
+
+    // Cumulative prices only move when reserves change after some time elapsed, so
+    // one observation is recorded per such change and the oldest one is dropped.
+    fn update_pool(&mut self, pool: Pool) {
+        let previous = self.pool();
+        if self.observations.count() == 0
+            || pool.price_0_cumulative != previous.price_0_cumulative
+            || pool.price_1_cumulative != previous.price_1_cumulative
+        {
+            self.observations
+                .push_back(pool.observe(pool.block_timestamp));
+            if self.observations.count() > MAX_PRICE_OBSERVATIONS {
+                self.observations.delete_front();
+            }
+        }
+
+        self.pool.set(Some(pool));
+    }
+}
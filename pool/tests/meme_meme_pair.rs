@@ -196,6 +196,7 @@ impl TestSuite {
             swap_creator_chain_id: self.swap_chain.id(),
 
             enable_mining: false,
+            launch_protection: None,
             mining_supply: None,
         };
 
@@ -221,6 +222,7 @@ impl TestSuite {
             swap_creator_chain_id: self.swap_chain.id(),
 
             enable_mining: false,
+            launch_protection: None,
             mining_supply: None,
         };
         self.meme_application_id_1 = Some(
@@ -254,6 +256,9 @@ impl TestSuite {
                         amount_1_out_min: None,
                         to: None,
                         block_timestamp: None,
+                        route: None,
+                        deadline: None,
+                        auto_claim: false,
                     },
                 );
             })
@@ -313,6 +318,7 @@ impl TestSuite {
                         amount_1_out_min: None,
                         to: None,
                         block_timestamp: None,
+                        deadline: None,
                     },
                 );
             })
@@ -363,6 +369,7 @@ impl TestSuite {
                         amount_1_out_min: None,
                         to: None,
                         block_timestamp: None,
+                        deadline: None,
                     },
                 );
             })
@@ -390,6 +397,7 @@ impl TestSuite {
                         amount_0,
                         amount_1,
                         to: None,
+                        pool_fee_percent_mul_100: None,
                     },
                 );
             })
@@ -179,6 +179,7 @@ impl TestSuite {
             swap_creator_chain_id: self.swap_chain.id(),
 
             enable_mining: false,
+            launch_protection: None,
             mining_supply: None,
         };
 
@@ -207,6 +208,9 @@ impl TestSuite {
                         amount_1_out_min: None,
                         to: None,
                         block_timestamp: None,
+                        route: None,
+                        deadline: None,
+                        auto_claim: false,
                     },
                 );
             })
@@ -244,6 +248,7 @@ impl TestSuite {
                         amount_0,
                         amount_1,
                         to: None,
+                        pool_fee_percent_mul_100: None,
                     },
                 );
             })
@@ -183,6 +183,7 @@ impl TestSuite {
             swap_creator_chain_id: self.swap_chain.id(),
 
             enable_mining: false,
+            launch_protection: None,
             mining_supply: None,
         };
 
@@ -211,6 +212,9 @@ impl TestSuite {
                         amount_1_out_min: None,
                         to: None,
                         block_timestamp: None,
+                        route: None,
+                        deadline: None,
+                        auto_claim: false,
                     },
                 );
             })
@@ -270,6 +274,7 @@ impl TestSuite {
                         amount_1_out_min: None,
                         to: None,
                         block_timestamp: None,
+                        deadline: None,
                     },
                 );
             })
@@ -306,6 +311,7 @@ impl TestSuite {
                         amount_1_out_min: None,
                         to: None,
                         block_timestamp: None,
+                        deadline: None,
                     },
                 );
             })
@@ -169,6 +169,7 @@ impl TestSuite {
             swap_creator_chain_id: self.swap_chain.id(),
 
             enable_mining: false,
+            launch_protection: None,
             mining_supply: None,
         };
 
@@ -197,6 +198,9 @@ impl TestSuite {
                         amount_1_out_min: None,
                         to: None,
                         block_timestamp: None,
+                        route: None,
+                        deadline: None,
+                        auto_claim: false,
                     },
                 );
             })
@@ -696,6 +696,7 @@ fn test_meme_parameters() -> MemeParameters {
         )
         .unwrap(),
         enable_mining: false,
+        launch_protection: None,
         mining_supply: None,
     }
 }
@@ -175,6 +175,7 @@ async fn bootstrap_multi_owner_single_leader_apps_process_frontend_protocol_oper
                 virtual_initial_liquidity: true,
                 swap_creator_chain_id: swap_chain.id(),
                 enable_mining: false,
+                launch_protection: Some(abi::swap::pool::LaunchProtection::default()),
                 mining_supply: None,
             },
             abi::meme::InstantiationArgument {
@@ -227,6 +228,7 @@ async fn bootstrap_multi_owner_single_leader_apps_process_frontend_protocol_oper
                     amount_0: Amount::ONE,
                     amount_1: Amount::ONE,
                     to: None,
+                    pool_fee_percent_mul_100: None,
                 },
             );
         })
@@ -365,6 +365,7 @@ async fn proxy_create_meme_real_logo_blob_same_input_in_memory_test() {
                         virtual_initial_liquidity: true,
                         swap_creator_chain_id: suite.swap_chain.id(),
                         enable_mining: false,
+                        launch_protection: None,
                         mining_supply: Some(Amount::from_tokens(10_499_100)),
                     },
                 },
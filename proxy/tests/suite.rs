@@ -318,6 +318,7 @@ impl TestSuite {
                             virtual_initial_liquidity,
                             swap_creator_chain_id: self.swap_chain.id(),
                             enable_mining,
+                            launch_protection: None,
                             mining_supply,
                         },
                     },
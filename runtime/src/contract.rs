@@ -11,7 +11,7 @@ use linera_sdk::{
     linera_base_types::{
         Account, AccountOwner, Amount, ApplicationId, ApplicationPermissions, BlockHeight, ChainId,
         ChainOwnership, ChangeApplicationPermissionsError, ChangeOwnershipError, ModuleId,
-        Timestamp,
+        StreamName, Timestamp,
     },
     Contract, ContractRuntime,
 };
@@ -90,6 +90,7 @@ impl<T: Contract<Message = M>, M: Serialize> ContractRuntimeContext
 {
     type Error = RuntimeError;
     type Message = M;
+    type EventValue = T::EventValue;
 
     fn authenticated_account(&mut self) -> Account {
         let chain_id = self.runtime.borrow_mut().chain_id();
@@ -152,6 +153,10 @@ impl<T: Contract<Message = M>, M: Serialize> ContractRuntimeContext
         self.runtime.borrow_mut().message_is_bouncing()
     }
 
+    fn emit(&mut self, stream_name: StreamName, value: &T::EventValue) -> u32 {
+        self.runtime.borrow_mut().emit(stream_name, value)
+    }
+
     fn message_origin_chain_id(&mut self) -> Option<ChainId> {
         self.runtime.borrow_mut().message_origin_chain_id()
     }
@@ -4,6 +4,7 @@ use linera_sdk::{
     linera_base_types::{
         Account, AccountOwner, Amount, ApplicationId, ApplicationPermissions, ChainId,
         ChainOwnership, ChangeApplicationPermissionsError, ChangeOwnershipError, ModuleId,
+        StreamName,
     },
 };
 use serde::Serialize;
@@ -11,6 +12,7 @@ use serde::Serialize;
 pub trait ContractRuntimeContext: BaseRuntimeContext {
     type Error: std::fmt::Debug + std::error::Error + 'static;
     type Message;
+    type EventValue;
 
     fn authenticated_account(&mut self) -> Account;
     fn authenticated_signer(&mut self) -> Option<AccountOwner>;
@@ -22,6 +24,8 @@ pub trait ContractRuntimeContext: BaseRuntimeContext {
     fn send_message(&mut self, destination: ChainId, message: Self::Message, tracking: bool);
     fn message_is_bouncing(&mut self) -> Option<bool>;
 
+    fn emit(&mut self, stream_name: StreamName, value: &Self::EventValue) -> u32;
+
     fn message_origin_chain_id(&mut self) -> Option<ChainId>;
     fn require_message_origin_chain_id(&mut self) -> Result<ChainId, Self::Error>;
     fn message_signer_account(&mut self) -> Account;
@@ -7,11 +7,12 @@ use abi::meme::{
 use abi::proxy::{ProxyMessage, ProxyOperation};
 use abi::swap::pool::{
     AddLiquidityTransferReceipt, AddLiquidityTransferReceiptPayload, BootstrapPolicy,
-    ClaimTransferReceipt, FundRequest, PoolMessage, PoolOperation,
+    ClaimTransferReceipt, ClaimTransferReceiptPayload, DynamicFee, FailedRequest, FundRequest,
+    LaunchProtection, PoolEvent, PoolMessage, PoolOperation, PoolStats,
 };
-use abi::swap::router::{SwapMessage, SwapOperation};
+use abi::swap::router::{SwapMessage, SwapOperation, SwapRoute};
 use abi::swap::transaction::{Transaction, TransactionType};
-use linera_sdk::linera_base_types::{Account, AccountOwner, Amount, Timestamp};
+use linera_sdk::linera_base_types::{Account, AccountOwner, Amount, ApplicationId, Timestamp};
 use serde::Deserialize;
 use serde_json::{json, Value};
 use std::io::{self, Read};
@@ -73,9 +74,7 @@ fn decode_single(request: &DecodeRequest) -> Value {
     let result = match (request.app_type.as_str(), request.payload_kind.as_str()) {
         ("pool", "operation") => decode_pool_operation(&request.application_id, &raw_bytes),
         ("pool", "message") => decode_pool_message(&request.application_id, &raw_bytes),
-        ("pool", "event") => Err(anyhow::anyhow!(
-            "pool:event canonical decoding is not implemented"
-        )),
+        ("pool", "event") => decode_pool_event(&request.application_id, &raw_bytes),
         ("swap", "operation") => decode_swap_operation(&request.application_id, &raw_bytes),
         ("swap", "message") => decode_swap_message(&request.application_id, &raw_bytes),
         ("meme", "operation") => decode_meme_operation(&request.application_id, &raw_bytes),
@@ -152,6 +151,7 @@ fn decode_pool_operation(application_id: &str, raw_bytes: &[u8]) -> anyhow::Resu
             amount_1_out_min,
             to,
             block_timestamp,
+            deadline,
         } => (
             "add_liquidity",
             json!({
@@ -163,6 +163,7 @@ fn decode_pool_operation(application_id: &str, raw_bytes: &[u8]) -> anyhow::Resu
                 "amount_1_out_min": encode_option_amount(amount_1_out_min),
                 "to": encode_option_account(to),
                 "block_timestamp_micros": encode_option_timestamp(block_timestamp),
+                "deadline_micros": encode_option_timestamp(deadline),
             }),
         ),
         PoolOperation::RemoveLiquidity {
@@ -171,6 +172,7 @@ fn decode_pool_operation(application_id: &str, raw_bytes: &[u8]) -> anyhow::Resu
             amount_1_out_min,
             to,
             block_timestamp,
+            deadline,
         } => (
             "remove_liquidity",
             json!({
@@ -181,6 +183,7 @@ fn decode_pool_operation(application_id: &str, raw_bytes: &[u8]) -> anyhow::Resu
                 "amount_1_out_min": encode_option_amount(amount_1_out_min),
                 "to": encode_option_account(to),
                 "block_timestamp_micros": encode_option_timestamp(block_timestamp),
+                "deadline_micros": encode_option_timestamp(deadline),
             }),
         ),
         PoolOperation::Swap {
@@ -190,6 +193,9 @@ fn decode_pool_operation(application_id: &str, raw_bytes: &[u8]) -> anyhow::Resu
             amount_1_out_min,
             to,
             block_timestamp,
+            route,
+            deadline,
+            auto_claim,
         } => (
             "swap",
             json!({
@@ -201,6 +207,9 @@ fn decode_pool_operation(application_id: &str, raw_bytes: &[u8]) -> anyhow::Resu
                 "amount_1_out_min": encode_option_amount(amount_1_out_min),
                 "to": encode_option_account(to),
                 "block_timestamp_micros": encode_option_timestamp(block_timestamp),
+                "route": route.map(encode_swap_route),
+                "deadline_micros": encode_option_timestamp(deadline),
+                "auto_claim": auto_claim,
             }),
         ),
         PoolOperation::Claim { token, amount } => (
@@ -236,6 +245,197 @@ fn decode_pool_operation(application_id: &str, raw_bytes: &[u8]) -> anyhow::Resu
                 "receipt": encode_swap_transfer_receipt(receipt),
             }),
         ),
+        PoolOperation::Twap { window_seconds } => (
+            "twap",
+            json!({
+                "operation_type": "twap",
+                "application_id": application_id,
+                "window_seconds": window_seconds,
+            }),
+        ),
+        PoolOperation::SwapExactOut {
+            amount_0_out,
+            amount_1_out,
+            amount_in_max,
+            to,
+            block_timestamp,
+            deadline,
+            auto_claim,
+        } => (
+            "swap_exact_out",
+            json!({
+                "operation_type": "swap_exact_out",
+                "application_id": application_id,
+                "amount_0_out": encode_option_amount(amount_0_out),
+                "amount_1_out": encode_option_amount(amount_1_out),
+                "amount_in_max": encode_amount(amount_in_max),
+                "to": encode_option_account(to),
+                "block_timestamp_micros": encode_option_timestamp(block_timestamp),
+                "deadline_micros": encode_option_timestamp(deadline),
+                "auto_claim": auto_claim,
+            }),
+        ),
+        PoolOperation::SetProtocolFeeShare {
+            protocol_fee_share_denominator,
+        } => (
+            "set_protocol_fee_share",
+            json!({
+                "operation_type": "set_protocol_fee_share",
+                "application_id": application_id,
+                "protocol_fee_share_denominator": protocol_fee_share_denominator,
+            }),
+        ),
+        PoolOperation::TransferLiquidity { to, liquidity } => (
+            "transfer_liquidity",
+            json!({
+                "operation_type": "transfer_liquidity",
+                "application_id": application_id,
+                "to": encode_account(to),
+                "liquidity": encode_amount(liquidity),
+            }),
+        ),
+        PoolOperation::ApproveLiquidity { spender, liquidity } => (
+            "approve_liquidity",
+            json!({
+                "operation_type": "approve_liquidity",
+                "application_id": application_id,
+                "spender": encode_account(spender),
+                "liquidity": encode_amount(liquidity),
+            }),
+        ),
+        PoolOperation::TransferLiquidityFrom {
+            from,
+            to,
+            liquidity,
+        } => (
+            "transfer_liquidity_from",
+            json!({
+                "operation_type": "transfer_liquidity_from",
+                "application_id": application_id,
+                "from": encode_account(from),
+                "to": encode_account(to),
+                "liquidity": encode_amount(liquidity),
+            }),
+        ),
+        PoolOperation::PlaceLimitOrder {
+            amount_0_in,
+            amount_1_in,
+            amount_out_min,
+            to,
+        } => (
+            "place_limit_order",
+            json!({
+                "operation_type": "place_limit_order",
+                "application_id": application_id,
+                "amount_0_in": encode_option_amount(amount_0_in),
+                "amount_1_in": encode_option_amount(amount_1_in),
+                "amount_out_min": encode_amount(amount_out_min),
+                "to": encode_option_account(to),
+            }),
+        ),
+        PoolOperation::CancelLimitOrder { order_id } => (
+            "cancel_limit_order",
+            json!({
+                "operation_type": "cancel_limit_order",
+                "application_id": application_id,
+                "order_id": order_id,
+            }),
+        ),
+        PoolOperation::SetPoolStatus { status } => (
+            "set_pool_status",
+            json!({
+                "operation_type": "set_pool_status",
+                "application_id": application_id,
+                "status": format!("{:?}", status),
+            }),
+        ),
+        PoolOperation::ClaimAll { tokens } => (
+            "claim_all",
+            json!({
+                "operation_type": "claim_all",
+                "application_id": application_id,
+                "tokens": tokens.map(encode_swap_path),
+            }),
+        ),
+        PoolOperation::ZapIn {
+            token,
+            amount_in,
+            min_liquidity,
+            to,
+            block_timestamp,
+            deadline,
+        } => (
+            "zap_in",
+            json!({
+                "operation_type": "zap_in",
+                "application_id": application_id,
+                "token": token.map(|value| value.to_string()),
+                "amount_in": encode_amount(amount_in),
+                "min_liquidity": encode_option_amount(min_liquidity),
+                "to": encode_option_account(to),
+                "block_timestamp_micros": encode_option_timestamp(block_timestamp),
+                "deadline_micros": encode_option_timestamp(deadline),
+            }),
+        ),
+        PoolOperation::ZapOut {
+            liquidity,
+            token,
+            amount_out_min,
+            to,
+            block_timestamp,
+            deadline,
+        } => (
+            "zap_out",
+            json!({
+                "operation_type": "zap_out",
+                "application_id": application_id,
+                "liquidity": encode_amount(liquidity),
+                "token": token.map(|value| value.to_string()),
+                "amount_out_min": encode_option_amount(amount_out_min),
+                "to": encode_option_account(to),
+                "block_timestamp_micros": encode_option_timestamp(block_timestamp),
+                "deadline_micros": encode_option_timestamp(deadline),
+            }),
+        ),
+        PoolOperation::Sync => (
+            "sync",
+            json!({
+                "operation_type": "sync",
+                "application_id": application_id,
+            }),
+        ),
+        PoolOperation::Skim { to } => (
+            "skim",
+            json!({
+                "operation_type": "skim",
+                "application_id": application_id,
+                "to": encode_account(to),
+            }),
+        ),
+        PoolOperation::SetDynamicFee { dynamic_fee } => (
+            "set_dynamic_fee",
+            json!({
+                "operation_type": "set_dynamic_fee",
+                "application_id": application_id,
+                "dynamic_fee": encode_option_dynamic_fee(dynamic_fee.as_ref()),
+            }),
+        ),
+        PoolOperation::MigrateLiquidity {
+            liquidity,
+            amount_0_out_min,
+            amount_1_out_min,
+            deadline,
+        } => (
+            "migrate_liquidity",
+            json!({
+                "operation_type": "migrate_liquidity",
+                "application_id": application_id,
+                "liquidity": encode_amount(liquidity),
+                "amount_0_out_min": encode_option_amount(amount_0_out_min),
+                "amount_1_out_min": encode_option_amount(amount_1_out_min),
+                "deadline_micros": encode_option_timestamp(deadline),
+            }),
+        ),
     };
     Ok(json!({
         "payload_type": payload_type,
@@ -309,6 +509,11 @@ fn decode_pool_message(application_id: &str, raw_bytes: &[u8]) -> anyhow::Result
             amount_1_out_min,
             to,
             block_timestamp,
+            route,
+            amount_0_out,
+            amount_1_out,
+            deadline,
+            auto_claim,
         } => (
             "swap",
             json!({
@@ -321,6 +526,11 @@ fn decode_pool_message(application_id: &str, raw_bytes: &[u8]) -> anyhow::Result
                 "amount_1_out_min": encode_option_amount(amount_1_out_min),
                 "to": encode_option_account(to),
                 "block_timestamp_micros": encode_option_timestamp(block_timestamp),
+                "route": route.map(encode_swap_route),
+                "amount_0_out": encode_option_amount(amount_0_out),
+                "amount_1_out": encode_option_amount(amount_1_out),
+                "deadline_micros": encode_option_timestamp(deadline),
+                "auto_claim": auto_claim,
             }),
         ),
         PoolMessage::AddLiquidity {
@@ -331,6 +541,7 @@ fn decode_pool_message(application_id: &str, raw_bytes: &[u8]) -> anyhow::Result
             amount_1_out_min,
             to,
             block_timestamp,
+            deadline,
         } => (
             "add_liquidity",
             json!({
@@ -343,6 +554,7 @@ fn decode_pool_message(application_id: &str, raw_bytes: &[u8]) -> anyhow::Result
                 "amount_1_out_min": encode_option_amount(amount_1_out_min),
                 "to": encode_option_account(to),
                 "block_timestamp_micros": encode_option_timestamp(block_timestamp),
+                "deadline_micros": encode_option_timestamp(deadline),
             }),
         ),
         PoolMessage::InitializeLiquidity {
@@ -370,6 +582,7 @@ fn decode_pool_message(application_id: &str, raw_bytes: &[u8]) -> anyhow::Result
             amount_1_out_min,
             to,
             block_timestamp,
+            deadline,
         } => (
             "remove_liquidity",
             json!({
@@ -381,6 +594,7 @@ fn decode_pool_message(application_id: &str, raw_bytes: &[u8]) -> anyhow::Result
                 "amount_1_out_min": encode_option_amount(amount_1_out_min),
                 "to": encode_option_account(to),
                 "block_timestamp_micros": encode_option_timestamp(block_timestamp),
+                "deadline_micros": encode_option_timestamp(deadline),
             }),
         ),
         PoolMessage::SetFeeTo { operator, account } => (
@@ -423,6 +637,219 @@ fn decode_pool_message(application_id: &str, raw_bytes: &[u8]) -> anyhow::Result
                 "amount": encode_amount(amount),
             }),
         ),
+        PoolMessage::SetProtocolFeeShare {
+            operator,
+            protocol_fee_share_denominator,
+        } => (
+            "set_protocol_fee_share",
+            json!({
+                "message_type": "set_protocol_fee_share",
+                "application_id": application_id,
+                "operator": encode_account(operator),
+                "protocol_fee_share_denominator": protocol_fee_share_denominator,
+            }),
+        ),
+        PoolMessage::TransferLiquidity {
+            origin,
+            to,
+            liquidity,
+        } => (
+            "transfer_liquidity",
+            json!({
+                "message_type": "transfer_liquidity",
+                "application_id": application_id,
+                "origin": encode_account(origin),
+                "to": encode_account(to),
+                "liquidity": encode_amount(liquidity),
+            }),
+        ),
+        PoolMessage::ApproveLiquidity {
+            origin,
+            spender,
+            liquidity,
+        } => (
+            "approve_liquidity",
+            json!({
+                "message_type": "approve_liquidity",
+                "application_id": application_id,
+                "origin": encode_account(origin),
+                "spender": encode_account(spender),
+                "liquidity": encode_amount(liquidity),
+            }),
+        ),
+        PoolMessage::TransferLiquidityFrom {
+            origin,
+            from,
+            to,
+            liquidity,
+        } => (
+            "transfer_liquidity_from",
+            json!({
+                "message_type": "transfer_liquidity_from",
+                "application_id": application_id,
+                "origin": encode_account(origin),
+                "from": encode_account(from),
+                "to": encode_account(to),
+                "liquidity": encode_amount(liquidity),
+            }),
+        ),
+        PoolMessage::PlaceLimitOrder {
+            origin,
+            amount_0_in,
+            amount_1_in,
+            amount_out_min,
+            to,
+        } => (
+            "place_limit_order",
+            json!({
+                "message_type": "place_limit_order",
+                "application_id": application_id,
+                "origin": encode_account(origin),
+                "amount_0_in": encode_option_amount(amount_0_in),
+                "amount_1_in": encode_option_amount(amount_1_in),
+                "amount_out_min": encode_amount(amount_out_min),
+                "to": encode_option_account(to),
+            }),
+        ),
+        PoolMessage::CancelLimitOrder { origin, order_id } => (
+            "cancel_limit_order",
+            json!({
+                "message_type": "cancel_limit_order",
+                "application_id": application_id,
+                "origin": encode_account(origin),
+                "order_id": order_id,
+            }),
+        ),
+        PoolMessage::SetPoolStatus { operator, status } => (
+            "set_pool_status",
+            json!({
+                "message_type": "set_pool_status",
+                "application_id": application_id,
+                "operator": encode_account(operator),
+                "status": format!("{:?}", status),
+            }),
+        ),
+        PoolMessage::ClaimAll { origin, tokens } => (
+            "claim_all",
+            json!({
+                "message_type": "claim_all",
+                "application_id": application_id,
+                "origin": encode_account(origin),
+                "tokens": tokens.map(encode_swap_path),
+            }),
+        ),
+        PoolMessage::ZapIn {
+            origin,
+            amount_0_in,
+            amount_1_in,
+            min_liquidity,
+            to,
+            block_timestamp,
+            deadline,
+        } => (
+            "zap_in",
+            json!({
+                "message_type": "zap_in",
+                "application_id": application_id,
+                "origin": encode_account(origin),
+                "amount_0_in": encode_option_amount(amount_0_in),
+                "amount_1_in": encode_option_amount(amount_1_in),
+                "min_liquidity": encode_option_amount(min_liquidity),
+                "to": encode_option_account(to),
+                "block_timestamp_micros": encode_option_timestamp(block_timestamp),
+                "deadline_micros": encode_option_timestamp(deadline),
+            }),
+        ),
+        PoolMessage::ZapOut {
+            origin,
+            liquidity,
+            token,
+            amount_out_min,
+            to,
+            block_timestamp,
+            deadline,
+        } => (
+            "zap_out",
+            json!({
+                "message_type": "zap_out",
+                "application_id": application_id,
+                "origin": encode_account(origin),
+                "liquidity": encode_amount(liquidity),
+                "token": token.map(|value| value.to_string()),
+                "amount_out_min": encode_option_amount(amount_out_min),
+                "to": encode_option_account(to),
+                "block_timestamp_micros": encode_option_timestamp(block_timestamp),
+                "deadline_micros": encode_option_timestamp(deadline),
+            }),
+        ),
+        PoolMessage::Sync { operator } => (
+            "sync",
+            json!({
+                "message_type": "sync",
+                "application_id": application_id,
+                "operator": encode_account(operator),
+            }),
+        ),
+        PoolMessage::Skim { operator, to } => (
+            "skim",
+            json!({
+                "message_type": "skim",
+                "application_id": application_id,
+                "operator": encode_account(operator),
+                "to": encode_account(to),
+            }),
+        ),
+        PoolMessage::SetDynamicFee {
+            operator,
+            dynamic_fee,
+        } => (
+            "set_dynamic_fee",
+            json!({
+                "message_type": "set_dynamic_fee",
+                "application_id": application_id,
+                "operator": encode_account(operator),
+                "dynamic_fee": encode_option_dynamic_fee(dynamic_fee.as_ref()),
+            }),
+        ),
+        PoolMessage::MigrateLiquidity {
+            origin,
+            liquidity,
+            amount_0_out_min,
+            amount_1_out_min,
+            deadline,
+        } => (
+            "migrate_liquidity",
+            json!({
+                "message_type": "migrate_liquidity",
+                "application_id": application_id,
+                "origin": encode_account(origin),
+                "liquidity": encode_amount(liquidity),
+                "amount_0_out_min": encode_option_amount(amount_0_out_min),
+                "amount_1_out_min": encode_option_amount(amount_1_out_min),
+                "deadline_micros": encode_option_timestamp(deadline),
+            }),
+        ),
+        PoolMessage::RequestFailed { origin, request } => (
+            "request_failed",
+            json!({
+                "message_type": "request_failed",
+                "application_id": application_id,
+                "origin": encode_account(origin),
+                "request": encode_failed_request(request),
+            }),
+        ),
+        PoolMessage::SwapSettled {
+            transaction,
+            status,
+        } => (
+            "swap_settled",
+            json!({
+                "message_type": "swap_settled",
+                "application_id": application_id,
+                "transaction": encode_transaction(transaction),
+                "status": format!("{:?}", status),
+            }),
+        ),
     };
     Ok(json!({
         "payload_type": payload_type,
@@ -431,6 +858,97 @@ fn decode_pool_message(application_id: &str, raw_bytes: &[u8]) -> anyhow::Result
     }))
 }
 
+fn decode_pool_event(application_id: &str, raw_bytes: &[u8]) -> anyhow::Result<Value> {
+    let event = bcs::from_bytes::<PoolEvent>(raw_bytes)?;
+    let (payload_type, decoded_payload_json) = match event {
+        PoolEvent::Swap {
+            transaction,
+            token_0_price,
+            token_1_price,
+            reserve_0,
+            reserve_1,
+        } => (
+            "swap",
+            json!({
+                "event_type": "swap",
+                "application_id": application_id,
+                "transaction": encode_transaction(transaction),
+                "token_0_price": encode_amount(token_0_price),
+                "token_1_price": encode_amount(token_1_price),
+                "reserve_0": encode_amount(reserve_0),
+                "reserve_1": encode_amount(reserve_1),
+            }),
+        ),
+        PoolEvent::AddLiquidity {
+            transaction,
+            reserve_0,
+            reserve_1,
+        } => (
+            "add_liquidity",
+            json!({
+                "event_type": "add_liquidity",
+                "application_id": application_id,
+                "transaction": encode_transaction(transaction),
+                "reserve_0": encode_amount(reserve_0),
+                "reserve_1": encode_amount(reserve_1),
+            }),
+        ),
+        PoolEvent::RemoveLiquidity {
+            transaction,
+            reserve_0,
+            reserve_1,
+        } => (
+            "remove_liquidity",
+            json!({
+                "event_type": "remove_liquidity",
+                "application_id": application_id,
+                "transaction": encode_transaction(transaction),
+                "reserve_0": encode_amount(reserve_0),
+                "reserve_1": encode_amount(reserve_1),
+            }),
+        ),
+        PoolEvent::Claim {
+            owner,
+            token,
+            amount,
+            created_at,
+        } => (
+            "claim",
+            json!({
+                "event_type": "claim",
+                "application_id": application_id,
+                "owner": encode_account(owner),
+                "token": token.map(|value| value.to_string()),
+                "amount": encode_amount(amount),
+                "created_at_micros": created_at.micros(),
+            }),
+        ),
+        PoolEvent::FeeChange {
+            operator,
+            pool_fee_percent_mul_100,
+            protocol_fee_share_denominator,
+            dynamic_fee,
+            created_at,
+        } => (
+            "fee_change",
+            json!({
+                "event_type": "fee_change",
+                "application_id": application_id,
+                "operator": encode_account(operator),
+                "pool_fee_percent_mul_100": pool_fee_percent_mul_100,
+                "protocol_fee_share_denominator": protocol_fee_share_denominator,
+                "dynamic_fee": encode_option_dynamic_fee(dynamic_fee.as_ref()),
+                "created_at_micros": created_at.micros(),
+            }),
+        ),
+    };
+    Ok(json!({
+        "payload_type": payload_type,
+        "decoder_version": "pool-event-rust-v1",
+        "decoded_payload_json": decoded_payload_json,
+    }))
+}
+
 fn decode_proxy_operation(application_id: &str, raw_bytes: &[u8]) -> anyhow::Result<Value> {
     let operation = bcs::from_bytes::<ProxyOperation>(raw_bytes)?;
     let payload_type = match operation {
@@ -599,6 +1117,7 @@ fn decode_swap_operation(application_id: &str, raw_bytes: &[u8]) -> anyhow::Resu
             amount_1,
             virtual_liquidity,
             to,
+            launch_protection,
         } => (
             "initialize_liquidity",
             json!({
@@ -611,6 +1130,7 @@ fn decode_swap_operation(application_id: &str, raw_bytes: &[u8]) -> anyhow::Resu
                 "amount_1": encode_amount(amount_1),
                 "virtual_liquidity": virtual_liquidity,
                 "to": encode_option_account(to),
+                "launch_protection": encode_option_launch_protection(launch_protection.as_ref()),
             }),
         ),
         SwapOperation::CreatePool {
@@ -619,6 +1139,7 @@ fn decode_swap_operation(application_id: &str, raw_bytes: &[u8]) -> anyhow::Resu
             amount_0,
             amount_1,
             to,
+            pool_fee_percent_mul_100,
         } => (
             "create_pool",
             json!({
@@ -629,6 +1150,7 @@ fn decode_swap_operation(application_id: &str, raw_bytes: &[u8]) -> anyhow::Resu
                 "amount_0": encode_amount(amount_0),
                 "amount_1": encode_amount(amount_1),
                 "to": encode_option_account(to),
+                "pool_fee_percent_mul_100": pool_fee_percent_mul_100,
             }),
         ),
         SwapOperation::UpdatePool {
@@ -639,6 +1161,7 @@ fn decode_swap_operation(application_id: &str, raw_bytes: &[u8]) -> anyhow::Resu
             token_1_price,
             reserve_0,
             reserve_1,
+            stats,
         } => (
             "update_pool",
             json!({
@@ -651,6 +1174,122 @@ fn decode_swap_operation(application_id: &str, raw_bytes: &[u8]) -> anyhow::Resu
                 "token_1_price": encode_amount(token_1_price),
                 "reserve_0": encode_amount(reserve_0),
                 "reserve_1": encode_amount(reserve_1),
+                "stats": encode_pool_stats(stats),
+            }),
+        ),
+        SwapOperation::Swap {
+            path,
+            amount_in,
+            amount_out_min,
+            deadline,
+        } => (
+            "swap",
+            json!({
+                "operation_type": "swap",
+                "application_id": application_id,
+                "path": encode_swap_path(path),
+                "amount_in": encode_amount(amount_in),
+                "amount_out_min": encode_option_amount(amount_out_min),
+                "deadline_micros": encode_option_timestamp(deadline),
+            }),
+        ),
+        SwapOperation::ContinueSwap {
+            origin,
+            route,
+            amount_in,
+        } => (
+            "continue_swap",
+            json!({
+                "operation_type": "continue_swap",
+                "application_id": application_id,
+                "origin": encode_account(origin),
+                "route": encode_swap_route(route),
+                "amount_in": encode_amount(amount_in),
+            }),
+        ),
+        SwapOperation::SetPoolStatus {
+            pool_application,
+            status,
+        } => (
+            "set_pool_status",
+            json!({
+                "operation_type": "set_pool_status",
+                "application_id": application_id,
+                "pool_application": encode_account(pool_application),
+                "status": format!("{:?}", status),
+            }),
+        ),
+        SwapOperation::UpdatePoolStatus {
+            token_0,
+            token_1,
+            status,
+        } => (
+            "update_pool_status",
+            json!({
+                "operation_type": "update_pool_status",
+                "application_id": application_id,
+                "token_0": token_0.to_string(),
+                "token_1": token_1.map(|value| value.to_string()),
+                "status": format!("{:?}", status),
+            }),
+        ),
+        SwapOperation::ClaimAll { pool_applications } => (
+            "claim_all",
+            json!({
+                "operation_type": "claim_all",
+                "application_id": application_id,
+                "pool_applications": pool_applications
+                    .into_iter()
+                    .map(encode_account)
+                    .collect::<Vec<_>>(),
+            }),
+        ),
+        SwapOperation::SetPoolBytecodeId { pool_bytecode_id } => (
+            "set_pool_bytecode_id",
+            json!({
+                "operation_type": "set_pool_bytecode_id",
+                "application_id": application_id,
+                "pool_bytecode_id": pool_bytecode_id.to_string(),
+            }),
+        ),
+        SwapOperation::MigrateLiquidity {
+            origin,
+            token_0,
+            token_1,
+            pool_fee_percent_mul_100,
+            amount_0,
+            amount_1,
+            amount_0_out_min,
+            amount_1_out_min,
+            deadline,
+        } => (
+            "migrate_liquidity",
+            json!({
+                "operation_type": "migrate_liquidity",
+                "application_id": application_id,
+                "origin": encode_account(origin),
+                "token_0": token_0.to_string(),
+                "token_1": token_1.map(|value| value.to_string()),
+                "pool_fee_percent_mul_100": pool_fee_percent_mul_100,
+                "amount_0": encode_amount(amount_0),
+                "amount_1": encode_amount(amount_1),
+                "amount_0_out_min": encode_option_amount(amount_0_out_min),
+                "amount_1_out_min": encode_option_amount(amount_1_out_min),
+                "deadline_micros": encode_option_timestamp(deadline),
+            }),
+        ),
+        SwapOperation::PromotePoolVersion {
+            token_0,
+            token_1,
+            pool_fee_percent_mul_100,
+        } => (
+            "promote_pool_version",
+            json!({
+                "operation_type": "promote_pool_version",
+                "application_id": application_id,
+                "token_0": token_0.to_string(),
+                "token_1": token_1.map(|value| value.to_string()),
+                "pool_fee_percent_mul_100": pool_fee_percent_mul_100,
             }),
         ),
     };
@@ -671,6 +1310,7 @@ fn decode_swap_message(application_id: &str, raw_bytes: &[u8]) -> anyhow::Result
             amount_1,
             virtual_liquidity,
             to,
+            launch_protection,
         } => (
             "initialize_liquidity",
             json!({
@@ -682,6 +1322,7 @@ fn decode_swap_message(application_id: &str, raw_bytes: &[u8]) -> anyhow::Result
                 "amount_1": encode_amount(amount_1),
                 "virtual_liquidity": virtual_liquidity,
                 "to": encode_option_account(to),
+                "launch_protection": encode_option_launch_protection(launch_protection.as_ref()),
             }),
         ),
         SwapMessage::CreatePool {
@@ -693,6 +1334,8 @@ fn decode_swap_message(application_id: &str, raw_bytes: &[u8]) -> anyhow::Result
             amount_1,
             bootstrap_policy,
             to,
+            pool_fee_percent_mul_100,
+            launch_protection,
         } => (
             "create_pool",
             json!({
@@ -706,6 +1349,8 @@ fn decode_swap_message(application_id: &str, raw_bytes: &[u8]) -> anyhow::Result
                 "amount_1": encode_amount(amount_1),
                 "bootstrap_policy": encode_bootstrap_policy(&bootstrap_policy),
                 "to": encode_option_account(to),
+                "pool_fee_percent_mul_100": pool_fee_percent_mul_100,
+                "launch_protection": encode_option_launch_protection(launch_protection.as_ref()),
             }),
         ),
         SwapMessage::PoolCreated {
@@ -717,6 +1362,7 @@ fn decode_swap_message(application_id: &str, raw_bytes: &[u8]) -> anyhow::Result
             amount_1,
             bootstrap_policy,
             to,
+            pool_fee_percent_mul_100,
         } => (
             "pool_created",
             json!({
@@ -730,6 +1376,7 @@ fn decode_swap_message(application_id: &str, raw_bytes: &[u8]) -> anyhow::Result
                 "amount_1": encode_amount(amount_1),
                 "bootstrap_policy": encode_bootstrap_policy(&bootstrap_policy),
                 "to": encode_option_account(to),
+                "pool_fee_percent_mul_100": pool_fee_percent_mul_100,
             }),
         ),
         SwapMessage::CreateUserPool {
@@ -738,6 +1385,7 @@ fn decode_swap_message(application_id: &str, raw_bytes: &[u8]) -> anyhow::Result
             amount_0,
             amount_1,
             to,
+            pool_fee_percent_mul_100,
         } => (
             "create_user_pool",
             json!({
@@ -748,6 +1396,7 @@ fn decode_swap_message(application_id: &str, raw_bytes: &[u8]) -> anyhow::Result
                 "amount_0": encode_amount(amount_0),
                 "amount_1": encode_amount(amount_1),
                 "to": encode_option_account(to),
+                "pool_fee_percent_mul_100": pool_fee_percent_mul_100,
             }),
         ),
         SwapMessage::UserPoolCreated {
@@ -778,6 +1427,7 @@ fn decode_swap_message(application_id: &str, raw_bytes: &[u8]) -> anyhow::Result
             token_1_price,
             reserve_0,
             reserve_1,
+            stats,
         } => (
             "update_pool",
             json!({
@@ -790,6 +1440,149 @@ fn decode_swap_message(application_id: &str, raw_bytes: &[u8]) -> anyhow::Result
                 "token_1_price": encode_amount(token_1_price),
                 "reserve_0": encode_amount(reserve_0),
                 "reserve_1": encode_amount(reserve_1),
+                "stats": encode_pool_stats(stats),
+            }),
+        ),
+        SwapMessage::Swap {
+            origin,
+            path,
+            amount_in,
+            amount_out_min,
+            deadline,
+        } => (
+            "swap",
+            json!({
+                "message_type": "swap",
+                "application_id": application_id,
+                "origin": encode_account(origin),
+                "path": encode_swap_path(path),
+                "amount_in": encode_amount(amount_in),
+                "amount_out_min": encode_option_amount(amount_out_min),
+                "deadline_micros": encode_option_timestamp(deadline),
+            }),
+        ),
+        SwapMessage::ExecuteSwapRoute {
+            origin,
+            route,
+            amount_in,
+        } => (
+            "execute_swap_route",
+            json!({
+                "message_type": "execute_swap_route",
+                "application_id": application_id,
+                "origin": encode_account(origin),
+                "route": encode_swap_route(route),
+                "amount_in": encode_amount(amount_in),
+            }),
+        ),
+        SwapMessage::UpdatePoolStatus {
+            token_0,
+            token_1,
+            status,
+        } => (
+            "update_pool_status",
+            json!({
+                "message_type": "update_pool_status",
+                "application_id": application_id,
+                "token_0": token_0.to_string(),
+                "token_1": token_1.map(|value| value.to_string()),
+                "status": format!("{:?}", status),
+            }),
+        ),
+        SwapMessage::ClaimAll {
+            origin,
+            pool_applications,
+        } => (
+            "claim_all",
+            json!({
+                "message_type": "claim_all",
+                "application_id": application_id,
+                "origin": encode_account(origin),
+                "pool_applications": pool_applications
+                    .into_iter()
+                    .map(encode_account)
+                    .collect::<Vec<_>>(),
+            }),
+        ),
+        SwapMessage::ExecuteClaimAll {
+            origin,
+            pool_applications,
+        } => (
+            "execute_claim_all",
+            json!({
+                "message_type": "execute_claim_all",
+                "application_id": application_id,
+                "origin": encode_account(origin),
+                "pool_applications": pool_applications
+                    .into_iter()
+                    .map(encode_account)
+                    .collect::<Vec<_>>(),
+            }),
+        ),
+        SwapMessage::MigrateLiquidity {
+            origin,
+            pool_application,
+            token_0,
+            token_1,
+            pool_fee_percent_mul_100,
+            amount_0,
+            amount_1,
+            amount_0_out_min,
+            amount_1_out_min,
+            deadline,
+        } => (
+            "migrate_liquidity",
+            json!({
+                "message_type": "migrate_liquidity",
+                "application_id": application_id,
+                "origin": encode_account(origin),
+                "pool_application": encode_account(pool_application),
+                "token_0": token_0.to_string(),
+                "token_1": token_1.map(|value| value.to_string()),
+                "pool_fee_percent_mul_100": pool_fee_percent_mul_100,
+                "amount_0": encode_amount(amount_0),
+                "amount_1": encode_amount(amount_1),
+                "amount_0_out_min": encode_option_amount(amount_0_out_min),
+                "amount_1_out_min": encode_option_amount(amount_1_out_min),
+                "deadline_micros": encode_option_timestamp(deadline),
+            }),
+        ),
+        SwapMessage::ExecuteMigrateLiquidity {
+            origin,
+            pool_application,
+            amount_0,
+            amount_1,
+            amount_0_out_min,
+            amount_1_out_min,
+            deadline,
+        } => (
+            "execute_migrate_liquidity",
+            json!({
+                "message_type": "execute_migrate_liquidity",
+                "application_id": application_id,
+                "origin": encode_account(origin),
+                "pool_application": encode_account(pool_application),
+                "amount_0": encode_amount(amount_0),
+                "amount_1": encode_amount(amount_1),
+                "amount_0_out_min": encode_option_amount(amount_0_out_min),
+                "amount_1_out_min": encode_option_amount(amount_1_out_min),
+                "deadline_micros": encode_option_timestamp(deadline),
+            }),
+        ),
+        SwapMessage::ContinueSwap {
+            origin,
+            pool_application,
+            route,
+            amount_in,
+        } => (
+            "continue_swap",
+            json!({
+                "message_type": "continue_swap",
+                "application_id": application_id,
+                "origin": encode_account(origin),
+                "pool_application": encode_account(pool_application),
+                "route": encode_swap_route(route),
+                "amount_in": encode_amount(amount_in),
             }),
         ),
     };
@@ -1216,6 +2009,7 @@ fn encode_transaction(transaction: Transaction) -> Value {
         "amount_1_in": encode_option_amount(transaction.amount_1_in),
         "amount_1_out": encode_option_amount(transaction.amount_1_out),
         "liquidity": encode_option_amount(transaction.liquidity),
+        "fee_percent_mul_100": transaction.fee_percent_mul_100,
         "created_at_micros": transaction.created_at.micros(),
     })
 }
@@ -1229,10 +2023,55 @@ fn encode_transaction_type(transaction_type: TransactionType) -> &'static str {
     }
 }
 
+fn encode_pool_stats(stats: PoolStats) -> Value {
+    json!({
+        "volume_0": encode_amount(stats.volume_0),
+        "volume_1": encode_amount(stats.volume_1),
+        "fees_0": encode_amount(stats.fees_0),
+        "fees_1": encode_amount(stats.fees_1),
+        "swap_count": stats.swap_count,
+        "last_trade_at_micros": encode_option_timestamp(stats.last_trade_at),
+    })
+}
+
+fn encode_failed_request(request: FailedRequest) -> Value {
+    json!({
+        "kind": format!("{:?}", request.kind),
+        "amount_0": encode_amount(request.amount_0),
+        "amount_1": encode_amount(request.amount_1),
+        "reason": format!("{:?}", request.reason),
+        "created_at_micros": request.created_at.micros(),
+    })
+}
+
 fn encode_option_timestamp(value: Option<Timestamp>) -> Option<u64> {
     value.map(|timestamp| timestamp.micros())
 }
 
+fn encode_option_dynamic_fee(dynamic_fee: Option<&DynamicFee>) -> Value {
+    match dynamic_fee {
+        Some(dynamic_fee) => json!({
+            "min_fee_percent_mul_100": dynamic_fee.min_fee_percent_mul_100,
+            "max_fee_percent_mul_100": dynamic_fee.max_fee_percent_mul_100,
+            "max_volatility_percent_mul_100": dynamic_fee.max_volatility_percent_mul_100,
+            "window_seconds": dynamic_fee.window_seconds,
+        }),
+        None => Value::Null,
+    }
+}
+
+fn encode_option_launch_protection(launch_protection: Option<&LaunchProtection>) -> Value {
+    match launch_protection {
+        Some(launch_protection) => json!({
+            "duration_minutes": launch_protection.duration_minutes,
+            "max_trade_percent_mul_100": launch_protection.max_trade_percent_mul_100,
+            "cooldown_secs": launch_protection.cooldown_secs,
+            "extra_fee_percent_mul_100": launch_protection.extra_fee_percent_mul_100,
+        }),
+        None => Value::Null,
+    }
+}
+
 fn encode_bootstrap_policy(policy: &BootstrapPolicy) -> Value {
     match policy {
         BootstrapPolicy::UserCreatePool => json!({
@@ -1257,6 +2096,13 @@ fn encode_claim_transfer_receipt(value: ClaimTransferReceipt) -> Value {
         "token": value.token.to_string(),
         "amount": encode_amount(value.amount),
         "result": encode_unit_result(value.result),
+        "route": value.route.map(encode_swap_route),
+    })
+}
+
+fn encode_claim_transfer_receipt_payload(value: ClaimTransferReceiptPayload) -> Value {
+    json!({
+        "route": encode_swap_route(value.route),
     })
 }
 
@@ -1306,6 +2152,34 @@ fn encode_fund_request(value: FundRequest) -> Value {
         "to": encode_option_account(value.to),
         "block_timestamp_micros": encode_option_timestamp(value.block_timestamp),
         "fund_type": format!("{:?}", value.fund_type),
+        "route": value.route.map(encode_swap_route),
+        "counterparty_amount_out": encode_option_amount(value.counterparty_amount_out),
+        "deadline_micros": encode_option_timestamp(value.deadline),
+        "auto_claim": value.auto_claim,
+    })
+}
+
+fn encode_swap_path(path: Vec<Option<ApplicationId>>) -> Value {
+    json!(path
+        .into_iter()
+        .map(|token| token.map(|value| value.to_string()))
+        .collect::<Vec<_>>())
+}
+
+fn encode_swap_route(value: SwapRoute) -> Value {
+    json!({
+        "hops": value
+            .hops
+            .into_iter()
+            .map(|hop| json!({
+                "pool_application": encode_account(hop.pool_application),
+                "token_0": hop.token_0.to_string(),
+                "token_1": hop.token_1.map(|value| value.to_string()),
+                "token_in": hop.token_in.map(|value| value.to_string()),
+            }))
+            .collect::<Vec<_>>(),
+        "amount_out_min": encode_option_amount(value.amount_out_min),
+        "deadline_micros": encode_option_timestamp(value.deadline),
     })
 }
 
@@ -1332,6 +2206,10 @@ fn encode_transfer_from_application_receipt_payload(
             "kind": "pool_swap",
             "receipt": encode_swap_transfer_receipt_payload(receipt),
         }),
+        TransferFromApplicationReceiptPayload::PoolClaim(receipt) => json!({
+            "kind": "pool_claim",
+            "receipt": encode_claim_transfer_receipt_payload(receipt),
+        }),
     }
 }
 
@@ -117,6 +117,7 @@ fn build_operation(input: &HelperInput) -> Result<Vec<u8>> {
             virtual_initial_liquidity: true,
             swap_creator_chain_id: input.swap_creator_chain_id,
             enable_mining: false,
+            launch_protection: None,
             mining_supply: Some(Amount::from_tokens(10_499_100)),
         },
     };
@@ -3,7 +3,9 @@ use abi::blob_gateway::{BlobData, BlobDataType, BlobGatewayMessage};
 use abi::meme::{MemeMessage, MemeOperation};
 use abi::proxy::ProxyMessage;
 use abi::store_type::StoreType;
-use abi::swap::pool::{ClaimTransferReceipt, FundRequest, FundType, PoolMessage, PoolOperation};
+use abi::swap::pool::{
+    ClaimTransferReceipt, FundRequest, FundType, PoolMessage, PoolOperation, PoolStats,
+};
 use abi::swap::router::{SwapMessage, SwapOperation};
 use abi::swap::transaction::{Transaction, TransactionType};
 use linera_sdk::linera_base_types::{
@@ -51,6 +53,9 @@ fn build_pool_swap_operation() -> PoolOperation {
         amount_1_out_min: Some(Amount::from_attos(7)),
         to: None,
         block_timestamp: None,
+        route: None,
+        deadline: None,
+        auto_claim: false,
     }
 }
 
@@ -71,6 +76,7 @@ fn build_pool_claim_transfer_receipt_message() -> PoolMessage {
             token: sample_application_id(0x11),
             amount: Amount::from_attos(13),
             result: Ok(()),
+            route: None,
         },
     }
 }
@@ -93,6 +99,10 @@ fn sample_fund_request() -> FundRequest {
         to: Some(sample_account(0x55, 0x66)),
         block_timestamp: Some(Timestamp::from(99)),
         fund_type: FundType::Swap,
+        route: None,
+        counterparty_amount_out: None,
+        deadline: None,
+        auto_claim: false,
     }
 }
 
@@ -105,6 +115,7 @@ fn build_swap_update_pool_operation() -> SwapOperation {
         token_1_price: Amount::from_attos(8),
         reserve_0: Amount::from_attos(9),
         reserve_1: Amount::from_attos(10),
+        stats: PoolStats::default(),
     }
 }
 
@@ -117,6 +128,7 @@ fn build_swap_update_pool_message() -> SwapMessage {
         token_1_price: Amount::from_attos(8),
         reserve_0: Amount::from_attos(9),
         reserve_1: Amount::from_attos(10),
+        stats: PoolStats::default(),
     }
 }
 
@@ -177,6 +189,7 @@ fn sample_transaction() -> Transaction {
         amount_1_in: Some(Amount::from_attos(4)),
         amount_1_out: None,
         liquidity: Some(Amount::from_attos(5)),
+        fee_percent_mul_100: None,
         created_at: Timestamp::from(99),
     }
 }
@@ -7,17 +7,33 @@ use abi::swap::router::{SwapMessage, SwapOperation, SwapResponse};
 use base::handler::Handler;
 use base::handler::HandlerError;
 use message::{
+    claim_all::ClaimAllHandler as MessageClaimAllHandler,
+    continue_swap::ContinueSwapHandler as MessageContinueSwapHandler,
     create_pool::CreatePoolHandler as MessageCreatePoolHandler,
     create_user_pool::CreateUserPoolHandler as MessageCreateUserPoolHandler,
+    execute_claim_all::ExecuteClaimAllHandler as MessageExecuteClaimAllHandler,
+    execute_migrate_liquidity::ExecuteMigrateLiquidityHandler as MessageExecuteMigrateLiquidityHandler,
+    execute_swap_route::ExecuteSwapRouteHandler as MessageExecuteSwapRouteHandler,
     initialize_liquidity::InitializeLiquidityHandler as MessageInitializeLiquidityHandler,
+    migrate_liquidity::MigrateLiquidityHandler as MessageMigrateLiquidityHandler,
     pool_created::PoolCreatedHandler as MessagePoolCreatedHandler,
+    swap::SwapHandler as MessageSwapHandler,
     update_pool::UpdatePoolHandler as MessageUpdatePoolHandler,
+    update_pool_status::UpdatePoolStatusHandler as MessageUpdatePoolStatusHandler,
     user_pool_created::UserPoolCreatedHandler as MessageUserPoolCreatedHandler,
 };
 use operation::{
+    claim_all::ClaimAllHandler as OperationClaimAllHandler,
+    continue_swap::ContinueSwapHandler as OperationContinueSwapHandler,
     create_pool::CreatePoolHandler as OperationCreatePoolHandler,
     initialize_liquidity::InitializeLiquidityHandler as OperationInitializeLiquidityHandler,
+    migrate_liquidity::MigrateLiquidityHandler as OperationMigrateLiquidityHandler,
+    promote_pool_version::PromotePoolVersionHandler as OperationPromotePoolVersionHandler,
+    set_pool_bytecode_id::SetPoolBytecodeIdHandler as OperationSetPoolBytecodeIdHandler,
+    set_pool_status::SetPoolStatusHandler as OperationSetPoolStatusHandler,
+    swap::SwapHandler as OperationSwapHandler,
     update_pool::UpdatePoolHandler as OperationUpdatePoolHandler,
+    update_pool_status::UpdatePoolStatusHandler as OperationUpdatePoolStatusHandler,
 };
 use runtime::interfaces::{
     access_control::AccessControl, contract::ContractRuntimeContext, meme::MemeRuntimeContext,
@@ -44,6 +60,28 @@ impl HandlerFactory {
             SwapOperation::UpdatePool { .. } => {
                 Box::new(OperationUpdatePoolHandler::new(runtime, state, op))
             }
+            SwapOperation::Swap { .. } => Box::new(OperationSwapHandler::new(runtime, state, op)),
+            SwapOperation::ContinueSwap { .. } => {
+                Box::new(OperationContinueSwapHandler::new(runtime, state, op))
+            }
+            SwapOperation::SetPoolStatus { .. } => {
+                Box::new(OperationSetPoolStatusHandler::new(runtime, state, op))
+            }
+            SwapOperation::UpdatePoolStatus { .. } => {
+                Box::new(OperationUpdatePoolStatusHandler::new(runtime, state, op))
+            }
+            SwapOperation::ClaimAll { .. } => {
+                Box::new(OperationClaimAllHandler::new(runtime, state, op))
+            }
+            SwapOperation::SetPoolBytecodeId { .. } => {
+                Box::new(OperationSetPoolBytecodeIdHandler::new(runtime, state, op))
+            }
+            SwapOperation::MigrateLiquidity { .. } => {
+                Box::new(OperationMigrateLiquidityHandler::new(runtime, state, op))
+            }
+            SwapOperation::PromotePoolVersion { .. } => {
+                Box::new(OperationPromotePoolVersionHandler::new(runtime, state, op))
+            }
         }
     }
 
@@ -73,6 +111,28 @@ impl HandlerFactory {
             SwapMessage::UserPoolCreated { .. } => {
                 Box::new(MessageUserPoolCreatedHandler::new(runtime, state, msg))
             }
+            SwapMessage::Swap { .. } => Box::new(MessageSwapHandler::new(runtime, state, msg)),
+            SwapMessage::ExecuteSwapRoute { .. } => {
+                Box::new(MessageExecuteSwapRouteHandler::new(runtime, state, msg))
+            }
+            SwapMessage::UpdatePoolStatus { .. } => {
+                Box::new(MessageUpdatePoolStatusHandler::new(runtime, state, msg))
+            }
+            SwapMessage::ClaimAll { .. } => {
+                Box::new(MessageClaimAllHandler::new(runtime, state, msg))
+            }
+            SwapMessage::ExecuteClaimAll { .. } => {
+                Box::new(MessageExecuteClaimAllHandler::new(runtime, state, msg))
+            }
+            SwapMessage::MigrateLiquidity { .. } => {
+                Box::new(MessageMigrateLiquidityHandler::new(runtime, state, msg))
+            }
+            SwapMessage::ExecuteMigrateLiquidity { .. } => Box::new(
+                MessageExecuteMigrateLiquidityHandler::new(runtime, state, msg),
+            ),
+            SwapMessage::ContinueSwap { .. } => {
+                Box::new(MessageContinueSwapHandler::new(runtime, state, msg))
+            }
         }
     }
 
@@ -2,7 +2,7 @@ use crate::interfaces::state::StateInterface;
 use abi::{
     policy::open_chain_fee_budget,
     swap::{
-        pool::BootstrapPolicy,
+        pool::{BootstrapPolicy, LaunchProtection},
         router::{SwapMessage, SwapResponse},
     },
 };
@@ -31,6 +31,8 @@ pub struct CreatePoolHandler<
     bootstrap_policy: BootstrapPolicy,
     to: Option<Account>,
     _deadline: Option<Timestamp>,
+    pool_fee_percent_mul_100: u16,
+    launch_protection: Option<LaunchProtection>,
 }
 
 impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInterface>
@@ -47,6 +49,8 @@ impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInt
         bootstrap_policy: BootstrapPolicy,
         to: Option<Account>,
         _deadline: Option<Timestamp>,
+        pool_fee_percent_mul_100: u16,
+        launch_protection: Option<LaunchProtection>,
     ) -> Self {
         Self {
             state,
@@ -60,6 +64,8 @@ impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInt
             bootstrap_policy,
             to,
             _deadline,
+            pool_fee_percent_mul_100,
+            launch_protection,
         }
     }
 
@@ -132,6 +138,8 @@ impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInt
                 amount_1: self.amount_1,
                 bootstrap_policy: self.bootstrap_policy.clone(),
                 to: self.to,
+                pool_fee_percent_mul_100: self.pool_fee_percent_mul_100,
+                launch_protection: self.launch_protection.clone(),
             },
             false,
         );
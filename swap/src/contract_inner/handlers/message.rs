@@ -1,6 +1,14 @@
+pub mod claim_all;
+pub mod continue_swap;
 pub mod create_pool;
 pub mod create_user_pool;
+pub mod execute_claim_all;
+pub mod execute_migrate_liquidity;
+pub mod execute_swap_route;
 pub mod initialize_liquidity;
+pub mod migrate_liquidity;
 pub mod pool_created;
+pub mod swap;
 pub mod update_pool;
+pub mod update_pool_status;
 pub mod user_pool_created;
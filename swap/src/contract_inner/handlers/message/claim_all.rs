@@ -0,0 +1,86 @@
+use crate::interfaces::state::StateInterface;
+use abi::swap::router::{SwapMessage, SwapResponse, MAX_CLAIM_ALL_POOLS};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::Account;
+use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct ClaimAllHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: Rc<RefCell<R>>,
+    state: S,
+
+    origin: Account,
+    pool_applications: Vec<Account>,
+}
+
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> ClaimAllHandler<R, S> {
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, msg: &SwapMessage) -> Self {
+        let SwapMessage::ClaimAll {
+            origin,
+            pool_applications,
+        } = msg
+        else {
+            panic!("Invalid message");
+        };
+
+        Self {
+            state,
+            runtime,
+
+            origin: *origin,
+            pool_applications: pool_applications.clone(),
+        }
+    }
+
+    // Pool chains are opened by the router for one pool application each
+    async fn is_pool_application(&self, pool_application: Account) -> bool {
+        self.state
+            .is_pool_chain(pool_application.chain_id)
+            .await
+            .expect("Failed: check pool chain")
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    Handler<SwapMessage, SwapResponse> for ClaimAllHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<SwapMessage, SwapResponse>>, HandlerError> {
+        self.runtime
+            .borrow_mut()
+            .only_application_creator()
+            .expect("Invalid swap chain");
+
+        // Caller lists the pools it has balance in, only pools are called with the
+        // authentication of origin
+        let mut pool_applications = Vec::new();
+        for pool_application in self.pool_applications.iter().take(MAX_CLAIM_ALL_POOLS) {
+            if pool_applications.contains(pool_application) {
+                continue;
+            }
+            if !self.is_pool_application(*pool_application).await {
+                log::warn!("Ignoring claim from untracked pool {}", pool_application);
+                continue;
+            }
+            pool_applications.push(*pool_application);
+        }
+        if pool_applications.is_empty() {
+            return Ok(None);
+        }
+
+        let mut outcome = HandlerOutcome::new();
+        outcome.with_message(
+            self.origin.chain_id,
+            SwapMessage::ExecuteClaimAll {
+                origin: self.origin,
+                pool_applications,
+            },
+            false,
+        );
+
+        Ok(Some(outcome))
+    }
+}
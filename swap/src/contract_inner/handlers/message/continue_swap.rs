@@ -0,0 +1,112 @@
+use crate::interfaces::state::StateInterface;
+use abi::swap::router::{SwapMessage, SwapResponse, SwapRoute, SwapRouteHop};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{Account, Amount};
+use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct ContinueSwapHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: Rc<RefCell<R>>,
+    state: S,
+
+    origin: Account,
+    pool_application: Account,
+    route: SwapRoute,
+    amount_in: Amount,
+}
+
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> ContinueSwapHandler<R, S> {
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, msg: &SwapMessage) -> Self {
+        let SwapMessage::ContinueSwap {
+            origin,
+            pool_application,
+            route,
+            amount_in,
+        } = msg
+        else {
+            panic!("Invalid message");
+        };
+
+        Self {
+            state,
+            runtime,
+
+            origin: *origin,
+            pool_application: *pool_application,
+            route: route.clone(),
+            amount_in: *amount_in,
+        }
+    }
+
+    async fn validate_pool_origin_chain(&mut self) -> bool {
+        let origin = self
+            .runtime
+            .borrow_mut()
+            .require_message_origin_chain_id()
+            .expect("Invalid message origin chain");
+        origin == self.pool_application.chain_id
+            && self
+                .state
+                .is_pool_chain(origin)
+                .await
+                .expect("Failed: check pool chain")
+    }
+
+    async fn is_catalogued_hop(&self, hop: &SwapRouteHop) -> bool {
+        if hop.token_in != Some(hop.token_0) && hop.token_in != hop.token_1 {
+            return false;
+        }
+        self.state
+            .get_pools_exchangable(hop.token_0, hop.token_1)
+            .await
+            .expect("Failed: get pools exchangable")
+            .iter()
+            .any(|pool| pool.pool_application == hop.pool_application)
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    Handler<SwapMessage, SwapResponse> for ContinueSwapHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<SwapMessage, SwapResponse>>, HandlerError> {
+        self.runtime
+            .borrow_mut()
+            .only_application_creator()
+            .expect("Invalid continue swap chain");
+
+        // Hop output is already settled to origin and stays there if the route is dropped
+        if !self.validate_pool_origin_chain().await {
+            log::warn!(
+                "Ignoring ContinueSwap from untracked pool chain {}",
+                self.pool_application.chain_id
+            );
+            return Ok(None);
+        }
+        for hop in self.route.hops.iter() {
+            if !self.is_catalogued_hop(hop).await {
+                log::warn!(
+                    "Ignoring ContinueSwap through uncatalogued pool {}",
+                    hop.pool_application
+                );
+                return Ok(None);
+            }
+        }
+
+        let mut outcome = HandlerOutcome::new();
+        outcome.with_message(
+            self.origin.chain_id,
+            SwapMessage::ExecuteSwapRoute {
+                origin: self.origin,
+                route: self.route.clone(),
+                amount_in: self.amount_in,
+            },
+            false,
+        );
+
+        Ok(Some(outcome))
+    }
+}
@@ -1,8 +1,8 @@
 use crate::interfaces::state::StateInterface;
 use abi::swap::{
     pool::{
-        BootstrapPolicy, InstantiationArgument as PoolInstantiationArgument, PoolAbi,
-        PoolParameters,
+        BootstrapPolicy, InstantiationArgument as PoolInstantiationArgument, LaunchProtection,
+        PoolAbi, PoolParameters,
     },
     router::{SwapMessage, SwapResponse},
 };
@@ -29,6 +29,8 @@ pub struct CreatePoolHandler<
     amount_1: Amount,
     bootstrap_policy: BootstrapPolicy,
     to: Option<Account>,
+    pool_fee_percent_mul_100: u16,
+    launch_protection: Option<LaunchProtection>,
 }
 
 impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInterface>
@@ -44,7 +46,8 @@ impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInt
             amount_1,
             bootstrap_policy,
             to,
-            ..
+            pool_fee_percent_mul_100,
+            launch_protection,
         } = msg
         else {
             panic!("Invalid message");
@@ -62,6 +65,8 @@ impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInt
             amount_1: *amount_1,
             bootstrap_policy: bootstrap_policy.clone(),
             to: *to,
+            pool_fee_percent_mul_100: *pool_fee_percent_mul_100,
+            launch_protection: launch_protection.clone(),
         }
     }
 }
@@ -79,6 +84,13 @@ impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInt
         let application_id = self.runtime.borrow_mut().application_id();
         let chain_id = self.runtime.borrow_mut().chain_id();
 
+        // Meme pools are sniped right after initialization, user pools are not. The meme
+        // creator may still launch without protection
+        let launch_protection = match self.bootstrap_policy {
+            BootstrapPolicy::UserCreatePool => None,
+            BootstrapPolicy::MemeInitializeLiquidity { .. } => self.launch_protection.clone(),
+        };
+
         let pool_application_id = self
             .runtime
             .borrow_mut()
@@ -89,9 +101,10 @@ impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInt
                     token_0: self.token_0,
                     token_1: self.token_1,
                     bootstrap_policy: self.bootstrap_policy.clone(),
+                    launch_protection,
                 },
                 &PoolInstantiationArgument {
-                    pool_fee_percent_mul_100: 30,
+                    pool_fee_percent_mul_100: self.pool_fee_percent_mul_100,
                     router_application_id: application_id,
                 },
             )
@@ -115,6 +128,7 @@ impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInt
                 amount_1: self.amount_1,
                 bootstrap_policy: self.bootstrap_policy.clone(),
                 to: self.to,
+                pool_fee_percent_mul_100: self.pool_fee_percent_mul_100,
             },
             false,
         );
@@ -28,6 +28,7 @@ pub struct CreateUserPoolHandler<
     amount_0: Amount,
     amount_1: Amount,
     to: Option<Account>,
+    pool_fee_percent_mul_100: u16,
 }
 
 impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInterface>
@@ -40,7 +41,7 @@ impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInt
             amount_0,
             amount_1,
             to,
-            ..
+            pool_fee_percent_mul_100,
         } = msg
         else {
             panic!("Invalid message");
@@ -55,6 +56,7 @@ impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInt
             amount_0: *amount_0,
             amount_1: *amount_1,
             to: *to,
+            pool_fee_percent_mul_100: *pool_fee_percent_mul_100,
         }
     }
 }
@@ -78,13 +80,15 @@ impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInt
         assert!(self.amount_0 > Amount::ZERO, "Invalid amount_0");
         assert!(self.amount_1 > Amount::ZERO, "Invalid amount_1");
 
+        // Other fee tiers of the same pair are independent pools
         if self
             .state
             .borrow()
-            .get_pool_exchangable(self.token_0, self.token_1)
+            .get_pools_exchangable(self.token_0, self.token_1)
             .await
-            .expect("Failed: get pool exchangable")
-            .is_some()
+            .expect("Failed: get pools exchangable")
+            .iter()
+            .any(|pool| pool.pool_fee_percent_mul_100 == self.pool_fee_percent_mul_100)
         {
             let creator = self.runtime.borrow_mut().message_signer_account();
             self.runtime.borrow_mut().transfer(
@@ -108,6 +112,8 @@ impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInt
             BootstrapPolicy::UserCreatePool,
             self.to,
             None,
+            self.pool_fee_percent_mul_100,
+            None,
         );
 
         handler.handle().await
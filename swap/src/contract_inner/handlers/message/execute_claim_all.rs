@@ -0,0 +1,68 @@
+use crate::interfaces::state::StateInterface;
+use abi::swap::{
+    pool::{PoolAbi, PoolOperation},
+    router::{SwapMessage, SwapResponse},
+};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{Account, AccountOwner, ApplicationId};
+use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct ExecuteClaimAllHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: Rc<RefCell<R>>,
+    _state: S,
+
+    origin: Account,
+    pool_applications: Vec<Account>,
+}
+
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> ExecuteClaimAllHandler<R, S> {
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, msg: &SwapMessage) -> Self {
+        let SwapMessage::ExecuteClaimAll {
+            origin,
+            pool_applications,
+        } = msg
+        else {
+            panic!("Invalid message");
+        };
+
+        Self {
+            _state: state,
+            runtime,
+
+            origin: *origin,
+            pool_applications: pool_applications.clone(),
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    Handler<SwapMessage, SwapResponse> for ExecuteClaimAllHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<SwapMessage, SwapResponse>>, HandlerError> {
+        // Pools claim to the authenticated account, so it must run with origin's authentication
+        assert_eq!(
+            self.runtime.borrow_mut().authenticated_account(),
+            self.origin,
+            "Invalid claim origin"
+        );
+
+        for pool_application in &self.pool_applications {
+            let AccountOwner::Address32(application_description_hash) = pool_application.owner
+            else {
+                panic!("Invalid owner");
+            };
+            let application_id = ApplicationId::new(application_description_hash);
+            let _ = self.runtime.borrow_mut().call_application(
+                application_id.with_abi::<PoolAbi>(),
+                &PoolOperation::ClaimAll { tokens: None },
+            );
+        }
+
+        Ok(None)
+    }
+}
@@ -0,0 +1,109 @@
+use crate::interfaces::state::StateInterface;
+use abi::swap::{
+    pool::{PoolAbi, PoolOperation, PoolResponse},
+    router::{SwapMessage, SwapResponse},
+};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{Account, AccountOwner, Amount, ApplicationId, Timestamp};
+use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct ExecuteMigrateLiquidityHandler<
+    R: ContractRuntimeContext + AccessControl,
+    S: StateInterface,
+> {
+    runtime: Rc<RefCell<R>>,
+    _state: S,
+
+    origin: Account,
+    pool_application: Account,
+    amount_0: Amount,
+    amount_1: Amount,
+    amount_0_out_min: Option<Amount>,
+    amount_1_out_min: Option<Amount>,
+    deadline: Option<Timestamp>,
+}
+
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    ExecuteMigrateLiquidityHandler<R, S>
+{
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, msg: &SwapMessage) -> Self {
+        let SwapMessage::ExecuteMigrateLiquidity {
+            origin,
+            pool_application,
+            amount_0,
+            amount_1,
+            amount_0_out_min,
+            amount_1_out_min,
+            deadline,
+        } = msg
+        else {
+            panic!("Invalid message");
+        };
+
+        Self {
+            _state: state,
+            runtime,
+
+            origin: *origin,
+            pool_application: *pool_application,
+            amount_0: *amount_0,
+            amount_1: *amount_1,
+            amount_0_out_min: *amount_0_out_min,
+            amount_1_out_min: *amount_1_out_min,
+            deadline: *deadline,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    Handler<SwapMessage, SwapResponse> for ExecuteMigrateLiquidityHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<SwapMessage, SwapResponse>>, HandlerError> {
+        // Target pool is only resolved by swap creator chain against the catalog
+        let creator_chain_id = self.runtime.borrow_mut().application_creator_chain_id();
+        assert_eq!(
+            self.runtime
+                .borrow_mut()
+                .require_message_origin_chain_id()
+                .expect("Invalid message origin chain"),
+            creator_chain_id,
+            "Invalid migration chain"
+        );
+
+        // The deposit is funded by origin, so it must run with origin's authentication
+        assert_eq!(
+            self.runtime.borrow_mut().authenticated_account(),
+            self.origin,
+            "Invalid migration origin"
+        );
+
+        // Pool refunds and records the deposit if it fails the minimums or the deadline
+        let call = PoolOperation::AddLiquidity {
+            amount_0_in: self.amount_0,
+            amount_1_in: self.amount_1,
+            amount_0_out_min: self.amount_0_out_min,
+            amount_1_out_min: self.amount_1_out_min,
+            to: None,
+            block_timestamp: None,
+            deadline: self.deadline,
+        };
+        let AccountOwner::Address32(application_description_hash) = self.pool_application.owner
+        else {
+            panic!("Invalid owner");
+        };
+        let application_id: ApplicationId = ApplicationId::new(application_description_hash);
+        match self
+            .runtime
+            .borrow_mut()
+            .call_application(application_id.with_abi::<PoolAbi>(), &call)
+        {
+            PoolResponse::Ok => Ok(None),
+            _ => panic!("Invalid response"),
+        }
+    }
+}
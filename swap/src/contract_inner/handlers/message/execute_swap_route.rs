@@ -0,0 +1,133 @@
+use crate::interfaces::state::StateInterface;
+use abi::swap::{
+    pool::{PoolAbi, PoolOperation, PoolResponse},
+    router::{SwapMessage, SwapResponse, SwapRoute},
+};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{Account, AccountOwner, Amount, ApplicationId};
+use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct ExecuteSwapRouteHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: Rc<RefCell<R>>,
+    _state: S,
+
+    origin: Account,
+    route: SwapRoute,
+    amount_in: Amount,
+}
+
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> ExecuteSwapRouteHandler<R, S> {
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, msg: &SwapMessage) -> Self {
+        let SwapMessage::ExecuteSwapRoute {
+            origin,
+            route,
+            amount_in,
+        } = msg
+        else {
+            panic!("Invalid message");
+        };
+
+        Self {
+            _state: state,
+            runtime,
+
+            origin: *origin,
+            route: route.clone(),
+            amount_in: *amount_in,
+        }
+    }
+
+    fn hop_operation(&self) -> (ApplicationId, PoolOperation) {
+        let (hop, remaining) = self.route.hops.split_first().expect("Invalid route");
+
+        // Slippage is only checked against the final output of the route
+        let (amount_out_min, route) = if remaining.is_empty() {
+            (self.route.amount_out_min, None)
+        } else {
+            (
+                None,
+                Some(SwapRoute {
+                    hops: remaining.to_vec(),
+                    ..self.route.clone()
+                }),
+            )
+        };
+        let operation = if hop.token_0_in() {
+            PoolOperation::Swap {
+                amount_0_in: Some(self.amount_in),
+                amount_1_in: None,
+                amount_0_out_min: None,
+                amount_1_out_min: amount_out_min,
+                to: None,
+                block_timestamp: None,
+                route,
+                deadline: self.route.deadline,
+                auto_claim: false,
+            }
+        } else {
+            PoolOperation::Swap {
+                amount_0_in: None,
+                amount_1_in: Some(self.amount_in),
+                amount_0_out_min: amount_out_min,
+                amount_1_out_min: None,
+                to: None,
+                block_timestamp: None,
+                route,
+                deadline: self.route.deadline,
+                auto_claim: false,
+            }
+        };
+
+        let AccountOwner::Address32(application_description_hash) = hop.pool_application.owner
+        else {
+            panic!("Invalid owner");
+        };
+        (ApplicationId::new(application_description_hash), operation)
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    Handler<SwapMessage, SwapResponse> for ExecuteSwapRouteHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<SwapMessage, SwapResponse>>, HandlerError> {
+        // Routes are only resolved or continued by swap creator chain against the catalog
+        let creator_chain_id = self.runtime.borrow_mut().application_creator_chain_id();
+        assert_eq!(
+            self.runtime
+                .borrow_mut()
+                .require_message_origin_chain_id()
+                .expect("Invalid message origin chain"),
+            creator_chain_id,
+            "Invalid route chain"
+        );
+
+        // Each hop is funded by origin, so it must run with origin's authentication
+        assert_eq!(
+            self.runtime.borrow_mut().authenticated_account(),
+            self.origin,
+            "Invalid route origin"
+        );
+
+        // Funds of previous hops are already settled to origin and stay there
+        let now = self.runtime.borrow_mut().system_time();
+        if self.route.expired(now) {
+            log::warn!("Swap route expired at {}", now);
+            return Ok(None);
+        }
+
+        let (application_id, operation) = self.hop_operation();
+        match self
+            .runtime
+            .borrow_mut()
+            .call_application(application_id.with_abi::<PoolAbi>(), &operation)
+        {
+            PoolResponse::Ok => Ok(None),
+            _ => panic!("Invalid response"),
+        }
+    }
+}
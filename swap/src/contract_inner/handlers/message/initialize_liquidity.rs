@@ -2,8 +2,8 @@ use crate::{
     contract_inner::handlers::create_pool::CreatePoolHandler, interfaces::state::StateInterface,
 };
 use abi::swap::{
-    pool::BootstrapPolicy,
-    router::{SwapMessage, SwapResponse},
+    pool::{BootstrapPolicy, LaunchProtection},
+    router::{SwapMessage, SwapResponse, DEFAULT_POOL_FEE_PERCENT_MUL_100},
 };
 use async_trait::async_trait;
 use base::handler::{Handler, HandlerError, HandlerOutcome};
@@ -27,6 +27,7 @@ pub struct InitializeLiquidityHandler<
     // Only for creator to initialize pool
     virtual_liquidity: bool,
     to: Option<Account>,
+    launch_protection: Option<LaunchProtection>,
 }
 
 impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInterface>
@@ -40,6 +41,7 @@ impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInt
             amount_1,
             virtual_liquidity,
             to,
+            launch_protection,
         } = msg
         else {
             panic!("Invalid message");
@@ -55,6 +57,7 @@ impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInt
             amount_1: *amount_1,
             virtual_liquidity: *virtual_liquidity,
             to: *to,
+            launch_protection: launch_protection.clone(),
         }
     }
 }
@@ -79,6 +82,8 @@ impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInt
             },
             self.to,
             None,
+            DEFAULT_POOL_FEE_PERCENT_MUL_100,
+            self.launch_protection.clone(),
         );
 
         handler.handle().await
@@ -0,0 +1,267 @@
+use crate::{
+    contract_inner::handlers::create_pool::CreatePoolHandler, interfaces::state::StateInterface,
+};
+use abi::swap::{
+    pool::BootstrapPolicy,
+    router::{PendingMigration, SwapMessage, SwapResponse},
+};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{Account, Amount, ApplicationId, ChainId, Timestamp};
+use runtime::interfaces::{
+    access_control::AccessControl, contract::ContractRuntimeContext, meme::MemeRuntimeContext,
+};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct MigrateLiquidityHandler<
+    R: ContractRuntimeContext + AccessControl + MemeRuntimeContext,
+    S: StateInterface,
+> {
+    runtime: Rc<RefCell<R>>,
+    state: Rc<RefCell<S>>,
+
+    origin: Account,
+    pool_application: Account,
+    token_0: ApplicationId,
+    token_1: Option<ApplicationId>,
+    pool_fee_percent_mul_100: u16,
+    amount_0: Amount,
+    amount_1: Amount,
+    amount_0_out_min: Option<Amount>,
+    amount_1_out_min: Option<Amount>,
+    deadline: Option<Timestamp>,
+}
+
+impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInterface>
+    MigrateLiquidityHandler<R, S>
+{
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, msg: &SwapMessage) -> Self {
+        let SwapMessage::MigrateLiquidity {
+            origin,
+            pool_application,
+            token_0,
+            token_1,
+            pool_fee_percent_mul_100,
+            amount_0,
+            amount_1,
+            amount_0_out_min,
+            amount_1_out_min,
+            deadline,
+        } = msg
+        else {
+            panic!("Invalid message");
+        };
+
+        Self {
+            state: Rc::new(RefCell::new(state)),
+            runtime,
+
+            origin: *origin,
+            pool_application: *pool_application,
+            token_0: *token_0,
+            token_1: *token_1,
+            pool_fee_percent_mul_100: *pool_fee_percent_mul_100,
+            amount_0: *amount_0,
+            amount_1: *amount_1,
+            amount_0_out_min: *amount_0_out_min,
+            amount_1_out_min: *amount_1_out_min,
+            deadline: *deadline,
+        }
+    }
+
+    async fn validate_pool_origin_chain(&mut self) -> bool {
+        let origin = self
+            .runtime
+            .borrow_mut()
+            .require_message_origin_chain_id()
+            .expect("Invalid message origin chain");
+        origin == self.pool_application.chain_id
+            && self
+                .state
+                .borrow()
+                .is_pool_chain(origin)
+                .await
+                .expect("Failed: check pool chain")
+    }
+
+    // First migration of a pair creates the new pool version with the migrated liquidity as
+    // initial liquidity, the catalog is re-pointed once router operators promote it. Swap
+    // creator chain pays the chain opening budget since the upgrade is decided by router
+    // operators.
+    async fn create_pool(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<SwapMessage, SwapResponse>>, HandlerError> {
+        let mut handler = CreatePoolHandler::new(
+            self.runtime.clone(),
+            self.state.clone(),
+            self.origin,
+            self.token_0,
+            self.token_1,
+            self.amount_0,
+            self.amount_1,
+            BootstrapPolicy::UserCreatePool,
+            None,
+            self.deadline,
+            self.pool_fee_percent_mul_100,
+            None,
+        );
+
+        handler.handle().await
+    }
+
+    // Only one new version is opened for a pool chain, migrations arriving before it is created
+    // wait for it and are executed once it is
+    async fn upgrade_pool(
+        &mut self,
+        pool_chain_id: ChainId,
+        amount_0: Amount,
+        amount_1: Amount,
+        amount_0_out_min: Option<Amount>,
+        amount_1_out_min: Option<Amount>,
+    ) -> Result<Option<HandlerOutcome<SwapMessage, SwapResponse>>, HandlerError> {
+        if self
+            .state
+            .borrow()
+            .pending_pool_upgrade(pool_chain_id)
+            .await
+            .expect("Failed: get pending pool upgrade")
+            .is_some()
+        {
+            self.state
+                .borrow_mut()
+                .queue_migration(
+                    pool_chain_id,
+                    PendingMigration {
+                        origin: self.origin,
+                        amount_0,
+                        amount_1,
+                        amount_0_out_min,
+                        amount_1_out_min,
+                        deadline: self.deadline,
+                    },
+                )
+                .await
+                .expect("Failed: queue migration");
+            return Ok(None);
+        }
+
+        let outcome = self.create_pool().await?;
+        // CreatePool goes to the chain just opened for the new version
+        let chain_id = *outcome
+            .as_ref()
+            .and_then(|outcome| outcome.messages.first())
+            .expect("Invalid create pool outcome")
+            .destination();
+        self.state
+            .borrow_mut()
+            .open_pool_upgrade(pool_chain_id, chain_id)
+            .expect("Failed: open pool upgrade");
+
+        Ok(outcome)
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInterface>
+    Handler<SwapMessage, SwapResponse> for MigrateLiquidityHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<SwapMessage, SwapResponse>>, HandlerError> {
+        // Underlying is already delivered to origin and stays there if nothing is migrated
+        if !self.validate_pool_origin_chain().await {
+            log::warn!(
+                "Ignoring MigrateLiquidity from untracked pool chain {}",
+                self.pool_application.chain_id
+            );
+            return Ok(None);
+        }
+
+        let pool = self
+            .state
+            .borrow()
+            .get_pools_exchangable(self.token_0, self.token_1)
+            .await
+            .expect("Failed: get pools exchangable")
+            .into_iter()
+            .find(|pool| pool.pool_fee_percent_mul_100 == self.pool_fee_percent_mul_100);
+
+        let Some(pool) = pool else {
+            return self.create_pool().await;
+        };
+
+        // The pair may be catalogued in reverse token order
+        let (amount_0, amount_1, amount_0_out_min, amount_1_out_min) =
+            if pool.token_0 == self.token_0 {
+                (
+                    self.amount_0,
+                    self.amount_1,
+                    self.amount_0_out_min,
+                    self.amount_1_out_min,
+                )
+            } else {
+                (
+                    self.amount_1,
+                    self.amount_0,
+                    self.amount_1_out_min,
+                    self.amount_0_out_min,
+                )
+            };
+
+        // Migrations into a waiting new version go on before the catalog is re-pointed
+        let pool_chain_id = pool.pool_application.chain_id;
+        let pool_application = if self
+            .state
+            .borrow()
+            .is_outdated_pool_chain(pool_chain_id)
+            .await
+            .expect("Failed: check pool chain bytecode")
+        {
+            let pool_upgrade = self
+                .state
+                .borrow()
+                .pool_upgrade(pool_chain_id)
+                .await
+                .expect("Failed: get pool upgrade");
+            let Some(pool_upgrade) = pool_upgrade else {
+                return self
+                    .upgrade_pool(
+                        pool_chain_id,
+                        amount_0,
+                        amount_1,
+                        amount_0_out_min,
+                        amount_1_out_min,
+                    )
+                    .await;
+            };
+            pool_upgrade
+        } else {
+            pool.pool_application
+        };
+        if pool_application == self.pool_application {
+            log::warn!(
+                "Ignoring MigrateLiquidity from latest pool {}",
+                self.pool_application.chain_id
+            );
+            return Ok(None);
+        }
+
+        let mut outcome = HandlerOutcome::new();
+
+        outcome.with_message(
+            self.origin.chain_id,
+            SwapMessage::ExecuteMigrateLiquidity {
+                origin: self.origin,
+                pool_application,
+                amount_0,
+                amount_1,
+                amount_0_out_min,
+                amount_1_out_min,
+                deadline: self.deadline,
+            },
+            false,
+        );
+
+        Ok(Some(outcome))
+    }
+}
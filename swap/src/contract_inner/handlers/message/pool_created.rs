@@ -24,6 +24,7 @@ pub struct PoolCreatedHandler<R: ContractRuntimeContext + AccessControl, S: Stat
     amount_1: Amount,
     bootstrap_policy: BootstrapPolicy,
     to: Option<Account>,
+    pool_fee_percent_mul_100: u16,
 }
 
 impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> PoolCreatedHandler<R, S> {
@@ -37,6 +38,7 @@ impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> PoolCreatedHa
             amount_1,
             bootstrap_policy,
             to,
+            pool_fee_percent_mul_100,
         } = msg
         else {
             panic!("Invalid message");
@@ -54,6 +56,7 @@ impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> PoolCreatedHa
             amount_1: *amount_1,
             bootstrap_policy: bootstrap_policy.clone(),
             to: *to,
+            pool_fee_percent_mul_100: *pool_fee_percent_mul_100,
         }
     }
 }
@@ -143,12 +146,14 @@ impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
         assert!(self.amount_1 > Amount::ZERO, "Invalid amount");
         assert!(self.amount_0 > Amount::ZERO, "Invalid amount");
 
-        if let Some(existing_pool) = self
+        let existing_pool = self
             .state
-            .get_pool_exchangable(self.token_0, self.token_1)
+            .get_pools_exchangable(self.token_0, self.token_1)
             .await
-            .expect("Failed: get pool exchangable")
-        {
+            .expect("Failed: get pools exchangable")
+            .into_iter()
+            .find(|pool| pool.pool_fee_percent_mul_100 == self.pool_fee_percent_mul_100);
+        if let Some(existing_pool) = &existing_pool {
             if existing_pool.pool_application == self.pool_application {
                 log::warn!(
                     "Ignoring duplicate PoolCreated receipt for pool {}",
@@ -156,7 +161,29 @@ impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
                 );
                 return Ok(None);
             }
-            panic!("Pool exists");
+            let pool_upgrade = self
+                .state
+                .pool_upgrade(existing_pool.pool_application.chain_id)
+                .await
+                .expect("Failed: get pool upgrade");
+            if pool_upgrade == Some(self.pool_application) {
+                log::warn!(
+                    "Ignoring duplicate PoolCreated receipt for pool {}",
+                    self.pool_application.chain_id
+                );
+                return Ok(None);
+            }
+            // Only the version opened to replace an older pool gives way to it, once
+            let replaced_pool_chain = self
+                .state
+                .replaced_pool_chain(self.pool_application.chain_id)
+                .await
+                .expect("Failed: get replaced pool chain");
+            assert!(
+                replaced_pool_chain == Some(existing_pool.pool_application.chain_id)
+                    && pool_upgrade.is_none(),
+                "Pool exists"
+            );
         }
 
         let outcome_message = match &self.bootstrap_policy {
@@ -182,26 +209,71 @@ impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
             }
         };
 
-        let timestamp = self.runtime.borrow_mut().system_time();
-        self.state
-            .create_pool(
-                self.creator,
-                self.token_0,
-                self.token_1,
-                self.pool_application,
-                timestamp,
-            )
-            .await
-            .expect("Failed: create pool");
+        let mut outcome = HandlerOutcome::new();
 
-        if outcome_message.is_none() {
-            return Ok(None);
+        if let Some(existing_pool) = existing_pool {
+            // Catalog keeps the old version until router operators promote the new one
+            let migrations = self
+                .state
+                .create_pool_upgrade(
+                    existing_pool.pool_application.chain_id,
+                    self.pool_application,
+                )
+                .await
+                .expect("Failed: create pool upgrade");
+            // Queued amounts follow the catalogued pool which may be in reverse token order
+            let reversed = existing_pool.token_0 != self.token_0;
+            for migration in migrations {
+                let (amount_0, amount_1, amount_0_out_min, amount_1_out_min) = if reversed {
+                    (
+                        migration.amount_1,
+                        migration.amount_0,
+                        migration.amount_1_out_min,
+                        migration.amount_0_out_min,
+                    )
+                } else {
+                    (
+                        migration.amount_0,
+                        migration.amount_1,
+                        migration.amount_0_out_min,
+                        migration.amount_1_out_min,
+                    )
+                };
+                outcome.with_message(
+                    migration.origin.chain_id,
+                    SwapMessage::ExecuteMigrateLiquidity {
+                        origin: migration.origin,
+                        pool_application: self.pool_application,
+                        amount_0,
+                        amount_1,
+                        amount_0_out_min,
+                        amount_1_out_min,
+                        deadline: migration.deadline,
+                    },
+                    false,
+                );
+            }
+        } else {
+            let timestamp = self.runtime.borrow_mut().system_time();
+            self.state
+                .create_pool(
+                    self.creator,
+                    self.token_0,
+                    self.token_1,
+                    self.pool_application,
+                    self.pool_fee_percent_mul_100,
+                    timestamp,
+                )
+                .await
+                .expect("Failed: create pool");
         }
 
-        let destination = self.creator.chain_id;
-        let mut outcome = HandlerOutcome::new();
-
-        outcome.with_message(destination, outcome_message.unwrap(), false);
+        if let Some(outcome_message) = outcome_message {
+            outcome.with_message(self.creator.chain_id, outcome_message, false);
+        }
+        if outcome.messages.is_empty() {
+            return Ok(None);
+        }
 
         Ok(Some(outcome))
     }
@@ -0,0 +1,114 @@
+use crate::interfaces::state::StateInterface;
+use abi::swap::router::{SwapMessage, SwapResponse, SwapRoute, SwapRouteHop};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{Account, Amount, ApplicationId, Timestamp};
+use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct SwapHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: Rc<RefCell<R>>,
+    state: S,
+
+    origin: Account,
+    path: Vec<Option<ApplicationId>>,
+    amount_in: Amount,
+    amount_out_min: Option<Amount>,
+    deadline: Option<Timestamp>,
+}
+
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> SwapHandler<R, S> {
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, msg: &SwapMessage) -> Self {
+        let SwapMessage::Swap {
+            origin,
+            path,
+            amount_in,
+            amount_out_min,
+            deadline,
+        } = msg
+        else {
+            panic!("Invalid message");
+        };
+
+        Self {
+            state,
+            runtime,
+
+            origin: *origin,
+            path: path.clone(),
+            amount_in: *amount_in,
+            amount_out_min: *amount_out_min,
+            deadline: *deadline,
+        }
+    }
+
+    async fn resolve_hop(
+        &self,
+        token_in: Option<ApplicationId>,
+        token_out: Option<ApplicationId>,
+    ) -> SwapRouteHop {
+        let (token_0, token_1) = match (token_in, token_out) {
+            (Some(token_in), _) => (token_in, token_out),
+            (None, Some(token_out)) => (token_out, None),
+            (None, None) => panic!("Invalid path"),
+        };
+        let pool = self
+            .state
+            .get_pool_exchangable(token_0, token_1)
+            .await
+            .expect("Failed: get pool exchangable")
+            .expect("Invalid pool");
+
+        SwapRouteHop {
+            pool_application: pool.pool_application,
+            token_0: pool.token_0,
+            token_1: pool.token_1,
+            token_in,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    Handler<SwapMessage, SwapResponse> for SwapHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<SwapMessage, SwapResponse>>, HandlerError> {
+        self.runtime
+            .borrow_mut()
+            .only_application_creator()
+            .expect("Invalid swap chain");
+
+        let mut hops = Vec::new();
+        for pair in self.path.windows(2) {
+            hops.push(self.resolve_hop(pair[0], pair[1]).await);
+        }
+
+        let route = SwapRoute {
+            hops,
+            amount_out_min: self.amount_out_min,
+            deadline: self.deadline,
+        };
+
+        // Nothing is funded yet, so an expired route is simply dropped
+        let now = self.runtime.borrow_mut().system_time();
+        if route.expired(now) {
+            log::warn!("Swap route expired at {}", now);
+            return Ok(None);
+        }
+
+        let mut outcome = HandlerOutcome::new();
+        outcome.with_message(
+            self.origin.chain_id,
+            SwapMessage::ExecuteSwapRoute {
+                origin: self.origin,
+                route,
+                amount_in: self.amount_in,
+            },
+            false,
+        );
+
+        Ok(Some(outcome))
+    }
+}
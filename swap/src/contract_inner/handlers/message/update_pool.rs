@@ -1,11 +1,12 @@
 use crate::interfaces::state::StateInterface;
 use abi::swap::{
+    pool::PoolStats,
     router::{SwapMessage, SwapResponse},
     transaction::Transaction,
 };
 use async_trait::async_trait;
 use base::handler::{Handler, HandlerError, HandlerOutcome};
-use linera_sdk::linera_base_types::{Amount, ApplicationId};
+use linera_sdk::linera_base_types::{Amount, ApplicationId, ChainId};
 use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
 use std::{cell::RefCell, rc::Rc};
 
@@ -20,26 +21,31 @@ pub struct UpdatePoolHandler<R: ContractRuntimeContext + AccessControl, S: State
     token_1_price: Amount,
     reserve_0: Amount,
     reserve_1: Amount,
+    stats: PoolStats,
 }
 
 impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> UpdatePoolHandler<R, S> {
-    async fn validate_pool_origin_chain(&mut self) {
+    // Pools of different fee tiers share the pair, so the pool is identified by its chain
+    async fn validate_pool_origin_chain(&mut self) -> ChainId {
         let origin = self
             .runtime
             .borrow_mut()
             .require_message_origin_chain_id()
             .expect("Invalid message origin chain");
-        let pool = self
+        let pools = self
             .state
-            .get_pool_exchangable(self.token_0, self.token_1)
+            .get_pools(self.token_0, self.token_1)
             .await
-            .expect("Failed: get pool exchangable")
-            .expect("Invalid pool");
+            .expect("Failed: get pools");
+        assert!(!pools.is_empty(), "Invalid pool");
 
-        assert_eq!(
-            origin, pool.pool_application.chain_id,
+        assert!(
+            pools
+                .iter()
+                .any(|pool| pool.pool_application.chain_id == origin),
             "Invalid pool origin chain"
         );
+        origin
     }
 
     pub fn new(runtime: Rc<RefCell<R>>, state: S, msg: &SwapMessage) -> Self {
@@ -51,6 +57,7 @@ impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> UpdatePoolHan
             token_1_price,
             reserve_0,
             reserve_1,
+            stats,
         } = msg
         else {
             panic!("Invalid message");
@@ -67,6 +74,7 @@ impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> UpdatePoolHan
             token_1_price: *token_1_price,
             reserve_0: *reserve_0,
             reserve_1: *reserve_1,
+            stats: *stats,
         }
     }
 }
@@ -78,10 +86,41 @@ impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
     async fn handle(
         &mut self,
     ) -> Result<Option<HandlerOutcome<SwapMessage, SwapResponse>>, HandlerError> {
-        self.validate_pool_origin_chain().await;
+        // Old pool versions keep serving their LPs, but are no longer catalogued
+        let origin = self
+            .runtime
+            .borrow_mut()
+            .require_message_origin_chain_id()
+            .expect("Invalid message origin chain");
+        if self
+            .state
+            .is_migrated_pool_chain(origin)
+            .await
+            .expect("Failed: check migrated pool chain")
+        {
+            log::warn!("Ignoring UpdatePool from migrated pool chain {}", origin);
+            return Ok(None);
+        }
+
+        // Waiting pool versions are not catalogued until they are promoted
+        if self
+            .state
+            .is_pool_upgrade_chain(origin)
+            .await
+            .expect("Failed: check pool upgrade chain")
+        {
+            log::warn!(
+                "Ignoring UpdatePool from waiting pool version chain {}",
+                origin
+            );
+            return Ok(None);
+        }
+
+        let pool_chain_id = self.validate_pool_origin_chain().await;
 
         self.state
             .update_pool(
+                pool_chain_id,
                 self.token_0,
                 self.token_1,
                 self.transaction,
@@ -89,6 +128,7 @@ impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
                 self.token_1_price,
                 self.reserve_0,
                 self.reserve_1,
+                self.stats,
             )
             .await
             .expect("Failed: update pool");
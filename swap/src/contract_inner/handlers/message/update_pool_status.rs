@@ -0,0 +1,113 @@
+use crate::interfaces::state::StateInterface;
+use abi::swap::{
+    pool::PoolStatus,
+    router::{SwapMessage, SwapResponse},
+};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{ApplicationId, ChainId};
+use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct UpdatePoolStatusHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: Rc<RefCell<R>>,
+    state: S,
+
+    token_0: ApplicationId,
+    token_1: Option<ApplicationId>,
+    status: PoolStatus,
+}
+
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> UpdatePoolStatusHandler<R, S> {
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, msg: &SwapMessage) -> Self {
+        let SwapMessage::UpdatePoolStatus {
+            token_0,
+            token_1,
+            status,
+        } = msg
+        else {
+            panic!("Invalid message");
+        };
+
+        Self {
+            runtime,
+            state,
+
+            token_0: *token_0,
+            token_1: *token_1,
+            status: *status,
+        }
+    }
+
+    async fn validate_pool_origin_chain(&mut self) -> ChainId {
+        let origin = self
+            .runtime
+            .borrow_mut()
+            .require_message_origin_chain_id()
+            .expect("Invalid message origin chain");
+        let pools = self
+            .state
+            .get_pools(self.token_0, self.token_1)
+            .await
+            .expect("Failed: get pools");
+
+        assert!(
+            pools
+                .iter()
+                .any(|pool| pool.pool_application.chain_id == origin),
+            "Invalid pool origin chain"
+        );
+        origin
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    Handler<SwapMessage, SwapResponse> for UpdatePoolStatusHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<SwapMessage, SwapResponse>>, HandlerError> {
+        // Old pool versions keep serving their LPs, but are no longer catalogued
+        let origin = self
+            .runtime
+            .borrow_mut()
+            .require_message_origin_chain_id()
+            .expect("Invalid message origin chain");
+        if self
+            .state
+            .is_migrated_pool_chain(origin)
+            .await
+            .expect("Failed: check migrated pool chain")
+        {
+            log::warn!(
+                "Ignoring UpdatePoolStatus from migrated pool chain {}",
+                origin
+            );
+            return Ok(None);
+        }
+
+        // Waiting pool versions are not catalogued until they are promoted
+        if self
+            .state
+            .is_pool_upgrade_chain(origin)
+            .await
+            .expect("Failed: check pool upgrade chain")
+        {
+            log::warn!(
+                "Ignoring UpdatePoolStatus from waiting pool version chain {}",
+                origin
+            );
+            return Ok(None);
+        }
+
+        let pool_chain_id = self.validate_pool_origin_chain().await;
+
+        self.state
+            .update_pool_status(pool_chain_id, self.token_0, self.token_1, self.status)
+            .await
+            .expect("Failed: update pool status");
+
+        Ok(None)
+    }
+}
@@ -64,6 +64,7 @@ impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
             amount_1_out_min: None,
             to: self.to,
             block_timestamp: None,
+            deadline: None,
         };
         let AccountOwner::Address32(application_description_hash) = self.pool_application.owner
         else {
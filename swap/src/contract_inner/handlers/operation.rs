@@ -1,3 +1,11 @@
+pub mod claim_all;
+pub mod continue_swap;
 pub mod create_pool;
 pub mod initialize_liquidity;
+pub mod migrate_liquidity;
+pub mod promote_pool_version;
+pub mod set_pool_bytecode_id;
+pub mod set_pool_status;
+pub mod swap;
 pub mod update_pool;
+pub mod update_pool_status;
@@ -0,0 +1,60 @@
+use crate::interfaces::state::StateInterface;
+use abi::swap::router::{SwapMessage, SwapOperation, SwapResponse, MAX_CLAIM_ALL_POOLS};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::Account;
+use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct ClaimAllHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: Rc<RefCell<R>>,
+    _state: S,
+
+    pool_applications: Vec<Account>,
+}
+
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> ClaimAllHandler<R, S> {
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, op: &SwapOperation) -> Self {
+        let SwapOperation::ClaimAll { pool_applications } = op else {
+            panic!("Invalid operation");
+        };
+
+        Self {
+            _state: state,
+            runtime,
+
+            pool_applications: pool_applications.clone(),
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    Handler<SwapMessage, SwapResponse> for ClaimAllHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<SwapMessage, SwapResponse>>, HandlerError> {
+        assert!(
+            !self.pool_applications.is_empty()
+                && self.pool_applications.len() <= MAX_CLAIM_ALL_POOLS,
+            "Invalid pools"
+        );
+
+        let origin = self.runtime.borrow_mut().authenticated_account();
+        let destination = self.runtime.borrow_mut().application_creator_chain_id();
+        let mut outcome = HandlerOutcome::new();
+
+        // Pools are only catalogued on swap creation chain
+        outcome.with_message(
+            destination,
+            SwapMessage::ClaimAll {
+                origin,
+                pool_applications: self.pool_applications.clone(),
+            },
+            false,
+        );
+
+        Ok(Some(outcome))
+    }
+}
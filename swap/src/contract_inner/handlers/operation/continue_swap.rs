@@ -0,0 +1,87 @@
+use crate::interfaces::state::StateInterface;
+use abi::swap::router::{SwapMessage, SwapOperation, SwapResponse, SwapRoute};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{Account, AccountOwner, Amount};
+use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct ContinueSwapHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: Rc<RefCell<R>>,
+    _state: S,
+
+    origin: Account,
+    route: SwapRoute,
+    amount_in: Amount,
+}
+
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> ContinueSwapHandler<R, S> {
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, op: &SwapOperation) -> Self {
+        let SwapOperation::ContinueSwap {
+            origin,
+            route,
+            amount_in,
+        } = op
+        else {
+            panic!("Invalid operation");
+        };
+
+        Self {
+            _state: state,
+            runtime,
+
+            origin: *origin,
+            route: route.clone(),
+            amount_in: *amount_in,
+        }
+    }
+
+    // The calling pool identifies the finished hop, creator chain checks its chain
+    fn pool_application(&mut self) -> Account {
+        self.runtime
+            .borrow_mut()
+            .not_application_creator()
+            .expect("Invalid continue swap chain");
+        let caller_id = self
+            .runtime
+            .borrow_mut()
+            .authenticated_caller_id()
+            .expect("Invalid caller");
+
+        Account {
+            chain_id: self.runtime.borrow_mut().chain_id(),
+            owner: AccountOwner::from(caller_id),
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    Handler<SwapMessage, SwapResponse> for ContinueSwapHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<SwapMessage, SwapResponse>>, HandlerError> {
+        let pool_application = self.pool_application();
+
+        assert!(!self.route.hops.is_empty(), "Invalid route");
+        assert!(self.amount_in > Amount::ZERO, "Invalid amount");
+
+        let destination = self.runtime.borrow_mut().application_creator_chain_id();
+        let mut outcome = HandlerOutcome::new();
+
+        // Hop output is already settled to origin, next hop must be funded from there
+        outcome.with_message(
+            destination,
+            SwapMessage::ContinueSwap {
+                origin: self.origin,
+                pool_application,
+                route: self.route.clone(),
+                amount_in: self.amount_in,
+            },
+            false,
+        );
+
+        Ok(Some(outcome))
+    }
+}
@@ -1,7 +1,9 @@
 use crate::interfaces::state::StateInterface;
 use abi::{
     policy::open_chain_fee_budget,
-    swap::router::{SwapMessage, SwapOperation, SwapResponse},
+    swap::router::{
+        SwapMessage, SwapOperation, SwapResponse, DEFAULT_POOL_FEE_PERCENT_MUL_100, POOL_FEE_TIERS,
+    },
 };
 use async_trait::async_trait;
 use base::handler::{Handler, HandlerError, HandlerOutcome};
@@ -23,6 +25,7 @@ pub struct CreatePoolHandler<
     amount_0: Amount,
     amount_1: Amount,
     to: Option<Account>,
+    pool_fee_percent_mul_100: u16,
 }
 
 impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInterface>
@@ -35,7 +38,7 @@ impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInt
             amount_0,
             amount_1,
             to,
-            ..
+            pool_fee_percent_mul_100,
         } = op
         else {
             panic!("Invalid operation");
@@ -50,6 +53,8 @@ impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInt
             amount_0: *amount_0,
             amount_1: *amount_1,
             to: *to,
+            pool_fee_percent_mul_100: pool_fee_percent_mul_100
+                .unwrap_or(DEFAULT_POOL_FEE_PERCENT_MUL_100),
         }
     }
 
@@ -119,6 +124,10 @@ impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInt
         assert!(Some(self.token_0) != self.token_1, "Invalid token pair");
         assert!(self.amount_0 > Amount::ZERO, "Invalid amount_0");
         assert!(self.amount_1 > Amount::ZERO, "Invalid amount_1");
+        assert!(
+            POOL_FEE_TIERS.contains(&self.pool_fee_percent_mul_100),
+            "Invalid fee tier"
+        );
 
         // Public CreatePool accepts only:
         // - meme / native
@@ -159,6 +168,7 @@ impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInt
                 amount_0: self.amount_0,
                 amount_1: self.amount_1,
                 to: self.to,
+                pool_fee_percent_mul_100: self.pool_fee_percent_mul_100,
             },
             false,
         );
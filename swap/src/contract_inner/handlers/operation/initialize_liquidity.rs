@@ -1,5 +1,8 @@
 use crate::interfaces::state::StateInterface;
-use abi::swap::router::{SwapMessage, SwapOperation, SwapResponse};
+use abi::swap::{
+    pool::LaunchProtection,
+    router::{SwapMessage, SwapOperation, SwapResponse},
+};
 use async_trait::async_trait;
 use base::handler::{Handler, HandlerError, HandlerOutcome};
 use linera_sdk::linera_base_types::{Account, Amount, ApplicationId, ChainId};
@@ -22,6 +25,7 @@ pub struct InitializeLiquidityHandler<
     amount_1: Amount,
     virtual_liquidity: bool,
     to: Option<Account>,
+    launch_protection: Option<LaunchProtection>,
 }
 
 impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInterface>
@@ -36,6 +40,7 @@ impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInt
             amount_1,
             virtual_liquidity,
             to,
+            launch_protection,
         } = op
         else {
             panic!("Invalid operation");
@@ -52,6 +57,7 @@ impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInt
             amount_1: *amount_1,
             virtual_liquidity: *virtual_liquidity,
             to: *to,
+            launch_protection: launch_protection.clone(),
         }
     }
 }
@@ -109,6 +115,9 @@ impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInt
 
         assert!(self.token_0 == caller_id, "Invalid caller");
         assert!(chain_id == self.token_0_creator_chain_id, "Invalid caller");
+        if let Some(launch_protection) = &self.launch_protection {
+            launch_protection.validate();
+        }
 
         let virtual_liquidity =
             self.formalize_virtual_liquidity(self.token_0, None, self.virtual_liquidity);
@@ -133,6 +142,7 @@ impl<R: ContractRuntimeContext + AccessControl + MemeRuntimeContext, S: StateInt
                 amount_1: self.amount_1,
                 virtual_liquidity,
                 to: self.to,
+                launch_protection: self.launch_protection.clone(),
             },
             false,
         );
@@ -0,0 +1,112 @@
+use crate::interfaces::state::StateInterface;
+use abi::swap::router::{SwapMessage, SwapOperation, SwapResponse};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{Account, AccountOwner, Amount, ApplicationId, Timestamp};
+use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct MigrateLiquidityHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: Rc<RefCell<R>>,
+    _state: S,
+
+    origin: Account,
+    token_0: ApplicationId,
+    token_1: Option<ApplicationId>,
+    pool_fee_percent_mul_100: u16,
+    amount_0: Amount,
+    amount_1: Amount,
+    amount_0_out_min: Option<Amount>,
+    amount_1_out_min: Option<Amount>,
+    deadline: Option<Timestamp>,
+}
+
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> MigrateLiquidityHandler<R, S> {
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, op: &SwapOperation) -> Self {
+        let SwapOperation::MigrateLiquidity {
+            origin,
+            token_0,
+            token_1,
+            pool_fee_percent_mul_100,
+            amount_0,
+            amount_1,
+            amount_0_out_min,
+            amount_1_out_min,
+            deadline,
+        } = op
+        else {
+            panic!("Invalid operation");
+        };
+
+        Self {
+            _state: state,
+            runtime,
+
+            origin: *origin,
+            token_0: *token_0,
+            token_1: *token_1,
+            pool_fee_percent_mul_100: *pool_fee_percent_mul_100,
+            amount_0: *amount_0,
+            amount_1: *amount_1,
+            amount_0_out_min: *amount_0_out_min,
+            amount_1_out_min: *amount_1_out_min,
+            deadline: *deadline,
+        }
+    }
+
+    // The calling pool identifies the migration source, creator chain checks its chain
+    fn pool_application(&mut self) -> Account {
+        self.runtime
+            .borrow_mut()
+            .not_application_creator()
+            .expect("Invalid migrate liquidity chain");
+        let caller_id = self
+            .runtime
+            .borrow_mut()
+            .authenticated_caller_id()
+            .expect("Invalid caller");
+
+        Account {
+            chain_id: self.runtime.borrow_mut().chain_id(),
+            owner: AccountOwner::from(caller_id),
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    Handler<SwapMessage, SwapResponse> for MigrateLiquidityHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<SwapMessage, SwapResponse>>, HandlerError> {
+        let pool_application = self.pool_application();
+
+        assert!(
+            self.amount_0 > Amount::ZERO && self.amount_1 > Amount::ZERO,
+            "Invalid amount"
+        );
+
+        let destination = self.runtime.borrow_mut().application_creator_chain_id();
+        let mut outcome = HandlerOutcome::new();
+
+        outcome.with_message(
+            destination,
+            SwapMessage::MigrateLiquidity {
+                origin: self.origin,
+                pool_application,
+                token_0: self.token_0,
+                token_1: self.token_1,
+                pool_fee_percent_mul_100: self.pool_fee_percent_mul_100,
+                amount_0: self.amount_0,
+                amount_1: self.amount_1,
+                amount_0_out_min: self.amount_0_out_min,
+                amount_1_out_min: self.amount_1_out_min,
+                deadline: self.deadline,
+            },
+            false,
+        );
+
+        Ok(Some(outcome))
+    }
+}
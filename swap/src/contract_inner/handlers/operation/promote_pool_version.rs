@@ -0,0 +1,81 @@
+use crate::interfaces::state::StateInterface;
+use abi::swap::router::{SwapMessage, SwapOperation, SwapResponse};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::ApplicationId;
+use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct PromotePoolVersionHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: Rc<RefCell<R>>,
+    state: S,
+
+    token_0: ApplicationId,
+    token_1: Option<ApplicationId>,
+    pool_fee_percent_mul_100: u16,
+}
+
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> PromotePoolVersionHandler<R, S> {
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, op: &SwapOperation) -> Self {
+        let SwapOperation::PromotePoolVersion {
+            token_0,
+            token_1,
+            pool_fee_percent_mul_100,
+        } = op
+        else {
+            panic!("Invalid operation");
+        };
+
+        Self {
+            state,
+            runtime,
+
+            token_0: *token_0,
+            token_1: *token_1,
+            pool_fee_percent_mul_100: *pool_fee_percent_mul_100,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    Handler<SwapMessage, SwapResponse> for PromotePoolVersionHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<SwapMessage, SwapResponse>>, HandlerError> {
+        // Owners of swap creator chain are the router operators
+        self.runtime
+            .borrow_mut()
+            .only_application_creator()
+            .expect("Permission denied");
+
+        let pool = self
+            .state
+            .get_pools_exchangable(self.token_0, self.token_1)
+            .await
+            .expect("Failed: get pools exchangable")
+            .into_iter()
+            .find(|pool| pool.pool_fee_percent_mul_100 == self.pool_fee_percent_mul_100)
+            .expect("Invalid pool");
+        let pool_upgrade = self
+            .state
+            .pool_upgrade(pool.pool_application.chain_id)
+            .await
+            .expect("Failed: get pool upgrade")
+            .expect("Invalid pool upgrade");
+
+        // Old version keeps serving its LPs until they migrate
+        self.state
+            .migrate_pool(
+                pool.pool_application.chain_id,
+                pool.token_0,
+                pool.token_1,
+                pool_upgrade,
+            )
+            .await
+            .expect("Failed: migrate pool");
+
+        Ok(None)
+    }
+}
@@ -0,0 +1,48 @@
+use crate::interfaces::state::StateInterface;
+use abi::swap::router::{SwapMessage, SwapOperation, SwapResponse};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::ModuleId;
+use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct SetPoolBytecodeIdHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: Rc<RefCell<R>>,
+    state: S,
+
+    pool_bytecode_id: ModuleId,
+}
+
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> SetPoolBytecodeIdHandler<R, S> {
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, op: &SwapOperation) -> Self {
+        let SwapOperation::SetPoolBytecodeId { pool_bytecode_id } = op else {
+            panic!("Invalid operation");
+        };
+
+        Self {
+            state,
+            runtime,
+
+            pool_bytecode_id: *pool_bytecode_id,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    Handler<SwapMessage, SwapResponse> for SetPoolBytecodeIdHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<SwapMessage, SwapResponse>>, HandlerError> {
+        // Owners of swap creator chain are the router operators
+        self.runtime
+            .borrow_mut()
+            .only_application_creator()
+            .expect("Permission denied");
+
+        // Existing pools keep their bytecode, liquidity moves over through MigrateLiquidity
+        self.state.set_pool_bytecode_id(self.pool_bytecode_id);
+        Ok(None)
+    }
+}
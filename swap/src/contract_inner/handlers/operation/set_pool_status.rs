@@ -0,0 +1,67 @@
+use crate::interfaces::state::StateInterface;
+use abi::swap::{
+    pool::{PoolAbi, PoolOperation, PoolStatus},
+    router::{SwapMessage, SwapOperation, SwapResponse},
+};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{Account, AccountOwner, ApplicationId};
+use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct SetPoolStatusHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: Rc<RefCell<R>>,
+    _state: S,
+
+    pool_application: Account,
+    status: PoolStatus,
+}
+
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> SetPoolStatusHandler<R, S> {
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, op: &SwapOperation) -> Self {
+        let SwapOperation::SetPoolStatus {
+            pool_application,
+            status,
+        } = op
+        else {
+            panic!("Invalid operation");
+        };
+
+        Self {
+            _state: state,
+            runtime,
+
+            pool_application: *pool_application,
+            status: *status,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    Handler<SwapMessage, SwapResponse> for SetPoolStatusHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<SwapMessage, SwapResponse>>, HandlerError> {
+        // Owners of swap creator chain are the router operators
+        self.runtime
+            .borrow_mut()
+            .only_application_creator()
+            .expect("Permission denied");
+
+        let AccountOwner::Address32(application_description_hash) = self.pool_application.owner
+        else {
+            panic!("Invalid owner");
+        };
+        let application_id: ApplicationId = ApplicationId::new(application_description_hash);
+        let call = PoolOperation::SetPoolStatus {
+            status: self.status,
+        };
+        let _ = self
+            .runtime
+            .borrow_mut()
+            .call_application(application_id.with_abi::<PoolAbi>(), &call);
+        Ok(None)
+    }
+}
@@ -0,0 +1,76 @@
+use crate::interfaces::state::StateInterface;
+use abi::swap::router::{SwapMessage, SwapOperation, SwapResponse};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::{Amount, ApplicationId, Timestamp};
+use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct SwapHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: Rc<RefCell<R>>,
+    _state: S,
+
+    path: Vec<Option<ApplicationId>>,
+    amount_in: Amount,
+    amount_out_min: Option<Amount>,
+    deadline: Option<Timestamp>,
+}
+
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> SwapHandler<R, S> {
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, op: &SwapOperation) -> Self {
+        let SwapOperation::Swap {
+            path,
+            amount_in,
+            amount_out_min,
+            deadline,
+        } = op
+        else {
+            panic!("Invalid operation");
+        };
+
+        Self {
+            _state: state,
+            runtime,
+
+            path: path.clone(),
+            amount_in: *amount_in,
+            amount_out_min: *amount_out_min,
+            deadline: *deadline,
+        }
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    Handler<SwapMessage, SwapResponse> for SwapHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<SwapMessage, SwapResponse>>, HandlerError> {
+        assert!(self.path.len() >= 2, "Invalid path");
+        assert!(
+            self.path.windows(2).all(|pair| pair[0] != pair[1]),
+            "Invalid path"
+        );
+        assert!(self.amount_in > Amount::ZERO, "Invalid amount");
+
+        let origin = self.runtime.borrow_mut().authenticated_account();
+        let destination = self.runtime.borrow_mut().application_creator_chain_id();
+        let mut outcome = HandlerOutcome::new();
+
+        // Pools are only catalogued on swap creation chain, so hops are resolved there
+        outcome.with_message(
+            destination,
+            SwapMessage::Swap {
+                origin,
+                path: self.path.clone(),
+                amount_in: self.amount_in,
+                amount_out_min: self.amount_out_min,
+                deadline: self.deadline,
+            },
+            false,
+        );
+
+        Ok(Some(outcome))
+    }
+}
@@ -1,5 +1,6 @@
 use crate::interfaces::state::StateInterface;
 use abi::swap::{
+    pool::PoolStats,
     router::{SwapMessage, SwapOperation, SwapResponse},
     transaction::Transaction,
 };
@@ -20,6 +21,7 @@ pub struct UpdatePoolHandler<R: ContractRuntimeContext + AccessControl, S: State
     token_1_price: Amount,
     reserve_0: Amount,
     reserve_1: Amount,
+    stats: PoolStats,
 }
 
 impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> UpdatePoolHandler<R, S> {
@@ -32,6 +34,7 @@ impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> UpdatePoolHan
             token_1_price,
             reserve_0,
             reserve_1,
+            stats,
         } = op
         else {
             panic!("Invalid operation");
@@ -48,6 +51,7 @@ impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> UpdatePoolHan
             token_1_price: *token_1_price,
             reserve_0: *reserve_0,
             reserve_1: *reserve_1,
+            stats: *stats,
         }
     }
 
@@ -85,6 +89,7 @@ impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
                 token_1_price: self.token_1_price,
                 reserve_0: self.reserve_0,
                 reserve_1: self.reserve_1,
+                stats: self.stats,
             },
             false,
         );
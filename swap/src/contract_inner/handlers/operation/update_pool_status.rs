@@ -0,0 +1,78 @@
+use crate::interfaces::state::StateInterface;
+use abi::swap::{
+    pool::PoolStatus,
+    router::{SwapMessage, SwapOperation, SwapResponse},
+};
+use async_trait::async_trait;
+use base::handler::{Handler, HandlerError, HandlerOutcome};
+use linera_sdk::linera_base_types::ApplicationId;
+use runtime::interfaces::{access_control::AccessControl, contract::ContractRuntimeContext};
+use std::{cell::RefCell, rc::Rc};
+
+pub struct UpdatePoolStatusHandler<R: ContractRuntimeContext + AccessControl, S: StateInterface> {
+    runtime: Rc<RefCell<R>>,
+    _state: S,
+
+    token_0: ApplicationId,
+    token_1: Option<ApplicationId>,
+    status: PoolStatus,
+}
+
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface> UpdatePoolStatusHandler<R, S> {
+    pub fn new(runtime: Rc<RefCell<R>>, state: S, op: &SwapOperation) -> Self {
+        let SwapOperation::UpdatePoolStatus {
+            token_0,
+            token_1,
+            status,
+        } = op
+        else {
+            panic!("Invalid operation");
+        };
+
+        Self {
+            _state: state,
+            runtime,
+
+            token_0: *token_0,
+            token_1: *token_1,
+            status: *status,
+        }
+    }
+
+    fn validate_application_caller(&mut self) {
+        self.runtime
+            .borrow_mut()
+            .not_application_creator()
+            .expect("Invalid update pool chain");
+        self.runtime
+            .borrow_mut()
+            .authenticated_caller_id()
+            .expect("Invalid caller");
+    }
+}
+
+#[async_trait(?Send)]
+impl<R: ContractRuntimeContext + AccessControl, S: StateInterface>
+    Handler<SwapMessage, SwapResponse> for UpdatePoolStatusHandler<R, S>
+{
+    async fn handle(
+        &mut self,
+    ) -> Result<Option<HandlerOutcome<SwapMessage, SwapResponse>>, HandlerError> {
+        self.validate_application_caller();
+
+        let destination = self.runtime.borrow_mut().application_creator_chain_id();
+        let mut outcome = HandlerOutcome::new();
+
+        outcome.with_message(
+            destination,
+            SwapMessage::UpdatePoolStatus {
+                token_0: self.token_0,
+                token_1: self.token_1,
+                status: self.status,
+            },
+            false,
+        );
+
+        Ok(Some(outcome))
+    }
+}
@@ -4,10 +4,11 @@ use abi::{
     meme::MemeResponse,
     policy::open_chain_fee_budget,
     swap::{
-        pool::{BootstrapPolicy, PoolOperation},
+        pool::{BootstrapPolicy, PoolOperation, PoolResponse, PoolStats, PoolStatus},
         router::{
             InstantiationArgument, SwapAbi, SwapMessage, SwapOperation, SwapParameters,
-            SwapResponse,
+            SwapResponse, SwapRoute, SwapRouteHop, DEFAULT_POOL_FEE_PERCENT_MUL_100,
+            MAX_CLAIM_ALL_POOLS,
         },
         transaction::{Transaction, TransactionType},
     },
@@ -17,7 +18,7 @@ use linera_sdk::{
     bcs,
     linera_base_types::{
         Account, AccountOwner, Amount, ApplicationId, ApplicationPermissions, ChainId,
-        ChainOwnership, ModuleId,
+        ChainOwnership, ModuleId, Timestamp,
     },
     util::BlockingWait,
     views::View,
@@ -56,6 +57,7 @@ async fn operation_initialize_liquidity() {
             amount_1: Amount::ONE,
             virtual_liquidity: false,
             to: None,
+            launch_protection: None,
         })
         .await;
 
@@ -74,6 +76,7 @@ async fn operation_create_pool() {
             amount_0: Amount::ONE,
             amount_1: Amount::ONE,
             to: None,
+            pool_fee_percent_mul_100: None,
         })
         .await;
 
@@ -81,6 +84,53 @@ async fn operation_create_pool() {
     assert_eq!(*swap.state.borrow().pool_id.get(), 1000);
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn operation_create_pool_forwards_fee_tier() {
+    let mut swap = create_and_instantiate_swap();
+    let (meme_1, meme_2) = public_create_pool_test_tokens();
+
+    let response = swap
+        .execute_operation(SwapOperation::CreatePool {
+            token_0: meme_1,
+            token_1: Some(meme_2),
+            amount_0: Amount::ONE,
+            amount_1: Amount::ONE,
+            to: None,
+            pool_fee_percent_mul_100: Some(100),
+        })
+        .await;
+
+    assert!(matches!(response, SwapResponse::Ok));
+    let runtime = swap.runtime.borrow();
+    let requests = runtime.created_send_message_requests();
+    assert!(matches!(
+        requests.last().unwrap().message,
+        SwapMessage::CreateUserPool {
+            pool_fee_percent_mul_100: 100,
+            ..
+        }
+    ));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn operation_create_pool_rejects_unknown_fee_tier() {
+    let mut swap = create_and_instantiate_swap();
+    let (meme_1, meme_2) = public_create_pool_test_tokens();
+
+    let result = std::panic::AssertUnwindSafe(swap.execute_operation(SwapOperation::CreatePool {
+        token_0: meme_1,
+        token_1: Some(meme_2),
+        amount_0: Amount::ONE,
+        amount_1: Amount::ONE,
+        to: None,
+        pool_fee_percent_mul_100: Some(25),
+    }))
+    .catch_unwind()
+    .await;
+
+    assert_rejected_without_pool_side_effect(&swap, result, meme_1, Some(meme_2));
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn operation_create_pool_rejects_same_token_pair() {
     let mut swap = create_and_instantiate_swap();
@@ -93,6 +143,7 @@ async fn operation_create_pool_rejects_same_token_pair() {
         amount_0: Amount::ONE,
         amount_1: Amount::ONE,
         to: None,
+        pool_fee_percent_mul_100: None,
     }))
     .catch_unwind()
     .await;
@@ -111,6 +162,7 @@ async fn operation_create_pool_rejects_one_sided_zero_amount_0() {
         amount_0: Amount::ZERO,
         amount_1: Amount::ONE,
         to: None,
+        pool_fee_percent_mul_100: None,
     }))
     .catch_unwind()
     .await;
@@ -129,6 +181,7 @@ async fn operation_create_pool_rejects_one_sided_zero_amount_1() {
         amount_0: Amount::ONE,
         amount_1: Amount::ZERO,
         to: None,
+        pool_fee_percent_mul_100: None,
     }))
     .catch_unwind()
     .await;
@@ -147,6 +200,7 @@ async fn operation_create_pool_rejects_shell_only_zero_amounts() {
         amount_0: Amount::ZERO,
         amount_1: Amount::ZERO,
         to: None,
+        pool_fee_percent_mul_100: None,
     }))
     .catch_unwind()
     .await;
@@ -166,6 +220,7 @@ async fn operation_create_pool_accepts_native_pair_with_two_positive_amounts() {
             amount_0: Amount::ONE,
             amount_1: Amount::ONE,
             to: None,
+            pool_fee_percent_mul_100: None,
         })
         .await;
 
@@ -185,6 +240,7 @@ async fn operation_create_pool_rejects_missing_signer_before_creator_chain_fundi
         amount_0: Amount::ONE,
         amount_1: Amount::ONE,
         to: None,
+        pool_fee_percent_mul_100: None,
     }))
     .catch_unwind()
     .await;
@@ -205,6 +261,7 @@ async fn operation_create_pool_uses_chain_fact_for_meme_identity_without_public_
             amount_0: Amount::ONE,
             amount_1: Amount::ONE,
             to: None,
+            pool_fee_percent_mul_100: None,
         })
         .await;
 
@@ -237,6 +294,7 @@ async fn operation_create_pool_rejects_non_meme_token_0() {
         amount_0: Amount::ONE,
         amount_1: Amount::ONE,
         to: None,
+        pool_fee_percent_mul_100: None,
     }))
     .catch_unwind()
     .await;
@@ -269,6 +327,7 @@ async fn operation_create_pool_rejects_non_meme_token_1() {
         amount_0: Amount::ONE,
         amount_1: Amount::ONE,
         to: None,
+        pool_fee_percent_mul_100: None,
     }))
     .catch_unwind()
     .await;
@@ -295,6 +354,7 @@ async fn operation_update_pool_rejects_missing_authenticated_caller() {
         token_1_price: Amount::ONE,
         reserve_0: Amount::ONE,
         reserve_1: Amount::ONE,
+        stats: PoolStats::default(),
     }))
     .catch_unwind()
     .await;
@@ -326,6 +386,7 @@ async fn operation_update_pool_rejects_creator_chain_execution() {
         token_1_price: Amount::ONE,
         reserve_0: Amount::ONE,
         reserve_1: Amount::ONE,
+        stats: PoolStats::default(),
     }))
     .catch_unwind()
     .await;
@@ -359,6 +420,7 @@ async fn operation_update_pool_accepts_application_caller_on_pool_chain() {
             token_1_price: Amount::ONE,
             reserve_0: Amount::ONE,
             reserve_1: Amount::ONE,
+            stats: PoolStats::default(),
         })
         .await;
 
@@ -394,6 +456,7 @@ async fn message_update_pool_rejects_missing_pool_catalog_entry() {
         token_1_price: Amount::ONE,
         reserve_0: Amount::ONE,
         reserve_1: Amount::ONE,
+        stats: PoolStats::default(),
     }))
     .catch_unwind()
     .await;
@@ -422,6 +485,7 @@ async fn message_update_pool_rejects_wrong_origin_chain() {
         token_1_price: Amount::ONE,
         reserve_0: Amount::ONE,
         reserve_1: Amount::ONE,
+        stats: PoolStats::default(),
     }))
     .catch_unwind()
     .await;
@@ -447,6 +511,7 @@ async fn message_update_pool_applies_each_valid_update() {
         token_1_price: Amount::from_str("0.66").unwrap(),
         reserve_0: Amount::from_tokens(100),
         reserve_1: Amount::from_tokens(200),
+        stats: PoolStats::default(),
     })
     .await;
 
@@ -458,6 +523,7 @@ async fn message_update_pool_applies_each_valid_update() {
         token_1_price: Amount::from_str("0.11").unwrap(),
         reserve_0: Amount::from_tokens(900),
         reserve_1: Amount::from_tokens(901),
+        stats: PoolStats::default(),
     })
     .await;
 
@@ -494,6 +560,7 @@ async fn message_update_pool_applies_received_order() {
         token_1_price: Amount::from_str("0.5").unwrap(),
         reserve_0: Amount::from_tokens(120),
         reserve_1: Amount::from_tokens(240),
+        stats: PoolStats::default(),
     })
     .await;
 
@@ -505,6 +572,7 @@ async fn message_update_pool_applies_received_order() {
         token_1_price: Amount::from_str("1.0").unwrap(),
         reserve_0: Amount::from_tokens(80),
         reserve_1: Amount::from_tokens(160),
+        stats: PoolStats::default(),
     })
     .await;
 
@@ -541,6 +609,7 @@ async fn message_update_pool_newer_transaction_advances_state() {
         token_1_price: Amount::from_str("1.0").unwrap(),
         reserve_0: Amount::from_tokens(80),
         reserve_1: Amount::from_tokens(160),
+        stats: PoolStats::default(),
     })
     .await;
 
@@ -552,6 +621,7 @@ async fn message_update_pool_newer_transaction_advances_state() {
         token_1_price: Amount::from_str("0.5").unwrap(),
         reserve_0: Amount::from_tokens(120),
         reserve_1: Amount::from_tokens(240),
+        stats: PoolStats::default(),
     })
     .await;
 
@@ -568,6 +638,50 @@ async fn message_update_pool_newer_transaction_advances_state() {
     assert_eq!(pool.reserve_1, Some(Amount::from_tokens(240)));
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn message_update_pool_records_cumulative_stats() {
+    let mut swap = create_and_instantiate_swap();
+    let (token_0, token_1, pool_application) = create_pool_for_update_tests(&mut swap).await;
+    swap.runtime
+        .borrow_mut()
+        .set_message_origin_chain_id(pool_application.chain_id);
+    let owner = authenticated_account(&swap);
+
+    let stats = PoolStats {
+        volume_0: Amount::from_tokens(30),
+        volume_1: Amount::from_tokens(60),
+        fees_0: Amount::from_str("0.03").unwrap(),
+        fees_1: Amount::from_str("0.06").unwrap(),
+        swap_count: 3,
+        last_trade_at: Some(11.into()),
+    };
+    swap.execute_message(SwapMessage::UpdatePool {
+        token_0,
+        token_1,
+        transaction: pool_transaction(100, owner, 11),
+        token_0_price: Amount::from_str("2.0").unwrap(),
+        token_1_price: Amount::from_str("0.5").unwrap(),
+        reserve_0: Amount::from_tokens(120),
+        reserve_1: Amount::from_tokens(240),
+        stats,
+    })
+    .await;
+
+    let pool = swap
+        .state
+        .borrow()
+        .get_pool(token_0, token_1)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(pool.volume_0, stats.volume_0);
+    assert_eq!(pool.volume_1, stats.volume_1);
+    assert_eq!(pool.fees_0, stats.fees_0);
+    assert_eq!(pool.fees_1, stats.fees_1);
+    assert_eq!(pool.swap_count, 3);
+    assert_eq!(pool.last_trade_at, Some(11.into()));
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn message_create_user_pool_rejects_same_token_pair() {
     let mut swap = create_and_instantiate_swap();
@@ -581,6 +695,7 @@ async fn message_create_user_pool_rejects_same_token_pair() {
         amount_0: Amount::ONE,
         amount_1: Amount::ONE,
         to: None,
+        pool_fee_percent_mul_100: DEFAULT_POOL_FEE_PERCENT_MUL_100,
     }))
     .catch_unwind()
     .await;
@@ -599,6 +714,7 @@ async fn message_create_user_pool_rejects_zero_amount() {
         amount_0: Amount::ZERO,
         amount_1: Amount::ONE,
         to: None,
+        pool_fee_percent_mul_100: DEFAULT_POOL_FEE_PERCENT_MUL_100,
     }))
     .catch_unwind()
     .await;
@@ -626,6 +742,7 @@ async fn message_create_user_pool_emits_create_pool_to_new_pool_chain() {
         amount_0: Amount::ONE,
         amount_1: Amount::ONE,
         to: None,
+        pool_fee_percent_mul_100: DEFAULT_POOL_FEE_PERCENT_MUL_100,
     })
     .await;
 
@@ -687,6 +804,7 @@ async fn message_create_user_pool_refunds_open_chain_budget_for_duplicate_existi
         amount_0: Amount::ONE,
         amount_1: Amount::ONE,
         to: None,
+        pool_fee_percent_mul_100: DEFAULT_POOL_FEE_PERCENT_MUL_100,
     })
     .await;
 
@@ -748,6 +866,7 @@ async fn message_pool_created_ignores_wrong_chain_receipt() {
             virtual_initial_liquidity: false,
         },
         to: None,
+        pool_fee_percent_mul_100: DEFAULT_POOL_FEE_PERCENT_MUL_100,
     })
     .await;
 
@@ -817,6 +936,7 @@ async fn create_pool_operation_pool_chain_created_pool_created_and_update_pool_a
             amount_0: Amount::ONE,
             amount_1: Amount::ONE,
             to: None,
+            pool_fee_percent_mul_100: None,
         })
         .await;
 
@@ -853,6 +973,7 @@ async fn create_pool_operation_pool_chain_created_pool_created_and_update_pool_a
             virtual_initial_liquidity: false,
         },
         to: None,
+        pool_fee_percent_mul_100: DEFAULT_POOL_FEE_PERCENT_MUL_100,
     })
     .await;
 
@@ -908,6 +1029,7 @@ async fn message_pool_created_ignores_duplicate_receipt() {
             virtual_initial_liquidity: false,
         },
         to: None,
+        pool_fee_percent_mul_100: DEFAULT_POOL_FEE_PERCENT_MUL_100,
     };
 
     swap.execute_message(message.clone()).await;
@@ -975,6 +1097,7 @@ async fn message_pool_created_rejects_different_pool_for_existing_pair() {
             token_0,
             Some(token_1),
             existing_pool_application,
+            DEFAULT_POOL_FEE_PERCENT_MUL_100,
             1.into(),
         )
         .await
@@ -989,6 +1112,7 @@ async fn message_pool_created_rejects_different_pool_for_existing_pair() {
         amount_1: Amount::ONE,
         bootstrap_policy: BootstrapPolicy::UserCreatePool,
         to: None,
+        pool_fee_percent_mul_100: DEFAULT_POOL_FEE_PERCENT_MUL_100,
     }))
     .catch_unwind()
     .await;
@@ -997,203 +1121,186 @@ async fn message_pool_created_rejects_different_pool_for_existing_pair() {
 }
 
 #[tokio::test(flavor = "multi_thread")]
-async fn message_pool_created_user_bootstrap_branch_does_not_call_meme_initialize_liquidity() {
-    let call_count = Rc::new(RefCell::new(0usize));
-    let mut swap = create_and_instantiate_swap_with_call_handler({
-        let call_count = call_count.clone();
-        move |_authenticated, _application_id, _operation| {
-            *call_count.borrow_mut() += 1;
-            bcs::to_bytes(&MemeResponse::Ok).unwrap()
-        }
-    });
+async fn message_pool_created_accepts_other_fee_tier_for_existing_pair() {
+    let mut swap = create_and_instantiate_swap();
+    let (token_0, token_1, existing_pool_application) =
+        create_pool_for_update_tests(&mut swap).await;
     let creator = authenticated_account(&swap);
-    let token_0 =
-        ApplicationId::from_str("b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bad")
-            .unwrap();
-    let token_1 =
-        ApplicationId::from_str("b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bae")
-            .unwrap();
     let pool_chain_id =
-        ChainId::from_str("aee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfea")
+        ChainId::from_str("bee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfea")
             .unwrap();
     let pool_application = Account {
         chain_id: pool_chain_id,
         owner: AccountOwner::from(
             ApplicationId::from_str(
-                "b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bb2",
+                "b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bb3",
             )
             .unwrap(),
         ),
     };
-
     swap.state
         .borrow_mut()
         .create_pool_chain(pool_chain_id)
         .unwrap();
 
+    // Created in reverse token order, it is still the same pair
     swap.execute_message(SwapMessage::PoolCreated {
         creator,
         pool_application,
-        token_0,
-        token_1: Some(token_1),
+        token_0: token_1.unwrap(),
+        token_1: Some(token_0),
         amount_0: Amount::ONE,
         amount_1: Amount::ONE,
         bootstrap_policy: BootstrapPolicy::UserCreatePool,
         to: None,
+        pool_fee_percent_mul_100: 100,
     })
     .await;
 
-    assert_eq!(*call_count.borrow(), 0);
-    let runtime = swap.runtime.borrow();
-    let requests = runtime.created_send_message_requests();
-    let request = requests.last().unwrap();
-    assert_eq!(request.destination, creator.chain_id);
-    assert!(!request.is_tracked);
-    assert!(matches!(
-        request.message,
-        SwapMessage::UserPoolCreated { .. }
-    ));
+    let pools = swap
+        .state
+        .borrow()
+        .get_pools_exchangable(token_0, token_1)
+        .await
+        .unwrap();
+    assert_eq!(pools.len(), 2);
+    assert_eq!(pools[0].pool_application, existing_pool_application);
+    assert_eq!(
+        pools[0].pool_fee_percent_mul_100,
+        DEFAULT_POOL_FEE_PERCENT_MUL_100
+    );
+    assert_eq!(pools[1].pool_application, pool_application);
+    assert_eq!(pools[1].pool_fee_percent_mul_100, 100);
 }
 
 #[tokio::test(flavor = "multi_thread")]
-async fn message_pool_created_meme_bootstrap_branch_calls_meme_initialize_liquidity() {
-    let call_count = Rc::new(RefCell::new(0usize));
-    let mut swap = create_and_instantiate_swap_with_call_handler({
-        let call_count = call_count.clone();
-        move |_authenticated, _application_id, _operation| {
-            *call_count.borrow_mut() += 1;
-            bcs::to_bytes(&MemeResponse::Ok).unwrap()
-        }
-    });
+async fn message_update_pool_updates_fee_tier_pool_of_origin_chain() {
+    let mut swap = create_and_instantiate_swap();
+    let (token_0, token_1, existing_pool_application) =
+        create_pool_for_update_tests(&mut swap).await;
     let creator = authenticated_account(&swap);
-    let token_0 =
-        ApplicationId::from_str("b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bad")
-            .unwrap();
-    let pool_chain_id =
-        ChainId::from_str("aee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfea")
-            .unwrap();
     let pool_application = Account {
-        chain_id: pool_chain_id,
+        chain_id: ChainId::from_str(
+            "bee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfea",
+        )
+        .unwrap(),
         owner: AccountOwner::from(
             ApplicationId::from_str(
-                "b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bb4",
+                "b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bb3",
             )
             .unwrap(),
         ),
     };
-
     swap.state
         .borrow_mut()
-        .create_pool_chain(pool_chain_id)
+        .create_pool(creator, token_0, token_1, pool_application, 5, 1.into())
+        .await
         .unwrap();
+    swap.runtime
+        .borrow_mut()
+        .set_message_origin_chain_id(pool_application.chain_id);
 
-    swap.execute_message(SwapMessage::PoolCreated {
-        creator,
-        pool_application,
+    swap.execute_message(SwapMessage::UpdatePool {
         token_0,
-        token_1: None,
-        amount_0: Amount::ONE,
-        amount_1: Amount::ONE,
-        bootstrap_policy: BootstrapPolicy::MemeInitializeLiquidity {
-            virtual_initial_liquidity: true,
-        },
-        to: None,
+        token_1,
+        transaction: pool_transaction(100, creator, 10),
+        token_0_price: Amount::ONE,
+        token_1_price: Amount::ONE,
+        reserve_0: Amount::from_tokens(100),
+        reserve_1: Amount::from_tokens(100),
+        stats: PoolStats::default(),
     })
     .await;
 
-    assert_eq!(*call_count.borrow(), 1);
+    let pools = swap
+        .state
+        .borrow()
+        .get_pools(token_0, token_1)
+        .await
+        .unwrap();
+    assert_eq!(pools[0].pool_application, existing_pool_application);
+    assert_eq!(pools[0].reserve_0, None);
+    assert_eq!(pools[1].reserve_0, Some(Amount::from_tokens(100)));
+
+    // Swaps are routed through the deepest pool of the pair
+    let pool = swap
+        .state
+        .borrow()
+        .get_pool(token_0, token_1)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(pool.pool_application, pool_application);
+    assert_eq!(pool.pool_fee_percent_mul_100, 5);
 }
 
 #[tokio::test(flavor = "multi_thread")]
-async fn message_user_pool_created_receipt_is_app_created_only() {
-    let call_count = Rc::new(RefCell::new(0usize));
-    let mut swap = create_and_instantiate_swap_with_call_handler({
-        let call_count = call_count.clone();
-        move |_authenticated, _application_id, _operation| {
-            *call_count.borrow_mut() += 1;
-            bcs::to_bytes(&MemeResponse::Ok).unwrap()
-        }
-    });
+async fn message_update_pool_status_routes_around_paused_pool() {
+    let mut swap = create_and_instantiate_swap();
+    let (token_0, token_1, existing_pool_application) =
+        create_pool_for_update_tests(&mut swap).await;
     let creator = authenticated_account(&swap);
-    let token_0 =
-        ApplicationId::from_str("b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bad")
-            .unwrap();
-    let token_1 =
-        ApplicationId::from_str("b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bae")
-            .unwrap();
-    let pool_chain_id =
-        ChainId::from_str("aee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfea")
-            .unwrap();
     let pool_application = Account {
-        chain_id: pool_chain_id,
+        chain_id: ChainId::from_str(
+            "bee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfea",
+        )
+        .unwrap(),
         owner: AccountOwner::from(
             ApplicationId::from_str(
-                "b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bb2",
+                "b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bb3",
             )
             .unwrap(),
         ),
     };
-
     swap.state
         .borrow_mut()
-        .create_pool_chain(pool_chain_id)
+        .create_pool(creator, token_0, token_1, pool_application, 5, 1.into())
+        .await
         .unwrap();
+    swap.runtime
+        .borrow_mut()
+        .set_message_origin_chain_id(pool_application.chain_id);
 
-    swap.execute_message(SwapMessage::PoolCreated {
-        creator,
-        pool_application,
+    swap.execute_message(SwapMessage::UpdatePool {
         token_0,
-        token_1: Some(token_1),
-        amount_0: Amount::ONE,
-        amount_1: Amount::ONE,
-        bootstrap_policy: BootstrapPolicy::UserCreatePool,
-        to: None,
+        token_1,
+        transaction: pool_transaction(100, creator, 10),
+        token_0_price: Amount::ONE,
+        token_1_price: Amount::ONE,
+        reserve_0: Amount::from_tokens(100),
+        reserve_1: Amount::from_tokens(100),
+        stats: PoolStats::default(),
     })
     .await;
+    swap.execute_message(SwapMessage::UpdatePoolStatus {
+        token_0,
+        token_1,
+        status: PoolStatus::SwapsPaused,
+    })
+    .await;
+
+    let pools = swap
+        .state
+        .borrow()
+        .get_pools(token_0, token_1)
+        .await
+        .unwrap();
+    assert_eq!(pools[0].status, PoolStatus::Active);
+    assert_eq!(pools[1].status, PoolStatus::SwapsPaused);
 
+    // Deeper but paused pool loses to the active one
     let pool = swap
         .state
         .borrow()
-        .get_pool(token_0, Some(token_1))
+        .get_pool(token_0, token_1)
         .await
         .unwrap()
         .unwrap();
-    assert_eq!(pool.pool_application, pool_application);
-    assert_eq!(pool.reserve_0, None);
-    assert_eq!(pool.reserve_1, None);
-    assert_eq!(pool.token_0_price, None);
-    assert_eq!(pool.token_1_price, None);
-    assert_eq!(*call_count.borrow(), 0);
-}
-
-#[tokio::test(flavor = "multi_thread")]
-async fn message_user_pool_created_rejects_non_application_pool_owner() {
-    let mut swap = create_and_instantiate_swap();
-    let pool_application = Account {
-        chain_id: ChainId::from_str(
-            "aee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfea",
-        )
-        .unwrap(),
-        owner: AccountOwner::CHAIN,
-    };
-    let (token_0, token_1) = public_create_pool_test_tokens();
-
-    let result = std::panic::AssertUnwindSafe(swap.execute_message(SwapMessage::UserPoolCreated {
-        pool_application,
-        token_0,
-        token_1: Some(token_1),
-        amount_0: Amount::ONE,
-        amount_1: Amount::ONE,
-        to: None,
-    }))
-    .catch_unwind()
-    .await;
-
-    assert!(result.is_err());
+    assert_eq!(pool.pool_application, existing_pool_application);
 }
 
 #[tokio::test(flavor = "multi_thread")]
-async fn message_user_pool_created_calls_pool_add_liquidity() {
+async fn operation_set_pool_status_calls_pool_on_creator_chain() {
     let observed_calls = Rc::new(RefCell::new(Vec::<(ApplicationId, Vec<u8>)>::new()));
     let mut swap = create_and_instantiate_swap_with_call_handler({
         let observed_calls = observed_calls.clone();
@@ -1201,12 +1308,714 @@ async fn message_user_pool_created_calls_pool_add_liquidity() {
             observed_calls
                 .borrow_mut()
                 .push((application_id, operation.clone()));
-            bcs::to_bytes(&MemeResponse::Ok).unwrap()
+            bcs::to_bytes(&PoolResponse::Ok).unwrap()
         }
     });
-    let pool_application = Account {
-        chain_id: ChainId::from_str(
-            "aee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfea",
+    let (_token_0, _token_1, pool_application) = create_pool_for_update_tests(&mut swap).await;
+
+    swap.execute_operation(SwapOperation::SetPoolStatus {
+        pool_application,
+        status: PoolStatus::FullyPaused,
+    })
+    .await;
+
+    let calls = observed_calls.borrow();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].0, route_pool_application_id(pool_application));
+    assert!(matches!(
+        bcs::from_bytes::<PoolOperation>(&calls[0].1).unwrap(),
+        PoolOperation::SetPoolStatus {
+            status: PoolStatus::FullyPaused
+        }
+    ));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn operation_set_pool_bytecode_id_outdates_existing_pool_chains() {
+    let mut swap = create_and_instantiate_swap();
+    let pool_chain_id =
+        ChainId::from_str("bee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfea")
+            .unwrap();
+    swap.state
+        .borrow_mut()
+        .create_pool_chain(pool_chain_id)
+        .unwrap();
+    assert!(!swap
+        .state
+        .borrow()
+        .is_outdated_pool_chain(pool_chain_id)
+        .await
+        .unwrap());
+
+    let creator_chain_id = swap.runtime.borrow_mut().application_creator_chain_id();
+    swap.runtime.borrow_mut().set_chain_id(creator_chain_id);
+    let pool_bytecode_id = upgraded_pool_bytecode_id();
+
+    swap.execute_operation(SwapOperation::SetPoolBytecodeId { pool_bytecode_id })
+        .await;
+
+    assert_eq!(swap.state.borrow().pool_bytecode_id(), pool_bytecode_id);
+    assert!(swap
+        .state
+        .borrow()
+        .is_outdated_pool_chain(pool_chain_id)
+        .await
+        .unwrap());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn operation_set_pool_bytecode_id_rejects_non_creator_chain() {
+    let mut swap = create_and_instantiate_swap();
+
+    let result =
+        std::panic::AssertUnwindSafe(swap.execute_operation(SwapOperation::SetPoolBytecodeId {
+            pool_bytecode_id: upgraded_pool_bytecode_id(),
+        }))
+        .catch_unwind()
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_migrate_liquidity_creates_new_version_pool_for_outdated_pool() {
+    let mut swap = create_and_instantiate_swap();
+    let (token_0, token_1, pool_application) = create_pool_for_migration_tests(&mut swap).await;
+    let origin = authenticated_account(&swap);
+    let pool_bytecode_id = upgraded_pool_bytecode_id();
+    swap.state
+        .borrow_mut()
+        .set_pool_bytecode_id(pool_bytecode_id);
+    swap.runtime
+        .borrow_mut()
+        .set_message_origin_chain_id(pool_application.chain_id);
+
+    swap.execute_message(SwapMessage::MigrateLiquidity {
+        origin,
+        pool_application,
+        token_0,
+        token_1,
+        pool_fee_percent_mul_100: DEFAULT_POOL_FEE_PERCENT_MUL_100,
+        amount_0: Amount::ONE,
+        amount_1: Amount::from_tokens(2),
+        amount_0_out_min: Some(Amount::ONE),
+        amount_1_out_min: None,
+        deadline: Some(Timestamp::from(100)),
+    })
+    .await;
+
+    let runtime = swap.runtime.borrow();
+    let requests = runtime.created_send_message_requests();
+    let request = requests.last().unwrap();
+    let SwapMessage::CreatePool {
+        creator,
+        pool_bytecode_id: message_pool_bytecode_id,
+        amount_0,
+        amount_1,
+        bootstrap_policy,
+        pool_fee_percent_mul_100,
+        ..
+    } = &request.message
+    else {
+        panic!("Expected CreatePool");
+    };
+    assert_eq!(*creator, origin);
+    assert_eq!(*message_pool_bytecode_id, pool_bytecode_id);
+    assert_eq!(*amount_0, Amount::ONE);
+    assert_eq!(*amount_1, Amount::from_tokens(2));
+    assert!(matches!(bootstrap_policy, BootstrapPolicy::UserCreatePool));
+    assert_eq!(*pool_fee_percent_mul_100, DEFAULT_POOL_FEE_PERCENT_MUL_100);
+    let new_pool_chain_id = request.destination;
+    drop(runtime);
+
+    assert_eq!(
+        swap.state
+            .borrow()
+            .pending_pool_upgrade(pool_application.chain_id)
+            .await
+            .unwrap(),
+        Some(new_pool_chain_id)
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_migrate_liquidity_waits_for_opening_pool_version() {
+    let mut swap = create_and_instantiate_swap();
+    let (token_0, token_1, old_pool_application) = create_pool_for_migration_tests(&mut swap).await;
+    let origin = authenticated_account(&swap);
+    let other_origin = Account {
+        chain_id: ChainId::from_str(
+            "dee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfea",
+        )
+        .unwrap(),
+        owner: origin.owner,
+    };
+    swap.state
+        .borrow_mut()
+        .set_pool_bytecode_id(upgraded_pool_bytecode_id());
+    swap.runtime
+        .borrow_mut()
+        .set_message_origin_chain_id(old_pool_application.chain_id);
+
+    swap.execute_message(SwapMessage::MigrateLiquidity {
+        origin,
+        pool_application: old_pool_application,
+        token_0,
+        token_1,
+        pool_fee_percent_mul_100: DEFAULT_POOL_FEE_PERCENT_MUL_100,
+        amount_0: Amount::ONE,
+        amount_1: Amount::from_tokens(2),
+        amount_0_out_min: None,
+        amount_1_out_min: None,
+        deadline: None,
+    })
+    .await;
+    let (request_count, new_pool_chain_id) = {
+        let runtime = swap.runtime.borrow();
+        let requests = runtime.created_send_message_requests();
+        (requests.len(), requests.last().unwrap().destination)
+    };
+
+    // Second migration must not open another chain for the same pool
+    swap.execute_message(SwapMessage::MigrateLiquidity {
+        origin: other_origin,
+        pool_application: old_pool_application,
+        token_0,
+        token_1,
+        pool_fee_percent_mul_100: DEFAULT_POOL_FEE_PERCENT_MUL_100,
+        amount_0: Amount::from_tokens(3),
+        amount_1: Amount::from_tokens(4),
+        amount_0_out_min: Some(Amount::ONE),
+        amount_1_out_min: None,
+        deadline: Some(Timestamp::from(100)),
+    })
+    .await;
+    assert_eq!(
+        swap.runtime.borrow().created_send_message_requests().len(),
+        request_count
+    );
+
+    let new_pool_application = Account {
+        chain_id: new_pool_chain_id,
+        owner: AccountOwner::from(
+            ApplicationId::from_str(
+                "b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bb4",
+            )
+            .unwrap(),
+        ),
+    };
+    swap.runtime
+        .borrow_mut()
+        .set_message_origin_chain_id(new_pool_chain_id);
+    swap.execute_message(SwapMessage::PoolCreated {
+        creator: origin,
+        pool_application: new_pool_application,
+        token_0,
+        token_1,
+        amount_0: Amount::ONE,
+        amount_1: Amount::from_tokens(2),
+        bootstrap_policy: BootstrapPolicy::UserCreatePool,
+        to: None,
+        pool_fee_percent_mul_100: DEFAULT_POOL_FEE_PERCENT_MUL_100,
+    })
+    .await;
+
+    assert_eq!(
+        swap.state
+            .borrow()
+            .pool_upgrade(old_pool_application.chain_id)
+            .await
+            .unwrap(),
+        Some(new_pool_application)
+    );
+    assert_eq!(
+        swap.state
+            .borrow()
+            .pending_pool_upgrade(old_pool_application.chain_id)
+            .await
+            .unwrap(),
+        None
+    );
+
+    let runtime = swap.runtime.borrow();
+    let requests = runtime.created_send_message_requests();
+    let request = requests
+        .iter()
+        .skip(request_count)
+        .find(|request| request.destination == other_origin.chain_id)
+        .unwrap();
+    let SwapMessage::ExecuteMigrateLiquidity {
+        origin: message_origin,
+        pool_application,
+        amount_0,
+        amount_1,
+        amount_0_out_min,
+        amount_1_out_min,
+        deadline,
+    } = &request.message
+    else {
+        panic!("Expected ExecuteMigrateLiquidity");
+    };
+    assert_eq!(*message_origin, other_origin);
+    assert_eq!(*pool_application, new_pool_application);
+    assert_eq!(*amount_0, Amount::from_tokens(3));
+    assert_eq!(*amount_1, Amount::from_tokens(4));
+    assert_eq!(*amount_0_out_min, Some(Amount::ONE));
+    assert_eq!(*amount_1_out_min, None);
+    assert_eq!(*deadline, Some(Timestamp::from(100)));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_pool_created_waits_for_promotion_and_migrations_follow_it() {
+    let mut swap = create_and_instantiate_swap();
+    let (token_0, token_1, old_pool_application) = create_pool_for_migration_tests(&mut swap).await;
+    let origin = authenticated_account(&swap);
+    let pool_id = swap
+        .state
+        .borrow()
+        .get_pool(token_0, token_1)
+        .await
+        .unwrap()
+        .unwrap()
+        .pool_id;
+    swap.state
+        .borrow_mut()
+        .set_pool_bytecode_id(upgraded_pool_bytecode_id());
+    let new_pool_application = Account {
+        chain_id: ChainId::from_str(
+            "cee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfea",
+        )
+        .unwrap(),
+        owner: AccountOwner::from(
+            ApplicationId::from_str(
+                "b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bb4",
+            )
+            .unwrap(),
+        ),
+    };
+    swap.state
+        .borrow_mut()
+        .create_pool_chain(new_pool_application.chain_id)
+        .unwrap();
+    swap.state
+        .borrow_mut()
+        .open_pool_upgrade(old_pool_application.chain_id, new_pool_application.chain_id)
+        .unwrap();
+
+    swap.execute_message(SwapMessage::PoolCreated {
+        creator: origin,
+        pool_application: new_pool_application,
+        token_0,
+        token_1,
+        amount_0: Amount::ONE,
+        amount_1: Amount::ONE,
+        bootstrap_policy: BootstrapPolicy::UserCreatePool,
+        to: None,
+        pool_fee_percent_mul_100: DEFAULT_POOL_FEE_PERCENT_MUL_100,
+    })
+    .await;
+
+    // Catalog keeps the old version until operators promote the new one
+    let pools = swap
+        .state
+        .borrow()
+        .get_pools_exchangable(token_0, token_1)
+        .await
+        .unwrap();
+    assert_eq!(pools.len(), 1);
+    assert_eq!(pools[0].pool_application, old_pool_application);
+    assert!(swap
+        .state
+        .borrow()
+        .is_pool_upgrade_chain(new_pool_application.chain_id)
+        .await
+        .unwrap());
+
+    // Later migrations from the old pool deposit into the new version right away
+    swap.runtime
+        .borrow_mut()
+        .set_message_origin_chain_id(old_pool_application.chain_id);
+    swap.execute_message(SwapMessage::MigrateLiquidity {
+        origin,
+        pool_application: old_pool_application,
+        token_0,
+        token_1,
+        pool_fee_percent_mul_100: DEFAULT_POOL_FEE_PERCENT_MUL_100,
+        amount_0: Amount::ONE,
+        amount_1: Amount::from_tokens(2),
+        amount_0_out_min: Some(Amount::ONE),
+        amount_1_out_min: None,
+        deadline: Some(Timestamp::from(100)),
+    })
+    .await;
+
+    {
+        let runtime = swap.runtime.borrow();
+        let requests = runtime.created_send_message_requests();
+        let request = requests.last().unwrap();
+        assert_eq!(request.destination, origin.chain_id);
+        let SwapMessage::ExecuteMigrateLiquidity {
+            origin: message_origin,
+            pool_application,
+            amount_0,
+            amount_1,
+            amount_0_out_min,
+            amount_1_out_min,
+            deadline,
+        } = &request.message
+        else {
+            panic!("Expected ExecuteMigrateLiquidity");
+        };
+        assert_eq!(*message_origin, origin);
+        assert_eq!(*pool_application, new_pool_application);
+        assert_eq!(*amount_0, Amount::ONE);
+        assert_eq!(*amount_1, Amount::from_tokens(2));
+        assert_eq!(*amount_0_out_min, Some(Amount::ONE));
+        assert_eq!(*amount_1_out_min, None);
+        assert_eq!(*deadline, Some(Timestamp::from(100)));
+    }
+
+    let creator_chain_id = swap.runtime.borrow_mut().application_creator_chain_id();
+    swap.runtime.borrow_mut().set_chain_id(creator_chain_id);
+    swap.execute_operation(SwapOperation::PromotePoolVersion {
+        token_0,
+        token_1,
+        pool_fee_percent_mul_100: DEFAULT_POOL_FEE_PERCENT_MUL_100,
+    })
+    .await;
+
+    let pools = swap
+        .state
+        .borrow()
+        .get_pools_exchangable(token_0, token_1)
+        .await
+        .unwrap();
+    assert_eq!(pools.len(), 1);
+    assert_eq!(pools[0].pool_id, pool_id);
+    assert_eq!(pools[0].pool_application, new_pool_application);
+    assert!(swap
+        .state
+        .borrow()
+        .is_migrated_pool_chain(old_pool_application.chain_id)
+        .await
+        .unwrap());
+    assert!(!swap
+        .state
+        .borrow()
+        .is_pool_upgrade_chain(new_pool_application.chain_id)
+        .await
+        .unwrap());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn operation_promote_pool_version_rejects_non_creator_chain() {
+    let mut swap = create_and_instantiate_swap();
+    let (token_0, token_1, _) = create_pool_for_migration_tests(&mut swap).await;
+
+    let result =
+        std::panic::AssertUnwindSafe(swap.execute_operation(SwapOperation::PromotePoolVersion {
+            token_0,
+            token_1,
+            pool_fee_percent_mul_100: DEFAULT_POOL_FEE_PERCENT_MUL_100,
+        }))
+        .catch_unwind()
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_execute_migrate_liquidity_calls_pool_add_liquidity() {
+    let observed_calls = Rc::new(RefCell::new(Vec::<(ApplicationId, Vec<u8>)>::new()));
+    let mut swap = create_and_instantiate_swap_with_call_handler({
+        let observed_calls = observed_calls.clone();
+        move |_authenticated, application_id, operation| {
+            observed_calls
+                .borrow_mut()
+                .push((application_id, operation.clone()));
+            bcs::to_bytes(&PoolResponse::Ok).unwrap()
+        }
+    });
+    let (_token_0, _token_1, pool_application) = create_pool_for_update_tests(&mut swap).await;
+    let origin = authenticated_account(&swap);
+    let creator_chain_id = swap.runtime.borrow_mut().application_creator_chain_id();
+    swap.runtime
+        .borrow_mut()
+        .set_message_origin_chain_id(creator_chain_id);
+
+    swap.execute_message(SwapMessage::ExecuteMigrateLiquidity {
+        origin,
+        pool_application,
+        amount_0: Amount::ONE,
+        amount_1: Amount::from_tokens(2),
+        amount_0_out_min: Some(Amount::ONE),
+        amount_1_out_min: Some(Amount::from_tokens(2)),
+        deadline: Some(Timestamp::from(100)),
+    })
+    .await;
+
+    let calls = observed_calls.borrow();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].0, route_pool_application_id(pool_application));
+    let PoolOperation::AddLiquidity {
+        amount_0_in,
+        amount_1_in,
+        amount_0_out_min,
+        amount_1_out_min,
+        to,
+        deadline,
+        ..
+    } = bcs::from_bytes::<PoolOperation>(&calls[0].1).unwrap()
+    else {
+        panic!("Expected AddLiquidity");
+    };
+    assert_eq!(amount_0_in, Amount::ONE);
+    assert_eq!(amount_1_in, Amount::from_tokens(2));
+    assert_eq!(amount_0_out_min, Some(Amount::ONE));
+    assert_eq!(amount_1_out_min, Some(Amount::from_tokens(2)));
+    assert_eq!(to, None);
+    assert_eq!(deadline, Some(Timestamp::from(100)));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_execute_migrate_liquidity_rejects_non_creator_chain() {
+    let mut swap = create_and_instantiate_swap();
+    let (_token_0, _token_1, pool_application) = create_pool_for_update_tests(&mut swap).await;
+    let origin = authenticated_account(&swap);
+    swap.runtime
+        .borrow_mut()
+        .set_message_origin_chain_id(pool_application.chain_id);
+
+    let result =
+        std::panic::AssertUnwindSafe(swap.execute_message(SwapMessage::ExecuteMigrateLiquidity {
+            origin,
+            pool_application,
+            amount_0: Amount::ONE,
+            amount_1: Amount::from_tokens(2),
+            amount_0_out_min: None,
+            amount_1_out_min: None,
+            deadline: None,
+        }))
+        .catch_unwind()
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_pool_created_user_bootstrap_branch_does_not_call_meme_initialize_liquidity() {
+    let call_count = Rc::new(RefCell::new(0usize));
+    let mut swap = create_and_instantiate_swap_with_call_handler({
+        let call_count = call_count.clone();
+        move |_authenticated, _application_id, _operation| {
+            *call_count.borrow_mut() += 1;
+            bcs::to_bytes(&MemeResponse::Ok).unwrap()
+        }
+    });
+    let creator = authenticated_account(&swap);
+    let token_0 =
+        ApplicationId::from_str("b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bad")
+            .unwrap();
+    let token_1 =
+        ApplicationId::from_str("b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bae")
+            .unwrap();
+    let pool_chain_id =
+        ChainId::from_str("aee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfea")
+            .unwrap();
+    let pool_application = Account {
+        chain_id: pool_chain_id,
+        owner: AccountOwner::from(
+            ApplicationId::from_str(
+                "b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bb2",
+            )
+            .unwrap(),
+        ),
+    };
+
+    swap.state
+        .borrow_mut()
+        .create_pool_chain(pool_chain_id)
+        .unwrap();
+
+    swap.execute_message(SwapMessage::PoolCreated {
+        creator,
+        pool_application,
+        token_0,
+        token_1: Some(token_1),
+        amount_0: Amount::ONE,
+        amount_1: Amount::ONE,
+        bootstrap_policy: BootstrapPolicy::UserCreatePool,
+        to: None,
+        pool_fee_percent_mul_100: DEFAULT_POOL_FEE_PERCENT_MUL_100,
+    })
+    .await;
+
+    assert_eq!(*call_count.borrow(), 0);
+    let runtime = swap.runtime.borrow();
+    let requests = runtime.created_send_message_requests();
+    let request = requests.last().unwrap();
+    assert_eq!(request.destination, creator.chain_id);
+    assert!(!request.is_tracked);
+    assert!(matches!(
+        request.message,
+        SwapMessage::UserPoolCreated { .. }
+    ));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_pool_created_meme_bootstrap_branch_calls_meme_initialize_liquidity() {
+    let call_count = Rc::new(RefCell::new(0usize));
+    let mut swap = create_and_instantiate_swap_with_call_handler({
+        let call_count = call_count.clone();
+        move |_authenticated, _application_id, _operation| {
+            *call_count.borrow_mut() += 1;
+            bcs::to_bytes(&MemeResponse::Ok).unwrap()
+        }
+    });
+    let creator = authenticated_account(&swap);
+    let token_0 =
+        ApplicationId::from_str("b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bad")
+            .unwrap();
+    let pool_chain_id =
+        ChainId::from_str("aee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfea")
+            .unwrap();
+    let pool_application = Account {
+        chain_id: pool_chain_id,
+        owner: AccountOwner::from(
+            ApplicationId::from_str(
+                "b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bb4",
+            )
+            .unwrap(),
+        ),
+    };
+
+    swap.state
+        .borrow_mut()
+        .create_pool_chain(pool_chain_id)
+        .unwrap();
+
+    swap.execute_message(SwapMessage::PoolCreated {
+        creator,
+        pool_application,
+        token_0,
+        token_1: None,
+        amount_0: Amount::ONE,
+        amount_1: Amount::ONE,
+        bootstrap_policy: BootstrapPolicy::MemeInitializeLiquidity {
+            virtual_initial_liquidity: true,
+        },
+        to: None,
+        pool_fee_percent_mul_100: DEFAULT_POOL_FEE_PERCENT_MUL_100,
+    })
+    .await;
+
+    assert_eq!(*call_count.borrow(), 1);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_user_pool_created_receipt_is_app_created_only() {
+    let call_count = Rc::new(RefCell::new(0usize));
+    let mut swap = create_and_instantiate_swap_with_call_handler({
+        let call_count = call_count.clone();
+        move |_authenticated, _application_id, _operation| {
+            *call_count.borrow_mut() += 1;
+            bcs::to_bytes(&MemeResponse::Ok).unwrap()
+        }
+    });
+    let creator = authenticated_account(&swap);
+    let token_0 =
+        ApplicationId::from_str("b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bad")
+            .unwrap();
+    let token_1 =
+        ApplicationId::from_str("b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bae")
+            .unwrap();
+    let pool_chain_id =
+        ChainId::from_str("aee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfea")
+            .unwrap();
+    let pool_application = Account {
+        chain_id: pool_chain_id,
+        owner: AccountOwner::from(
+            ApplicationId::from_str(
+                "b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bb2",
+            )
+            .unwrap(),
+        ),
+    };
+
+    swap.state
+        .borrow_mut()
+        .create_pool_chain(pool_chain_id)
+        .unwrap();
+
+    swap.execute_message(SwapMessage::PoolCreated {
+        creator,
+        pool_application,
+        token_0,
+        token_1: Some(token_1),
+        amount_0: Amount::ONE,
+        amount_1: Amount::ONE,
+        bootstrap_policy: BootstrapPolicy::UserCreatePool,
+        to: None,
+        pool_fee_percent_mul_100: DEFAULT_POOL_FEE_PERCENT_MUL_100,
+    })
+    .await;
+
+    let pool = swap
+        .state
+        .borrow()
+        .get_pool(token_0, Some(token_1))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(pool.pool_application, pool_application);
+    assert_eq!(pool.reserve_0, None);
+    assert_eq!(pool.reserve_1, None);
+    assert_eq!(pool.token_0_price, None);
+    assert_eq!(pool.token_1_price, None);
+    assert_eq!(*call_count.borrow(), 0);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_user_pool_created_rejects_non_application_pool_owner() {
+    let mut swap = create_and_instantiate_swap();
+    let pool_application = Account {
+        chain_id: ChainId::from_str(
+            "aee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfea",
+        )
+        .unwrap(),
+        owner: AccountOwner::CHAIN,
+    };
+    let (token_0, token_1) = public_create_pool_test_tokens();
+
+    let result = std::panic::AssertUnwindSafe(swap.execute_message(SwapMessage::UserPoolCreated {
+        pool_application,
+        token_0,
+        token_1: Some(token_1),
+        amount_0: Amount::ONE,
+        amount_1: Amount::ONE,
+        to: None,
+    }))
+    .catch_unwind()
+    .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_user_pool_created_calls_pool_add_liquidity() {
+    let observed_calls = Rc::new(RefCell::new(Vec::<(ApplicationId, Vec<u8>)>::new()));
+    let mut swap = create_and_instantiate_swap_with_call_handler({
+        let observed_calls = observed_calls.clone();
+        move |_authenticated, application_id, operation| {
+            observed_calls
+                .borrow_mut()
+                .push((application_id, operation.clone()));
+            bcs::to_bytes(&MemeResponse::Ok).unwrap()
+        }
+    });
+    let pool_application = Account {
+        chain_id: ChainId::from_str(
+            "aee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfea",
         )
         .unwrap(),
         owner: AccountOwner::from(
@@ -1216,50 +2025,658 @@ async fn message_user_pool_created_calls_pool_add_liquidity() {
             .unwrap(),
         ),
     };
-    let token_0 =
-        ApplicationId::from_str("b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bad")
-            .unwrap();
-    let token_1 =
-        ApplicationId::from_str("b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bae")
-            .unwrap();
+    let token_0 =
+        ApplicationId::from_str("b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bad")
+            .unwrap();
+    let token_1 =
+        ApplicationId::from_str("b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bae")
+            .unwrap();
+
+    swap.execute_message(SwapMessage::UserPoolCreated {
+        pool_application,
+        token_0,
+        token_1: Some(token_1),
+        amount_0: Amount::ONE,
+        amount_1: Amount::ONE,
+        to: None,
+    })
+    .await;
+
+    let calls = observed_calls.borrow();
+    assert_eq!(calls.len(), 1);
+
+    let AccountOwner::Address32(application_description_hash) = pool_application.owner else {
+        panic!("Invalid owner");
+    };
+    let expected_application_id = ApplicationId::new(application_description_hash);
+    assert_eq!(calls[0].0, expected_application_id.forget_abi());
+
+    let operation = bcs::from_bytes::<PoolOperation>(&calls[0].1).unwrap();
+    let PoolOperation::AddLiquidity {
+        amount_0_in,
+        amount_1_in,
+        amount_0_out_min,
+        amount_1_out_min,
+        to,
+        block_timestamp,
+        deadline: None,
+    } = operation
+    else {
+        panic!("Expected AddLiquidity");
+    };
+    assert_eq!(amount_0_in, Amount::ONE);
+    assert_eq!(amount_1_in, Amount::ONE);
+    assert_eq!(amount_0_out_min, None);
+    assert_eq!(amount_1_out_min, None);
+    assert_eq!(to, None);
+    assert_eq!(block_timestamp, None);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn operation_swap_rejects_single_token_path() {
+    let mut swap = create_and_instantiate_swap();
+    let (meme_1, _) = public_create_pool_test_tokens();
+
+    let result = std::panic::AssertUnwindSafe(swap.execute_operation(SwapOperation::Swap {
+        path: vec![Some(meme_1)],
+        amount_in: Amount::ONE,
+        amount_out_min: None,
+        deadline: None,
+    }))
+    .catch_unwind()
+    .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn operation_swap_resolves_route_on_creator_chain() {
+    let mut swap = create_and_instantiate_swap();
+    let (meme_1, meme_2) = public_create_pool_test_tokens();
+    let origin = authenticated_account(&swap);
+
+    let response = swap
+        .execute_operation(SwapOperation::Swap {
+            path: vec![Some(meme_1), None, Some(meme_2)],
+            amount_in: Amount::ONE,
+            amount_out_min: Some(Amount::ONE),
+            deadline: None,
+        })
+        .await;
+
+    assert!(matches!(response, SwapResponse::Ok));
+    let expected_destination = swap.runtime.borrow_mut().application_creator_chain_id();
+    let runtime = swap.runtime.borrow();
+    let requests = runtime.created_send_message_requests();
+    let request = requests.last().unwrap();
+    assert_eq!(request.destination, expected_destination);
+    let SwapMessage::Swap {
+        origin: message_origin,
+        path,
+        ..
+    } = &request.message
+    else {
+        panic!("Expected Swap");
+    };
+    assert_eq!(*message_origin, origin);
+    assert_eq!(path.len(), 3);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_swap_resolves_route_through_native_pools() {
+    let mut swap = create_and_instantiate_swap();
+    let (meme_1, meme_2, pool_application_1, pool_application_2) =
+        create_native_pools_for_route_tests(&mut swap).await;
+    let origin = authenticated_account(&swap);
+    let creator_chain_id = swap.runtime.borrow_mut().application_creator_chain_id();
+    swap.runtime.borrow_mut().set_chain_id(creator_chain_id);
+
+    swap.execute_message(SwapMessage::Swap {
+        origin,
+        path: vec![Some(meme_1), None, Some(meme_2)],
+        amount_in: Amount::ONE,
+        amount_out_min: Some(Amount::ONE),
+        deadline: None,
+    })
+    .await;
+
+    let runtime = swap.runtime.borrow();
+    let requests = runtime.created_send_message_requests();
+    let request = requests.last().unwrap();
+    assert_eq!(request.destination, origin.chain_id);
+    let SwapMessage::ExecuteSwapRoute {
+        origin: message_origin,
+        route,
+        amount_in,
+    } = &request.message
+    else {
+        panic!("Expected ExecuteSwapRoute");
+    };
+    assert_eq!(*message_origin, origin);
+    assert_eq!(*amount_in, Amount::ONE);
+    assert_eq!(route.amount_out_min, Some(Amount::ONE));
+    assert_eq!(
+        route.hops,
+        vec![
+            SwapRouteHop {
+                pool_application: pool_application_1,
+                token_0: meme_1,
+                token_1: None,
+                token_in: Some(meme_1),
+            },
+            SwapRouteHop {
+                pool_application: pool_application_2,
+                token_0: meme_2,
+                token_1: None,
+                token_in: None,
+            },
+        ]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_swap_rejects_missing_hop_pool() {
+    let mut swap = create_and_instantiate_swap();
+    let (meme_1, meme_2) = public_create_pool_test_tokens();
+    let origin = authenticated_account(&swap);
+    let creator_chain_id = swap.runtime.borrow_mut().application_creator_chain_id();
+    swap.runtime.borrow_mut().set_chain_id(creator_chain_id);
+
+    let result = std::panic::AssertUnwindSafe(swap.execute_message(SwapMessage::Swap {
+        origin,
+        path: vec![Some(meme_1), Some(meme_2)],
+        amount_in: Amount::ONE,
+        amount_out_min: None,
+        deadline: None,
+    }))
+    .catch_unwind()
+    .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_execute_swap_route_calls_first_hop_pool() {
+    let observed_calls = Rc::new(RefCell::new(Vec::<(ApplicationId, Vec<u8>)>::new()));
+    let mut swap = create_and_instantiate_swap_with_call_handler({
+        let observed_calls = observed_calls.clone();
+        move |_authenticated, application_id, operation| {
+            observed_calls
+                .borrow_mut()
+                .push((application_id, operation.clone()));
+            bcs::to_bytes(&MemeResponse::Ok).unwrap()
+        }
+    });
+    let (meme_1, meme_2, pool_application_1, pool_application_2) =
+        create_native_pools_for_route_tests(&mut swap).await;
+    let origin = authenticated_account(&swap);
+    let creator_chain_id = swap.runtime.borrow_mut().application_creator_chain_id();
+    swap.runtime
+        .borrow_mut()
+        .set_message_origin_chain_id(creator_chain_id);
+    let hops = vec![
+        SwapRouteHop {
+            pool_application: pool_application_1,
+            token_0: meme_1,
+            token_1: None,
+            token_in: Some(meme_1),
+        },
+        SwapRouteHop {
+            pool_application: pool_application_2,
+            token_0: meme_2,
+            token_1: None,
+            token_in: None,
+        },
+    ];
+
+    swap.execute_message(SwapMessage::ExecuteSwapRoute {
+        origin,
+        route: SwapRoute {
+            hops: hops.clone(),
+            amount_out_min: Some(Amount::ONE),
+            deadline: None,
+        },
+        amount_in: Amount::ONE,
+    })
+    .await;
+
+    let calls = observed_calls.borrow();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].0, route_pool_application_id(pool_application_1));
+
+    let PoolOperation::Swap {
+        amount_0_in,
+        amount_1_in,
+        amount_0_out_min,
+        amount_1_out_min,
+        route,
+        ..
+    } = bcs::from_bytes::<PoolOperation>(&calls[0].1).unwrap()
+    else {
+        panic!("Expected Swap");
+    };
+    assert_eq!(amount_0_in, Some(Amount::ONE));
+    assert_eq!(amount_1_in, None);
+    assert_eq!(amount_0_out_min, None);
+    assert_eq!(amount_1_out_min, None);
+    assert_eq!(
+        route,
+        Some(SwapRoute {
+            hops: hops[1..].to_vec(),
+            amount_out_min: Some(Amount::ONE),
+            deadline: None,
+        })
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_execute_swap_route_applies_slippage_on_last_hop() {
+    let observed_calls = Rc::new(RefCell::new(Vec::<(ApplicationId, Vec<u8>)>::new()));
+    let mut swap = create_and_instantiate_swap_with_call_handler({
+        let observed_calls = observed_calls.clone();
+        move |_authenticated, application_id, operation| {
+            observed_calls
+                .borrow_mut()
+                .push((application_id, operation.clone()));
+            bcs::to_bytes(&MemeResponse::Ok).unwrap()
+        }
+    });
+    let (_, meme_2, _, pool_application_2) = create_native_pools_for_route_tests(&mut swap).await;
+    let origin = authenticated_account(&swap);
+    let creator_chain_id = swap.runtime.borrow_mut().application_creator_chain_id();
+    swap.runtime
+        .borrow_mut()
+        .set_message_origin_chain_id(creator_chain_id);
+
+    swap.execute_message(SwapMessage::ExecuteSwapRoute {
+        origin,
+        route: SwapRoute {
+            hops: vec![SwapRouteHop {
+                pool_application: pool_application_2,
+                token_0: meme_2,
+                token_1: None,
+                token_in: None,
+            }],
+            amount_out_min: Some(Amount::ONE),
+            deadline: None,
+        },
+        amount_in: Amount::ONE,
+    })
+    .await;
+
+    let calls = observed_calls.borrow();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].0, route_pool_application_id(pool_application_2));
+
+    let PoolOperation::Swap {
+        amount_0_in,
+        amount_1_in,
+        amount_0_out_min,
+        amount_1_out_min,
+        route,
+        ..
+    } = bcs::from_bytes::<PoolOperation>(&calls[0].1).unwrap()
+    else {
+        panic!("Expected Swap");
+    };
+    assert_eq!(amount_0_in, None);
+    assert_eq!(amount_1_in, Some(Amount::ONE));
+    assert_eq!(amount_0_out_min, Some(Amount::ONE));
+    assert_eq!(amount_1_out_min, None);
+    assert_eq!(route, None);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn operation_claim_all_forwards_listed_pools_to_creator_chain() {
+    let mut swap = create_and_instantiate_swap();
+    let (_meme_1, _meme_2, pool_application_1, pool_application_2) =
+        create_native_pools_for_route_tests(&mut swap).await;
+    let origin = authenticated_account(&swap);
+
+    swap.execute_operation(SwapOperation::ClaimAll {
+        pool_applications: vec![pool_application_1, pool_application_2],
+    })
+    .await;
+
+    let creator_chain_id = swap.runtime.borrow_mut().application_creator_chain_id();
+    let runtime = swap.runtime.borrow();
+    let requests = runtime.created_send_message_requests();
+    let request = requests.last().unwrap();
+    assert_eq!(request.destination, creator_chain_id);
+    assert!(matches!(
+        &request.message,
+        SwapMessage::ClaimAll {
+            origin: message_origin,
+            pool_applications,
+        } if *message_origin == origin
+            && *pool_applications == vec![pool_application_1, pool_application_2]
+    ));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn operation_claim_all_rejects_too_many_pools() {
+    let mut swap = create_and_instantiate_swap();
+    let (_meme_1, _meme_2, pool_application_1, _) =
+        create_native_pools_for_route_tests(&mut swap).await;
+
+    let result = std::panic::AssertUnwindSafe(swap.execute_operation(SwapOperation::ClaimAll {
+        pool_applications: vec![pool_application_1; MAX_CLAIM_ALL_POOLS + 1],
+    }))
+    .catch_unwind()
+    .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_claim_all_keeps_listed_pools_of_pool_chains() {
+    let mut swap = create_and_instantiate_swap();
+    let (_meme_1, _meme_2, pool_application_1, pool_application_2) =
+        create_native_pools_for_route_tests(&mut swap).await;
+    let origin = authenticated_account(&swap);
+    swap.state
+        .borrow_mut()
+        .create_pool_chain(pool_application_1.chain_id)
+        .unwrap();
+    let creator_chain_id = swap.runtime.borrow_mut().application_creator_chain_id();
+    swap.runtime.borrow_mut().set_chain_id(creator_chain_id);
+    let untracked_pool_application = Account {
+        chain_id: creator_chain_id,
+        owner: pool_application_2.owner,
+    };
 
-    swap.execute_message(SwapMessage::UserPoolCreated {
-        pool_application,
-        token_0,
-        token_1: Some(token_1),
-        amount_0: Amount::ONE,
-        amount_1: Amount::ONE,
-        to: None,
+    swap.execute_message(SwapMessage::ClaimAll {
+        origin,
+        pool_applications: vec![
+            pool_application_1,
+            pool_application_2,
+            pool_application_1,
+            untracked_pool_application,
+        ],
+    })
+    .await;
+
+    let runtime = swap.runtime.borrow();
+    let requests = runtime.created_send_message_requests();
+    let request = requests.last().unwrap();
+    assert_eq!(request.destination, origin.chain_id);
+    let SwapMessage::ExecuteClaimAll {
+        origin: message_origin,
+        pool_applications,
+    } = &request.message
+    else {
+        panic!("Expected ExecuteClaimAll");
+    };
+    assert_eq!(*message_origin, origin);
+    assert_eq!(
+        *pool_applications,
+        vec![pool_application_1, pool_application_2]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_execute_claim_all_calls_claim_all_on_each_pool() {
+    let observed_calls = Rc::new(RefCell::new(Vec::<(ApplicationId, Vec<u8>)>::new()));
+    let mut swap = create_and_instantiate_swap_with_call_handler({
+        let observed_calls = observed_calls.clone();
+        move |_authenticated, application_id, operation| {
+            observed_calls
+                .borrow_mut()
+                .push((application_id, operation.clone()));
+            bcs::to_bytes(&PoolResponse::Ok).unwrap()
+        }
+    });
+    let (_meme_1, _meme_2, pool_application_1, pool_application_2) =
+        create_native_pools_for_route_tests(&mut swap).await;
+    let origin = authenticated_account(&swap);
+
+    swap.execute_message(SwapMessage::ExecuteClaimAll {
+        origin,
+        pool_applications: vec![pool_application_1, pool_application_2],
     })
     .await;
 
     let calls = observed_calls.borrow();
-    assert_eq!(calls.len(), 1);
+    assert_eq!(calls.len(), 2);
+    assert_eq!(calls[0].0, route_pool_application_id(pool_application_1));
+    assert_eq!(calls[1].0, route_pool_application_id(pool_application_2));
+    for (_, operation) in calls.iter() {
+        assert!(matches!(
+            bcs::from_bytes::<PoolOperation>(operation).unwrap(),
+            PoolOperation::ClaimAll { tokens: None }
+        ));
+    }
+}
 
-    let AccountOwner::Address32(application_description_hash) = pool_application.owner else {
-        panic!("Invalid owner");
+#[tokio::test(flavor = "multi_thread")]
+async fn message_execute_swap_route_rejects_foreign_origin() {
+    let mut swap = create_and_instantiate_swap();
+    let (_, meme_2, _, pool_application_2) = create_native_pools_for_route_tests(&mut swap).await;
+    let mut origin = authenticated_account(&swap);
+    origin.owner = AccountOwner::from_str(
+        "0x02e900512d2fca22897f80a2f6932ff454f2752ef7afad18729dd25e5b5b6e01",
+    )
+    .unwrap();
+    let creator_chain_id = swap.runtime.borrow_mut().application_creator_chain_id();
+    swap.runtime
+        .borrow_mut()
+        .set_message_origin_chain_id(creator_chain_id);
+
+    let result =
+        std::panic::AssertUnwindSafe(swap.execute_message(SwapMessage::ExecuteSwapRoute {
+            origin,
+            route: SwapRoute {
+                hops: vec![SwapRouteHop {
+                    pool_application: pool_application_2,
+                    token_0: meme_2,
+                    token_1: None,
+                    token_in: None,
+                }],
+                amount_out_min: None,
+                deadline: None,
+            },
+            amount_in: Amount::ONE,
+        }))
+        .catch_unwind()
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_execute_swap_route_rejects_non_creator_chain() {
+    let mut swap = create_and_instantiate_swap();
+    let (_, meme_2, _, pool_application_2) = create_native_pools_for_route_tests(&mut swap).await;
+    let origin = authenticated_account(&swap);
+    swap.runtime
+        .borrow_mut()
+        .set_message_origin_chain_id(pool_application_2.chain_id);
+
+    let result =
+        std::panic::AssertUnwindSafe(swap.execute_message(SwapMessage::ExecuteSwapRoute {
+            origin,
+            route: SwapRoute {
+                hops: vec![SwapRouteHop {
+                    pool_application: pool_application_2,
+                    token_0: meme_2,
+                    token_1: None,
+                    token_in: None,
+                }],
+                amount_out_min: None,
+                deadline: None,
+            },
+            amount_in: Amount::ONE,
+        }))
+        .catch_unwind()
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn operation_continue_swap_forwards_calling_pool_to_creator_chain() {
+    let mut swap = create_and_instantiate_swap();
+    let (_, meme_2, _, pool_application_2) = create_native_pools_for_route_tests(&mut swap).await;
+    let origin = authenticated_account(&swap);
+    let route = SwapRoute {
+        hops: vec![SwapRouteHop {
+            pool_application: pool_application_2,
+            token_0: meme_2,
+            token_1: None,
+            token_in: None,
+        }],
+        amount_out_min: None,
+        deadline: None,
     };
-    let expected_application_id = ApplicationId::new(application_description_hash);
-    assert_eq!(calls[0].0, expected_application_id.forget_abi());
 
-    let operation = bcs::from_bytes::<PoolOperation>(&calls[0].1).unwrap();
-    let PoolOperation::AddLiquidity {
-        amount_0_in,
-        amount_1_in,
-        amount_0_out_min,
-        amount_1_out_min,
-        to,
-        block_timestamp,
-    } = operation
+    swap.execute_operation(SwapOperation::ContinueSwap {
+        origin,
+        route: route.clone(),
+        amount_in: Amount::ONE,
+    })
+    .await;
+
+    let creator_chain_id = swap.runtime.borrow_mut().application_creator_chain_id();
+    let caller_id = swap.runtime.borrow_mut().authenticated_caller_id().unwrap();
+    let runtime = swap.runtime.borrow();
+    let requests = runtime.created_send_message_requests();
+    let request = requests.last().unwrap();
+    assert_eq!(request.destination, creator_chain_id);
+    let SwapMessage::ContinueSwap {
+        pool_application,
+        route: message_route,
+        ..
+    } = &request.message
     else {
-        panic!("Expected AddLiquidity");
+        panic!("Expected ContinueSwap");
     };
-    assert_eq!(amount_0_in, Amount::ONE);
-    assert_eq!(amount_1_in, Amount::ONE);
-    assert_eq!(amount_0_out_min, None);
-    assert_eq!(amount_1_out_min, None);
-    assert_eq!(to, None);
-    assert_eq!(block_timestamp, None);
+    assert_eq!(pool_application.chain_id, origin.chain_id);
+    assert_eq!(pool_application.owner, AccountOwner::from(caller_id));
+    assert_eq!(*message_route, route);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_continue_swap_executes_catalogued_route() {
+    let mut swap = create_and_instantiate_swap();
+    let (_, meme_2, pool_application_1, pool_application_2) =
+        create_native_pools_for_route_tests(&mut swap).await;
+    let origin = authenticated_account(&swap);
+    swap.state
+        .borrow_mut()
+        .create_pool_chain(pool_application_1.chain_id)
+        .unwrap();
+    let creator_chain_id = swap.runtime.borrow_mut().application_creator_chain_id();
+    swap.runtime.borrow_mut().set_chain_id(creator_chain_id);
+    swap.runtime
+        .borrow_mut()
+        .set_message_origin_chain_id(pool_application_1.chain_id);
+    let route = SwapRoute {
+        hops: vec![SwapRouteHop {
+            pool_application: pool_application_2,
+            token_0: meme_2,
+            token_1: None,
+            token_in: None,
+        }],
+        amount_out_min: None,
+        deadline: None,
+    };
+
+    swap.execute_message(SwapMessage::ContinueSwap {
+        origin,
+        pool_application: pool_application_1,
+        route: route.clone(),
+        amount_in: Amount::ONE,
+    })
+    .await;
+
+    let runtime = swap.runtime.borrow();
+    let requests = runtime.created_send_message_requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].destination, origin.chain_id);
+    let SwapMessage::ExecuteSwapRoute {
+        route: message_route,
+        ..
+    } = &requests[0].message
+    else {
+        panic!("Expected ExecuteSwapRoute");
+    };
+    assert_eq!(*message_route, route);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_continue_swap_drops_uncatalogued_hop() {
+    let mut swap = create_and_instantiate_swap();
+    let (_, meme_2, pool_application_1, _) = create_native_pools_for_route_tests(&mut swap).await;
+    let origin = authenticated_account(&swap);
+    swap.state
+        .borrow_mut()
+        .create_pool_chain(pool_application_1.chain_id)
+        .unwrap();
+    let creator_chain_id = swap.runtime.borrow_mut().application_creator_chain_id();
+    swap.runtime.borrow_mut().set_chain_id(creator_chain_id);
+    swap.runtime
+        .borrow_mut()
+        .set_message_origin_chain_id(pool_application_1.chain_id);
+
+    // Pool of meme_1 is catalogued, but not for the pair of meme_2
+    swap.execute_message(SwapMessage::ContinueSwap {
+        origin,
+        pool_application: pool_application_1,
+        route: SwapRoute {
+            hops: vec![SwapRouteHop {
+                pool_application: pool_application_1,
+                token_0: meme_2,
+                token_1: None,
+                token_in: None,
+            }],
+            amount_out_min: None,
+            deadline: None,
+        },
+        amount_in: Amount::ONE,
+    })
+    .await;
+
+    assert!(swap
+        .runtime
+        .borrow()
+        .created_send_message_requests()
+        .is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn message_continue_swap_drops_untracked_pool_chain() {
+    let mut swap = create_and_instantiate_swap();
+    let (_, meme_2, pool_application_1, pool_application_2) =
+        create_native_pools_for_route_tests(&mut swap).await;
+    let origin = authenticated_account(&swap);
+    let creator_chain_id = swap.runtime.borrow_mut().application_creator_chain_id();
+    swap.runtime.borrow_mut().set_chain_id(creator_chain_id);
+    swap.runtime
+        .borrow_mut()
+        .set_message_origin_chain_id(pool_application_1.chain_id);
+
+    swap.execute_message(SwapMessage::ContinueSwap {
+        origin,
+        pool_application: pool_application_1,
+        route: SwapRoute {
+            hops: vec![SwapRouteHop {
+                pool_application: pool_application_2,
+                token_0: meme_2,
+                token_1: None,
+                token_in: None,
+            }],
+            amount_out_min: None,
+            deadline: None,
+        },
+        amount_in: Amount::ONE,
+    })
+    .await;
+
+    assert!(swap
+        .runtime
+        .borrow()
+        .created_send_message_requests()
+        .is_empty());
 }
 
 fn mock_application_call(
@@ -1350,6 +2767,7 @@ fn pool_transaction(transaction_id: u32, from: Account, created_at: u64) -> Tran
         amount_1_in: Some(Amount::ONE),
         amount_1_out: None,
         liquidity: Some(Amount::ONE),
+        fee_percent_mul_100: None,
         created_at: created_at.into(),
     }
 }
@@ -1382,13 +2800,122 @@ async fn create_pool_for_update_tests_with_application(
 
     swap.state
         .borrow_mut()
-        .create_pool(creator, token_0, Some(token_1), pool_application, 1.into())
+        .create_pool(
+            creator,
+            token_0,
+            Some(token_1),
+            pool_application,
+            DEFAULT_POOL_FEE_PERCENT_MUL_100,
+            1.into(),
+        )
         .await
         .unwrap();
 
     (token_0, Some(token_1), pool_application)
 }
 
+// Meme / native pool on its own pool chain, created with the instantiation bytecode
+async fn create_pool_for_migration_tests(
+    swap: &mut SwapContract,
+) -> (ApplicationId, Option<ApplicationId>, Account) {
+    let (token_0, _) = public_create_pool_test_tokens();
+    let creator = authenticated_account(swap);
+    let pool_application = Account {
+        chain_id: ChainId::from_str(
+            "bee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfea",
+        )
+        .unwrap(),
+        owner: AccountOwner::from(
+            ApplicationId::from_str(
+                "b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bb3",
+            )
+            .unwrap(),
+        ),
+    };
+
+    swap.state
+        .borrow_mut()
+        .create_pool_chain(pool_application.chain_id)
+        .unwrap();
+    swap.state
+        .borrow_mut()
+        .create_pool(
+            creator,
+            token_0,
+            None,
+            pool_application,
+            DEFAULT_POOL_FEE_PERCENT_MUL_100,
+            1.into(),
+        )
+        .await
+        .unwrap();
+
+    (token_0, None, pool_application)
+}
+
+fn upgraded_pool_bytecode_id() -> ModuleId {
+    ModuleId::from_str("c94e486abcfc016e937dad4297523060095f405530c95d498d981a94141589f167693295a14c3b48460ad6f75d67d2414428227550eb8cee8ecaa37e8646518300").unwrap()
+}
+
+async fn create_native_pools_for_route_tests(
+    swap: &mut SwapContract,
+) -> (ApplicationId, ApplicationId, Account, Account) {
+    let (token_0, token_1) = public_create_pool_test_tokens();
+    let creator = authenticated_account(swap);
+    let pool_application_0 = Account {
+        chain_id: creator.chain_id,
+        owner: AccountOwner::from(
+            ApplicationId::from_str(
+                "b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bb0",
+            )
+            .unwrap(),
+        ),
+    };
+    let pool_application_1 = Account {
+        chain_id: creator.chain_id,
+        owner: AccountOwner::from(
+            ApplicationId::from_str(
+                "b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bb1",
+            )
+            .unwrap(),
+        ),
+    };
+
+    swap.state
+        .borrow_mut()
+        .create_pool(
+            creator,
+            token_0,
+            None,
+            pool_application_0,
+            DEFAULT_POOL_FEE_PERCENT_MUL_100,
+            1.into(),
+        )
+        .await
+        .unwrap();
+    swap.state
+        .borrow_mut()
+        .create_pool(
+            creator,
+            token_1,
+            None,
+            pool_application_1,
+            DEFAULT_POOL_FEE_PERCENT_MUL_100,
+            1.into(),
+        )
+        .await
+        .unwrap();
+
+    (token_0, token_1, pool_application_0, pool_application_1)
+}
+
+fn route_pool_application_id(pool_application: Account) -> ApplicationId {
+    let AccountOwner::Address32(application_description_hash) = pool_application.owner else {
+        panic!("Invalid owner");
+    };
+    ApplicationId::new(application_description_hash)
+}
+
 fn create_and_instantiate_swap() -> SwapContract {
     create_and_instantiate_swap_with_call_handler(mock_application_call)
 }
@@ -1,5 +1,6 @@
 use abi::swap::{
-    router::{InstantiationArgument, Pool},
+    pool::{PoolStats, PoolStatus},
+    router::{InstantiationArgument, PendingMigration, Pool},
     transaction::Transaction,
 };
 use async_trait::async_trait;
@@ -15,6 +16,20 @@ pub trait StateInterface {
         argument: InstantiationArgument,
     );
 
+    async fn get_pools(
+        &self,
+        token_0: ApplicationId,
+        token_1: Option<ApplicationId>,
+    ) -> Result<Vec<Pool>, Self::Error>;
+
+    async fn get_pools_exchangable(
+        &self,
+        token_0: ApplicationId,
+        token_1: Option<ApplicationId>,
+    ) -> Result<Vec<Pool>, Self::Error>;
+
+    async fn get_all_pools(&self) -> Result<Vec<Pool>, Self::Error>;
+
     async fn get_pool(
         &self,
         token_0: ApplicationId,
@@ -29,12 +44,56 @@ pub trait StateInterface {
 
     fn pool_bytecode_id(&self) -> ModuleId;
 
+    fn set_pool_bytecode_id(&mut self, pool_bytecode_id: ModuleId);
+
+    // Pool chains created with a bytecode other than the current one
+    async fn is_outdated_pool_chain(&self, chain_id: ChainId) -> Result<bool, Self::Error>;
+
+    async fn is_migrated_pool_chain(&self, chain_id: ChainId) -> Result<bool, Self::Error>;
+
+    // Chain opened for a newer version of the pool on pool_chain_id, migrations wait for it
+    fn open_pool_upgrade(
+        &mut self,
+        pool_chain_id: ChainId,
+        chain_id: ChainId,
+    ) -> Result<(), Self::Error>;
+
+    async fn pending_pool_upgrade(
+        &self,
+        pool_chain_id: ChainId,
+    ) -> Result<Option<ChainId>, Self::Error>;
+
+    async fn queue_migration(
+        &mut self,
+        pool_chain_id: ChainId,
+        migration: PendingMigration,
+    ) -> Result<(), Self::Error>;
+
+    // Newer version of the pool on pool_chain_id, not catalogued until it is promoted. Returns
+    // the migrations which waited for it
+    async fn create_pool_upgrade(
+        &mut self,
+        pool_chain_id: ChainId,
+        pool_application: linera_sdk::linera_base_types::Account,
+    ) -> Result<Vec<PendingMigration>, Self::Error>;
+
+    async fn pool_upgrade(
+        &self,
+        pool_chain_id: ChainId,
+    ) -> Result<Option<linera_sdk::linera_base_types::Account>, Self::Error>;
+
+    async fn is_pool_upgrade_chain(&self, chain_id: ChainId) -> Result<bool, Self::Error>;
+
+    // Catalogued pool chain the pool version on chain_id replaces
+    async fn replaced_pool_chain(&self, chain_id: ChainId) -> Result<Option<ChainId>, Self::Error>;
+
     async fn create_pool(
         &mut self,
         creator: linera_sdk::linera_base_types::Account,
         token_0: ApplicationId,
         token_1: Option<ApplicationId>,
         pool_application: linera_sdk::linera_base_types::Account,
+        pool_fee_percent_mul_100: u16,
         timestamp: Timestamp,
     ) -> Result<(), Self::Error>;
 
@@ -44,6 +103,7 @@ pub trait StateInterface {
 
     async fn update_pool(
         &mut self,
+        pool_chain_id: ChainId,
         token_0: ApplicationId,
         token_1: Option<ApplicationId>,
         transaction: Transaction,
@@ -51,5 +111,23 @@ pub trait StateInterface {
         token_1_price: Amount,
         reserve_0: Amount,
         reserve_1: Amount,
+        stats: PoolStats,
+    ) -> Result<(), Self::Error>;
+
+    async fn update_pool_status(
+        &mut self,
+        pool_chain_id: ChainId,
+        token_0: ApplicationId,
+        token_1: Option<ApplicationId>,
+        status: PoolStatus,
+    ) -> Result<(), Self::Error>;
+
+    // Re-point the catalog entry of the pool on pool_chain_id to its waiting newer version
+    async fn migrate_pool(
+        &mut self,
+        pool_chain_id: ChainId,
+        token_0: ApplicationId,
+        token_1: Option<ApplicationId>,
+        pool_application: linera_sdk::linera_base_types::Account,
     ) -> Result<(), Self::Error>;
 }
@@ -1,10 +1,13 @@
 #![cfg_attr(target_arch = "wasm32", no_main)]
 
-use abi::swap::router::{Pool, SwapAbi, SwapOperation};
+use abi::swap::{
+    pool::PoolStatus,
+    router::{Pool, SwapAbi, SwapOperation},
+};
 use async_graphql::{EmptySubscription, Object, Request, Response, Schema};
 use linera_sdk::{
     linera_base_types::WithServiceAbi,
-    linera_base_types::{Account, Amount, ApplicationId, ChainId},
+    linera_base_types::{Account, Amount, ApplicationId, ChainId, ModuleId},
     views::View,
     Service, ServiceRuntime,
 };
@@ -62,25 +65,42 @@ impl QueryRoot {
         self.state.pool_id.get()
     }
 
-    async fn pools(&self) -> Vec<Pool> {
-        let mut pools: Vec<_> = self
+    // Newest first, without arguments all pools are returned. Next page starts after the last
+    // pool id which must be a catalogued pool
+    async fn pools(
+        &self,
+        first: Option<usize>,
+        after: Option<u64>,
+        token: Option<ApplicationId>,
+        creator: Option<Account>,
+        has_liquidity: Option<bool>,
+    ) -> async_graphql::Result<Vec<Pool>> {
+        Ok(self
             .state
-            .meme_native_pools
-            .index_values()
+            .query_pools(token, creator, has_liquidity, first, after)
+            .await?)
+    }
+
+    async fn pool(
+        &self,
+        token_0: ApplicationId,
+        token_1: Option<ApplicationId>,
+        pool_fee_percent_mul_100: Option<u16>,
+    ) -> Option<Pool> {
+        self.state
+            .lookup_pool(token_0, token_1, pool_fee_percent_mul_100)
             .await
-            .unwrap()
-            .into_iter()
-            .map(|(_, pool)| pool)
-            .collect();
-        for (_, _pools) in self.state.meme_meme_pools.index_values().await.unwrap() {
-            pools.extend_from_slice(&_pools.into_values().collect::<Vec<Pool>>());
-        }
-        pools
+            .expect("Failed: lookup pool")
     }
 
     async fn creator_chain_id(&self) -> ChainId {
         self.runtime.application_creator_chain_id()
     }
+
+    // Bytecode of pools created from now on
+    async fn pool_bytecode_id(&self) -> Option<ModuleId> {
+        *self.state.pool_bytecode_id.get()
+    }
 }
 
 struct MutationRoot {
@@ -96,6 +116,7 @@ impl MutationRoot {
         amount_0: Amount,
         amount_1: Amount,
         to: Option<Account>,
+        pool_fee_percent_mul_100: Option<u16>,
     ) -> [u8; 0] {
         assert!(
             self.runtime.application_creator_chain_id() != self.runtime.chain_id(),
@@ -108,24 +129,84 @@ impl MutationRoot {
             amount_0,
             amount_1,
             to,
+            pool_fee_percent_mul_100,
         });
         []
     }
+
+    async fn claim_all(&self, pool_applications: Vec<Account>) -> [u8; 0] {
+        assert!(
+            self.runtime.application_creator_chain_id() != self.runtime.chain_id(),
+            "Permission denied"
+        );
+
+        self.runtime
+            .schedule_operation(&SwapOperation::ClaimAll { pool_applications });
+        []
+    }
+
+    // Only operators of swap creator chain can pause pools
+    async fn set_pool_status(&self, pool_application: Account, status: PoolStatus) -> [u8; 0] {
+        assert!(
+            self.runtime.application_creator_chain_id() == self.runtime.chain_id(),
+            "Permission denied"
+        );
+
+        self.runtime
+            .schedule_operation(&SwapOperation::SetPoolStatus {
+                pool_application,
+                status,
+            });
+        []
+    }
+
+    // Only operators of swap creator chain can upgrade pool bytecode
+    async fn set_pool_bytecode_id(&self, pool_bytecode_id: ModuleId) -> [u8; 0] {
+        assert!(
+            self.runtime.application_creator_chain_id() == self.runtime.chain_id(),
+            "Permission denied"
+        );
+
+        self.runtime
+            .schedule_operation(&SwapOperation::SetPoolBytecodeId { pool_bytecode_id });
+        []
+    }
+
+    // Only operators of swap creator chain can re-point the catalog to a new pool version
+    async fn promote_pool_version(
+        &self,
+        token_0: ApplicationId,
+        token_1: Option<ApplicationId>,
+        pool_fee_percent_mul_100: u16,
+    ) -> [u8; 0] {
+        assert!(
+            self.runtime.application_creator_chain_id() == self.runtime.chain_id(),
+            "Permission denied"
+        );
+
+        self.runtime
+            .schedule_operation(&SwapOperation::PromotePoolVersion {
+                token_0,
+                token_1,
+                pool_fee_percent_mul_100,
+            });
+        []
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::SwapService;
-    use abi::swap::router::Pool;
+    use abi::swap::{pool::PoolStatus, router::Pool};
     use async_graphql::Request;
     use linera_sdk::{
-        linera_base_types::{Account, AccountOwner, ApplicationId, ChainId},
+        linera_base_types::{Account, AccountOwner, Amount, ApplicationId, ChainId},
         views::View,
         Service, ServiceRuntime,
     };
-    use serde_json::json;
+    use serde_json::{json, Value};
     use std::{collections::HashMap, str::FromStr, sync::Arc};
-    use swap::state::SwapState;
+    use swap::{interfaces::state::StateInterface, state::SwapState};
 
     #[test]
     fn query() {}
@@ -173,7 +254,7 @@ mod tests {
                 &token_0,
                 HashMap::from([(
                     token_1,
-                    Pool {
+                    vec![Pool {
                         creator,
                         pool_id: 1000,
                         token_0,
@@ -184,10 +265,23 @@ mod tests {
                         reserve_0: None,
                         reserve_1: None,
                         created_at: 1.into(),
-                    },
+                        pool_fee_percent_mul_100: 100,
+                        status: PoolStatus::Active,
+                        volume_0: Amount::ZERO,
+                        volume_1: Amount::ZERO,
+                        fees_0: Amount::ZERO,
+                        fees_1: Amount::ZERO,
+                        swap_count: 0,
+                        last_trade_at: None,
+                    }],
                 )]),
             )
             .unwrap();
+        state
+            .pool_meme_memes
+            .insert(&1000, vec![token_0, token_1])
+            .unwrap();
+        state.pool_id.set(1001);
 
         let service = SwapService {
             state: Arc::new(state),
@@ -195,7 +289,7 @@ mod tests {
         };
         let response = service
             .handle_query(Request::new(
-                "query { pools { poolId token0 token1 poolApplication token0Price token1Price reserve0 reserve1 } }",
+                "query { pools { poolId token0 token1 poolApplication token0Price token1Price reserve0 reserve1 poolFeePercentMul100 status } }",
             ))
             .await;
 
@@ -207,5 +301,134 @@ mod tests {
         assert_eq!(data["pools"][0]["reserve1"], json!(null));
         assert_eq!(data["pools"][0]["token0Price"], json!(null));
         assert_eq!(data["pools"][0]["token1Price"], json!(null));
+        assert_eq!(data["pools"][0]["poolFeePercentMul100"], json!(100));
+        assert_eq!(data["pools"][0]["status"], json!("ACTIVE"));
+    }
+
+    #[tokio::test]
+    async fn pools_query_pages_filters_and_looks_up_pairs() {
+        let runtime = Arc::new(ServiceRuntime::<SwapService>::new());
+        let mut state = SwapState::load(runtime.root_view_storage_context())
+            .await
+            .expect("Failed to load swap state");
+        let token_0 = ApplicationId::from_str(
+            "b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bad",
+        )
+        .unwrap();
+        let token_1 = ApplicationId::from_str(
+            "b10ac11c3569d9e1b6e22fe50f8c1de8b33a01173b4563c614aa07d8b8eb5bae",
+        )
+        .unwrap();
+        let creator = Account {
+            chain_id: ChainId::from_str(
+                "aee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfe8",
+            )
+            .unwrap(),
+            owner: AccountOwner::from_str(
+                "0x5279b3ae14d3b38e14b65a74aefe44824ea88b25c7841836e9ec77d991a5bc7f",
+            )
+            .unwrap(),
+        };
+        let pool_application = |chain_id: &str| Account {
+            chain_id: ChainId::from_str(chain_id).unwrap(),
+            owner: AccountOwner::from(token_0),
+        };
+
+        state.pool_id.set(1000);
+        // 1000: token_0/native, 1001: token_1/native, 1002: token_0/token_1
+        for (pool_token_0, pool_token_1, chain_id) in [
+            (
+                token_0,
+                None,
+                "bee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfe8",
+            ),
+            (
+                token_1,
+                None,
+                "cee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfe8",
+            ),
+            (
+                token_0,
+                Some(token_1),
+                "dee928d4bf3880353b4a3cd9b6f88e6cc6e5ed050860abae439e7782e9b2dfe8",
+            ),
+        ] {
+            state
+                .create_pool(
+                    creator,
+                    pool_token_0,
+                    pool_token_1,
+                    pool_application(chain_id),
+                    30,
+                    1.into(),
+                )
+                .await
+                .unwrap();
+        }
+        let mut pools = state.pair_pools(token_1, None).await.unwrap();
+        pools[0].reserve_0 = Some(Amount::ONE);
+        pools[0].reserve_1 = Some(Amount::from_tokens(10));
+        pools[0].token_0_price = Some(Amount::from_tokens(10));
+        state.meme_native_pools.insert(&token_1, pools).unwrap();
+
+        let service = SwapService {
+            state: Arc::new(state),
+            runtime,
+        };
+        let data = query_data(&service, "query { pools(first: 2) { poolId } }").await;
+        assert_eq!(pool_ids(&data), vec![1002, 1001]);
+        let data = query_data(
+            &service,
+            "query { pools(first: 2, after: 1001) { poolId } }",
+        )
+        .await;
+        assert_eq!(pool_ids(&data), vec![1000]);
+
+        let data = query_data(
+            &service,
+            &format!("query {{ pools(token: \"{}\") {{ poolId }} }}", token_1),
+        )
+        .await;
+        assert_eq!(pool_ids(&data), vec![1002, 1001]);
+
+        let data = query_data(&service, "query { pools(hasLiquidity: true) { poolId } }").await;
+        assert_eq!(pool_ids(&data), vec![1001]);
+        let response = service
+            .handle_query(Request::new("query { pools(after: 999) { poolId } }"))
+            .await;
+        assert!(!response.errors.is_empty());
+
+        let data = query_data(
+            &service,
+            &format!(
+                "query {{ pool(token0: \"{}\", token1: \"{}\") {{ poolId }} }}",
+                token_1, token_0
+            ),
+        )
+        .await;
+        assert_eq!(data["pool"]["poolId"], json!(1002));
+        let data = query_data(
+            &service,
+            &format!(
+            "query {{ pool(token0: \"{}\", token1: \"{}\", poolFeePercentMul100: 100) {{ poolId }} }}",
+            token_1, token_0
+        ))
+        .await;
+        assert_eq!(data["pool"], json!(null));
+    }
+
+    async fn query_data(service: &SwapService, query: &str) -> Value {
+        let response = service.handle_query(Request::new(query)).await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        response.data.into_json().unwrap()
+    }
+
+    fn pool_ids(data: &Value) -> Vec<u64> {
+        data["pools"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|pool| pool["poolId"].as_u64().unwrap())
+            .collect()
     }
 }
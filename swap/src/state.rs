@@ -1,6 +1,6 @@
-use abi::swap::router::Pool;
+use abi::swap::router::{PendingMigration, Pool};
 use linera_sdk::{
-    linera_base_types::{ApplicationId, ChainId, ModuleId},
+    linera_base_types::{Account, ApplicationId, ChainId, ModuleId},
     views::{linera_views, MapView, RegisterView, RootView, ViewStorageContext},
 };
 use std::collections::HashMap;
@@ -9,17 +9,34 @@ use std::collections::HashMap;
 #[derive(RootView)]
 #[view(context = ViewStorageContext)]
 pub struct SwapState {
-    pub meme_meme_pools: MapView<ApplicationId, HashMap<ApplicationId, Pool>>,
-    pub meme_native_pools: MapView<ApplicationId, Pool>,
+    // One pool for each fee tier of the pair
+    pub meme_meme_pools: MapView<ApplicationId, HashMap<ApplicationId, Vec<Pool>>>,
+    pub meme_native_pools: MapView<ApplicationId, Vec<Pool>>,
 
     pub pool_id: RegisterView<u64>,
     // Token pair in the two elementes vec
     pub pool_meme_memes: MapView<u64, Vec<ApplicationId>>,
     pub pool_meme_natives: MapView<u64, ApplicationId>,
+    // Catalog indexes of pool ids in creation order, a token indexes pools on either side
+    pub token_pool_ids: MapView<ApplicationId, Vec<u64>>,
+    pub creator_pool_ids: MapView<Account, Vec<u64>>,
 
     pub pool_bytecode_id: RegisterView<Option<ModuleId>>,
 
     pub pool_chains: MapView<ChainId, bool>,
+    // Bytecode each pool chain is created with
+    pub pool_chain_bytecode_ids: MapView<ChainId, ModuleId>,
+    // Newer pool versions waiting for router operators to re-point the catalog, keyed by the
+    // chain of the catalogued pool they replace
+    pub pool_upgrades: MapView<ChainId, Account>,
+    // Chain of each waiting pool version to the chain it replaces, from the chain opening on
+    pub pool_upgrade_chains: MapView<ChainId, ChainId>,
+    // Chains opened for a newer pool version which is not created yet, keyed like pool_upgrades
+    pub pending_pool_upgrades: MapView<ChainId, ChainId>,
+    // Migrations arriving while the new pool version is being created, keyed like pool_upgrades
+    pub pending_migrations: MapView<ChainId, Vec<PendingMigration>>,
+    // Pool chains whose catalog entry is re-pointed to a newer pool version
+    pub migrated_pool_chains: MapView<ChainId, Account>,
     // We cannot invoke meme application to get meme creator chain id due to reentrant error
     // So we have to record it
     pub token_creator_chain_ids: MapView<ApplicationId, ChainId>,
@@ -3,7 +3,8 @@ use std::{cell::RefCell, rc::Rc};
 use super::errors::StateError;
 use crate::{interfaces::state::StateInterface, state::SwapState};
 use abi::swap::{
-    router::{InstantiationArgument, Pool},
+    pool::{PoolStats, PoolStatus},
+    router::{InstantiationArgument, PendingMigration, Pool},
     transaction::Transaction,
 };
 use async_trait::async_trait;
@@ -28,6 +29,29 @@ impl StateInterface for StateAdapter {
         self.state.borrow_mut().instantiate(owner, argument)
     }
 
+    async fn get_pools(
+        &self,
+        token_0: ApplicationId,
+        token_1: Option<ApplicationId>,
+    ) -> Result<Vec<Pool>, Self::Error> {
+        self.state.borrow().get_pools(token_0, token_1).await
+    }
+
+    async fn get_pools_exchangable(
+        &self,
+        token_0: ApplicationId,
+        token_1: Option<ApplicationId>,
+    ) -> Result<Vec<Pool>, Self::Error> {
+        self.state
+            .borrow()
+            .get_pools_exchangable(token_0, token_1)
+            .await
+    }
+
+    async fn get_all_pools(&self) -> Result<Vec<Pool>, Self::Error> {
+        self.state.borrow().get_all_pools().await
+    }
+
     async fn get_pool(
         &self,
         token_0: ApplicationId,
@@ -51,17 +75,93 @@ impl StateInterface for StateAdapter {
         self.state.borrow().pool_bytecode_id()
     }
 
+    fn set_pool_bytecode_id(&mut self, pool_bytecode_id: ModuleId) {
+        self.state
+            .borrow_mut()
+            .set_pool_bytecode_id(pool_bytecode_id)
+    }
+
+    async fn is_outdated_pool_chain(&self, chain_id: ChainId) -> Result<bool, Self::Error> {
+        self.state.borrow().is_outdated_pool_chain(chain_id).await
+    }
+
+    async fn is_migrated_pool_chain(&self, chain_id: ChainId) -> Result<bool, Self::Error> {
+        self.state.borrow().is_migrated_pool_chain(chain_id).await
+    }
+
+    fn open_pool_upgrade(
+        &mut self,
+        pool_chain_id: ChainId,
+        chain_id: ChainId,
+    ) -> Result<(), Self::Error> {
+        self.state
+            .borrow_mut()
+            .open_pool_upgrade(pool_chain_id, chain_id)
+    }
+
+    async fn pending_pool_upgrade(
+        &self,
+        pool_chain_id: ChainId,
+    ) -> Result<Option<ChainId>, Self::Error> {
+        self.state
+            .borrow()
+            .pending_pool_upgrade(pool_chain_id)
+            .await
+    }
+
+    async fn queue_migration(
+        &mut self,
+        pool_chain_id: ChainId,
+        migration: PendingMigration,
+    ) -> Result<(), Self::Error> {
+        self.state
+            .borrow_mut()
+            .queue_migration(pool_chain_id, migration)
+            .await
+    }
+
+    async fn create_pool_upgrade(
+        &mut self,
+        pool_chain_id: ChainId,
+        pool_application: Account,
+    ) -> Result<Vec<PendingMigration>, Self::Error> {
+        self.state
+            .borrow_mut()
+            .create_pool_upgrade(pool_chain_id, pool_application)
+            .await
+    }
+
+    async fn pool_upgrade(&self, pool_chain_id: ChainId) -> Result<Option<Account>, Self::Error> {
+        self.state.borrow().pool_upgrade(pool_chain_id).await
+    }
+
+    async fn is_pool_upgrade_chain(&self, chain_id: ChainId) -> Result<bool, Self::Error> {
+        self.state.borrow().is_pool_upgrade_chain(chain_id).await
+    }
+
+    async fn replaced_pool_chain(&self, chain_id: ChainId) -> Result<Option<ChainId>, Self::Error> {
+        self.state.borrow().replaced_pool_chain(chain_id).await
+    }
+
     async fn create_pool(
         &mut self,
         creator: Account,
         token_0: ApplicationId,
         token_1: Option<ApplicationId>,
         pool_application: Account,
+        pool_fee_percent_mul_100: u16,
         timestamp: Timestamp,
     ) -> Result<(), Self::Error> {
         self.state
             .borrow_mut()
-            .create_pool(creator, token_0, token_1, pool_application, timestamp)
+            .create_pool(
+                creator,
+                token_0,
+                token_1,
+                pool_application,
+                pool_fee_percent_mul_100,
+                timestamp,
+            )
             .await
     }
 
@@ -81,6 +181,7 @@ impl StateInterface for StateAdapter {
 
     async fn update_pool(
         &mut self,
+        pool_chain_id: ChainId,
         token_0: ApplicationId,
         token_1: Option<ApplicationId>,
         transaction: Transaction,
@@ -88,10 +189,12 @@ impl StateInterface for StateAdapter {
         token_1_price: Amount,
         reserve_0: Amount,
         reserve_1: Amount,
+        stats: PoolStats,
     ) -> Result<(), Self::Error> {
         self.state
             .borrow_mut()
             .update_pool(
+                pool_chain_id,
                 token_0,
                 token_1,
                 transaction,
@@ -99,7 +202,34 @@ impl StateInterface for StateAdapter {
                 token_1_price,
                 reserve_0,
                 reserve_1,
+                stats,
             )
             .await
     }
+
+    async fn update_pool_status(
+        &mut self,
+        pool_chain_id: ChainId,
+        token_0: ApplicationId,
+        token_1: Option<ApplicationId>,
+        status: PoolStatus,
+    ) -> Result<(), Self::Error> {
+        self.state
+            .borrow_mut()
+            .update_pool_status(pool_chain_id, token_0, token_1, status)
+            .await
+    }
+
+    async fn migrate_pool(
+        &mut self,
+        pool_chain_id: ChainId,
+        token_0: ApplicationId,
+        token_1: Option<ApplicationId>,
+        pool_application: Account,
+    ) -> Result<(), Self::Error> {
+        self.state
+            .borrow_mut()
+            .migrate_pool(pool_chain_id, token_0, token_1, pool_application)
+            .await
+    }
 }
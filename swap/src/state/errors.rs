@@ -5,4 +5,7 @@ use thiserror::Error;
 pub enum StateError {
     #[error("View error")]
     ViewError(#[from] linera_sdk::views::ViewError),
+
+    #[error("Invalid pool cursor")]
+    InvalidPoolCursor,
 }
@@ -1,50 +1,81 @@
 use crate::interfaces::state::StateInterface;
 use crate::state::{errors::StateError, SwapState};
 use abi::swap::{
-    router::{InstantiationArgument, Pool},
+    pool::{PoolStats, PoolStatus},
+    router::{InstantiationArgument, PendingMigration, Pool, POOL_FEE_TIERS},
     transaction::Transaction,
 };
 use async_trait::async_trait;
 use linera_sdk::linera_base_types::{Account, Amount, ApplicationId, ChainId, ModuleId, Timestamp};
 
+const FIRST_POOL_ID: u64 = 1000;
+
 #[async_trait(?Send)]
 impl StateInterface for SwapState {
     type Error = StateError;
 
     fn instantiate(&mut self, _owner: Account, argument: InstantiationArgument) {
         self.pool_bytecode_id.set(Some(argument.pool_bytecode_id));
-        self.pool_id.set(1000);
+        self.pool_id.set(FIRST_POOL_ID);
     }
 
-    async fn get_pool(
+    async fn get_pools(
         &self,
         token_0: ApplicationId,
         token_1: Option<ApplicationId>,
-    ) -> Result<Option<Pool>, StateError> {
-        match token_1 {
-            Some(token_1) => Ok(self
-                .meme_meme_pools
-                .get(&token_0)
-                .await?
-                .and_then(|pools| pools.get(&token_1).cloned())),
-            _ => Ok(self.meme_native_pools.get(&token_0).await?),
-        }
+    ) -> Result<Vec<Pool>, StateError> {
+        self.pair_pools(token_0, token_1).await
     }
 
-    async fn get_pool_exchangable(
+    async fn get_pools_exchangable(
         &self,
         token_0: ApplicationId,
         token_1: Option<ApplicationId>,
-    ) -> Result<Option<Pool>, StateError> {
-        if let Some(pool) = self.get_pool(token_0, token_1).await? {
-            return Ok(Some(pool));
+    ) -> Result<Vec<Pool>, StateError> {
+        let mut pools = self.get_pools(token_0, token_1).await?;
+
+        // Different fee tiers of the same pair may be created in different token order
+        if let Some(token_1) = token_1 {
+            pools.extend(self.get_pools(token_1, Some(token_0)).await?);
         }
 
-        if token_1.is_none() {
-            return Ok(None);
+        Ok(pools)
+    }
+
+    async fn get_all_pools(&self) -> Result<Vec<Pool>, StateError> {
+        let mut pools: Vec<_> = self
+            .meme_native_pools
+            .index_values()
+            .await?
+            .into_iter()
+            .flat_map(|(_, pools)| pools)
+            .collect();
+        for (_, _pools) in self.meme_meme_pools.index_values().await? {
+            pools.extend(_pools.into_values().flatten());
         }
+        Ok(pools)
+    }
 
-        self.get_pool(token_1.unwrap(), Some(token_0)).await
+    async fn get_pool(
+        &self,
+        token_0: ApplicationId,
+        token_1: Option<ApplicationId>,
+    ) -> Result<Option<Pool>, StateError> {
+        Ok(preferred_pool(
+            self.get_pools(token_0, token_1).await?,
+            token_0,
+        ))
+    }
+
+    async fn get_pool_exchangable(
+        &self,
+        token_0: ApplicationId,
+        token_1: Option<ApplicationId>,
+    ) -> Result<Option<Pool>, StateError> {
+        Ok(preferred_pool(
+            self.get_pools_exchangable(token_0, token_1).await?,
+            token_0,
+        ))
     }
 
     fn pool_bytecode_id(&self) -> ModuleId {
@@ -53,23 +84,112 @@ impl StateInterface for SwapState {
             .expect("Not initialized pool_bytecode_id")
     }
 
+    fn set_pool_bytecode_id(&mut self, pool_bytecode_id: ModuleId) {
+        self.pool_bytecode_id.set(Some(pool_bytecode_id));
+    }
+
+    async fn is_outdated_pool_chain(&self, chain_id: ChainId) -> Result<bool, StateError> {
+        Ok(self
+            .pool_chain_bytecode_ids
+            .get(&chain_id)
+            .await?
+            .is_some_and(|pool_bytecode_id| pool_bytecode_id != self.pool_bytecode_id()))
+    }
+
+    async fn is_migrated_pool_chain(&self, chain_id: ChainId) -> Result<bool, StateError> {
+        Ok(self.migrated_pool_chains.contains_key(&chain_id).await?)
+    }
+
+    fn open_pool_upgrade(
+        &mut self,
+        pool_chain_id: ChainId,
+        chain_id: ChainId,
+    ) -> Result<(), StateError> {
+        self.pending_pool_upgrades
+            .insert(&pool_chain_id, chain_id)?;
+        self.pool_upgrade_chains.insert(&chain_id, pool_chain_id)?;
+        Ok(())
+    }
+
+    async fn pending_pool_upgrade(
+        &self,
+        pool_chain_id: ChainId,
+    ) -> Result<Option<ChainId>, StateError> {
+        Ok(self.pending_pool_upgrades.get(&pool_chain_id).await?)
+    }
+
+    async fn queue_migration(
+        &mut self,
+        pool_chain_id: ChainId,
+        migration: PendingMigration,
+    ) -> Result<(), StateError> {
+        let mut migrations = self
+            .pending_migrations
+            .get(&pool_chain_id)
+            .await?
+            .unwrap_or_default();
+        migrations.push(migration);
+        self.pending_migrations.insert(&pool_chain_id, migrations)?;
+        Ok(())
+    }
+
+    async fn create_pool_upgrade(
+        &mut self,
+        pool_chain_id: ChainId,
+        pool_application: Account,
+    ) -> Result<Vec<PendingMigration>, StateError> {
+        self.pool_upgrades
+            .insert(&pool_chain_id, pool_application)?;
+        self.pool_upgrade_chains
+            .insert(&pool_application.chain_id, pool_chain_id)?;
+        self.pending_pool_upgrades.remove(&pool_chain_id)?;
+
+        let migrations = self
+            .pending_migrations
+            .get(&pool_chain_id)
+            .await?
+            .unwrap_or_default();
+        self.pending_migrations.remove(&pool_chain_id)?;
+        Ok(migrations)
+    }
+
+    async fn pool_upgrade(&self, pool_chain_id: ChainId) -> Result<Option<Account>, StateError> {
+        Ok(self.pool_upgrades.get(&pool_chain_id).await?)
+    }
+
+    async fn is_pool_upgrade_chain(&self, chain_id: ChainId) -> Result<bool, StateError> {
+        Ok(self.pool_upgrade_chains.contains_key(&chain_id).await?)
+    }
+
+    async fn replaced_pool_chain(&self, chain_id: ChainId) -> Result<Option<ChainId>, StateError> {
+        Ok(self.pool_upgrade_chains.get(&chain_id).await?)
+    }
+
     async fn create_pool(
         &mut self,
         creator: Account,
         token_0: ApplicationId,
         token_1: Option<ApplicationId>,
         pool_application: Account,
+        pool_fee_percent_mul_100: u16,
         timestamp: Timestamp,
     ) -> Result<(), StateError> {
         assert!(
-            self.get_pool_exchangable(token_0, token_1).await?.is_none(),
+            POOL_FEE_TIERS.contains(&pool_fee_percent_mul_100),
+            "Invalid fee tier"
+        );
+        assert!(
+            self.get_pools_exchangable(token_0, token_1)
+                .await?
+                .iter()
+                .all(|pool| pool.pool_fee_percent_mul_100 != pool_fee_percent_mul_100),
             "Pool exists"
         );
 
-        let pool_id = self.pool_id.get();
+        let pool_id = *self.pool_id.get();
         let pool = Pool {
             creator,
-            pool_id: *pool_id,
+            pool_id,
             token_0,
             token_1,
             pool_application,
@@ -78,30 +198,38 @@ impl StateInterface for SwapState {
             reserve_0: None,
             reserve_1: None,
             created_at: timestamp,
+            pool_fee_percent_mul_100,
+            status: PoolStatus::Active,
+            volume_0: Amount::ZERO,
+            volume_1: Amount::ZERO,
+            fees_0: Amount::ZERO,
+            fees_1: Amount::ZERO,
+            swap_count: 0,
+            last_trade_at: None,
         };
 
-        if let Some(token_1) = token_1 {
-            let mut pools = self
-                .meme_meme_pools
-                .get(&token_0)
-                .await?
-                .unwrap_or_default();
+        let mut pools = self.get_pools(token_0, token_1).await?;
+        pools.push(pool);
+        self.insert_pools(token_0, token_1, pools).await?;
 
-            pools.insert(token_1, pool);
-            self.meme_meme_pools.insert(&token_0, pools)?;
+        if let Some(token_1) = token_1 {
             self.pool_meme_memes
                 .insert(&pool_id, vec![token_0, token_1])?;
         } else {
-            self.meme_native_pools.insert(&token_0, pool)?;
             self.pool_meme_natives.insert(&pool_id, token_0)?;
         }
 
+        self.index_pool(pool_id, token_0, token_1, creator).await?;
+
         self.pool_id.set(pool_id + 1);
         Ok(())
     }
 
     fn create_pool_chain(&mut self, chain_id: ChainId) -> Result<(), StateError> {
         self.pool_chains.insert(&chain_id, true)?;
+        // Pool chain is always created right before the pool application with current bytecode
+        self.pool_chain_bytecode_ids
+            .insert(&chain_id, self.pool_bytecode_id())?;
         Ok(())
     }
 
@@ -111,6 +239,7 @@ impl StateInterface for SwapState {
 
     async fn update_pool(
         &mut self,
+        pool_chain_id: ChainId,
         token_0: ApplicationId,
         token_1: Option<ApplicationId>,
         _transaction: Transaction,
@@ -118,27 +247,261 @@ impl StateInterface for SwapState {
         token_1_price: Amount,
         reserve_0: Amount,
         reserve_1: Amount,
+        stats: PoolStats,
     ) -> Result<(), StateError> {
-        let Some(mut pool) = self.get_pool_exchangable(token_0, token_1).await? else {
+        let mut pools = self.get_pools(token_0, token_1).await?;
+        let Some(pool) = pools
+            .iter_mut()
+            .find(|pool| pool.pool_application.chain_id == pool_chain_id)
+        else {
             panic!("Invalid pool");
         };
         pool.token_0_price = Some(token_0_price);
         pool.token_1_price = Some(token_1_price);
         pool.reserve_0 = Some(reserve_0);
         pool.reserve_1 = Some(reserve_1);
+        pool.volume_0 = stats.volume_0;
+        pool.volume_1 = stats.volume_1;
+        pool.fees_0 = stats.fees_0;
+        pool.fees_1 = stats.fees_1;
+        pool.swap_count = stats.swap_count;
+        pool.last_trade_at = stats.last_trade_at;
+
+        self.insert_pools(token_0, token_1, pools).await
+    }
+
+    async fn update_pool_status(
+        &mut self,
+        pool_chain_id: ChainId,
+        token_0: ApplicationId,
+        token_1: Option<ApplicationId>,
+        status: PoolStatus,
+    ) -> Result<(), StateError> {
+        let mut pools = self.get_pools(token_0, token_1).await?;
+        let Some(pool) = pools
+            .iter_mut()
+            .find(|pool| pool.pool_application.chain_id == pool_chain_id)
+        else {
+            panic!("Invalid pool");
+        };
+        pool.status = status;
+
+        self.insert_pools(token_0, token_1, pools).await
+    }
+
+    async fn migrate_pool(
+        &mut self,
+        pool_chain_id: ChainId,
+        token_0: ApplicationId,
+        token_1: Option<ApplicationId>,
+        pool_application: Account,
+    ) -> Result<(), StateError> {
+        let mut pools = self.get_pools(token_0, token_1).await?;
+        let Some(pool) = pools
+            .iter_mut()
+            .find(|pool| pool.pool_application.chain_id == pool_chain_id)
+        else {
+            panic!("Invalid pool");
+        };
+        // Pool id and creator stay with the pair, market facts come from the new pool
+        pool.pool_application = pool_application;
+        pool.token_0_price = None;
+        pool.token_1_price = None;
+        pool.reserve_0 = None;
+        pool.reserve_1 = None;
+        pool.status = PoolStatus::Active;
+        pool.volume_0 = Amount::ZERO;
+        pool.volume_1 = Amount::ZERO;
+        pool.fees_0 = Amount::ZERO;
+        pool.fees_1 = Amount::ZERO;
+        pool.swap_count = 0;
+        pool.last_trade_at = None;
+
+        self.insert_pools(token_0, token_1, pools).await?;
+        self.migrated_pool_chains
+            .insert(&pool_chain_id, pool_application)?;
+        self.pool_upgrades.remove(&pool_chain_id)?;
+        self.pool_upgrade_chains
+            .remove(&pool_application.chain_id)?;
+        Ok(())
+    }
+}
 
+// Catalog reads are shared with the service, so they stay out of the ?Send state interface
+impl SwapState {
+    pub async fn pair_pools(
+        &self,
+        token_0: ApplicationId,
+        token_1: Option<ApplicationId>,
+    ) -> Result<Vec<Pool>, StateError> {
+        match token_1 {
+            Some(token_1) => Ok(self
+                .meme_meme_pools
+                .get(&token_0)
+                .await?
+                .and_then(|pools| pools.get(&token_1).cloned())
+                .unwrap_or_default()),
+            _ => Ok(self
+                .meme_native_pools
+                .get(&token_0)
+                .await?
+                .unwrap_or_default()),
+        }
+    }
+
+    pub async fn pool_by_id(&self, pool_id: u64) -> Result<Option<Pool>, StateError> {
+        let (token_0, token_1) = if let Some(tokens) = self.pool_meme_memes.get(&pool_id).await? {
+            (tokens[0], Some(tokens[1]))
+        } else if let Some(token_0) = self.pool_meme_natives.get(&pool_id).await? {
+            (token_0, None)
+        } else {
+            return Ok(None);
+        };
+        Ok(self
+            .pair_pools(token_0, token_1)
+            .await?
+            .into_iter()
+            .find(|pool| pool.pool_id == pool_id))
+    }
+
+    // Meme meme pairs are found in either token order, without fee tier the preferred pool of
+    // the pair is returned
+    pub async fn lookup_pool(
+        &self,
+        token_0: ApplicationId,
+        token_1: Option<ApplicationId>,
+        pool_fee_percent_mul_100: Option<u16>,
+    ) -> Result<Option<Pool>, StateError> {
+        let mut pools = self.pair_pools(token_0, token_1).await?;
         if let Some(token_1) = token_1 {
-            let mut pools = self
+            pools.extend(self.pair_pools(token_1, Some(token_0)).await?);
+        }
+        match pool_fee_percent_mul_100 {
+            Some(pool_fee_percent_mul_100) => Ok(pools
+                .into_iter()
+                .find(|pool| pool.pool_fee_percent_mul_100 == pool_fee_percent_mul_100)),
+            None => Ok(preferred_pool(pools, token_0)),
+        }
+    }
+
+    // Newest pools created before the cursor pool, walking the pool id indexes lazily
+    pub async fn query_pools(
+        &self,
+        token: Option<ApplicationId>,
+        creator: Option<Account>,
+        has_liquidity: Option<bool>,
+        first: Option<usize>,
+        after: Option<u64>,
+    ) -> Result<Vec<Pool>, StateError> {
+        if let Some(after) = after {
+            if self.pool_by_id(after).await?.is_none() {
+                return Err(StateError::InvalidPoolCursor);
+            }
+        }
+
+        let mut pool_ids = match (token, creator) {
+            (Some(token), _) => self.token_pool_ids.get(&token).await?.unwrap_or_default(),
+            (None, Some(creator)) => self
+                .creator_pool_ids
+                .get(&creator)
+                .await?
+                .unwrap_or_default(),
+            (None, None) => (FIRST_POOL_ID..*self.pool_id.get()).collect(),
+        };
+        pool_ids.reverse();
+
+        let first = first.unwrap_or(usize::MAX);
+        let matches = |pool: &Pool| {
+            creator.is_none_or(|creator| pool.creator == creator)
+                && has_liquidity
+                    .is_none_or(|has_liquidity| pool_has_liquidity(pool) == has_liquidity)
+        };
+
+        let mut pools = Vec::new();
+        for pool_id in pool_ids {
+            if pools.len() >= first {
+                break;
+            }
+            if after.is_some_and(|after| pool_id >= after) {
+                continue;
+            }
+            if let Some(pool) = self.pool_by_id(pool_id).await? {
+                if matches(&pool) {
+                    pools.push(pool);
+                }
+            }
+        }
+        Ok(pools)
+    }
+
+    // Pool ids only grow, so indexes stay in creation order
+    async fn index_pool(
+        &mut self,
+        pool_id: u64,
+        token_0: ApplicationId,
+        token_1: Option<ApplicationId>,
+        creator: Account,
+    ) -> Result<(), StateError> {
+        for token in [Some(token_0), token_1].into_iter().flatten() {
+            let mut pool_ids = self.token_pool_ids.get(&token).await?.unwrap_or_default();
+            pool_ids.push(pool_id);
+            self.token_pool_ids.insert(&token, pool_ids)?;
+        }
+        let mut pool_ids = self
+            .creator_pool_ids
+            .get(&creator)
+            .await?
+            .unwrap_or_default();
+        pool_ids.push(pool_id);
+        self.creator_pool_ids.insert(&creator, pool_ids)?;
+        Ok(())
+    }
+
+    async fn insert_pools(
+        &mut self,
+        token_0: ApplicationId,
+        token_1: Option<ApplicationId>,
+        pools: Vec<Pool>,
+    ) -> Result<(), StateError> {
+        if let Some(token_1) = token_1 {
+            let mut pair_pools = self
                 .meme_meme_pools
                 .get(&token_0)
                 .await?
                 .unwrap_or_default();
 
-            pools.insert(token_1, pool);
-            self.meme_meme_pools.insert(&token_0, pools)?;
+            pair_pools.insert(token_1, pools);
+            self.meme_meme_pools.insert(&token_0, pair_pools)?;
         } else {
-            self.meme_native_pools.insert(&token_0, pool)?;
+            self.meme_native_pools.insert(&token_0, pools)?;
         }
         Ok(())
     }
 }
+
+fn pool_has_liquidity(pool: &Pool) -> bool {
+    pool.reserve_0.is_some_and(|reserve| reserve > Amount::ZERO)
+        && pool.reserve_1.is_some_and(|reserve| reserve > Amount::ZERO)
+}
+
+// Swaps go through the deepest pool of the pair, or the earliest one before reserves are known.
+// Pools accepting swaps always win over paused ones.
+fn preferred_pool(pools: Vec<Pool>, token_0: ApplicationId) -> Option<Pool> {
+    let rank = |pool: &Pool| {
+        let reserve = if pool.token_0 == token_0 {
+            pool.reserve_0
+        } else {
+            pool.reserve_1
+        }
+        .unwrap_or(Amount::ZERO);
+        (pool.status.swaps_enabled(), reserve)
+    };
+
+    pools.into_iter().reduce(|preferred, pool| {
+        if rank(&pool) > rank(&preferred) {
+            pool
+        } else {
+            preferred
+        }
+    })
+}
@@ -158,6 +158,7 @@ impl TestSuite {
                     virtual_initial_liquidity: true,
                     swap_creator_chain_id: swap_chain.id(),
                     enable_mining,
+                    launch_protection: None,
                     mining_supply: if enable_mining {
                         Some(Amount::from_tokens(10000000))
                     } else {
@@ -481,6 +482,9 @@ impl TestSuite {
                         amount_1_out_min: None,
                         to: None,
                         block_timestamp: None,
+                        route: None,
+                        deadline: None,
+                        auto_claim: false,
                     },
                 );
             })
@@ -219,6 +219,7 @@ impl TestSuite {
             swap_creator_chain_id: self.swap_chain.id(),
 
             enable_mining: false,
+            launch_protection: None,
             mining_supply: None,
         };
 
@@ -244,6 +245,7 @@ impl TestSuite {
             swap_creator_chain_id: self.swap_chain.id(),
 
             enable_mining: false,
+            launch_protection: None,
             mining_supply: None,
         };
         self.meme_application_id_1 = Some(
@@ -277,6 +279,9 @@ impl TestSuite {
                         amount_1_out_min: None,
                         to: None,
                         block_timestamp: None,
+                        route: None,
+                        deadline: None,
+                        auto_claim: false,
                     },
                 );
             })
@@ -336,6 +341,7 @@ impl TestSuite {
                         amount_1_out_min: None,
                         to: None,
                         block_timestamp: None,
+                        deadline: None,
                     },
                 );
             })
@@ -385,6 +391,7 @@ impl TestSuite {
                         amount_0,
                         amount_1,
                         to: None,
+                        pool_fee_percent_mul_100: None,
                     },
                 );
             })
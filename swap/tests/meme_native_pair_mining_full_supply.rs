@@ -206,6 +206,7 @@ impl TestSuite {
             swap_creator_chain_id: self.swap_chain.id(),
 
             enable_mining: true,
+            launch_protection: None,
             mining_supply: None,
         };
 
@@ -199,6 +199,7 @@ impl TestSuite {
             swap_creator_chain_id: self.swap_chain.id(),
 
             enable_mining: true,
+            launch_protection: None,
             mining_supply: Some(10000000.into()),
         };
 
@@ -150,6 +150,7 @@ impl TestSuite {
             swap_creator_chain_id: self.swap_chain.id(),
 
             enable_mining: false,
+            launch_protection: None,
             mining_supply: None,
         };
 
@@ -182,6 +183,7 @@ impl TestSuite {
                         amount_0,
                         amount_1,
                         to: None,
+                        pool_fee_percent_mul_100: None,
                     },
                 );
             })
@@ -37,6 +37,13 @@ export interface InstantiationArgument {
   amsApplicationId?: string
 }
 
+export interface LaunchProtection {
+  duration_minutes: number
+  max_trade_percent_mul_100?: number
+  cooldown_secs?: number
+  extra_fee_percent_mul_100?: number
+}
+
 export interface MemeParameters {
   creator: Account
   initialLiquidity?: Liquidity
@@ -44,6 +51,7 @@ export interface MemeParameters {
   swapCreatorChainId: string
   enableMining: boolean
   miningSupply?: string
+  launchProtection?: LaunchProtection
 }
 
 export interface BalanceOfRequest extends BaseRequest {
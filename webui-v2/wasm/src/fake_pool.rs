@@ -1,4 +1,4 @@
-use abi::swap::pool::PoolOperation;
+use abi::swap::pool::{DynamicFee, PoolOperation};
 use async_graphql::{Error, Object};
 use linera_base::{
     data_types::{Amount, Timestamp},
@@ -27,6 +27,8 @@ impl MutationRoot {
         amount_1_out_min: Option<Amount>,
         to: Option<Account>,
         block_timestamp: Option<Timestamp>,
+        deadline: Option<Timestamp>,
+        auto_claim: Option<bool>,
     ) -> Result<Vec<u8>, Error> {
         Ok(bcs::to_bytes(&PoolOperation::Swap {
             amount_0_in,
@@ -35,6 +37,9 @@ impl MutationRoot {
             amount_1_out_min,
             to,
             block_timestamp,
+            route: None,
+            deadline,
+            auto_claim: auto_claim.unwrap_or_default(),
         })?)
     }
 
@@ -46,6 +51,7 @@ impl MutationRoot {
         amount_1_out_min: Option<Amount>,
         to: Option<Account>,
         block_timestamp: Option<Timestamp>,
+        deadline: Option<Timestamp>,
     ) -> Result<Vec<u8>, Error> {
         Ok(bcs::to_bytes(&PoolOperation::AddLiquidity {
             amount_0_in,
@@ -54,6 +60,7 @@ impl MutationRoot {
             amount_1_out_min,
             to,
             block_timestamp,
+            deadline,
         })?)
     }
 
@@ -64,6 +71,7 @@ impl MutationRoot {
         amount_1_out_min: Option<Amount>,
         to: Option<Account>,
         block_timestamp: Option<Timestamp>,
+        deadline: Option<Timestamp>,
     ) -> Result<Vec<u8>, Error> {
         Ok(bcs::to_bytes(&PoolOperation::RemoveLiquidity {
             liquidity,
@@ -71,10 +79,137 @@ impl MutationRoot {
             amount_1_out_min,
             to,
             block_timestamp,
+            deadline,
         })?)
     }
 
     async fn claim(&self, token: Option<ApplicationId>, amount: Amount) -> Result<Vec<u8>, Error> {
         Ok(bcs::to_bytes(&PoolOperation::Claim { token, amount })?)
     }
+
+    async fn claim_all(
+        &self,
+        tokens: Option<Vec<Option<ApplicationId>>>,
+    ) -> Result<Vec<u8>, Error> {
+        Ok(bcs::to_bytes(&PoolOperation::ClaimAll { tokens })?)
+    }
+
+    async fn transfer_liquidity(&self, to: Account, liquidity: Amount) -> Result<Vec<u8>, Error> {
+        Ok(bcs::to_bytes(&PoolOperation::TransferLiquidity {
+            to,
+            liquidity,
+        })?)
+    }
+
+    async fn approve_liquidity(
+        &self,
+        spender: Account,
+        liquidity: Amount,
+    ) -> Result<Vec<u8>, Error> {
+        Ok(bcs::to_bytes(&PoolOperation::ApproveLiquidity {
+            spender,
+            liquidity,
+        })?)
+    }
+
+    async fn transfer_liquidity_from(
+        &self,
+        from: Account,
+        to: Account,
+        liquidity: Amount,
+    ) -> Result<Vec<u8>, Error> {
+        Ok(bcs::to_bytes(&PoolOperation::TransferLiquidityFrom {
+            from,
+            to,
+            liquidity,
+        })?)
+    }
+
+    async fn place_limit_order(
+        &self,
+        amount_0_in: Option<Amount>,
+        amount_1_in: Option<Amount>,
+        amount_out_min: Amount,
+        to: Option<Account>,
+    ) -> Result<Vec<u8>, Error> {
+        Ok(bcs::to_bytes(&PoolOperation::PlaceLimitOrder {
+            amount_0_in,
+            amount_1_in,
+            amount_out_min,
+            to,
+        })?)
+    }
+
+    async fn cancel_limit_order(&self, order_id: u64) -> Result<Vec<u8>, Error> {
+        Ok(bcs::to_bytes(&PoolOperation::CancelLimitOrder {
+            order_id,
+        })?)
+    }
+
+    async fn zap_in(
+        &self,
+        token: Option<ApplicationId>,
+        amount_in: Amount,
+        min_liquidity: Option<Amount>,
+        to: Option<Account>,
+        block_timestamp: Option<Timestamp>,
+        deadline: Option<Timestamp>,
+    ) -> Result<Vec<u8>, Error> {
+        Ok(bcs::to_bytes(&PoolOperation::ZapIn {
+            token,
+            amount_in,
+            min_liquidity,
+            to,
+            block_timestamp,
+            deadline,
+        })?)
+    }
+
+    async fn zap_out(
+        &self,
+        liquidity: Amount,
+        token: Option<ApplicationId>,
+        amount_out_min: Option<Amount>,
+        to: Option<Account>,
+        block_timestamp: Option<Timestamp>,
+        deadline: Option<Timestamp>,
+    ) -> Result<Vec<u8>, Error> {
+        Ok(bcs::to_bytes(&PoolOperation::ZapOut {
+            liquidity,
+            token,
+            amount_out_min,
+            to,
+            block_timestamp,
+            deadline,
+        })?)
+    }
+
+    async fn sync(&self) -> Result<Vec<u8>, Error> {
+        Ok(bcs::to_bytes(&PoolOperation::Sync)?)
+    }
+
+    async fn skim(&self, to: Account) -> Result<Vec<u8>, Error> {
+        Ok(bcs::to_bytes(&PoolOperation::Skim { to })?)
+    }
+
+    async fn set_dynamic_fee(&self, dynamic_fee: Option<DynamicFee>) -> Result<Vec<u8>, Error> {
+        Ok(bcs::to_bytes(&PoolOperation::SetDynamicFee {
+            dynamic_fee,
+        })?)
+    }
+
+    async fn migrate_liquidity(
+        &self,
+        liquidity: Amount,
+        amount_0_out_min: Option<Amount>,
+        amount_1_out_min: Option<Amount>,
+        deadline: Option<Timestamp>,
+    ) -> Result<Vec<u8>, Error> {
+        Ok(bcs::to_bytes(&PoolOperation::MigrateLiquidity {
+            liquidity,
+            amount_0_out_min,
+            amount_1_out_min,
+            deadline,
+        })?)
+    }
 }
@@ -27,6 +27,8 @@ impl MutationRoot {
         amount_1_out_min: Option<Amount>,
         to: Option<Account>,
         block_timestamp: Option<Timestamp>,
+        deadline: Option<Timestamp>,
+        auto_claim: Option<bool>,
     ) -> Result<Vec<u8>, Error> {
         Ok(bcs::to_bytes(&PoolOperation::Swap {
             amount_0_in,
@@ -35,6 +37,9 @@ impl MutationRoot {
             amount_1_out_min,
             to,
             block_timestamp,
+            route: None,
+            deadline,
+            auto_claim: auto_claim.unwrap_or_default(),
         })?)
     }
 
@@ -46,6 +51,7 @@ impl MutationRoot {
         amount_1_out_min: Option<Amount>,
         to: Option<Account>,
         block_timestamp: Option<Timestamp>,
+        deadline: Option<Timestamp>,
     ) -> Result<Vec<u8>, Error> {
         Ok(bcs::to_bytes(&PoolOperation::AddLiquidity {
             amount_0_in,
@@ -54,6 +60,7 @@ impl MutationRoot {
             amount_1_out_min,
             to,
             block_timestamp,
+            deadline,
         })?)
     }
 
@@ -64,6 +71,7 @@ impl MutationRoot {
         amount_1_out_min: Option<Amount>,
         to: Option<Account>,
         block_timestamp: Option<Timestamp>,
+        deadline: Option<Timestamp>,
     ) -> Result<Vec<u8>, Error> {
         Ok(bcs::to_bytes(&PoolOperation::RemoveLiquidity {
             liquidity,
@@ -71,6 +79,7 @@ impl MutationRoot {
             amount_1_out_min,
             to,
             block_timestamp,
+            deadline,
         })?)
     }
 }